@@ -2,9 +2,23 @@
 
 use std::{fmt, io, mem::size_of};
 
+use anyhow::Context;
 use byteorder::{ByteOrder, NativeEndian};
 
-use crate::{DecodeError, Emitable, Field, Parseable, Rest};
+use crate::{
+    constants::*,
+    utils::{
+        nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+        parsers::{parse_string, parse_u32},
+    },
+    DecodeError,
+    Emitable,
+    Field,
+    Parseable,
+    ParseableParametrized,
+    Rest,
+    NETLINK_HEADER_LEN,
+};
 
 const CODE: Field = 0..4;
 const PAYLOAD: Rest = 4..;
@@ -79,23 +93,56 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> ErrorBuffer<T> {
 pub struct ErrorMessage {
     pub code: i32,
     pub header: Vec<u8>,
+    /// Extended ACK attributes. The kernel only sends them when `NETLINK_EXT_ACK` is enabled on
+    /// the socket, in which case the `NLM_F_ACK_TLVS` flag is set on the message header.
+    pub nlas: Vec<ExtAckNla>,
 }
 
 pub type AckMessage = ErrorMessage;
 
 impl Emitable for ErrorMessage {
     fn buffer_len(&self) -> usize {
-        size_of::<i32>() + self.header.len()
+        if self.nlas.is_empty() {
+            size_of::<i32>() + self.header.len()
+        } else {
+            size_of::<i32>() + align(self.header.len()) + self.nlas.as_slice().buffer_len()
+        }
     }
     fn emit(&self, buffer: &mut [u8]) {
         let mut buffer = ErrorBuffer::new(buffer);
         buffer.set_code(self.code);
-        buffer.payload_mut().copy_from_slice(&self.header)
+        let payload = buffer.payload_mut();
+        if self.nlas.is_empty() {
+            payload.copy_from_slice(&self.header)
+        } else {
+            let nlas_start = align(self.header.len());
+            payload[..self.header.len()].copy_from_slice(&self.header);
+            for byte in &mut payload[self.header.len()..nlas_start] {
+                *byte = 0;
+            }
+            self.nlas.as_slice().emit(&mut payload[nlas_start..]);
+        }
     }
 }
 
-impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<ErrorBuffer<&'buffer T>> for ErrorMessage {
+impl<'buffer, T: AsRef<[u8]> + ?Sized + 'buffer> Parseable<ErrorBuffer<&'buffer T>>
+    for ErrorMessage
+{
     fn parse(buf: &ErrorBuffer<&'buffer T>) -> Result<ErrorMessage, DecodeError> {
+        ErrorMessage::parse_with_param(buf, 0)
+    }
+}
+
+/// Parse an error message, given the flags of the netlink header that carries it. The flags are
+/// needed to know whether the original message was echoed back entirely or only its header
+/// (`NLM_F_CAPPED`), and whether extended ACK attributes follow (`NLM_F_ACK_TLVS`).
+impl<'buffer, T: AsRef<[u8]> + ?Sized + 'buffer> ParseableParametrized<ErrorBuffer<&'buffer T>, u16>
+    for ErrorMessage
+{
+    fn parse_with_param(
+        buf: &ErrorBuffer<&'buffer T>,
+        flags: u16,
+    ) -> Result<ErrorMessage, DecodeError> {
         // FIXME: The payload of an error is basically a truncated packet, which requires custom
         // logic to parse correctly. For now we just return it as a Vec<u8>
         // let header: NetlinkHeader = {
@@ -104,9 +151,42 @@ impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<ErrorBuffer<&'buffer T>> for E
         //         .parse()
         //         .context("failed to parse nelink header")?
         // };
+        let payload = buf.payload();
+        if flags & NLM_F_ACK_TLVS == 0 {
+            return Ok(ErrorMessage {
+                code: buf.code(),
+                header: payload.to_vec(),
+                nlas: vec![],
+            });
+        }
+
+        let header_len = if flags & NLM_F_CAPPED != 0 {
+            NETLINK_HEADER_LEN
+        } else if payload.len() >= 4 {
+            NativeEndian::read_u32(&payload[..4]) as usize
+        } else {
+            payload.len()
+        };
+        if header_len > payload.len() {
+            return Err(format!(
+                "invalid ErrorBuffer: echoed message is {} bytes but the payload is only {} bytes",
+                header_len,
+                payload.len()
+            )
+            .into());
+        }
+
+        let nlas_start = align(header_len).min(payload.len());
+        let mut nlas = vec![];
+        for nla in NlasIterator::new(&payload[nlas_start..]) {
+            let nla = &nla.context("invalid extended ACK attribute")?;
+            nlas.push(ExtAckNla::parse(nla)?);
+        }
+
         Ok(ErrorMessage {
             code: buf.code(),
-            header: buf.payload().to_vec(),
+            header: payload[..header_len].to_vec(),
+            nlas,
         })
     }
 }
@@ -120,11 +200,33 @@ impl ErrorMessage {
     pub fn to_io(&self) -> io::Error {
         io::Error::from_raw_os_error(self.code.abs())
     }
+
+    /// Return the human readable error message sent by the kernel in the extended ACK
+    /// (`NLMSGERR_ATTR_MSG`), if any.
+    pub fn message(&self) -> Option<&str> {
+        self.nlas.iter().find_map(|nla| match nla {
+            ExtAckNla::Message(msg) => Some(msg.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Return the offset of the invalid attribute in the original message (`NLMSGERR_ATTR_OFFS`),
+    /// if any.
+    pub fn offset(&self) -> Option<u32> {
+        self.nlas.iter().find_map(|nla| match nla {
+            ExtAckNla::Offset(offset) => Some(*offset),
+            _ => None,
+        })
+    }
 }
 
 impl fmt::Display for ErrorMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.to_io(), f)
+        fmt::Display::fmt(&self.to_io(), f)?;
+        if let Some(msg) = self.message() {
+            write!(f, ": {}", msg)?;
+        }
+        Ok(())
     }
 }
 
@@ -134,6 +236,97 @@ impl From<ErrorMessage> for io::Error {
     }
 }
 
+/// Extended ACK attribute, as found after the echoed message in an `NLMSG_ERROR` message
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ExtAckNla {
+    /// Human readable error message
+    Message(String),
+    /// Offset of the invalid attribute in the original message
+    Offset(u32),
+    /// Subsystem specific cookie
+    Cookie(Vec<u8>),
+    /// Policy of the rejected attribute. This is a nested list of `NL_POLICY_TYPE_ATTR_*`
+    /// attributes, which `netlink-packet-generic` knows how to parse.
+    Policy(Vec<u8>),
+    /// Type of a missing required attribute
+    MissingType(u32),
+    /// Offset of the nest where an attribute was missing
+    MissingNest(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for ExtAckNla {
+    fn value_len(&self) -> usize {
+        use self::ExtAckNla::*;
+        match *self {
+            Message(ref s) => s.len() + 1,
+            Offset(_) | MissingType(_) | MissingNest(_) => 4,
+            Cookie(ref bytes) | Policy(ref bytes) => bytes.len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::ExtAckNla::*;
+        match *self {
+            Message(ref s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Offset(value) | MissingType(value) | MissingNest(value) => {
+                NativeEndian::write_u32(buffer, value)
+            }
+            Cookie(ref bytes) | Policy(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::ExtAckNla::*;
+        match *self {
+            Message(_) => NLMSGERR_ATTR_MSG,
+            Offset(_) => NLMSGERR_ATTR_OFFS,
+            Cookie(_) => NLMSGERR_ATTR_COOKIE,
+            Policy(_) => NLMSGERR_ATTR_POLICY,
+            MissingType(_) => NLMSGERR_ATTR_MISS_TYPE,
+            MissingNest(_) => NLMSGERR_ATTR_MISS_NEST,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, ExtAckNla::Policy(_))
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for ExtAckNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::ExtAckNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NLMSGERR_ATTR_MSG => {
+                Message(parse_string(payload).context("invalid NLMSGERR_ATTR_MSG value")?)
+            }
+            NLMSGERR_ATTR_OFFS => {
+                Offset(parse_u32(payload).context("invalid NLMSGERR_ATTR_OFFS value")?)
+            }
+            NLMSGERR_ATTR_COOKIE => Cookie(payload.to_vec()),
+            NLMSGERR_ATTR_POLICY => Policy(payload.to_vec()),
+            NLMSGERR_ATTR_MISS_TYPE => {
+                MissingType(parse_u32(payload).context("invalid NLMSGERR_ATTR_MISS_TYPE value")?)
+            }
+            NLMSGERR_ATTR_MISS_NEST => {
+                MissingNest(parse_u32(payload).context("invalid NLMSGERR_ATTR_MISS_NEST value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid extended ACK attribute")?),
+        })
+    }
+}
+
+fn align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,6 +337,7 @@ mod tests {
         let err_msg = ErrorMessage {
             code: -95,
             header: vec![],
+            nlas: vec![],
         };
 
         let to_io: io::Error = err_msg.to_io();
@@ -151,4 +345,65 @@ mod tests {
         assert_eq!(err_msg.to_string(), io_err.to_string());
         assert_eq!(to_io.raw_os_error(), io_err.raw_os_error());
     }
+
+    // NLMSG_ERROR payload received when trying to create a link of an unknown kind with
+    // NETLINK_EXT_ACK enabled: the request header is echoed back (NLM_F_CAPPED is set) and
+    // followed by the error message and the offset of the invalid attribute.
+    #[rustfmt::skip]
+    static EXT_ACK_PAYLOAD: [u8; 52] = [
+        0xa1, 0xff, 0xff, 0xff, // error code = -95 (EOPNOTSUPP)
+        // echoed header
+        0x38, 0x00, 0x00, 0x00, // length = 56
+        0x10, 0x00, // message type = 16 (RTM_NEWLINK)
+        0x05, 0x06, // flags
+        0x01, 0x00, 0x00, 0x00, // sequence number = 1
+        0x00, 0x00, 0x00, 0x00, // port number = 0
+        // NLMSGERR_ATTR_MSG
+        0x18, 0x00, 0x01, 0x00,
+        0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x20, // "Unknown "
+        0x64, 0x65, 0x76, 0x69, 0x63, 0x65, 0x20, 0x74, // "device t"
+        0x79, 0x70, 0x65, 0x00, // "ype\0"
+        // NLMSGERR_ATTR_OFFS
+        0x08, 0x00, 0x02, 0x00,
+        0x28, 0x00, 0x00, 0x00, // offset = 40
+    ];
+
+    #[test]
+    fn parse_ext_ack() {
+        let buf = ErrorBuffer::new_checked(&EXT_ACK_PAYLOAD[..]).unwrap();
+        let msg = ErrorMessage::parse_with_param(&buf, NLM_F_CAPPED | NLM_F_ACK_TLVS).unwrap();
+        assert_eq!(msg.code, -95);
+        assert_eq!(msg.header, EXT_ACK_PAYLOAD[4..20].to_vec());
+        assert_eq!(
+            msg.nlas,
+            vec![
+                ExtAckNla::Message("Unknown device type".into()),
+                ExtAckNla::Offset(40),
+            ]
+        );
+        assert_eq!(msg.message(), Some("Unknown device type"));
+        assert_eq!(msg.offset(), Some(40));
+        assert_eq!(
+            msg.to_string(),
+            format!("{}: Unknown device type", io::Error::from_raw_os_error(95))
+        );
+    }
+
+    #[test]
+    fn parse_without_ext_ack_flag() {
+        let buf = ErrorBuffer::new_checked(&EXT_ACK_PAYLOAD[..]).unwrap();
+        let msg = ErrorMessage::parse(&buf).unwrap();
+        assert_eq!(msg.header, EXT_ACK_PAYLOAD[4..].to_vec());
+        assert!(msg.nlas.is_empty());
+    }
+
+    #[test]
+    fn emit_ext_ack() {
+        let buf = ErrorBuffer::new_checked(&EXT_ACK_PAYLOAD[..]).unwrap();
+        let msg = ErrorMessage::parse_with_param(&buf, NLM_F_CAPPED | NLM_F_ACK_TLVS).unwrap();
+        assert_eq!(msg.buffer_len(), EXT_ACK_PAYLOAD.len());
+        let mut emitted = vec![0; msg.buffer_len()];
+        msg.emit(&mut emitted);
+        assert_eq!(emitted, EXT_ACK_PAYLOAD.to_vec());
+    }
 }
@@ -16,6 +16,7 @@ use crate::{
     NetlinkPayload,
     NetlinkSerializable,
     Parseable,
+    ParseableParametrized,
 };
 
 /// Represent a netlink message.
@@ -101,7 +102,8 @@ where
             NLMSG_ERROR => {
                 let buf =
                     ErrorBuffer::new_checked(&bytes).context("failed to parse NLMSG_ERROR")?;
-                let msg = ErrorMessage::parse(&buf).context("failed to parse NLMSG_ERROR")?;
+                let msg = ErrorMessage::parse_with_param(&buf, header.flags)
+                    .context("failed to parse NLMSG_ERROR")?;
                 if msg.code >= 0 {
                     Ack(msg as AckMessage)
                 } else {
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+
+use futures::StreamExt;
+use genetlink::new_connection;
+use netlink_packet_core::{NetlinkMessage, NetlinkPayload, NLM_F_DUMP, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+use netlink_packet_macsec::{
+    nlas::{MacsecAttrs, MacsecRxscAttrs, MacsecSaAttrs, MacsecSecyAttrs},
+    Macsec,
+    MacsecCmd,
+};
+
+#[tokio::main]
+async fn main() {
+    let (connection, mut handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let genlmsg: GenlMessage<Macsec> = GenlMessage::from_payload(Macsec {
+        cmd: MacsecCmd::GetTxsc,
+        nlas: vec![],
+    });
+    let mut nlmsg = NetlinkMessage::from(genlmsg);
+    nlmsg.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+    let mut res = handle.request(nlmsg).await.unwrap();
+
+    while let Some(result) = res.next().await {
+        let rx_packet = result.unwrap();
+        match rx_packet.payload {
+            NetlinkPayload::InnerMessage(genlmsg) => {
+                print_macsec_payload(genlmsg.payload);
+            }
+            NetlinkPayload::Error(e) => {
+                eprintln!("Error: {:?}", e.to_io());
+            }
+            _ => (),
+        };
+    }
+}
+
+fn print_macsec_payload(macsec: Macsec) {
+    for nla in &macsec.nlas {
+        match nla {
+            MacsecAttrs::IfIndex(v) => println!("IfIndex: {}", v),
+            MacsecAttrs::Secy(nlas) => {
+                for nla in nlas {
+                    match nla {
+                        MacsecSecyAttrs::Sci(v) => println!("  TXSC: {:016x}", v),
+                        MacsecSecyAttrs::CipherSuite(v) => println!("  CipherSuite: {:016x}", v),
+                        MacsecSecyAttrs::EncodingSa(v) => println!("  EncodingSa: {}", v),
+                        _ => (),
+                    }
+                }
+            }
+            MacsecAttrs::TxsaList(list) => {
+                for sa in list {
+                    print_macsec_sa("  ", sa);
+                }
+            }
+            MacsecAttrs::RxscList(list) => {
+                for rxsc in list {
+                    for nla in rxsc {
+                        match nla {
+                            MacsecRxscAttrs::Sci(v) => println!("  RXSC: {:016x}", v),
+                            MacsecRxscAttrs::Active(v) => println!("    Active: {}", v),
+                            MacsecRxscAttrs::SaList(list) => {
+                                for sa in list {
+                                    print_macsec_sa("    ", sa);
+                                }
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn print_macsec_sa(indent: &str, nlas: &[MacsecSaAttrs]) {
+    for nla in nlas {
+        match nla {
+            MacsecSaAttrs::An(v) => println!("{}SA: {}", indent, v),
+            MacsecSaAttrs::Active(v) => println!("{}  Active: {}", indent, v),
+            MacsecSaAttrs::Pn(v) => println!("{}  Pn: {}", indent, v),
+            MacsecSaAttrs::XPn(v) => println!("{}  Pn: {}", indent, v),
+            MacsecSaAttrs::KeyId(v) => println!("{}  KeyId: {:02x?}", indent, v),
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+
+pub const MACSEC_GENL_NAME: &str = "macsec";
+pub const MACSEC_GENL_VERSION: u8 = 1;
+
+pub const MACSEC_MAX_KEY_LEN: usize = 128;
+pub const MACSEC_KEYID_LEN: usize = 16;
+pub const MACSEC_SALT_LEN: usize = 12;
+
+pub const MACSEC_CIPHER_ID_GCM_AES_128: u64 = 0x0080_C200_0100_0001;
+pub const MACSEC_CIPHER_ID_GCM_AES_256: u64 = 0x0080_C200_0100_0002;
+pub const MACSEC_CIPHER_ID_GCM_AES_XPN_128: u64 = 0x0080_C200_0100_0003;
+pub const MACSEC_CIPHER_ID_GCM_AES_XPN_256: u64 = 0x0080_C200_0100_0004;
+
+pub const MACSEC_CMD_GET_TXSC: u8 = 0;
+pub const MACSEC_CMD_ADD_RXSC: u8 = 1;
+pub const MACSEC_CMD_DEL_RXSC: u8 = 2;
+pub const MACSEC_CMD_UPD_RXSC: u8 = 3;
+pub const MACSEC_CMD_ADD_TXSA: u8 = 4;
+pub const MACSEC_CMD_DEL_TXSA: u8 = 5;
+pub const MACSEC_CMD_UPD_TXSA: u8 = 6;
+pub const MACSEC_CMD_ADD_RXSA: u8 = 7;
+pub const MACSEC_CMD_DEL_RXSA: u8 = 8;
+pub const MACSEC_CMD_UPD_RXSA: u8 = 9;
+pub const MACSEC_CMD_UPD_OFFLOAD: u8 = 10;
+
+pub const MACSEC_ATTR_UNSPEC: u16 = 0;
+pub const MACSEC_ATTR_IFINDEX: u16 = 1;
+pub const MACSEC_ATTR_RXSC_CONFIG: u16 = 2;
+pub const MACSEC_ATTR_SA_CONFIG: u16 = 3;
+pub const MACSEC_ATTR_SECY: u16 = 4;
+pub const MACSEC_ATTR_TXSA_LIST: u16 = 5;
+pub const MACSEC_ATTR_RXSC_LIST: u16 = 6;
+pub const MACSEC_ATTR_TXSC_STATS: u16 = 7;
+pub const MACSEC_ATTR_SECY_STATS: u16 = 8;
+pub const MACSEC_ATTR_OFFLOAD: u16 = 9;
+
+pub const MACSEC_SECY_ATTR_UNSPEC: u16 = 0;
+pub const MACSEC_SECY_ATTR_SCI: u16 = 1;
+pub const MACSEC_SECY_ATTR_ENCODING_SA: u16 = 2;
+pub const MACSEC_SECY_ATTR_WINDOW: u16 = 3;
+pub const MACSEC_SECY_ATTR_CIPHER_SUITE: u16 = 4;
+pub const MACSEC_SECY_ATTR_ICV_LEN: u16 = 5;
+pub const MACSEC_SECY_ATTR_PROTECT: u16 = 6;
+pub const MACSEC_SECY_ATTR_REPLAY: u16 = 7;
+pub const MACSEC_SECY_ATTR_OPER: u16 = 8;
+pub const MACSEC_SECY_ATTR_VALIDATE: u16 = 9;
+pub const MACSEC_SECY_ATTR_ENCRYPT: u16 = 10;
+pub const MACSEC_SECY_ATTR_INC_SCI: u16 = 11;
+pub const MACSEC_SECY_ATTR_ES: u16 = 12;
+pub const MACSEC_SECY_ATTR_SCB: u16 = 13;
+pub const MACSEC_SECY_ATTR_PAD: u16 = 14;
+
+pub const MACSEC_RXSC_ATTR_UNSPEC: u16 = 0;
+pub const MACSEC_RXSC_ATTR_SCI: u16 = 1;
+pub const MACSEC_RXSC_ATTR_ACTIVE: u16 = 2;
+pub const MACSEC_RXSC_ATTR_SA_LIST: u16 = 3;
+pub const MACSEC_RXSC_ATTR_STATS: u16 = 4;
+pub const MACSEC_RXSC_ATTR_PAD: u16 = 5;
+
+pub const MACSEC_SA_ATTR_UNSPEC: u16 = 0;
+pub const MACSEC_SA_ATTR_AN: u16 = 1;
+pub const MACSEC_SA_ATTR_ACTIVE: u16 = 2;
+pub const MACSEC_SA_ATTR_PN: u16 = 3;
+pub const MACSEC_SA_ATTR_KEY: u16 = 4;
+pub const MACSEC_SA_ATTR_KEYID: u16 = 5;
+pub const MACSEC_SA_ATTR_STATS: u16 = 6;
+pub const MACSEC_SA_ATTR_PAD: u16 = 7;
+pub const MACSEC_SA_ATTR_SSCI: u16 = 8;
+pub const MACSEC_SA_ATTR_SALT: u16 = 9;
+
+pub const MACSEC_OFFLOAD_ATTR_UNSPEC: u16 = 0;
+pub const MACSEC_OFFLOAD_ATTR_TYPE: u16 = 1;
+pub const MACSEC_OFFLOAD_ATTR_PAD: u16 = 2;
+
+pub const MACSEC_OFFLOAD_OFF: u8 = 0;
+pub const MACSEC_OFFLOAD_PHY: u8 = 1;
+pub const MACSEC_OFFLOAD_MAC: u8 = 2;
+
+pub const MACSEC_VALIDATE_DISABLED: u8 = 0;
+pub const MACSEC_VALIDATE_CHECK: u8 = 1;
+pub const MACSEC_VALIDATE_STRICT: u8 = 2;
+
+pub const MACSEC_RXSC_STATS_ATTR_UNSPEC: u16 = 0;
+pub const MACSEC_RXSC_STATS_ATTR_IN_OCTETS_VALIDATED: u16 = 1;
+pub const MACSEC_RXSC_STATS_ATTR_IN_OCTETS_DECRYPTED: u16 = 2;
+pub const MACSEC_RXSC_STATS_ATTR_IN_PKTS_UNCHECKED: u16 = 3;
+pub const MACSEC_RXSC_STATS_ATTR_IN_PKTS_DELAYED: u16 = 4;
+pub const MACSEC_RXSC_STATS_ATTR_IN_PKTS_OK: u16 = 5;
+pub const MACSEC_RXSC_STATS_ATTR_IN_PKTS_INVALID: u16 = 6;
+pub const MACSEC_RXSC_STATS_ATTR_IN_PKTS_LATE: u16 = 7;
+pub const MACSEC_RXSC_STATS_ATTR_IN_PKTS_NOT_VALID: u16 = 8;
+pub const MACSEC_RXSC_STATS_ATTR_IN_PKTS_NOT_USING_SA: u16 = 9;
+pub const MACSEC_RXSC_STATS_ATTR_IN_PKTS_UNUSED_SA: u16 = 10;
+pub const MACSEC_RXSC_STATS_ATTR_PAD: u16 = 11;
+
+pub const MACSEC_SA_STATS_ATTR_UNSPEC: u16 = 0;
+pub const MACSEC_SA_STATS_ATTR_IN_PKTS_OK: u16 = 1;
+pub const MACSEC_SA_STATS_ATTR_IN_PKTS_INVALID: u16 = 2;
+pub const MACSEC_SA_STATS_ATTR_IN_PKTS_NOT_VALID: u16 = 3;
+pub const MACSEC_SA_STATS_ATTR_IN_PKTS_NOT_USING_SA: u16 = 4;
+pub const MACSEC_SA_STATS_ATTR_IN_PKTS_UNUSED_SA: u16 = 5;
+pub const MACSEC_SA_STATS_ATTR_OUT_PKTS_PROTECTED: u16 = 6;
+pub const MACSEC_SA_STATS_ATTR_OUT_PKTS_ENCRYPTED: u16 = 7;
+pub const MACSEC_SA_STATS_ATTR_PAD: u16 = 8;
+
+pub const MACSEC_TXSC_STATS_ATTR_UNSPEC: u16 = 0;
+pub const MACSEC_TXSC_STATS_ATTR_OUT_PKTS_PROTECTED: u16 = 1;
+pub const MACSEC_TXSC_STATS_ATTR_OUT_PKTS_ENCRYPTED: u16 = 2;
+pub const MACSEC_TXSC_STATS_ATTR_OUT_OCTETS_PROTECTED: u16 = 3;
+pub const MACSEC_TXSC_STATS_ATTR_OUT_OCTETS_ENCRYPTED: u16 = 4;
+pub const MACSEC_TXSC_STATS_ATTR_PAD: u16 = 5;
+
+pub const MACSEC_SECY_STATS_ATTR_UNSPEC: u16 = 0;
+pub const MACSEC_SECY_STATS_ATTR_OUT_PKTS_UNTAGGED: u16 = 1;
+pub const MACSEC_SECY_STATS_ATTR_IN_PKTS_UNTAGGED: u16 = 2;
+pub const MACSEC_SECY_STATS_ATTR_OUT_PKTS_TOO_LONG: u16 = 3;
+pub const MACSEC_SECY_STATS_ATTR_IN_PKTS_NO_TAG: u16 = 4;
+pub const MACSEC_SECY_STATS_ATTR_IN_PKTS_BAD_TAG: u16 = 5;
+pub const MACSEC_SECY_STATS_ATTR_IN_PKTS_UNKNOWN_SCI: u16 = 6;
+pub const MACSEC_SECY_STATS_ATTR_IN_PKTS_NO_SCI: u16 = 7;
+pub const MACSEC_SECY_STATS_ATTR_IN_PKTS_OVERRUN: u16 = 8;
+pub const MACSEC_SECY_STATS_ATTR_PAD: u16 = 9;
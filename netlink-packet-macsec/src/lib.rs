@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: MIT
+
+use crate::constants::*;
+use anyhow::Context;
+use netlink_packet_generic::{GenlFamily, GenlHeader};
+use netlink_packet_utils::{nla::NlasIterator, traits::*, DecodeError};
+use nlas::MacsecAttrs;
+use std::convert::{TryFrom, TryInto};
+
+pub mod constants;
+pub mod nlas;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MacsecCmd {
+    /// Dump the MACsec devices, with their secure channels and associations
+    GetTxsc,
+    AddRxsc,
+    DelRxsc,
+    UpdRxsc,
+    AddTxsa,
+    DelTxsa,
+    UpdTxsa,
+    AddRxsa,
+    DelRxsa,
+    UpdRxsa,
+    UpdOffload,
+}
+
+impl From<MacsecCmd> for u8 {
+    fn from(cmd: MacsecCmd) -> Self {
+        use MacsecCmd::*;
+        match cmd {
+            GetTxsc => MACSEC_CMD_GET_TXSC,
+            AddRxsc => MACSEC_CMD_ADD_RXSC,
+            DelRxsc => MACSEC_CMD_DEL_RXSC,
+            UpdRxsc => MACSEC_CMD_UPD_RXSC,
+            AddTxsa => MACSEC_CMD_ADD_TXSA,
+            DelTxsa => MACSEC_CMD_DEL_TXSA,
+            UpdTxsa => MACSEC_CMD_UPD_TXSA,
+            AddRxsa => MACSEC_CMD_ADD_RXSA,
+            DelRxsa => MACSEC_CMD_DEL_RXSA,
+            UpdRxsa => MACSEC_CMD_UPD_RXSA,
+            UpdOffload => MACSEC_CMD_UPD_OFFLOAD,
+        }
+    }
+}
+
+impl TryFrom<u8> for MacsecCmd {
+    type Error = DecodeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        use MacsecCmd::*;
+        Ok(match value {
+            MACSEC_CMD_GET_TXSC => GetTxsc,
+            MACSEC_CMD_ADD_RXSC => AddRxsc,
+            MACSEC_CMD_DEL_RXSC => DelRxsc,
+            MACSEC_CMD_UPD_RXSC => UpdRxsc,
+            MACSEC_CMD_ADD_TXSA => AddTxsa,
+            MACSEC_CMD_DEL_TXSA => DelTxsa,
+            MACSEC_CMD_UPD_TXSA => UpdTxsa,
+            MACSEC_CMD_ADD_RXSA => AddRxsa,
+            MACSEC_CMD_DEL_RXSA => DelRxsa,
+            MACSEC_CMD_UPD_RXSA => UpdRxsa,
+            MACSEC_CMD_UPD_OFFLOAD => UpdOffload,
+            cmd => {
+                return Err(DecodeError::from(format!(
+                    "Unknown macsec command: {}",
+                    cmd
+                )))
+            }
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Macsec {
+    pub cmd: MacsecCmd,
+    pub nlas: Vec<MacsecAttrs>,
+}
+
+impl GenlFamily for Macsec {
+    fn family_name() -> &'static str {
+        MACSEC_GENL_NAME
+    }
+
+    fn version(&self) -> u8 {
+        MACSEC_GENL_VERSION
+    }
+
+    fn command(&self) -> u8 {
+        self.cmd.into()
+    }
+}
+
+impl Emitable for Macsec {
+    fn emit(&self, buffer: &mut [u8]) {
+        self.nlas.as_slice().emit(buffer)
+    }
+
+    fn buffer_len(&self) -> usize {
+        self.nlas.as_slice().buffer_len()
+    }
+}
+
+impl ParseableParametrized<[u8], GenlHeader> for Macsec {
+    fn parse_with_param(buf: &[u8], header: GenlHeader) -> Result<Self, DecodeError> {
+        Ok(Self {
+            cmd: header.cmd.try_into()?,
+            nlas: parse_nlas(buf)?,
+        })
+    }
+}
+
+fn parse_nlas(buf: &[u8]) -> Result<Vec<MacsecAttrs>, DecodeError> {
+    let mut nlas = Vec::new();
+    let error_msg = "failed to parse message attributes";
+    for nla in NlasIterator::new(buf) {
+        let nla = &nla.context(error_msg)?;
+        let parsed = MacsecAttrs::parse(nla).context(error_msg)?;
+        nlas.push(parsed);
+    }
+    Ok(nlas)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nlas::{MacsecRxscAttrs, MacsecSaAttrs, MacsecSaStatsAttrs, MacsecSecyAttrs};
+
+    #[rustfmt::skip]
+    static ADD_RXSA: [u8; 92] = [
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = MACSEC_ATTR_IFINDEX
+        0x07, 0x00, 0x00, 0x00, // 7
+        0x10, 0x00, 0x02, 0x00, // length = 16, type = MACSEC_ATTR_RXSC_CONFIG
+        0x0c, 0x00, 0x01, 0x00, // length = 12, type = MACSEC_RXSC_ATTR_SCI
+        0x52, 0x54, 0x00, 0x12, // 52:54:00:12:34:56, port 1
+        0x34, 0x56, 0x00, 0x01,
+        0x44, 0x00, 0x03, 0x00, // length = 68, type = MACSEC_ATTR_SA_CONFIG
+        0x05, 0x00, 0x01, 0x00, // length = 5, type = MACSEC_SA_ATTR_AN
+        0x02, 0x00, 0x00, 0x00, // 2 + padding
+        0x08, 0x00, 0x03, 0x00, // length = 8, type = MACSEC_SA_ATTR_PN
+        0x01, 0x00, 0x00, 0x00, // 1
+        0x14, 0x00, 0x04, 0x00, // length = 20, type = MACSEC_SA_ATTR_KEY
+        0x00, 0x01, 0x02, 0x03,
+        0x04, 0x05, 0x06, 0x07,
+        0x08, 0x09, 0x0a, 0x0b,
+        0x0c, 0x0d, 0x0e, 0x0f,
+        0x14, 0x00, 0x05, 0x00, // length = 20, type = MACSEC_SA_ATTR_KEYID
+        0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x02, 0x00, // length = 5, type = MACSEC_SA_ATTR_ACTIVE
+        0x01, 0x00, 0x00, 0x00, // 1 + padding
+    ];
+
+    fn add_rxsa() -> Macsec {
+        let mut key_id = [0; MACSEC_KEYID_LEN];
+        key_id[0] = 1;
+        Macsec {
+            cmd: MacsecCmd::AddRxsa,
+            nlas: vec![
+                MacsecAttrs::IfIndex(7),
+                MacsecAttrs::RxscConfig(vec![MacsecRxscAttrs::Sci(0x5254_0012_3456_0001)]),
+                MacsecAttrs::SaConfig(vec![
+                    MacsecSaAttrs::An(2),
+                    MacsecSaAttrs::Pn(1),
+                    MacsecSaAttrs::Key((0..16).collect()),
+                    MacsecSaAttrs::KeyId(key_id),
+                    MacsecSaAttrs::Active(1),
+                ]),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_add_rxsa() {
+        let header = GenlHeader {
+            cmd: MACSEC_CMD_ADD_RXSA,
+            version: MACSEC_GENL_VERSION,
+        };
+        assert_eq!(
+            Macsec::parse_with_param(&ADD_RXSA[..], header).unwrap(),
+            add_rxsa()
+        );
+    }
+
+    #[test]
+    fn emit_add_rxsa() {
+        let msg = add_rxsa();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &ADD_RXSA[..]);
+    }
+
+    #[rustfmt::skip]
+    static DUMP: [u8; 88] = [
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = MACSEC_ATTR_IFINDEX
+        0x07, 0x00, 0x00, 0x00, // 7
+        0x18, 0x00, 0x04, 0x00, // length = 24, type = MACSEC_ATTR_SECY
+        0x0c, 0x00, 0x01, 0x00, // length = 12, type = MACSEC_SECY_ATTR_SCI
+        0x52, 0x54, 0x00, 0xab, // 52:54:00:ab:cd:ef, port 1
+        0xcd, 0xef, 0x00, 0x01,
+        0x05, 0x00, 0x0a, 0x00, // length = 5, type = MACSEC_SECY_ATTR_ENCRYPT
+        0x01, 0x00, 0x00, 0x00, // 1 + padding
+        0x38, 0x00, 0x06, 0x00, // length = 56, type = MACSEC_ATTR_RXSC_LIST
+        0x34, 0x00, 0x01, 0x00, // length = 52, first receive secure channel
+        0x05, 0x00, 0x02, 0x00, // length = 5, type = MACSEC_RXSC_ATTR_ACTIVE
+        0x01, 0x00, 0x00, 0x00, // 1 + padding
+        0x0c, 0x00, 0x01, 0x00, // length = 12, type = MACSEC_RXSC_ATTR_SCI
+        0x52, 0x54, 0x00, 0x12, // 52:54:00:12:34:56, port 1
+        0x34, 0x56, 0x00, 0x01,
+        0x1c, 0x00, 0x03, 0x00, // length = 28, type = MACSEC_RXSC_ATTR_SA_LIST
+        0x18, 0x00, 0x01, 0x00, // length = 24, first receive SA
+        0x05, 0x00, 0x01, 0x00, // length = 5, type = MACSEC_SA_ATTR_AN
+        0x02, 0x00, 0x00, 0x00, // 2 + padding
+        0x0c, 0x00, 0x06, 0x00, // length = 12, type = MACSEC_SA_ATTR_STATS
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = MACSEC_SA_STATS_ATTR_IN_PKTS_OK
+        0x2a, 0x00, 0x00, 0x00, // 42
+    ];
+
+    fn dump() -> Macsec {
+        Macsec {
+            cmd: MacsecCmd::GetTxsc,
+            nlas: vec![
+                MacsecAttrs::IfIndex(7),
+                MacsecAttrs::Secy(vec![
+                    MacsecSecyAttrs::Sci(0x5254_00ab_cdef_0001),
+                    MacsecSecyAttrs::Encrypt(1),
+                ]),
+                MacsecAttrs::RxscList(vec![vec![
+                    MacsecRxscAttrs::Active(1),
+                    MacsecRxscAttrs::Sci(0x5254_0012_3456_0001),
+                    MacsecRxscAttrs::SaList(vec![vec![
+                        MacsecSaAttrs::An(2),
+                        MacsecSaAttrs::Stats(vec![MacsecSaStatsAttrs::InPktsOk(42)]),
+                    ]]),
+                ]]),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_dump() {
+        let header = GenlHeader {
+            cmd: MACSEC_CMD_GET_TXSC,
+            version: MACSEC_GENL_VERSION,
+        };
+        assert_eq!(Macsec::parse_with_param(&DUMP[..], header).unwrap(), dump());
+    }
+
+    #[test]
+    fn emit_dump() {
+        let msg = dump();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &DUMP[..]);
+    }
+}
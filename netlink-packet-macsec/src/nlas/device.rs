@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+
+use super::{
+    emit_list,
+    list_len,
+    parse_list,
+    parse_nested,
+    MacsecOffloadAttrs,
+    MacsecRxscAttrs,
+    MacsecSaAttrs,
+    MacsecSecyAttrs,
+    MacsecSecyStatsAttrs,
+    MacsecTxscStatsAttrs,
+};
+use crate::constants::*;
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::*,
+    traits::*,
+    DecodeError,
+};
+
+/// Top level attributes of the `macsec` generic netlink family
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MacsecAttrs {
+    /// Index of the MACsec device
+    IfIndex(u32),
+    /// Receive secure channel to add, update or remove, or that the receive
+    /// SA of `SaConfig` belongs to
+    RxscConfig(Vec<MacsecRxscAttrs>),
+    /// Secure association to add, update or remove
+    SaConfig(Vec<MacsecSaAttrs>),
+    Secy(Vec<MacsecSecyAttrs>),
+    /// Transmit SAs of the device, as sent by the kernel
+    TxsaList(Vec<Vec<MacsecSaAttrs>>),
+    /// Receive secure channels of the device, as sent by the kernel
+    RxscList(Vec<Vec<MacsecRxscAttrs>>),
+    TxscStats(Vec<MacsecTxscStatsAttrs>),
+    SecyStats(Vec<MacsecSecyStatsAttrs>),
+    Offload(Vec<MacsecOffloadAttrs>),
+    Other(DefaultNla),
+}
+
+impl Nla for MacsecAttrs {
+    fn value_len(&self) -> usize {
+        use self::MacsecAttrs::*;
+        match self {
+            IfIndex(_) => 4,
+            RxscConfig(nlas) => nlas.as_slice().buffer_len(),
+            SaConfig(nlas) => nlas.as_slice().buffer_len(),
+            Secy(nlas) => nlas.as_slice().buffer_len(),
+            TxsaList(list) => list_len(list),
+            RxscList(list) => list_len(list),
+            TxscStats(nlas) => nlas.as_slice().buffer_len(),
+            SecyStats(nlas) => nlas.as_slice().buffer_len(),
+            Offload(nlas) => nlas.as_slice().buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MacsecAttrs::*;
+        match self {
+            IfIndex(_) => MACSEC_ATTR_IFINDEX,
+            RxscConfig(_) => MACSEC_ATTR_RXSC_CONFIG,
+            SaConfig(_) => MACSEC_ATTR_SA_CONFIG,
+            Secy(_) => MACSEC_ATTR_SECY,
+            TxsaList(_) => MACSEC_ATTR_TXSA_LIST,
+            RxscList(_) => MACSEC_ATTR_RXSC_LIST,
+            TxscStats(_) => MACSEC_ATTR_TXSC_STATS,
+            SecyStats(_) => MACSEC_ATTR_SECY_STATS,
+            Offload(_) => MACSEC_ATTR_OFFLOAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MacsecAttrs::*;
+        match self {
+            IfIndex(v) => NativeEndian::write_u32(buffer, *v),
+            RxscConfig(nlas) => nlas.as_slice().emit(buffer),
+            SaConfig(nlas) => nlas.as_slice().emit(buffer),
+            Secy(nlas) => nlas.as_slice().emit(buffer),
+            TxsaList(list) => emit_list(list, buffer),
+            RxscList(list) => emit_list(list, buffer),
+            TxscStats(nlas) => nlas.as_slice().emit(buffer),
+            SecyStats(nlas) => nlas.as_slice().emit(buffer),
+            Offload(nlas) => nlas.as_slice().emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacsecAttrs {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MacsecAttrs::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_ATTR_IFINDEX => {
+                IfIndex(parse_u32(payload).context("invalid MACSEC_ATTR_IFINDEX value")?)
+            }
+            MACSEC_ATTR_RXSC_CONFIG => RxscConfig(parse_nested(
+                payload,
+                "invalid MACSEC_ATTR_RXSC_CONFIG value",
+            )?),
+            MACSEC_ATTR_SA_CONFIG => SaConfig(parse_nested(
+                payload,
+                "invalid MACSEC_ATTR_SA_CONFIG value",
+            )?),
+            MACSEC_ATTR_SECY => Secy(parse_nested(payload, "invalid MACSEC_ATTR_SECY value")?),
+            MACSEC_ATTR_TXSA_LIST => {
+                TxsaList(parse_list(payload, "invalid MACSEC_ATTR_TXSA_LIST value")?)
+            }
+            MACSEC_ATTR_RXSC_LIST => {
+                RxscList(parse_list(payload, "invalid MACSEC_ATTR_RXSC_LIST value")?)
+            }
+            MACSEC_ATTR_TXSC_STATS => TxscStats(parse_nested(
+                payload,
+                "invalid MACSEC_ATTR_TXSC_STATS value",
+            )?),
+            MACSEC_ATTR_SECY_STATS => SecyStats(parse_nested(
+                payload,
+                "invalid MACSEC_ATTR_SECY_STATS value",
+            )?),
+            MACSEC_ATTR_OFFLOAD => {
+                Offload(parse_nested(payload, "invalid MACSEC_ATTR_OFFLOAD value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+mod device;
+mod offload;
+mod rxsc;
+mod sa;
+mod secy;
+mod stats;
+
+pub use device::MacsecAttrs;
+pub use offload::MacsecOffloadAttrs;
+pub use rxsc::MacsecRxscAttrs;
+pub use sa::MacsecSaAttrs;
+pub use secy::MacsecSecyAttrs;
+pub use stats::{
+    MacsecRxscStatsAttrs,
+    MacsecSaStatsAttrs,
+    MacsecSecyStatsAttrs,
+    MacsecTxscStatsAttrs,
+};
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{Nla, NlaBuffer, NlasIterator},
+    traits::*,
+    DecodeError,
+};
+
+// The entries of `MACSEC_ATTR_TXSA_LIST`, `MACSEC_ATTR_RXSC_LIST` and
+// `MACSEC_RXSC_ATTR_SA_LIST` are nested attributes whose type is their
+// position in the list, starting at 1.
+struct ListEntry<'a, T>(u16, &'a [T]);
+
+impl<'a, T: Nla> Nla for ListEntry<'a, T> {
+    fn value_len(&self) -> usize {
+        self.1.buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.0
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.1.emit(buffer)
+    }
+}
+
+fn list_entries<T>(list: &[Vec<T>]) -> Vec<ListEntry<'_, T>> {
+    list.iter()
+        .enumerate()
+        .map(|(i, nlas)| ListEntry(i as u16 + 1, nlas.as_slice()))
+        .collect()
+}
+
+pub(crate) fn list_len<T: Nla>(list: &[Vec<T>]) -> usize {
+    list_entries(list).as_slice().buffer_len()
+}
+
+pub(crate) fn emit_list<T: Nla>(list: &[Vec<T>], buffer: &mut [u8]) {
+    list_entries(list).as_slice().emit(buffer)
+}
+
+pub(crate) fn parse_nested<T>(payload: &[u8], error_msg: &str) -> Result<Vec<T>, DecodeError>
+where
+    T: for<'a> Parseable<NlaBuffer<&'a [u8]>>,
+{
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        let nla = &nla.context(error_msg.to_string())?;
+        nlas.push(T::parse(nla).context(error_msg.to_string())?);
+    }
+    Ok(nlas)
+}
+
+pub(crate) fn parse_list<T>(payload: &[u8], error_msg: &str) -> Result<Vec<Vec<T>>, DecodeError>
+where
+    T: for<'a> Parseable<NlaBuffer<&'a [u8]>>,
+{
+    let mut list = Vec::new();
+    for entry in NlasIterator::new(payload) {
+        let entry = entry.context(error_msg.to_string())?;
+        list.push(parse_nested(entry.value(), error_msg)?);
+    }
+    Ok(list)
+}
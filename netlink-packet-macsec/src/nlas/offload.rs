@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+
+use crate::constants::*;
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::*,
+    traits::*,
+    DecodeError,
+};
+
+/// Attributes of `MACSEC_ATTR_OFFLOAD`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MacsecOffloadAttrs {
+    /// One of the `MACSEC_OFFLOAD_*` constants
+    Type(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for MacsecOffloadAttrs {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Type(_) => 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Type(_) => MACSEC_OFFLOAD_ATTR_TYPE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Type(v) => buffer[0] = *v,
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacsecOffloadAttrs {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_OFFLOAD_ATTR_TYPE => {
+                Self::Type(parse_u8(payload).context("invalid MACSEC_OFFLOAD_ATTR_TYPE value")?)
+            }
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+
+use super::{emit_list, list_len, parse_list, parse_nested, MacsecRxscStatsAttrs, MacsecSaAttrs};
+use crate::constants::*;
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::*,
+    traits::*,
+    DecodeError,
+};
+
+/// Attributes of a receive secure channel, found in `MACSEC_ATTR_RXSC_CONFIG`
+/// and in the entries of `MACSEC_ATTR_RXSC_LIST`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MacsecRxscAttrs {
+    /// Secure channel identifier, in host byte order
+    Sci(u64),
+    Active(u8),
+    /// Receive SAs of the channel, as sent by the kernel
+    SaList(Vec<Vec<MacsecSaAttrs>>),
+    Stats(Vec<MacsecRxscStatsAttrs>),
+    Other(DefaultNla),
+}
+
+impl Nla for MacsecRxscAttrs {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Sci(_) => 8,
+            Self::Active(_) => 1,
+            Self::SaList(list) => list_len(list),
+            Self::Stats(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Sci(_) => MACSEC_RXSC_ATTR_SCI,
+            Self::Active(_) => MACSEC_RXSC_ATTR_ACTIVE,
+            Self::SaList(_) => MACSEC_RXSC_ATTR_SA_LIST,
+            Self::Stats(_) => MACSEC_RXSC_ATTR_STATS,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Sci(v) => BigEndian::write_u64(buffer, *v),
+            Self::Active(v) => buffer[0] = *v,
+            Self::SaList(list) => emit_list(list, buffer),
+            Self::Stats(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacsecRxscAttrs {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_RXSC_ATTR_SCI => {
+                Self::Sci(parse_u64_be(payload).context("invalid MACSEC_RXSC_ATTR_SCI value")?)
+            }
+            MACSEC_RXSC_ATTR_ACTIVE => {
+                Self::Active(parse_u8(payload).context("invalid MACSEC_RXSC_ATTR_ACTIVE value")?)
+            }
+            MACSEC_RXSC_ATTR_SA_LIST => Self::SaList(parse_list(
+                payload,
+                "invalid MACSEC_RXSC_ATTR_SA_LIST value",
+            )?),
+            MACSEC_RXSC_ATTR_STATS => Self::Stats(parse_nested(
+                payload,
+                "invalid MACSEC_RXSC_ATTR_STATS value",
+            )?),
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
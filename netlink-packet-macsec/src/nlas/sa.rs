@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+
+use super::{parse_nested, MacsecSaStatsAttrs};
+use crate::constants::*;
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::*,
+    traits::*,
+    DecodeError,
+};
+use std::convert::TryInto;
+
+/// Attributes of a secure association, found in `MACSEC_ATTR_SA_CONFIG` and
+/// in the entries of `MACSEC_ATTR_TXSA_LIST` and `MACSEC_RXSC_ATTR_SA_LIST`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MacsecSaAttrs {
+    /// Association number, between 0 and 3
+    An(u8),
+    Active(u8),
+    /// Packet number, with the `GCM-AES-128` and `GCM-AES-256` ciphers
+    Pn(u32),
+    /// Extended packet number, with the `GCM-AES-XPN-*` ciphers
+    XPn(u64),
+    /// Key material. The kernel never sends it back.
+    Key(Vec<u8>),
+    KeyId([u8; MACSEC_KEYID_LEN]),
+    Stats(Vec<MacsecSaStatsAttrs>),
+    /// Short secure channel identifier, with the `GCM-AES-XPN-*` ciphers, in
+    /// host byte order
+    Ssci(u32),
+    /// Salt, with the `GCM-AES-XPN-*` ciphers
+    Salt([u8; MACSEC_SALT_LEN]),
+    Other(DefaultNla),
+}
+
+impl Nla for MacsecSaAttrs {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::An(_) | Self::Active(_) => 1,
+            Self::Pn(_) | Self::Ssci(_) => 4,
+            Self::XPn(_) => 8,
+            Self::Key(bytes) => bytes.len(),
+            Self::KeyId(_) => MACSEC_KEYID_LEN,
+            Self::Stats(nlas) => nlas.as_slice().buffer_len(),
+            Self::Salt(_) => MACSEC_SALT_LEN,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::An(_) => MACSEC_SA_ATTR_AN,
+            Self::Active(_) => MACSEC_SA_ATTR_ACTIVE,
+            Self::Pn(_) | Self::XPn(_) => MACSEC_SA_ATTR_PN,
+            Self::Key(_) => MACSEC_SA_ATTR_KEY,
+            Self::KeyId(_) => MACSEC_SA_ATTR_KEYID,
+            Self::Stats(_) => MACSEC_SA_ATTR_STATS,
+            Self::Ssci(_) => MACSEC_SA_ATTR_SSCI,
+            Self::Salt(_) => MACSEC_SA_ATTR_SALT,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::An(v) | Self::Active(v) => buffer[0] = *v,
+            Self::Pn(v) => NativeEndian::write_u32(buffer, *v),
+            Self::XPn(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Key(bytes) => buffer.copy_from_slice(bytes),
+            Self::KeyId(v) => buffer.copy_from_slice(v),
+            Self::Stats(nlas) => nlas.as_slice().emit(buffer),
+            Self::Ssci(v) => BigEndian::write_u32(buffer, *v),
+            Self::Salt(v) => buffer.copy_from_slice(v),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacsecSaAttrs {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_SA_ATTR_AN => {
+                Self::An(parse_u8(payload).context("invalid MACSEC_SA_ATTR_AN value")?)
+            }
+            MACSEC_SA_ATTR_ACTIVE => {
+                Self::Active(parse_u8(payload).context("invalid MACSEC_SA_ATTR_ACTIVE value")?)
+            }
+            MACSEC_SA_ATTR_PN if payload.len() == 8 => {
+                Self::XPn(parse_u64(payload).context("invalid MACSEC_SA_ATTR_PN value")?)
+            }
+            MACSEC_SA_ATTR_PN => {
+                Self::Pn(parse_u32(payload).context("invalid MACSEC_SA_ATTR_PN value")?)
+            }
+            MACSEC_SA_ATTR_KEY => Self::Key(payload.to_vec()),
+            MACSEC_SA_ATTR_KEYID => Self::KeyId(
+                payload
+                    .try_into()
+                    .context("invalid MACSEC_SA_ATTR_KEYID value")?,
+            ),
+            MACSEC_SA_ATTR_STATS => {
+                Self::Stats(parse_nested(payload, "invalid MACSEC_SA_ATTR_STATS value")?)
+            }
+            MACSEC_SA_ATTR_SSCI => {
+                Self::Ssci(parse_u32_be(payload).context("invalid MACSEC_SA_ATTR_SSCI value")?)
+            }
+            MACSEC_SA_ATTR_SALT => Self::Salt(
+                payload
+                    .try_into()
+                    .context("invalid MACSEC_SA_ATTR_SALT value")?,
+            ),
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
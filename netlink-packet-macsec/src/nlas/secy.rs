@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: MIT
+
+use crate::constants::*;
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::*,
+    traits::*,
+    DecodeError,
+};
+
+/// Attributes of `MACSEC_ATTR_SECY`, describing the secure entity of a
+/// MACsec device and its transmit secure channel
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MacsecSecyAttrs {
+    /// Secure channel identifier, in host byte order
+    Sci(u64),
+    /// Association number of the SA used for transmission
+    EncodingSa(u8),
+    Window(u32),
+    /// One of the `MACSEC_CIPHER_ID_*` constants
+    CipherSuite(u64),
+    IcvLen(u8),
+    Protect(u8),
+    Replay(u8),
+    Oper(u8),
+    /// One of the `MACSEC_VALIDATE_*` constants
+    Validate(u8),
+    Encrypt(u8),
+    IncSci(u8),
+    Es(u8),
+    Scb(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for MacsecSecyAttrs {
+    fn value_len(&self) -> usize {
+        use self::MacsecSecyAttrs::*;
+        match self {
+            Sci(_) | CipherSuite(_) => 8,
+            Window(_) => 4,
+            EncodingSa(_) | IcvLen(_) | Protect(_) | Replay(_) | Oper(_) | Validate(_)
+            | Encrypt(_) | IncSci(_) | Es(_) | Scb(_) => 1,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MacsecSecyAttrs::*;
+        match self {
+            Sci(_) => MACSEC_SECY_ATTR_SCI,
+            EncodingSa(_) => MACSEC_SECY_ATTR_ENCODING_SA,
+            Window(_) => MACSEC_SECY_ATTR_WINDOW,
+            CipherSuite(_) => MACSEC_SECY_ATTR_CIPHER_SUITE,
+            IcvLen(_) => MACSEC_SECY_ATTR_ICV_LEN,
+            Protect(_) => MACSEC_SECY_ATTR_PROTECT,
+            Replay(_) => MACSEC_SECY_ATTR_REPLAY,
+            Oper(_) => MACSEC_SECY_ATTR_OPER,
+            Validate(_) => MACSEC_SECY_ATTR_VALIDATE,
+            Encrypt(_) => MACSEC_SECY_ATTR_ENCRYPT,
+            IncSci(_) => MACSEC_SECY_ATTR_INC_SCI,
+            Es(_) => MACSEC_SECY_ATTR_ES,
+            Scb(_) => MACSEC_SECY_ATTR_SCB,
+            Other(nla) => nla.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MacsecSecyAttrs::*;
+        match self {
+            Sci(v) => BigEndian::write_u64(buffer, *v),
+            CipherSuite(v) => NativeEndian::write_u64(buffer, *v),
+            Window(v) => NativeEndian::write_u32(buffer, *v),
+            EncodingSa(v) | IcvLen(v) | Protect(v) | Replay(v) | Oper(v) | Validate(v)
+            | Encrypt(v) | IncSci(v) | Es(v) | Scb(v) => buffer[0] = *v,
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacsecSecyAttrs {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MacsecSecyAttrs::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_SECY_ATTR_SCI => {
+                Sci(parse_u64_be(payload).context("invalid MACSEC_SECY_ATTR_SCI value")?)
+            }
+            MACSEC_SECY_ATTR_ENCODING_SA => {
+                EncodingSa(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_ENCODING_SA value")?)
+            }
+            MACSEC_SECY_ATTR_WINDOW => {
+                Window(parse_u32(payload).context("invalid MACSEC_SECY_ATTR_WINDOW value")?)
+            }
+            MACSEC_SECY_ATTR_CIPHER_SUITE => CipherSuite(
+                parse_u64(payload).context("invalid MACSEC_SECY_ATTR_CIPHER_SUITE value")?,
+            ),
+            MACSEC_SECY_ATTR_ICV_LEN => {
+                IcvLen(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_ICV_LEN value")?)
+            }
+            MACSEC_SECY_ATTR_PROTECT => {
+                Protect(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_PROTECT value")?)
+            }
+            MACSEC_SECY_ATTR_REPLAY => {
+                Replay(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_REPLAY value")?)
+            }
+            MACSEC_SECY_ATTR_OPER => {
+                Oper(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_OPER value")?)
+            }
+            MACSEC_SECY_ATTR_VALIDATE => {
+                Validate(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_VALIDATE value")?)
+            }
+            MACSEC_SECY_ATTR_ENCRYPT => {
+                Encrypt(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_ENCRYPT value")?)
+            }
+            MACSEC_SECY_ATTR_INC_SCI => {
+                IncSci(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_INC_SCI value")?)
+            }
+            MACSEC_SECY_ATTR_ES => {
+                Es(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_ES value")?)
+            }
+            MACSEC_SECY_ATTR_SCB => {
+                Scb(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_SCB value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,363 @@
+// SPDX-License-Identifier: MIT
+
+use crate::constants::*;
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::*,
+    traits::*,
+    DecodeError,
+};
+
+/// Statistics of a receive secure channel (`MACSEC_RXSC_STATS_ATTR_*`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MacsecRxscStatsAttrs {
+    InOctetsValidated(u64),
+    InOctetsDecrypted(u64),
+    InPktsUnchecked(u64),
+    InPktsDelayed(u64),
+    InPktsOk(u64),
+    InPktsInvalid(u64),
+    InPktsLate(u64),
+    InPktsNotValid(u64),
+    InPktsNotUsingSa(u64),
+    InPktsUnusedSa(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for MacsecRxscStatsAttrs {
+    fn value_len(&self) -> usize {
+        use self::MacsecRxscStatsAttrs::*;
+        match self {
+            InOctetsValidated(_) | InOctetsDecrypted(_) | InPktsUnchecked(_) | InPktsDelayed(_)
+            | InPktsOk(_) | InPktsInvalid(_) | InPktsLate(_) | InPktsNotValid(_)
+            | InPktsNotUsingSa(_) | InPktsUnusedSa(_) => 8,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MacsecRxscStatsAttrs::*;
+        match self {
+            InOctetsValidated(_) => MACSEC_RXSC_STATS_ATTR_IN_OCTETS_VALIDATED,
+            InOctetsDecrypted(_) => MACSEC_RXSC_STATS_ATTR_IN_OCTETS_DECRYPTED,
+            InPktsUnchecked(_) => MACSEC_RXSC_STATS_ATTR_IN_PKTS_UNCHECKED,
+            InPktsDelayed(_) => MACSEC_RXSC_STATS_ATTR_IN_PKTS_DELAYED,
+            InPktsOk(_) => MACSEC_RXSC_STATS_ATTR_IN_PKTS_OK,
+            InPktsInvalid(_) => MACSEC_RXSC_STATS_ATTR_IN_PKTS_INVALID,
+            InPktsLate(_) => MACSEC_RXSC_STATS_ATTR_IN_PKTS_LATE,
+            InPktsNotValid(_) => MACSEC_RXSC_STATS_ATTR_IN_PKTS_NOT_VALID,
+            InPktsNotUsingSa(_) => MACSEC_RXSC_STATS_ATTR_IN_PKTS_NOT_USING_SA,
+            InPktsUnusedSa(_) => MACSEC_RXSC_STATS_ATTR_IN_PKTS_UNUSED_SA,
+            Other(nla) => nla.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MacsecRxscStatsAttrs::*;
+        match self {
+            InOctetsValidated(v) | InOctetsDecrypted(v) | InPktsUnchecked(v) | InPktsDelayed(v)
+            | InPktsOk(v) | InPktsInvalid(v) | InPktsLate(v) | InPktsNotValid(v)
+            | InPktsNotUsingSa(v) | InPktsUnusedSa(v) => NativeEndian::write_u64(buffer, *v),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacsecRxscStatsAttrs {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MacsecRxscStatsAttrs::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_RXSC_STATS_ATTR_IN_OCTETS_VALIDATED => InOctetsValidated(
+                parse_u64(payload)
+                    .context("invalid MACSEC_RXSC_STATS_ATTR_IN_OCTETS_VALIDATED value")?,
+            ),
+            MACSEC_RXSC_STATS_ATTR_IN_OCTETS_DECRYPTED => InOctetsDecrypted(
+                parse_u64(payload)
+                    .context("invalid MACSEC_RXSC_STATS_ATTR_IN_OCTETS_DECRYPTED value")?,
+            ),
+            MACSEC_RXSC_STATS_ATTR_IN_PKTS_UNCHECKED => InPktsUnchecked(
+                parse_u64(payload)
+                    .context("invalid MACSEC_RXSC_STATS_ATTR_IN_PKTS_UNCHECKED value")?,
+            ),
+            MACSEC_RXSC_STATS_ATTR_IN_PKTS_DELAYED => InPktsDelayed(
+                parse_u64(payload)
+                    .context("invalid MACSEC_RXSC_STATS_ATTR_IN_PKTS_DELAYED value")?,
+            ),
+            MACSEC_RXSC_STATS_ATTR_IN_PKTS_OK => InPktsOk(
+                parse_u64(payload).context("invalid MACSEC_RXSC_STATS_ATTR_IN_PKTS_OK value")?,
+            ),
+            MACSEC_RXSC_STATS_ATTR_IN_PKTS_INVALID => InPktsInvalid(
+                parse_u64(payload)
+                    .context("invalid MACSEC_RXSC_STATS_ATTR_IN_PKTS_INVALID value")?,
+            ),
+            MACSEC_RXSC_STATS_ATTR_IN_PKTS_LATE => InPktsLate(
+                parse_u64(payload).context("invalid MACSEC_RXSC_STATS_ATTR_IN_PKTS_LATE value")?,
+            ),
+            MACSEC_RXSC_STATS_ATTR_IN_PKTS_NOT_VALID => InPktsNotValid(
+                parse_u64(payload)
+                    .context("invalid MACSEC_RXSC_STATS_ATTR_IN_PKTS_NOT_VALID value")?,
+            ),
+            MACSEC_RXSC_STATS_ATTR_IN_PKTS_NOT_USING_SA => InPktsNotUsingSa(
+                parse_u64(payload)
+                    .context("invalid MACSEC_RXSC_STATS_ATTR_IN_PKTS_NOT_USING_SA value")?,
+            ),
+            MACSEC_RXSC_STATS_ATTR_IN_PKTS_UNUSED_SA => InPktsUnusedSa(
+                parse_u64(payload)
+                    .context("invalid MACSEC_RXSC_STATS_ATTR_IN_PKTS_UNUSED_SA value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Statistics of a secure association (`MACSEC_SA_STATS_ATTR_*`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MacsecSaStatsAttrs {
+    InPktsOk(u32),
+    InPktsInvalid(u32),
+    InPktsNotValid(u32),
+    InPktsNotUsingSa(u32),
+    InPktsUnusedSa(u32),
+    OutPktsProtected(u32),
+    OutPktsEncrypted(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for MacsecSaStatsAttrs {
+    fn value_len(&self) -> usize {
+        use self::MacsecSaStatsAttrs::*;
+        match self {
+            InPktsOk(_) | InPktsInvalid(_) | InPktsNotValid(_) | InPktsNotUsingSa(_)
+            | InPktsUnusedSa(_) | OutPktsProtected(_) | OutPktsEncrypted(_) => 4,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MacsecSaStatsAttrs::*;
+        match self {
+            InPktsOk(_) => MACSEC_SA_STATS_ATTR_IN_PKTS_OK,
+            InPktsInvalid(_) => MACSEC_SA_STATS_ATTR_IN_PKTS_INVALID,
+            InPktsNotValid(_) => MACSEC_SA_STATS_ATTR_IN_PKTS_NOT_VALID,
+            InPktsNotUsingSa(_) => MACSEC_SA_STATS_ATTR_IN_PKTS_NOT_USING_SA,
+            InPktsUnusedSa(_) => MACSEC_SA_STATS_ATTR_IN_PKTS_UNUSED_SA,
+            OutPktsProtected(_) => MACSEC_SA_STATS_ATTR_OUT_PKTS_PROTECTED,
+            OutPktsEncrypted(_) => MACSEC_SA_STATS_ATTR_OUT_PKTS_ENCRYPTED,
+            Other(nla) => nla.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MacsecSaStatsAttrs::*;
+        match self {
+            InPktsOk(v) | InPktsInvalid(v) | InPktsNotValid(v) | InPktsNotUsingSa(v)
+            | InPktsUnusedSa(v) | OutPktsProtected(v) | OutPktsEncrypted(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacsecSaStatsAttrs {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MacsecSaStatsAttrs::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_SA_STATS_ATTR_IN_PKTS_OK => InPktsOk(
+                parse_u32(payload).context("invalid MACSEC_SA_STATS_ATTR_IN_PKTS_OK value")?,
+            ),
+            MACSEC_SA_STATS_ATTR_IN_PKTS_INVALID => InPktsInvalid(
+                parse_u32(payload).context("invalid MACSEC_SA_STATS_ATTR_IN_PKTS_INVALID value")?,
+            ),
+            MACSEC_SA_STATS_ATTR_IN_PKTS_NOT_VALID => InPktsNotValid(
+                parse_u32(payload)
+                    .context("invalid MACSEC_SA_STATS_ATTR_IN_PKTS_NOT_VALID value")?,
+            ),
+            MACSEC_SA_STATS_ATTR_IN_PKTS_NOT_USING_SA => InPktsNotUsingSa(
+                parse_u32(payload)
+                    .context("invalid MACSEC_SA_STATS_ATTR_IN_PKTS_NOT_USING_SA value")?,
+            ),
+            MACSEC_SA_STATS_ATTR_IN_PKTS_UNUSED_SA => InPktsUnusedSa(
+                parse_u32(payload)
+                    .context("invalid MACSEC_SA_STATS_ATTR_IN_PKTS_UNUSED_SA value")?,
+            ),
+            MACSEC_SA_STATS_ATTR_OUT_PKTS_PROTECTED => OutPktsProtected(
+                parse_u32(payload)
+                    .context("invalid MACSEC_SA_STATS_ATTR_OUT_PKTS_PROTECTED value")?,
+            ),
+            MACSEC_SA_STATS_ATTR_OUT_PKTS_ENCRYPTED => OutPktsEncrypted(
+                parse_u32(payload)
+                    .context("invalid MACSEC_SA_STATS_ATTR_OUT_PKTS_ENCRYPTED value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Statistics of the transmit secure channel (`MACSEC_TXSC_STATS_ATTR_*`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MacsecTxscStatsAttrs {
+    OutPktsProtected(u64),
+    OutPktsEncrypted(u64),
+    OutOctetsProtected(u64),
+    OutOctetsEncrypted(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for MacsecTxscStatsAttrs {
+    fn value_len(&self) -> usize {
+        use self::MacsecTxscStatsAttrs::*;
+        match self {
+            OutPktsProtected(_)
+            | OutPktsEncrypted(_)
+            | OutOctetsProtected(_)
+            | OutOctetsEncrypted(_) => 8,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MacsecTxscStatsAttrs::*;
+        match self {
+            OutPktsProtected(_) => MACSEC_TXSC_STATS_ATTR_OUT_PKTS_PROTECTED,
+            OutPktsEncrypted(_) => MACSEC_TXSC_STATS_ATTR_OUT_PKTS_ENCRYPTED,
+            OutOctetsProtected(_) => MACSEC_TXSC_STATS_ATTR_OUT_OCTETS_PROTECTED,
+            OutOctetsEncrypted(_) => MACSEC_TXSC_STATS_ATTR_OUT_OCTETS_ENCRYPTED,
+            Other(nla) => nla.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MacsecTxscStatsAttrs::*;
+        match self {
+            OutPktsProtected(v)
+            | OutPktsEncrypted(v)
+            | OutOctetsProtected(v)
+            | OutOctetsEncrypted(v) => NativeEndian::write_u64(buffer, *v),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacsecTxscStatsAttrs {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MacsecTxscStatsAttrs::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_TXSC_STATS_ATTR_OUT_PKTS_PROTECTED => OutPktsProtected(
+                parse_u64(payload)
+                    .context("invalid MACSEC_TXSC_STATS_ATTR_OUT_PKTS_PROTECTED value")?,
+            ),
+            MACSEC_TXSC_STATS_ATTR_OUT_PKTS_ENCRYPTED => OutPktsEncrypted(
+                parse_u64(payload)
+                    .context("invalid MACSEC_TXSC_STATS_ATTR_OUT_PKTS_ENCRYPTED value")?,
+            ),
+            MACSEC_TXSC_STATS_ATTR_OUT_OCTETS_PROTECTED => OutOctetsProtected(
+                parse_u64(payload)
+                    .context("invalid MACSEC_TXSC_STATS_ATTR_OUT_OCTETS_PROTECTED value")?,
+            ),
+            MACSEC_TXSC_STATS_ATTR_OUT_OCTETS_ENCRYPTED => OutOctetsEncrypted(
+                parse_u64(payload)
+                    .context("invalid MACSEC_TXSC_STATS_ATTR_OUT_OCTETS_ENCRYPTED value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Statistics of the secure entity (`MACSEC_SECY_STATS_ATTR_*`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MacsecSecyStatsAttrs {
+    OutPktsUntagged(u64),
+    InPktsUntagged(u64),
+    OutPktsTooLong(u64),
+    InPktsNoTag(u64),
+    InPktsBadTag(u64),
+    InPktsUnknownSci(u64),
+    InPktsNoSci(u64),
+    InPktsOverrun(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for MacsecSecyStatsAttrs {
+    fn value_len(&self) -> usize {
+        use self::MacsecSecyStatsAttrs::*;
+        match self {
+            OutPktsUntagged(_) | InPktsUntagged(_) | OutPktsTooLong(_) | InPktsNoTag(_)
+            | InPktsBadTag(_) | InPktsUnknownSci(_) | InPktsNoSci(_) | InPktsOverrun(_) => 8,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MacsecSecyStatsAttrs::*;
+        match self {
+            OutPktsUntagged(_) => MACSEC_SECY_STATS_ATTR_OUT_PKTS_UNTAGGED,
+            InPktsUntagged(_) => MACSEC_SECY_STATS_ATTR_IN_PKTS_UNTAGGED,
+            OutPktsTooLong(_) => MACSEC_SECY_STATS_ATTR_OUT_PKTS_TOO_LONG,
+            InPktsNoTag(_) => MACSEC_SECY_STATS_ATTR_IN_PKTS_NO_TAG,
+            InPktsBadTag(_) => MACSEC_SECY_STATS_ATTR_IN_PKTS_BAD_TAG,
+            InPktsUnknownSci(_) => MACSEC_SECY_STATS_ATTR_IN_PKTS_UNKNOWN_SCI,
+            InPktsNoSci(_) => MACSEC_SECY_STATS_ATTR_IN_PKTS_NO_SCI,
+            InPktsOverrun(_) => MACSEC_SECY_STATS_ATTR_IN_PKTS_OVERRUN,
+            Other(nla) => nla.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MacsecSecyStatsAttrs::*;
+        match self {
+            OutPktsUntagged(v) | InPktsUntagged(v) | OutPktsTooLong(v) | InPktsNoTag(v)
+            | InPktsBadTag(v) | InPktsUnknownSci(v) | InPktsNoSci(v) | InPktsOverrun(v) => {
+                NativeEndian::write_u64(buffer, *v)
+            }
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacsecSecyStatsAttrs {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MacsecSecyStatsAttrs::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_SECY_STATS_ATTR_OUT_PKTS_UNTAGGED => OutPktsUntagged(
+                parse_u64(payload)
+                    .context("invalid MACSEC_SECY_STATS_ATTR_OUT_PKTS_UNTAGGED value")?,
+            ),
+            MACSEC_SECY_STATS_ATTR_IN_PKTS_UNTAGGED => InPktsUntagged(
+                parse_u64(payload)
+                    .context("invalid MACSEC_SECY_STATS_ATTR_IN_PKTS_UNTAGGED value")?,
+            ),
+            MACSEC_SECY_STATS_ATTR_OUT_PKTS_TOO_LONG => OutPktsTooLong(
+                parse_u64(payload)
+                    .context("invalid MACSEC_SECY_STATS_ATTR_OUT_PKTS_TOO_LONG value")?,
+            ),
+            MACSEC_SECY_STATS_ATTR_IN_PKTS_NO_TAG => InPktsNoTag(
+                parse_u64(payload)
+                    .context("invalid MACSEC_SECY_STATS_ATTR_IN_PKTS_NO_TAG value")?,
+            ),
+            MACSEC_SECY_STATS_ATTR_IN_PKTS_BAD_TAG => InPktsBadTag(
+                parse_u64(payload)
+                    .context("invalid MACSEC_SECY_STATS_ATTR_IN_PKTS_BAD_TAG value")?,
+            ),
+            MACSEC_SECY_STATS_ATTR_IN_PKTS_UNKNOWN_SCI => InPktsUnknownSci(
+                parse_u64(payload)
+                    .context("invalid MACSEC_SECY_STATS_ATTR_IN_PKTS_UNKNOWN_SCI value")?,
+            ),
+            MACSEC_SECY_STATS_ATTR_IN_PKTS_NO_SCI => InPktsNoSci(
+                parse_u64(payload)
+                    .context("invalid MACSEC_SECY_STATS_ATTR_IN_PKTS_NO_SCI value")?,
+            ),
+            MACSEC_SECY_STATS_ATTR_IN_PKTS_OVERRUN => InPktsOverrun(
+                parse_u64(payload)
+                    .context("invalid MACSEC_SECY_STATS_ATTR_IN_PKTS_OVERRUN value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nlas::{NlaBuffer, NlasIterator},
+    DecodeError,
+};
+
+pub const ADDRESS_LABEL_HEADER_LEN: usize = 12;
+
+buffer!(AddressLabelMessageBuffer(ADDRESS_LABEL_HEADER_LEN) {
+    family: (u8, 0),
+    reserved: (u8, 1),
+    prefix_len: (u8, 2),
+    flags: (u8, 3),
+    index: (u32, 4..8),
+    seq: (u32, 8..ADDRESS_LABEL_HEADER_LEN),
+    payload: (slice, ADDRESS_LABEL_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> AddressLabelMessageBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
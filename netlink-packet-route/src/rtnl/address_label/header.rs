@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use super::{AddressLabelMessageBuffer, ADDRESS_LABEL_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Address label message header (`struct ifaddrlblmsg`).
+///
+/// ```no_rust
+/// 0                8                16              24               32
+/// +----------------+----------------+----------------+----------------+
+/// | address family |    reserved    | prefix length  |     flags      |
+/// +----------------+----------------+----------------+----------------+
+/// |                          interface index                          |
+/// +----------------+----------------+----------------+----------------+
+/// |                          sequence number                          |
+/// +----------------+----------------+----------------+----------------+
+/// ```
+// see https://github.com/torvalds/linux/blob/master/include/uapi/linux/if_addrlabel.h
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AddressLabelHeader {
+    /// Address family: `AF_INET6`.
+    pub family: u8,
+    /// Prefix length of the addresses the label applies to.
+    pub prefix_len: u8,
+    pub flags: u8,
+    /// Index of the interface the label applies to, or 0 for all the interfaces.
+    pub index: u32,
+    /// Sequence number of the label table, set by the kernel.
+    pub seq: u32,
+}
+
+impl Emitable for AddressLabelHeader {
+    fn buffer_len(&self) -> usize {
+        ADDRESS_LABEL_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = AddressLabelMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_reserved(0);
+        packet.set_prefix_len(self.prefix_len);
+        packet.set_flags(self.flags);
+        packet.set_index(self.index);
+        packet.set_seq(self.seq);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<AddressLabelMessageBuffer<T>> for AddressLabelHeader {
+    fn parse(buf: &AddressLabelMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(AddressLabelHeader {
+            family: buf.family(),
+            prefix_len: buf.prefix_len(),
+            flags: buf.flags(),
+            index: buf.index(),
+            seq: buf.seq(),
+        })
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::address_label::Nla,
+    traits::{Emitable, Parseable},
+    AddressLabelHeader,
+    AddressLabelMessageBuffer,
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AddressLabelMessage {
+    pub header: AddressLabelHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddressLabelMessageBuffer<&'a T>> for AddressLabelMessage {
+    fn parse(buf: &AddressLabelMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: AddressLabelHeader::parse(buf)
+                .context("failed to parse address label message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse address label message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddressLabelMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &AddressLabelMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for AddressLabelMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv6Addr;
+
+    use crate::{
+        constants::*,
+        nlas::address_label::Nla,
+        traits::{Emitable, ParseableParametrized},
+        AddressLabelHeader,
+        AddressLabelMessage,
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
+    };
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_address_label() {
+        let data = vec![
+            0x38, 0x00, 0x00, 0x00, // length = 56
+            0x48, 0x00, // message type = 72 = RTM_NEWADDRLABEL
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // ifaddrlblmsg
+            0x0a, // family = AF_INET6
+            0x00, // reserved
+            0x60, // prefix length = 96
+            0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // interface index = 0
+            0x05, 0x00, 0x00, 0x00, // seq = 5
+
+            // NLAs
+            0x14, 0x00, 0x01, 0x00, // length = 20, type = IFAL_ADDRESS
+            0x00, 0x00, 0x00, 0x00, // ::ffff:0.0.0.0
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xff, 0xff,
+            0x00, 0x00, 0x00, 0x00,
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = IFAL_LABEL
+            0x04, 0x00, 0x00, 0x00, // 4
+        ];
+        let expected = RtnlMessage::NewAddressLabel(AddressLabelMessage {
+            header: AddressLabelHeader {
+                family: AF_INET6 as u8,
+                prefix_len: 96,
+                flags: 0,
+                index: 0,
+                seq: 5,
+            },
+            nlas: vec![
+                Nla::Address(Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0, 0)),
+                Nla::Label(4),
+            ],
+        });
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWADDRLABEL).unwrap();
+        assert_eq!(expected, actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_ipv6, parse_u32},
+    traits::Parseable,
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Prefix the label applies to
+    Address(Ipv6Addr),
+    Label(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Address(_) => 16,
+            Label(_) => 4,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Address(ref addr) => buffer.copy_from_slice(&addr.octets()),
+            Label(value) => NativeEndian::write_u32(buffer, value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match *self {
+            Unspec(_) => IFAL_UNSPEC,
+            Address(_) => IFAL_ADDRESS,
+            Label(_) => IFAL_LABEL,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFAL_UNSPEC => Unspec(payload.to_vec()),
+            IFAL_ADDRESS => Address(
+                parse_ipv6(payload)
+                    .context("invalid IFAL_ADDRESS value")?
+                    .into(),
+            ),
+            IFAL_LABEL => Label(parse_u32(payload).context("invalid IFAL_LABEL value")?),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -4,15 +4,23 @@ use crate::{
     constants::*,
     traits::{Parseable, ParseableParametrized},
     AddressHeader,
+    AddressLabelMessage,
+    AddressLabelMessageBuffer,
     AddressMessage,
     AddressMessageBuffer,
     DecodeError,
     LinkMessage,
     LinkMessageBuffer,
+    MdbMessage,
+    MdbMessageBuffer,
     NeighbourMessage,
     NeighbourMessageBuffer,
     NeighbourTableMessage,
     NeighbourTableMessageBuffer,
+    NetconfMessage,
+    NetconfMessageBuffer,
+    NexthopMessage,
+    NexthopMessageBuffer,
     NsidMessage,
     NsidMessageBuffer,
     RouteHeader,
@@ -21,6 +29,10 @@ use crate::{
     RtnlMessage,
     RuleMessage,
     RuleMessageBuffer,
+    StatsMessage,
+    StatsMessageBuffer,
+    TcActionMessage,
+    TcActionMessageBuffer,
     TcMessage,
     TcMessageBuffer,
 };
@@ -177,6 +189,18 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<RtnlMessageBuffer<&'a T>
                 }
             }
 
+            // TC action messages
+            RTM_NEWACTION | RTM_DELACTION | RTM_GETACTION => {
+                let err = "invalid tc action message";
+                let msg = TcActionMessage::parse(&TcActionMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWACTION => NewTrafficAction(msg),
+                    RTM_DELACTION => DelTrafficAction(msg),
+                    RTM_GETACTION => GetTrafficAction(msg),
+                    _ => unreachable!(),
+                }
+            }
+
             // ND ID Messages
             RTM_NEWNSID | RTM_GETNSID | RTM_DELNSID => {
                 let err = "invalid nsid message";
@@ -189,6 +213,65 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<RtnlMessageBuffer<&'a T>
                 }
             }
 
+            // Nexthop messages
+            RTM_NEWNEXTHOP | RTM_DELNEXTHOP | RTM_GETNEXTHOP => {
+                let err = "invalid nexthop message";
+                let msg = NexthopMessage::parse(&NexthopMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWNEXTHOP => NewNexthop(msg),
+                    RTM_DELNEXTHOP => DelNexthop(msg),
+                    RTM_GETNEXTHOP => GetNexthop(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Link statistics messages
+            RTM_NEWSTATS | RTM_GETSTATS => {
+                let err = "invalid stats message";
+                let msg = StatsMessage::parse(&StatsMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWSTATS => NewStats(msg),
+                    RTM_GETSTATS => GetStats(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Bridge multicast database messages
+            RTM_NEWMDB | RTM_DELMDB | RTM_GETMDB => {
+                let err = "invalid MDB message";
+                let msg = MdbMessage::parse(&MdbMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWMDB => NewMdb(msg),
+                    RTM_DELMDB => DelMdb(msg),
+                    RTM_GETMDB => GetMdb(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Netconf messages
+            RTM_NEWNETCONF | RTM_DELNETCONF | RTM_GETNETCONF => {
+                let err = "invalid netconf message";
+                let msg = NetconfMessage::parse(&NetconfMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWNETCONF => NewNetconf(msg),
+                    RTM_DELNETCONF => DelNetconf(msg),
+                    RTM_GETNETCONF => GetNetconf(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Address label messages
+            RTM_NEWADDRLABEL | RTM_DELADDRLABEL | RTM_GETADDRLABEL => {
+                let err = "invalid address label message";
+                let msg = AddressLabelMessage::parse(&AddressLabelMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWADDRLABEL => NewAddressLabel(msg),
+                    RTM_DELADDRLABEL => DelAddressLabel(msg),
+                    RTM_GETADDRLABEL => GetAddressLabel(msg),
+                    _ => unreachable!(),
+                }
+            }
+
             _ => return Err(format!("Unknown message type: {}", message_type).into()),
         };
         Ok(message)
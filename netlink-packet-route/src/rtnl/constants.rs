@@ -58,6 +58,9 @@ pub const RTM_NEWCACHEREPORT: u16 = 96;
 pub const RTM_NEWCHAIN: u16 = 100;
 pub const RTM_DELCHAIN: u16 = 101;
 pub const RTM_GETCHAIN: u16 = 102;
+pub const RTM_NEWNEXTHOP: u16 = 104;
+pub const RTM_DELNEXTHOP: u16 = 105;
+pub const RTM_GETNEXTHOP: u16 = 106;
 pub const RTM_NEWLINKPROP: u16 = 108;
 pub const RTM_DELLINKPROP: u16 = 109;
 
@@ -259,6 +262,7 @@ pub const AF_IRDA: u16 = libc::AF_IRDA as u16;
 pub const AF_PPPOX: u16 = libc::AF_PPPOX as u16;
 pub const AF_WANPIPE: u16 = libc::AF_WANPIPE as u16;
 pub const AF_LLC: u16 = libc::AF_LLC as u16;
+pub const AF_MPLS: u16 = 28;
 pub const AF_CAN: u16 = libc::AF_CAN as u16;
 pub const AF_TIPC: u16 = libc::AF_TIPC as u16;
 pub const AF_BLUETOOTH: u16 = libc::AF_BLUETOOTH as u16;
@@ -276,6 +280,35 @@ pub const NETNSA_PID: u16 = 2;
 pub const NETNSA_FD: u16 = 3;
 pub const NETNSA_NSID_NOT_ASSIGNED: i32 = -1;
 
+pub const NHA_UNSPEC: u16 = 0;
+pub const NHA_ID: u16 = 1;
+pub const NHA_GROUP: u16 = 2;
+pub const NHA_GROUP_TYPE: u16 = 3;
+pub const NHA_BLACKHOLE: u16 = 4;
+pub const NHA_OIF: u16 = 5;
+pub const NHA_GATEWAY: u16 = 6;
+pub const NHA_ENCAP_TYPE: u16 = 7;
+pub const NHA_ENCAP: u16 = 8;
+pub const NHA_GROUPS: u16 = 9;
+pub const NHA_MASTER: u16 = 10;
+pub const NHA_FDB: u16 = 11;
+pub const NHA_RES_GROUP: u16 = 12;
+pub const NHA_RES_BUCKET: u16 = 13;
+
+pub const NEXTHOP_GRP_TYPE_MPATH: u16 = 0;
+pub const NEXTHOP_GRP_TYPE_RES: u16 = 1;
+
+pub const NHA_RES_GROUP_PAD: u16 = 0;
+pub const NHA_RES_GROUP_BUCKETS: u16 = 1;
+pub const NHA_RES_GROUP_IDLE_TIMER: u16 = 2;
+pub const NHA_RES_GROUP_UNBALANCED_TIMER: u16 = 3;
+pub const NHA_RES_GROUP_UNBALANCED_TIME: u16 = 4;
+
+pub const NHA_RES_BUCKET_PAD: u16 = 0;
+pub const NHA_RES_BUCKET_INDEX: u16 = 1;
+pub const NHA_RES_BUCKET_IDLE_TIME: u16 = 2;
+pub const NHA_RES_BUCKET_NH_ID: u16 = 3;
+
 /// Neighbour cache entry state: the neighbour has not (yet) been resolved
 pub const NUD_INCOMPLETE: u16 = 1;
 /// Neighbour cache entry state: the neighbour entry is valid until its lifetime expires
@@ -304,8 +337,13 @@ pub const NTF_MASTER: u8 = 4;
 pub const NTF_PROXY: u8 = 8;
 pub const NTF_EXT_LEARNED: u8 = 16;
 pub const NTF_OFFLOADED: u8 = 32;
+pub const NTF_STICKY: u8 = 64;
 pub const NTF_ROUTER: u8 = 128;
 
+// Extended neighbour cache entry flags (`NDA_FLAGS_EXT`)
+pub const NTF_EXT_MANAGED: u32 = 1;
+pub const NTF_EXT_LOCKED: u32 = 2;
+
 pub const TCA_UNSPEC: u16 = 0;
 pub const TCA_KIND: u16 = 1;
 pub const TCA_OPTIONS: u16 = 2;
@@ -330,6 +368,299 @@ pub const TCA_STATS_RATE_EST64: u16 = 5;
 pub const TCA_STATS_PAD: u16 = 6;
 pub const TCA_STATS_BASIC_HW: u16 = 7;
 
+pub const TC_H_MAJ_MASK: u32 = 0xffff_0000;
+pub const TC_H_MIN_MASK: u32 = 0x0000_ffff;
+pub const TC_H_UNSPEC: u32 = 0;
+pub const TC_H_ROOT: u32 = 0xffff_ffff;
+pub const TC_H_INGRESS: u32 = 0xffff_fff1;
+pub const TC_H_CLSACT: u32 = TC_H_INGRESS;
+pub const TC_H_MIN_PRIORITY: u32 = 0xffe0;
+pub const TC_H_MIN_INGRESS: u32 = 0xfff2;
+pub const TC_H_MIN_EGRESS: u32 = 0xfff3;
+
+pub const ETH_P_ALL: u16 = 0x0003;
+pub const ETH_P_IP: u16 = 0x0800;
+pub const ETH_P_ARP: u16 = 0x0806;
+pub const ETH_P_8021Q: u16 = 0x8100;
+pub const ETH_P_IPV6: u16 = 0x86dd;
+pub const ETH_P_8021AD: u16 = 0x88a8;
+
+pub const TCA_FQ_CODEL_UNSPEC: u16 = 0;
+pub const TCA_FQ_CODEL_TARGET: u16 = 1;
+pub const TCA_FQ_CODEL_LIMIT: u16 = 2;
+pub const TCA_FQ_CODEL_INTERVAL: u16 = 3;
+pub const TCA_FQ_CODEL_ECN: u16 = 4;
+pub const TCA_FQ_CODEL_FLOWS: u16 = 5;
+pub const TCA_FQ_CODEL_QUANTUM: u16 = 6;
+pub const TCA_FQ_CODEL_CE_THRESHOLD: u16 = 7;
+pub const TCA_FQ_CODEL_DROP_BATCH_SIZE: u16 = 8;
+pub const TCA_FQ_CODEL_MEMORY_LIMIT: u16 = 9;
+pub const TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR: u16 = 10;
+pub const TCA_FQ_CODEL_CE_THRESHOLD_MASK: u16 = 11;
+pub const TCA_FQ_CODEL_XSTATS_QDISC: u32 = 0;
+pub const TCA_FQ_CODEL_XSTATS_CLASS: u32 = 1;
+
+pub const TCA_HTB_UNSPEC: u16 = 0;
+pub const TCA_HTB_PARMS: u16 = 1;
+pub const TCA_HTB_INIT: u16 = 2;
+pub const TCA_HTB_CTAB: u16 = 3;
+pub const TCA_HTB_RTAB: u16 = 4;
+pub const TCA_HTB_DIRECT_QLEN: u16 = 5;
+pub const TCA_HTB_RATE64: u16 = 6;
+pub const TCA_HTB_CEIL64: u16 = 7;
+pub const TCA_HTB_PAD: u16 = 8;
+pub const TCA_HTB_OFFLOAD: u16 = 9;
+
+pub const TCA_TBF_UNSPEC: u16 = 0;
+pub const TCA_TBF_PARMS: u16 = 1;
+pub const TCA_TBF_RTAB: u16 = 2;
+pub const TCA_TBF_PTAB: u16 = 3;
+pub const TCA_TBF_RATE64: u16 = 4;
+pub const TCA_TBF_PRATE64: u16 = 5;
+pub const TCA_TBF_BURST: u16 = 6;
+pub const TCA_TBF_PBURST: u16 = 7;
+pub const TCA_TBF_PAD: u16 = 8;
+
+pub const TCA_NETEM_UNSPEC: u16 = 0;
+pub const TCA_NETEM_CORR: u16 = 1;
+pub const TCA_NETEM_DELAY_DIST: u16 = 2;
+pub const TCA_NETEM_REORDER: u16 = 3;
+pub const TCA_NETEM_CORRUPT: u16 = 4;
+pub const TCA_NETEM_LOSS: u16 = 5;
+pub const TCA_NETEM_RATE: u16 = 6;
+pub const TCA_NETEM_ECN: u16 = 7;
+pub const TCA_NETEM_RATE64: u16 = 8;
+pub const TCA_NETEM_PAD: u16 = 9;
+pub const TCA_NETEM_LATENCY64: u16 = 10;
+pub const TCA_NETEM_JITTER64: u16 = 11;
+pub const TCA_NETEM_SLOT: u16 = 12;
+pub const TCA_NETEM_SLOT_DIST: u16 = 13;
+pub const TCA_NETEM_PRNG_SEED: u16 = 14;
+pub const NETEM_LOSS_UNSPEC: u16 = 0;
+pub const NETEM_LOSS_GI: u16 = 1;
+pub const NETEM_LOSS_GE: u16 = 2;
+
+pub const TC_PRIO_MAX: usize = 15;
+
+pub const TCA_ACT_UNSPEC: u16 = 0;
+pub const TCA_ACT_KIND: u16 = 1;
+pub const TCA_ACT_OPTIONS: u16 = 2;
+pub const TCA_ACT_INDEX: u16 = 3;
+pub const TCA_ACT_STATS: u16 = 4;
+pub const TCA_ACT_PAD: u16 = 5;
+pub const TCA_ACT_COOKIE: u16 = 6;
+pub const TCA_ACT_FLAGS: u16 = 7;
+pub const TCA_ACT_HW_STATS: u16 = 8;
+pub const TCA_ACT_USED_HW_STATS: u16 = 9;
+pub const TCA_ACT_IN_HW_COUNT: u16 = 10;
+pub const TCA_ACT_MAX_PRIO: u16 = 32;
+pub const TCA_ROOT_UNSPEC: u16 = 0;
+pub const TCA_ROOT_TAB: u16 = 1;
+pub const TCA_ROOT_FLAGS: u16 = 2;
+pub const TCA_ROOT_COUNT: u16 = 3;
+pub const TCA_ROOT_TIME_DELTA: u16 = 4;
+pub const TCA_FLAG_LARGE_DUMP_ON: u32 = 1;
+pub const TC_ACT_UNSPEC: i32 = -1;
+pub const TC_ACT_OK: i32 = 0;
+pub const TC_ACT_RECLASSIFY: i32 = 1;
+pub const TC_ACT_SHOT: i32 = 2;
+pub const TC_ACT_PIPE: i32 = 3;
+pub const TC_ACT_STOLEN: i32 = 4;
+pub const TC_ACT_QUEUED: i32 = 5;
+pub const TC_ACT_REPEAT: i32 = 6;
+pub const TC_ACT_REDIRECT: i32 = 7;
+pub const TC_ACT_TRAP: i32 = 8;
+pub const TC_ACT_JUMP: i32 = 0x1000_0000;
+pub const TC_ACT_GOTO_CHAIN: i32 = 0x2000_0000;
+
+pub const TCA_GACT_UNSPEC: u16 = 0;
+pub const TCA_GACT_TM: u16 = 1;
+pub const TCA_GACT_PARMS: u16 = 2;
+pub const TCA_GACT_PROB: u16 = 3;
+pub const TCA_GACT_PAD: u16 = 4;
+pub const PGACT_NONE: u16 = 0;
+pub const PGACT_NETRAND: u16 = 1;
+pub const PGACT_DETERM: u16 = 2;
+
+pub const TCA_MIRRED_UNSPEC: u16 = 0;
+pub const TCA_MIRRED_TM: u16 = 1;
+pub const TCA_MIRRED_PARMS: u16 = 2;
+pub const TCA_MIRRED_PAD: u16 = 3;
+pub const TCA_MIRRED_BLOCKID: u16 = 4;
+pub const TCA_EGRESS_REDIR: i32 = 1;
+pub const TCA_EGRESS_MIRROR: i32 = 2;
+pub const TCA_INGRESS_REDIR: i32 = 3;
+pub const TCA_INGRESS_MIRROR: i32 = 4;
+
+pub const TCA_POLICE_UNSPEC: u16 = 0;
+pub const TCA_POLICE_TBF: u16 = 1;
+pub const TCA_POLICE_RATE: u16 = 2;
+pub const TCA_POLICE_PEAKRATE: u16 = 3;
+pub const TCA_POLICE_AVRATE: u16 = 4;
+pub const TCA_POLICE_RESULT: u16 = 5;
+pub const TCA_POLICE_TM: u16 = 6;
+pub const TCA_POLICE_PAD: u16 = 7;
+pub const TCA_POLICE_RATE64: u16 = 8;
+pub const TCA_POLICE_PEAKRATE64: u16 = 9;
+pub const TCA_POLICE_PKTRATE64: u16 = 10;
+pub const TCA_POLICE_PKTBURST64: u16 = 11;
+
+pub const TCA_PEDIT_UNSPEC: u16 = 0;
+pub const TCA_PEDIT_TM: u16 = 1;
+pub const TCA_PEDIT_PARMS: u16 = 2;
+pub const TCA_PEDIT_PAD: u16 = 3;
+pub const TCA_PEDIT_PARMS_EX: u16 = 4;
+pub const TCA_PEDIT_KEYS_EX: u16 = 5;
+pub const TCA_PEDIT_KEY_EX: u16 = 6;
+
+pub const TCA_VLAN_UNSPEC: u16 = 0;
+pub const TCA_VLAN_TM: u16 = 1;
+pub const TCA_VLAN_PARMS: u16 = 2;
+pub const TCA_VLAN_PUSH_VLAN_ID: u16 = 3;
+pub const TCA_VLAN_PUSH_VLAN_PROTOCOL: u16 = 4;
+pub const TCA_VLAN_PAD: u16 = 5;
+pub const TCA_VLAN_PUSH_VLAN_PRIORITY: u16 = 6;
+pub const TCA_VLAN_PUSH_ETH_DST: u16 = 7;
+pub const TCA_VLAN_PUSH_ETH_SRC: u16 = 8;
+pub const TCA_VLAN_ACT_POP: i32 = 1;
+pub const TCA_VLAN_ACT_PUSH: i32 = 2;
+pub const TCA_VLAN_ACT_MODIFY: i32 = 3;
+pub const TCA_VLAN_ACT_POP_ETH: i32 = 4;
+pub const TCA_VLAN_ACT_PUSH_ETH: i32 = 5;
+
+pub const TCA_U32_UNSPEC: u16 = 0;
+pub const TCA_U32_CLASSID: u16 = 1;
+pub const TCA_U32_HASH: u16 = 2;
+pub const TCA_U32_LINK: u16 = 3;
+pub const TCA_U32_DIVISOR: u16 = 4;
+pub const TCA_U32_SEL: u16 = 5;
+pub const TCA_U32_POLICE: u16 = 6;
+pub const TCA_U32_ACT: u16 = 7;
+pub const TCA_U32_INDEV: u16 = 8;
+pub const TCA_U32_PCNT: u16 = 9;
+pub const TCA_U32_MARK: u16 = 10;
+pub const TCA_U32_FLAGS: u16 = 11;
+pub const TCA_U32_PAD: u16 = 12;
+pub const TC_U32_TERMINAL: u8 = 1;
+pub const TC_U32_OFFSET: u8 = 2;
+pub const TC_U32_VAROFFSET: u8 = 4;
+pub const TC_U32_EAT: u8 = 8;
+
+pub const TCA_MATCHALL_UNSPEC: u16 = 0;
+pub const TCA_MATCHALL_CLASSID: u16 = 1;
+pub const TCA_MATCHALL_ACT: u16 = 2;
+pub const TCA_MATCHALL_FLAGS: u16 = 3;
+pub const TCA_MATCHALL_PCNT: u16 = 4;
+pub const TCA_MATCHALL_PAD: u16 = 5;
+
+pub const TCA_BPF_UNSPEC: u16 = 0;
+pub const TCA_BPF_ACT: u16 = 1;
+pub const TCA_BPF_POLICE: u16 = 2;
+pub const TCA_BPF_CLASSID: u16 = 3;
+pub const TCA_BPF_OPS_LEN: u16 = 4;
+pub const TCA_BPF_OPS: u16 = 5;
+pub const TCA_BPF_FD: u16 = 6;
+pub const TCA_BPF_NAME: u16 = 7;
+pub const TCA_BPF_FLAGS: u16 = 8;
+pub const TCA_BPF_FLAGS_GEN: u16 = 9;
+pub const TCA_BPF_TAG: u16 = 10;
+pub const TCA_BPF_ID: u16 = 11;
+pub const TCA_BPF_FLAG_ACT_DIRECT: u32 = 1;
+
+pub const TCA_FLOWER_UNSPEC: u16 = 0;
+pub const TCA_FLOWER_CLASSID: u16 = 1;
+pub const TCA_FLOWER_INDEV: u16 = 2;
+pub const TCA_FLOWER_ACT: u16 = 3;
+pub const TCA_FLOWER_KEY_ETH_DST: u16 = 4;
+pub const TCA_FLOWER_KEY_ETH_DST_MASK: u16 = 5;
+pub const TCA_FLOWER_KEY_ETH_SRC: u16 = 6;
+pub const TCA_FLOWER_KEY_ETH_SRC_MASK: u16 = 7;
+pub const TCA_FLOWER_KEY_ETH_TYPE: u16 = 8;
+pub const TCA_FLOWER_KEY_IP_PROTO: u16 = 9;
+pub const TCA_FLOWER_KEY_IPV4_SRC: u16 = 10;
+pub const TCA_FLOWER_KEY_IPV4_SRC_MASK: u16 = 11;
+pub const TCA_FLOWER_KEY_IPV4_DST: u16 = 12;
+pub const TCA_FLOWER_KEY_IPV4_DST_MASK: u16 = 13;
+pub const TCA_FLOWER_KEY_IPV6_SRC: u16 = 14;
+pub const TCA_FLOWER_KEY_IPV6_SRC_MASK: u16 = 15;
+pub const TCA_FLOWER_KEY_IPV6_DST: u16 = 16;
+pub const TCA_FLOWER_KEY_IPV6_DST_MASK: u16 = 17;
+pub const TCA_FLOWER_KEY_TCP_SRC: u16 = 18;
+pub const TCA_FLOWER_KEY_TCP_DST: u16 = 19;
+pub const TCA_FLOWER_KEY_UDP_SRC: u16 = 20;
+pub const TCA_FLOWER_KEY_UDP_DST: u16 = 21;
+pub const TCA_FLOWER_FLAGS: u16 = 22;
+pub const TCA_FLOWER_KEY_VLAN_ID: u16 = 23;
+pub const TCA_FLOWER_KEY_VLAN_PRIO: u16 = 24;
+pub const TCA_FLOWER_KEY_VLAN_ETH_TYPE: u16 = 25;
+pub const TCA_FLOWER_KEY_ENC_KEY_ID: u16 = 26;
+pub const TCA_FLOWER_KEY_ENC_IPV4_SRC: u16 = 27;
+pub const TCA_FLOWER_KEY_ENC_IPV4_SRC_MASK: u16 = 28;
+pub const TCA_FLOWER_KEY_ENC_IPV4_DST: u16 = 29;
+pub const TCA_FLOWER_KEY_ENC_IPV4_DST_MASK: u16 = 30;
+pub const TCA_FLOWER_KEY_ENC_IPV6_SRC: u16 = 31;
+pub const TCA_FLOWER_KEY_ENC_IPV6_SRC_MASK: u16 = 32;
+pub const TCA_FLOWER_KEY_ENC_IPV6_DST: u16 = 33;
+pub const TCA_FLOWER_KEY_ENC_IPV6_DST_MASK: u16 = 34;
+pub const TCA_FLOWER_KEY_TCP_SRC_MASK: u16 = 35;
+pub const TCA_FLOWER_KEY_TCP_DST_MASK: u16 = 36;
+pub const TCA_FLOWER_KEY_UDP_SRC_MASK: u16 = 37;
+pub const TCA_FLOWER_KEY_UDP_DST_MASK: u16 = 38;
+pub const TCA_FLOWER_KEY_SCTP_SRC_MASK: u16 = 39;
+pub const TCA_FLOWER_KEY_SCTP_DST_MASK: u16 = 40;
+pub const TCA_FLOWER_KEY_SCTP_SRC: u16 = 41;
+pub const TCA_FLOWER_KEY_SCTP_DST: u16 = 42;
+pub const TCA_FLOWER_KEY_ENC_UDP_SRC_PORT: u16 = 43;
+pub const TCA_FLOWER_KEY_ENC_UDP_SRC_PORT_MASK: u16 = 44;
+pub const TCA_FLOWER_KEY_ENC_UDP_DST_PORT: u16 = 45;
+pub const TCA_FLOWER_KEY_ENC_UDP_DST_PORT_MASK: u16 = 46;
+pub const TCA_FLOWER_KEY_FLAGS: u16 = 47;
+pub const TCA_FLOWER_KEY_FLAGS_MASK: u16 = 48;
+pub const TCA_FLOWER_KEY_ICMPV4_CODE: u16 = 49;
+pub const TCA_FLOWER_KEY_ICMPV4_CODE_MASK: u16 = 50;
+pub const TCA_FLOWER_KEY_ICMPV4_TYPE: u16 = 51;
+pub const TCA_FLOWER_KEY_ICMPV4_TYPE_MASK: u16 = 52;
+pub const TCA_FLOWER_KEY_ICMPV6_CODE: u16 = 53;
+pub const TCA_FLOWER_KEY_ICMPV6_CODE_MASK: u16 = 54;
+pub const TCA_FLOWER_KEY_ICMPV6_TYPE: u16 = 55;
+pub const TCA_FLOWER_KEY_ICMPV6_TYPE_MASK: u16 = 56;
+pub const TCA_FLOWER_KEY_ARP_SIP: u16 = 57;
+pub const TCA_FLOWER_KEY_ARP_SIP_MASK: u16 = 58;
+pub const TCA_FLOWER_KEY_ARP_TIP: u16 = 59;
+pub const TCA_FLOWER_KEY_ARP_TIP_MASK: u16 = 60;
+pub const TCA_FLOWER_KEY_ARP_OP: u16 = 61;
+pub const TCA_FLOWER_KEY_ARP_OP_MASK: u16 = 62;
+pub const TCA_FLOWER_KEY_ARP_SHA: u16 = 63;
+pub const TCA_FLOWER_KEY_ARP_SHA_MASK: u16 = 64;
+pub const TCA_FLOWER_KEY_ARP_THA: u16 = 65;
+pub const TCA_FLOWER_KEY_ARP_THA_MASK: u16 = 66;
+pub const TCA_FLOWER_KEY_MPLS_TTL: u16 = 67;
+pub const TCA_FLOWER_KEY_MPLS_BOS: u16 = 68;
+pub const TCA_FLOWER_KEY_MPLS_TC: u16 = 69;
+pub const TCA_FLOWER_KEY_MPLS_LABEL: u16 = 70;
+pub const TCA_FLOWER_KEY_TCP_FLAGS: u16 = 71;
+pub const TCA_FLOWER_KEY_TCP_FLAGS_MASK: u16 = 72;
+pub const TCA_FLOWER_KEY_IP_TOS: u16 = 73;
+pub const TCA_FLOWER_KEY_IP_TOS_MASK: u16 = 74;
+pub const TCA_FLOWER_KEY_IP_TTL: u16 = 75;
+pub const TCA_FLOWER_KEY_IP_TTL_MASK: u16 = 76;
+pub const TCA_FLOWER_KEY_CVLAN_ID: u16 = 77;
+pub const TCA_FLOWER_KEY_CVLAN_PRIO: u16 = 78;
+pub const TCA_FLOWER_KEY_CVLAN_ETH_TYPE: u16 = 79;
+pub const TCA_FLOWER_KEY_ENC_IP_TOS: u16 = 80;
+pub const TCA_FLOWER_KEY_ENC_IP_TOS_MASK: u16 = 81;
+pub const TCA_FLOWER_KEY_ENC_IP_TTL: u16 = 82;
+pub const TCA_FLOWER_KEY_ENC_IP_TTL_MASK: u16 = 83;
+pub const TCA_FLOWER_KEY_ENC_OPTS: u16 = 84;
+pub const TCA_FLOWER_KEY_ENC_OPTS_MASK: u16 = 85;
+pub const TCA_FLOWER_IN_HW_COUNT: u16 = 86;
+
+pub const TCA_CLS_FLAGS_SKIP_HW: u32 = 1;
+pub const TCA_CLS_FLAGS_SKIP_SW: u32 = 2;
+pub const TCA_CLS_FLAGS_IN_HW: u32 = 4;
+pub const TCA_CLS_FLAGS_NOT_IN_HW: u32 = 8;
+pub const TCA_CLS_FLAGS_VERBOSE: u32 = 16;
+
 pub const NDTA_UNSPEC: u16 = 0;
 pub const NDTA_NAME: u16 = 1;
 pub const NDTA_THRESH1: u16 = 2;
@@ -341,6 +672,48 @@ pub const NDTA_STATS: u16 = 7;
 pub const NDTA_GC_INTERVAL: u16 = 8;
 pub const NDTA_PAD: u16 = 9;
 
+pub const NDTPA_UNSPEC: u16 = 0;
+pub const NDTPA_IFINDEX: u16 = 1;
+pub const NDTPA_REFCNT: u16 = 2;
+pub const NDTPA_REACHABLE_TIME: u16 = 3;
+pub const NDTPA_BASE_REACHABLE_TIME: u16 = 4;
+pub const NDTPA_RETRANS_TIME: u16 = 5;
+pub const NDTPA_GC_STALETIME: u16 = 6;
+pub const NDTPA_DELAY_PROBE_TIME: u16 = 7;
+pub const NDTPA_QUEUE_LEN: u16 = 8;
+pub const NDTPA_APP_PROBES: u16 = 9;
+pub const NDTPA_UCAST_PROBES: u16 = 10;
+pub const NDTPA_MCAST_PROBES: u16 = 11;
+pub const NDTPA_ANYCAST_DELAY: u16 = 12;
+pub const NDTPA_PROXY_DELAY: u16 = 13;
+pub const NDTPA_PROXY_QLEN: u16 = 14;
+pub const NDTPA_LOCKTIME: u16 = 15;
+pub const NDTPA_QUEUE_LENBYTES: u16 = 16;
+pub const NDTPA_MCAST_REPROBES: u16 = 17;
+pub const NDTPA_PAD: u16 = 18;
+pub const NDTPA_INTERVAL_PROBE_TIME_MS: u16 = 19;
+
+pub const NETCONFA_UNSPEC: u16 = 0;
+pub const NETCONFA_IFINDEX: u16 = 1;
+pub const NETCONFA_FORWARDING: u16 = 2;
+pub const NETCONFA_RP_FILTER: u16 = 3;
+pub const NETCONFA_MC_FORWARDING: u16 = 4;
+pub const NETCONFA_PROXY_NEIGH: u16 = 5;
+pub const NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN: u16 = 6;
+pub const NETCONFA_INPUT: u16 = 7;
+pub const NETCONFA_BC_FORWARDING: u16 = 8;
+
+/// Value of `NETCONFA_IFINDEX` for the configuration of all the interfaces
+/// (`net.ipv4.conf.all`)
+pub const NETCONFA_IFINDEX_ALL: i32 = -1;
+/// Value of `NETCONFA_IFINDEX` for the default configuration of new interfaces
+/// (`net.ipv4.conf.default`)
+pub const NETCONFA_IFINDEX_DEFAULT: i32 = -2;
+
+pub const IFAL_UNSPEC: u16 = 0;
+pub const IFAL_ADDRESS: u16 = 1;
+pub const IFAL_LABEL: u16 = 2;
+
 pub const RTA_UNSPEC: u16 = 0;
 pub const RTA_DST: u16 = 1;
 pub const RTA_SRC: u16 = 2;
@@ -368,6 +741,109 @@ pub const RTA_EXPIRES: u16 = 23;
 pub const RTA_PAD: u16 = 24;
 pub const RTA_UID: u16 = 25;
 pub const RTA_TTL_PROPAGATE: u16 = 26;
+pub const RTA_IP_PROTO: u16 = 27;
+pub const RTA_SPORT: u16 = 28;
+pub const RTA_DPORT: u16 = 29;
+pub const RTA_NH_ID: u16 = 30;
+
+pub const LWTUNNEL_ENCAP_NONE: u16 = 0;
+pub const LWTUNNEL_ENCAP_MPLS: u16 = 1;
+pub const LWTUNNEL_ENCAP_IP: u16 = 2;
+pub const LWTUNNEL_ENCAP_ILA: u16 = 3;
+pub const LWTUNNEL_ENCAP_IP6: u16 = 4;
+pub const LWTUNNEL_ENCAP_SEG6: u16 = 5;
+pub const LWTUNNEL_ENCAP_BPF: u16 = 6;
+pub const LWTUNNEL_ENCAP_SEG6_LOCAL: u16 = 7;
+pub const LWTUNNEL_ENCAP_RPL: u16 = 8;
+pub const LWTUNNEL_ENCAP_IOAM6: u16 = 9;
+pub const LWTUNNEL_ENCAP_XFRM: u16 = 10;
+
+pub const MPLS_IPTUNNEL_UNSPEC: u16 = 0;
+pub const MPLS_IPTUNNEL_DST: u16 = 1;
+pub const MPLS_IPTUNNEL_TTL: u16 = 2;
+
+pub const MPLS_LABEL_IPV4NULL: u32 = 0;
+pub const MPLS_LABEL_RTALERT: u32 = 1;
+pub const MPLS_LABEL_IPV6NULL: u32 = 2;
+pub const MPLS_LABEL_IMPLNULL: u32 = 3;
+pub const MPLS_LABEL_ENTROPY: u32 = 7;
+pub const MPLS_LABEL_GAL: u32 = 13;
+pub const MPLS_LABEL_OAMALERT: u32 = 14;
+pub const MPLS_LABEL_EXTENSION: u32 = 15;
+pub const MPLS_LABEL_FIRST_UNRESERVED: u32 = 16;
+
+pub const LWTUNNEL_IP_UNSPEC: u16 = 0;
+pub const LWTUNNEL_IP_ID: u16 = 1;
+pub const LWTUNNEL_IP_DST: u16 = 2;
+pub const LWTUNNEL_IP_SRC: u16 = 3;
+pub const LWTUNNEL_IP_TTL: u16 = 4;
+pub const LWTUNNEL_IP_TOS: u16 = 5;
+pub const LWTUNNEL_IP_FLAGS: u16 = 6;
+pub const LWTUNNEL_IP_PAD: u16 = 7;
+pub const LWTUNNEL_IP_OPTS: u16 = 8;
+
+pub const LWTUNNEL_IP6_UNSPEC: u16 = 0;
+pub const LWTUNNEL_IP6_ID: u16 = 1;
+pub const LWTUNNEL_IP6_DST: u16 = 2;
+pub const LWTUNNEL_IP6_SRC: u16 = 3;
+pub const LWTUNNEL_IP6_HOPLIMIT: u16 = 4;
+pub const LWTUNNEL_IP6_TC: u16 = 5;
+pub const LWTUNNEL_IP6_FLAGS: u16 = 6;
+pub const LWTUNNEL_IP6_PAD: u16 = 7;
+pub const LWTUNNEL_IP6_OPTS: u16 = 8;
+
+pub const SEG6_IPTUNNEL_UNSPEC: u16 = 0;
+pub const SEG6_IPTUNNEL_SRH: u16 = 1;
+
+pub const SEG6_IPTUN_MODE_INLINE: u32 = 0;
+pub const SEG6_IPTUN_MODE_ENCAP: u32 = 1;
+pub const SEG6_IPTUN_MODE_L2ENCAP: u32 = 2;
+pub const SEG6_IPTUN_MODE_ENCAP_RED: u32 = 3;
+pub const SEG6_IPTUN_MODE_L2ENCAP_RED: u32 = 4;
+
+pub const SEG6_LOCAL_UNSPEC: u16 = 0;
+pub const SEG6_LOCAL_ACTION: u16 = 1;
+pub const SEG6_LOCAL_SRH: u16 = 2;
+pub const SEG6_LOCAL_TABLE: u16 = 3;
+pub const SEG6_LOCAL_NH4: u16 = 4;
+pub const SEG6_LOCAL_NH6: u16 = 5;
+pub const SEG6_LOCAL_IIF: u16 = 6;
+pub const SEG6_LOCAL_OIF: u16 = 7;
+pub const SEG6_LOCAL_BPF: u16 = 8;
+pub const SEG6_LOCAL_VRFTABLE: u16 = 9;
+pub const SEG6_LOCAL_COUNTERS: u16 = 10;
+pub const SEG6_LOCAL_FLAVORS: u16 = 11;
+
+pub const SEG6_LOCAL_ACTION_UNSPEC: u32 = 0;
+pub const SEG6_LOCAL_ACTION_END: u32 = 1;
+pub const SEG6_LOCAL_ACTION_END_X: u32 = 2;
+pub const SEG6_LOCAL_ACTION_END_T: u32 = 3;
+pub const SEG6_LOCAL_ACTION_END_DX2: u32 = 4;
+pub const SEG6_LOCAL_ACTION_END_DX6: u32 = 5;
+pub const SEG6_LOCAL_ACTION_END_DX4: u32 = 6;
+pub const SEG6_LOCAL_ACTION_END_DT6: u32 = 7;
+pub const SEG6_LOCAL_ACTION_END_DT4: u32 = 8;
+pub const SEG6_LOCAL_ACTION_END_B6: u32 = 9;
+pub const SEG6_LOCAL_ACTION_END_B6_ENCAP: u32 = 10;
+pub const SEG6_LOCAL_ACTION_END_BM: u32 = 11;
+pub const SEG6_LOCAL_ACTION_END_S: u32 = 12;
+pub const SEG6_LOCAL_ACTION_END_AS: u32 = 13;
+pub const SEG6_LOCAL_ACTION_END_AM: u32 = 14;
+pub const SEG6_LOCAL_ACTION_END_BPF: u32 = 15;
+pub const SEG6_LOCAL_ACTION_END_DT46: u32 = 16;
+
+/// Routing type of the IPv6 Segment Routing Header
+pub const IPV6_SRCRT_TYPE_4: u8 = 4;
+
+pub const LWT_BPF_UNSPEC: u16 = 0;
+pub const LWT_BPF_IN: u16 = 1;
+pub const LWT_BPF_OUT: u16 = 2;
+pub const LWT_BPF_XMIT: u16 = 3;
+pub const LWT_BPF_XMIT_HEADROOM: u16 = 4;
+
+pub const LWT_BPF_PROG_UNSPEC: u16 = 0;
+pub const LWT_BPF_PROG_FD: u16 = 1;
+pub const LWT_BPF_PROG_NAME: u16 = 2;
 
 pub const RTAX_UNSPEC: u16 = 0;
 pub const RTAX_LOCK: u16 = 1;
@@ -395,16 +871,133 @@ pub const IFLA_INFO_XSTATS: u16 = 3;
 pub const IFLA_INFO_SLAVE_KIND: u16 = 4;
 pub const IFLA_INFO_SLAVE_DATA: u16 = 5;
 // Bridge flags
-pub const IFLA_BRIDGE_FLAGS: u16 = 47;
+pub const IFLA_BRIDGE_FLAGS: u16 = 0;
 pub const BRIDGE_FLAGS_MASTER: u16 = 1; /* Bridge command to/from master */
 pub const BRIDGE_FLAGS_SELF: u16 = 2; /* Bridge command to/from lowerdev */
 
-pub const IFLA_BRIDGE_VLAN_INFO: u16 = 48;
+pub const IFLA_BRIDGE_MODE: u16 = 1;
+pub const BRIDGE_MODE_VEB: u16 = 0;
+pub const BRIDGE_MODE_VEPA: u16 = 1;
+pub const BRIDGE_MODE_UNDEF: u16 = 0xffff;
+
+pub const IFLA_BRIDGE_VLAN_INFO: u16 = 2;
 pub const BRIDGE_VLAN_INFO_MASTER: u16 = 1;
-pub const BRIDGE_VLAN_INFO_PVID: u16 = 4;
-pub const BRIDGE_VLAN_INFO_UNTAGGED: u16 = 8;
-pub const BRIDGE_VLAN_INFO_RANGE_BEGIN: u16 = 16;
-pub const BRIDGE_VLAN_INFO_RANGE_END: u16 = 32;
+pub const BRIDGE_VLAN_INFO_PVID: u16 = 2;
+pub const BRIDGE_VLAN_INFO_UNTAGGED: u16 = 4;
+pub const BRIDGE_VLAN_INFO_RANGE_BEGIN: u16 = 8;
+pub const BRIDGE_VLAN_INFO_RANGE_END: u16 = 16;
+pub const BRIDGE_VLAN_INFO_BRENTRY: u16 = 32;
+pub const BRIDGE_VLAN_INFO_ONLINK: u16 = 64;
+
+pub const IFLA_BRIDGE_VLAN_TUNNEL_INFO: u16 = 3;
+
+pub const IFLA_BRPORT_UNSPEC: u16 = 0;
+pub const IFLA_BRPORT_STATE: u16 = 1;
+pub const IFLA_BRPORT_PRIORITY: u16 = 2;
+pub const IFLA_BRPORT_COST: u16 = 3;
+pub const IFLA_BRPORT_MODE: u16 = 4;
+pub const IFLA_BRPORT_GUARD: u16 = 5;
+pub const IFLA_BRPORT_PROTECT: u16 = 6;
+pub const IFLA_BRPORT_FAST_LEAVE: u16 = 7;
+pub const IFLA_BRPORT_LEARNING: u16 = 8;
+pub const IFLA_BRPORT_UNICAST_FLOOD: u16 = 9;
+pub const IFLA_BRPORT_PROXYARP: u16 = 10;
+pub const IFLA_BRPORT_LEARNING_SYNC: u16 = 11;
+pub const IFLA_BRPORT_PROXYARP_WIFI: u16 = 12;
+pub const IFLA_BRPORT_ROOT_ID: u16 = 13;
+pub const IFLA_BRPORT_BRIDGE_ID: u16 = 14;
+pub const IFLA_BRPORT_DESIGNATED_PORT: u16 = 15;
+pub const IFLA_BRPORT_DESIGNATED_COST: u16 = 16;
+pub const IFLA_BRPORT_ID: u16 = 17;
+pub const IFLA_BRPORT_NO: u16 = 18;
+pub const IFLA_BRPORT_TOPOLOGY_CHANGE_ACK: u16 = 19;
+pub const IFLA_BRPORT_CONFIG_PENDING: u16 = 20;
+pub const IFLA_BRPORT_MESSAGE_AGE_TIMER: u16 = 21;
+pub const IFLA_BRPORT_FORWARD_DELAY_TIMER: u16 = 22;
+pub const IFLA_BRPORT_HOLD_TIMER: u16 = 23;
+pub const IFLA_BRPORT_FLUSH: u16 = 24;
+pub const IFLA_BRPORT_MULTICAST_ROUTER: u16 = 25;
+pub const IFLA_BRPORT_PAD: u16 = 26;
+pub const IFLA_BRPORT_MCAST_FLOOD: u16 = 27;
+pub const IFLA_BRPORT_MCAST_TO_UCAST: u16 = 28;
+pub const IFLA_BRPORT_VLAN_TUNNEL: u16 = 29;
+pub const IFLA_BRPORT_BCAST_FLOOD: u16 = 30;
+pub const IFLA_BRPORT_GROUP_FWD_MASK: u16 = 31;
+pub const IFLA_BRPORT_NEIGH_SUPPRESS: u16 = 32;
+pub const IFLA_BRPORT_ISOLATED: u16 = 33;
+pub const IFLA_BRPORT_BACKUP_PORT: u16 = 34;
+
+pub const BR_STATE_DISABLED: u8 = 0;
+pub const BR_STATE_LISTENING: u8 = 1;
+pub const BR_STATE_LEARNING: u8 = 2;
+pub const BR_STATE_FORWARDING: u8 = 3;
+pub const BR_STATE_BLOCKING: u8 = 4;
+
+pub const MDBA_UNSPEC: u16 = 0;
+pub const MDBA_MDB: u16 = 1;
+pub const MDBA_ROUTER: u16 = 2;
+
+pub const MDBA_MDB_UNSPEC: u16 = 0;
+pub const MDBA_MDB_ENTRY: u16 = 1;
+
+pub const MDBA_MDB_ENTRY_UNSPEC: u16 = 0;
+pub const MDBA_MDB_ENTRY_INFO: u16 = 1;
+
+pub const MDBA_MDB_EATTR_UNSPEC: u16 = 0;
+pub const MDBA_MDB_EATTR_TIMER: u16 = 1;
+pub const MDBA_MDB_EATTR_SRC_LIST: u16 = 2;
+pub const MDBA_MDB_EATTR_GROUP_MODE: u16 = 3;
+pub const MDBA_MDB_EATTR_SOURCE: u16 = 4;
+pub const MDBA_MDB_EATTR_RTPROT: u16 = 5;
+
+pub const MDBA_MDB_SRCLIST_UNSPEC: u16 = 0;
+pub const MDBA_MDB_SRCLIST_ENTRY: u16 = 1;
+
+pub const MDBA_MDB_SRCATTR_UNSPEC: u16 = 0;
+pub const MDBA_MDB_SRCATTR_ADDRESS: u16 = 1;
+pub const MDBA_MDB_SRCATTR_TIMER: u16 = 2;
+
+pub const MDBA_ROUTER_UNSPEC: u16 = 0;
+pub const MDBA_ROUTER_PORT: u16 = 1;
+
+pub const MDBA_ROUTER_PATTR_UNSPEC: u16 = 0;
+pub const MDBA_ROUTER_PATTR_TIMER: u16 = 1;
+pub const MDBA_ROUTER_PATTR_TYPE: u16 = 2;
+pub const MDBA_ROUTER_PATTR_INET_TIMER: u16 = 3;
+pub const MDBA_ROUTER_PATTR_INET6_TIMER: u16 = 4;
+pub const MDBA_ROUTER_PATTR_VID: u16 = 5;
+
+pub const MDBA_SET_ENTRY_UNSPEC: u16 = 0;
+pub const MDBA_SET_ENTRY: u16 = 1;
+pub const MDBA_SET_ENTRY_ATTRS: u16 = 2;
+
+pub const MDBE_ATTR_UNSPEC: u16 = 0;
+pub const MDBE_ATTR_SOURCE: u16 = 1;
+pub const MDBE_ATTR_SRC_LIST: u16 = 2;
+pub const MDBE_ATTR_GROUP_MODE: u16 = 3;
+pub const MDBE_ATTR_RTPROT: u16 = 4;
+
+pub const MDBE_SRC_LIST_UNSPEC: u16 = 0;
+pub const MDBE_SRC_LIST_ENTRY: u16 = 1;
+
+pub const MDBE_SRCATTR_UNSPEC: u16 = 0;
+pub const MDBE_SRCATTR_ADDRESS: u16 = 1;
+
+pub const MDB_TEMPORARY: u8 = 0;
+pub const MDB_PERMANENT: u8 = 1;
+
+pub const MDB_FLAGS_OFFLOAD: u8 = 1;
+pub const MDB_FLAGS_FAST_LEAVE: u8 = 2;
+pub const MDB_FLAGS_STAR_EXCL: u8 = 4;
+pub const MDB_FLAGS_BLOCKED: u8 = 8;
+
+pub const MDB_RTR_TYPE_DISABLED: u8 = 0;
+pub const MDB_RTR_TYPE_TEMP_QUERY: u8 = 1;
+pub const MDB_RTR_TYPE_PERM: u8 = 2;
+pub const MDB_RTR_TYPE_TEMP: u8 = 3;
+
+pub const MCAST_EXCLUDE: u8 = 0;
+pub const MCAST_INCLUDE: u8 = 1;
 
 pub const IFLA_BR_UNSPEC: u16 = 0;
 pub const IFLA_BR_FORWARD_DELAY: u16 = 1;
@@ -468,6 +1061,19 @@ pub const IFLA_VLAN_INGRESS_QOS: u16 = 4;
 pub const IFLA_VLAN_PROTOCOL: u16 = 5;
 pub const IFLA_VRF_UNSPEC: u16 = 0;
 pub const IFLA_VRF_TABLE: u16 = 1;
+pub const IFLA_TUN_UNSPEC: u16 = 0;
+pub const IFLA_TUN_OWNER: u16 = 1;
+pub const IFLA_TUN_GROUP: u16 = 2;
+pub const IFLA_TUN_TYPE: u16 = 3;
+pub const IFLA_TUN_PI: u16 = 4;
+pub const IFLA_TUN_VNET_HDR: u16 = 5;
+pub const IFLA_TUN_PERSIST: u16 = 6;
+pub const IFLA_TUN_MULTI_QUEUE: u16 = 7;
+pub const IFLA_TUN_NUM_QUEUES: u16 = 8;
+pub const IFLA_TUN_NUM_DISABLED_QUEUES: u16 = 9;
+// Values of IFLA_TUN_TYPE
+pub const IFF_TUN: u8 = 1;
+pub const IFF_TAP: u8 = 2;
 pub const IFLA_IPVLAN_UNSPEC: u16 = 0;
 pub const IFLA_IPVLAN_MODE: u16 = 1;
 pub const IFLA_IPVLAN_FLAGS: u16 = 2;
@@ -478,6 +1084,106 @@ pub const IFLA_IPOIB_UMCAST: u16 = 3;
 pub const VETH_INFO_UNSPEC: u16 = 0;
 pub const VETH_INFO_PEER: u16 = 1;
 
+pub const IFLA_BOND_UNSPEC: u16 = 0;
+pub const IFLA_BOND_MODE: u16 = 1;
+pub const IFLA_BOND_ACTIVE_SLAVE: u16 = 2;
+pub const IFLA_BOND_MIIMON: u16 = 3;
+pub const IFLA_BOND_UPDELAY: u16 = 4;
+pub const IFLA_BOND_DOWNDELAY: u16 = 5;
+pub const IFLA_BOND_USE_CARRIER: u16 = 6;
+pub const IFLA_BOND_ARP_INTERVAL: u16 = 7;
+pub const IFLA_BOND_ARP_IP_TARGET: u16 = 8;
+pub const IFLA_BOND_ARP_VALIDATE: u16 = 9;
+pub const IFLA_BOND_ARP_ALL_TARGETS: u16 = 10;
+pub const IFLA_BOND_PRIMARY: u16 = 11;
+pub const IFLA_BOND_PRIMARY_RESELECT: u16 = 12;
+pub const IFLA_BOND_FAIL_OVER_MAC: u16 = 13;
+pub const IFLA_BOND_XMIT_HASH_POLICY: u16 = 14;
+pub const IFLA_BOND_RESEND_IGMP: u16 = 15;
+pub const IFLA_BOND_NUM_PEER_NOTIF: u16 = 16;
+pub const IFLA_BOND_ALL_SLAVES_ACTIVE: u16 = 17;
+pub const IFLA_BOND_MIN_LINKS: u16 = 18;
+pub const IFLA_BOND_LP_INTERVAL: u16 = 19;
+pub const IFLA_BOND_PACKETS_PER_SLAVE: u16 = 20;
+pub const IFLA_BOND_AD_LACP_RATE: u16 = 21;
+pub const IFLA_BOND_AD_SELECT: u16 = 22;
+pub const IFLA_BOND_AD_INFO: u16 = 23;
+pub const IFLA_BOND_AD_ACTOR_SYS_PRIO: u16 = 24;
+pub const IFLA_BOND_AD_USER_PORT_KEY: u16 = 25;
+pub const IFLA_BOND_AD_ACTOR_SYSTEM: u16 = 26;
+pub const IFLA_BOND_TLB_DYNAMIC_LB: u16 = 27;
+pub const IFLA_BOND_PEER_NOTIF_DELAY: u16 = 28;
+pub const IFLA_BOND_AD_LACP_ACTIVE: u16 = 29;
+pub const IFLA_BOND_MISSED_MAX: u16 = 30;
+pub const IFLA_BOND_NS_IP6_TARGET: u16 = 31;
+
+pub const IFLA_BOND_AD_INFO_UNSPEC: u16 = 0;
+pub const IFLA_BOND_AD_INFO_AGGREGATOR: u16 = 1;
+pub const IFLA_BOND_AD_INFO_NUM_PORTS: u16 = 2;
+pub const IFLA_BOND_AD_INFO_ACTOR_KEY: u16 = 3;
+pub const IFLA_BOND_AD_INFO_PARTNER_KEY: u16 = 4;
+pub const IFLA_BOND_AD_INFO_PARTNER_MAC: u16 = 5;
+
+pub const IFLA_BOND_SLAVE_UNSPEC: u16 = 0;
+pub const IFLA_BOND_SLAVE_STATE: u16 = 1;
+pub const IFLA_BOND_SLAVE_MII_STATUS: u16 = 2;
+pub const IFLA_BOND_SLAVE_LINK_FAILURE_COUNT: u16 = 3;
+pub const IFLA_BOND_SLAVE_PERM_HWADDR: u16 = 4;
+pub const IFLA_BOND_SLAVE_QUEUE_ID: u16 = 5;
+pub const IFLA_BOND_SLAVE_AD_AGGREGATOR_ID: u16 = 6;
+pub const IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE: u16 = 7;
+pub const IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE: u16 = 8;
+pub const IFLA_BOND_SLAVE_PRIO: u16 = 9;
+
+pub const BOND_MODE_ROUNDROBIN: u8 = 0;
+pub const BOND_MODE_ACTIVEBACKUP: u8 = 1;
+pub const BOND_MODE_XOR: u8 = 2;
+pub const BOND_MODE_BROADCAST: u8 = 3;
+pub const BOND_MODE_8023AD: u8 = 4;
+pub const BOND_MODE_TLB: u8 = 5;
+pub const BOND_MODE_ALB: u8 = 6;
+
+pub const BOND_XMIT_POLICY_LAYER2: u8 = 0;
+pub const BOND_XMIT_POLICY_LAYER34: u8 = 1;
+pub const BOND_XMIT_POLICY_LAYER23: u8 = 2;
+pub const BOND_XMIT_POLICY_ENCAP23: u8 = 3;
+pub const BOND_XMIT_POLICY_ENCAP34: u8 = 4;
+pub const BOND_XMIT_POLICY_VLAN_SRCMAC: u8 = 5;
+
+pub const BOND_AD_STABLE: u8 = 0;
+pub const BOND_AD_BANDWIDTH: u8 = 1;
+pub const BOND_AD_COUNT: u8 = 2;
+
+pub const AD_LACP_SLOW: u8 = 0;
+pub const AD_LACP_FAST: u8 = 1;
+
+pub const BOND_PRI_RESELECT_ALWAYS: u8 = 0;
+pub const BOND_PRI_RESELECT_BETTER: u8 = 1;
+pub const BOND_PRI_RESELECT_FAILURE: u8 = 2;
+
+pub const BOND_FOM_NONE: u8 = 0;
+pub const BOND_FOM_ACTIVE: u8 = 1;
+pub const BOND_FOM_FOLLOW: u8 = 2;
+
+pub const BOND_ARP_VALIDATE_NONE: u32 = 0;
+pub const BOND_ARP_VALIDATE_ACTIVE: u32 = 1;
+pub const BOND_ARP_VALIDATE_BACKUP: u32 = 2;
+pub const BOND_ARP_VALIDATE_ALL: u32 = 3;
+pub const BOND_ARP_FILTER: u32 = 4;
+pub const BOND_ARP_FILTER_ACTIVE: u32 = 5;
+pub const BOND_ARP_FILTER_BACKUP: u32 = 6;
+
+pub const BOND_OPT_ARP_ALL_TARGETS_ANY: u32 = 0;
+pub const BOND_OPT_ARP_ALL_TARGETS_ALL: u32 = 1;
+
+pub const BOND_STATE_ACTIVE: u8 = 0;
+pub const BOND_STATE_BACKUP: u8 = 1;
+
+pub const BOND_LINK_UP: u8 = 0;
+pub const BOND_LINK_FAIL: u8 = 1;
+pub const BOND_LINK_DOWN: u8 = 2;
+pub const BOND_LINK_BACK: u8 = 3;
+
 pub const ARPHRD_NETROM: u16 = 0;
 pub const ARPHRD_ETHER: u16 = 1;
 pub const ARPHRD_EETHER: u16 = 2;
@@ -689,6 +1395,20 @@ pub const NDA_IFINDEX: u16 = 8;
 pub const NDA_MASTER: u16 = 9;
 pub const NDA_LINK_NETNSID: u16 = 10;
 pub const NDA_SRC_VNI: u16 = 11;
+pub const NDA_PROTOCOL: u16 = 12;
+pub const NDA_NH_ID: u16 = 13;
+pub const NDA_FDB_EXT_ATTRS: u16 = 14;
+pub const NDA_FLAGS_EXT: u16 = 15;
+pub const NDA_NDM_STATE_MASK: u16 = 16;
+pub const NDA_NDM_FLAGS_MASK: u16 = 17;
+
+pub const NFEA_UNSPEC: u16 = 0;
+pub const NFEA_ACTIVITY_NOTIFY: u16 = 1;
+pub const NFEA_DONT_REFRESH: u16 = 2;
+
+// Values of `NFEA_ACTIVITY_NOTIFY`
+pub const FDB_NOTIFY_BIT: u8 = 1;
+pub const FDB_NOTIFY_INACTIVE_BIT: u8 = 2;
 
 /// see `https://github.com/torvalds/linux/blob/master/include/uapi/linux/fib_rules.h`
 
@@ -763,8 +1483,6 @@ pub const FIB_RULE_OIF_DETACHED: u32 = 10;
 pub const FIB_RULE_FIND_SADDR: u32 = 10000;
 
 // pub const MACVLAN_FLAG_NOPROMISC: int = 1;
-// pub const IPVLAN_F_PRIVATE: int = 1;
-// pub const IPVLAN_F_VEPA: int = 2;
 // pub const MAX_VLAN_LIST_LEN: int = 1;
 // pub const PORT_PROFILE_MAX: int = 40;
 // pub const PORT_UUID_MAX: int = 16;
@@ -804,14 +1522,13 @@ pub const RTNH_F_UNRESOLVED: u8 = 32;
 // pub const RTNH_COMPARE_MASK: int = 25;
 // pub const RTNH_ALIGNTO: int = 4;
 // pub const RTNETLINK_HAVE_PEERINFO: int = 1;
-// pub const RTAX_FEATURE_ECN: int = 1;
-// pub const RTAX_FEATURE_SACK: int = 2;
-// pub const RTAX_FEATURE_TIMESTAMP: int = 4;
-// pub const RTAX_FEATURE_ALLFRAG: int = 8;
-// pub const RTAX_FEATURE_MASK: int = 15;
+pub const RTAX_FEATURE_ECN: u32 = 1;
+pub const RTAX_FEATURE_SACK: u32 = 2;
+pub const RTAX_FEATURE_TIMESTAMP: u32 = 4;
+pub const RTAX_FEATURE_ALLFRAG: u32 = 8;
+pub const RTAX_FEATURE_MASK: u32 = 15;
 // #[allow(overflowing_literals)]
 // pub const TCM_IFINDEX_MAGIC_BLOCK: int = 0xffff_ffff;
-// pub const TCA_FLAG_LARGE_DUMP_ON: int = 1;
 
 pub const RTEXT_FILTER_VF: u32 = 1;
 pub const RTEXT_FILTER_BRVLAN: u32 = 2;
@@ -829,40 +1546,6 @@ pub const RTEXT_FILTER_SKIP_STATS: u32 = 8;
 // pub const BRIDGE_MODE_UNSPEC: int = 0;
 // pub const BRIDGE_MODE_HAIRPIN: int = 1;
 //
-// pub const IFLA_BRPORT_UNSPEC: int = 0;
-// pub const IFLA_BRPORT_STATE: int = 1;
-// pub const IFLA_BRPORT_PRIORITY: int = 2;
-// pub const IFLA_BRPORT_COST: int = 3;
-// pub const IFLA_BRPORT_MODE: int = 4;
-// pub const IFLA_BRPORT_GUARD: int = 5;
-// pub const IFLA_BRPORT_PROTECT: int = 6;
-// pub const IFLA_BRPORT_FAST_LEAVE: int = 7;
-// pub const IFLA_BRPORT_LEARNING: int = 8;
-// pub const IFLA_BRPORT_UNICAST_FLOOD: int = 9;
-// pub const IFLA_BRPORT_PROXYARP: int = 10;
-// pub const IFLA_BRPORT_LEARNING_SYNC: int = 11;
-// pub const IFLA_BRPORT_PROXYARP_WIFI: int = 12;
-// pub const IFLA_BRPORT_ROOT_ID: int = 13;
-// pub const IFLA_BRPORT_BRIDGE_ID: int = 14;
-// pub const IFLA_BRPORT_DESIGNATED_PORT: int = 15;
-// pub const IFLA_BRPORT_DESIGNATED_COST: int = 16;
-// pub const IFLA_BRPORT_ID: int = 17;
-// pub const IFLA_BRPORT_NO: int = 18;
-// pub const IFLA_BRPORT_TOPOLOGY_CHANGE_ACK: int = 19;
-// pub const IFLA_BRPORT_CONFIG_PENDING: int = 20;
-// pub const IFLA_BRPORT_MESSAGE_AGE_TIMER: int = 21;
-// pub const IFLA_BRPORT_FORWARD_DELAY_TIMER: int = 22;
-// pub const IFLA_BRPORT_HOLD_TIMER: int = 23;
-// pub const IFLA_BRPORT_FLUSH: int = 24;
-// pub const IFLA_BRPORT_MULTICAST_ROUTER: int = 25;
-// pub const IFLA_BRPORT_PAD: int = 26;
-// pub const IFLA_BRPORT_MCAST_FLOOD: int = 27;
-// pub const IFLA_BRPORT_MCAST_TO_UCAST: int = 28;
-// pub const IFLA_BRPORT_VLAN_TUNNEL: int = 29;
-// pub const IFLA_BRPORT_BCAST_FLOOD: int = 30;
-// pub const IFLA_BRPORT_GROUP_FWD_MASK: int = 31;
-// pub const IFLA_BRPORT_NEIGH_SUPPRESS: int = 32;
-//
 // pub const IFLA_VLAN_QOS_UNSPEC: int = 0;
 // pub const IFLA_VLAN_QOS_MAPPING: int = 1;
 //
@@ -889,38 +1572,46 @@ pub const MACVLAN_MODE_SOURCE: u32 = 16;
 // pub const IFLA_VRF_UNSPEC: int = 0;
 // pub const IFLA_VRF_TABLE: int = 1;
 //
-// pub const IFLA_VRF_PORT_UNSPEC: int = 0;
-// pub const IFLA_VRF_PORT_TABLE: int = 1;
+pub const IFLA_VRF_PORT_UNSPEC: u16 = 0;
+pub const IFLA_VRF_PORT_TABLE: u16 = 1;
 //
-// pub const IFLA_MACSEC_UNSPEC: int = 0;
-// pub const IFLA_MACSEC_SCI: int = 1;
-// pub const IFLA_MACSEC_PORT: int = 2;
-// pub const IFLA_MACSEC_ICV_LEN: int = 3;
-// pub const IFLA_MACSEC_CIPHER_SUITE: int = 4;
-// pub const IFLA_MACSEC_WINDOW: int = 5;
-// pub const IFLA_MACSEC_ENCODING_SA: int = 6;
-// pub const IFLA_MACSEC_ENCRYPT: int = 7;
-// pub const IFLA_MACSEC_PROTECT: int = 8;
-// pub const IFLA_MACSEC_INC_SCI: int = 9;
-// pub const IFLA_MACSEC_ES: int = 10;
-// pub const IFLA_MACSEC_SCB: int = 11;
-// pub const IFLA_MACSEC_REPLAY_PROTECT: int = 12;
-// pub const IFLA_MACSEC_VALIDATION: int = 13;
-// pub const IFLA_MACSEC_PAD: int = 14;
-//
-// pub const MACSEC_VALIDATE_DISABLED: int = 0;
-// pub const MACSEC_VALIDATE_CHECK: int = 1;
-// pub const MACSEC_VALIDATE_STRICT: int = 2;
-// pub const MACSEC_VALIDATE_MAX: int = 2;
-//
-// pub const IFLA_IPVLAN_UNSPEC: int = 0;
-// pub const IFLA_IPVLAN_MODE: int = 1;
-// pub const IFLA_IPVLAN_FLAGS: int = 2;
+pub const IFLA_MACSEC_UNSPEC: u16 = 0;
+pub const IFLA_MACSEC_SCI: u16 = 1;
+pub const IFLA_MACSEC_PORT: u16 = 2;
+pub const IFLA_MACSEC_ICV_LEN: u16 = 3;
+pub const IFLA_MACSEC_CIPHER_SUITE: u16 = 4;
+pub const IFLA_MACSEC_WINDOW: u16 = 5;
+pub const IFLA_MACSEC_ENCODING_SA: u16 = 6;
+pub const IFLA_MACSEC_ENCRYPT: u16 = 7;
+pub const IFLA_MACSEC_PROTECT: u16 = 8;
+pub const IFLA_MACSEC_INC_SCI: u16 = 9;
+pub const IFLA_MACSEC_ES: u16 = 10;
+pub const IFLA_MACSEC_SCB: u16 = 11;
+pub const IFLA_MACSEC_REPLAY_PROTECT: u16 = 12;
+pub const IFLA_MACSEC_VALIDATION: u16 = 13;
+pub const IFLA_MACSEC_PAD: u16 = 14;
+pub const IFLA_MACSEC_OFFLOAD: u16 = 15;
+
+pub const MACSEC_VALIDATE_DISABLED: u8 = 0;
+pub const MACSEC_VALIDATE_CHECK: u8 = 1;
+pub const MACSEC_VALIDATE_STRICT: u8 = 2;
+
+pub const MACSEC_OFFLOAD_OFF: u8 = 0;
+pub const MACSEC_OFFLOAD_PHY: u8 = 1;
+pub const MACSEC_OFFLOAD_MAC: u8 = 2;
+
+pub const MACSEC_CIPHER_ID_GCM_AES_128: u64 = 0x0080_C200_0100_0001;
+pub const MACSEC_CIPHER_ID_GCM_AES_256: u64 = 0x0080_C200_0100_0002;
+pub const MACSEC_CIPHER_ID_GCM_AES_XPN_128: u64 = 0x0080_C200_0100_0003;
+pub const MACSEC_CIPHER_ID_GCM_AES_XPN_256: u64 = 0x0080_C200_0100_0004;
 //
-// pub const IPVLAN_MODE_L2: int = 0;
-// pub const IPVLAN_MODE_L3: int = 1;
-// pub const IPVLAN_MODE_L3S: int = 2;
-// pub const IPVLAN_MODE_MAX: int = 3;
+// Values of IFLA_IPVLAN_MODE
+pub const IPVLAN_MODE_L2: u16 = 0;
+pub const IPVLAN_MODE_L3: u16 = 1;
+pub const IPVLAN_MODE_L3S: u16 = 2;
+// Flags of IFLA_IPVLAN_FLAGS
+pub const IPVLAN_F_PRIVATE: u16 = 1;
+pub const IPVLAN_F_VEPA: u16 = 2;
 //
 // FROM https://elixir.bootlin.com/linux/v5.9.8/source/include/uapi/linux/if_link.h#L531
 pub const IFLA_VXLAN_UNSPEC: u16 = 0;
@@ -954,19 +1645,114 @@ pub const IFLA_VXLAN_GPE: u16 = 27;
 pub const IFLA_VXLAN_TTL_INHERIT: u16 = 28;
 pub const IFLA_VXLAN_DF: u16 = 29;
 pub const __IFLA_VXLAN_MAX: u16 = 30;
-//
-// pub const IFLA_GENEVE_UNSPEC: int = 0;
-// pub const IFLA_GENEVE_ID: int = 1;
-// pub const IFLA_GENEVE_REMOTE: int = 2;
-// pub const IFLA_GENEVE_TTL: int = 3;
-// pub const IFLA_GENEVE_TOS: int = 4;
-// pub const IFLA_GENEVE_PORT: int = 5;
-// pub const IFLA_GENEVE_COLLECT_METADATA: int = 6;
-// pub const IFLA_GENEVE_REMOTE6: int = 7;
-// pub const IFLA_GENEVE_UDP_CSUM: int = 8;
-// pub const IFLA_GENEVE_UDP_ZERO_CSUM6_TX: int = 9;
-// pub const IFLA_GENEVE_UDP_ZERO_CSUM6_RX: int = 10;
-// pub const IFLA_GENEVE_LABEL: int = 11;
+// FROM https://elixir.bootlin.com/linux/v5.9.8/source/include/uapi/linux/if_link.h#L573
+pub const IFLA_GENEVE_UNSPEC: u16 = 0;
+pub const IFLA_GENEVE_ID: u16 = 1;
+pub const IFLA_GENEVE_REMOTE: u16 = 2;
+pub const IFLA_GENEVE_TTL: u16 = 3;
+pub const IFLA_GENEVE_TOS: u16 = 4;
+pub const IFLA_GENEVE_PORT: u16 = 5;
+pub const IFLA_GENEVE_COLLECT_METADATA: u16 = 6;
+pub const IFLA_GENEVE_REMOTE6: u16 = 7;
+pub const IFLA_GENEVE_UDP_CSUM: u16 = 8;
+pub const IFLA_GENEVE_UDP_ZERO_CSUM6_TX: u16 = 9;
+pub const IFLA_GENEVE_UDP_ZERO_CSUM6_RX: u16 = 10;
+pub const IFLA_GENEVE_LABEL: u16 = 11;
+pub const IFLA_GENEVE_TTL_INHERIT: u16 = 12;
+pub const IFLA_GENEVE_DF: u16 = 13;
+pub const IFLA_GENEVE_INNER_PROTO_INHERIT: u16 = 14;
+
+pub const GENEVE_DF_UNSET: u8 = 0;
+pub const GENEVE_DF_SET: u8 = 1;
+pub const GENEVE_DF_INHERIT: u8 = 2;
+
+// FROM https://elixir.bootlin.com/linux/v5.9.8/source/include/uapi/linux/if_tunnel.h
+pub const IFLA_IPTUN_UNSPEC: u16 = 0;
+pub const IFLA_IPTUN_LINK: u16 = 1;
+pub const IFLA_IPTUN_LOCAL: u16 = 2;
+pub const IFLA_IPTUN_REMOTE: u16 = 3;
+pub const IFLA_IPTUN_TTL: u16 = 4;
+pub const IFLA_IPTUN_TOS: u16 = 5;
+pub const IFLA_IPTUN_ENCAP_LIMIT: u16 = 6;
+pub const IFLA_IPTUN_FLOWINFO: u16 = 7;
+pub const IFLA_IPTUN_FLAGS: u16 = 8;
+pub const IFLA_IPTUN_PROTO: u16 = 9;
+pub const IFLA_IPTUN_PMTUDISC: u16 = 10;
+pub const IFLA_IPTUN_6RD_PREFIX: u16 = 11;
+pub const IFLA_IPTUN_6RD_RELAY_PREFIX: u16 = 12;
+pub const IFLA_IPTUN_6RD_PREFIXLEN: u16 = 13;
+pub const IFLA_IPTUN_6RD_RELAY_PREFIXLEN: u16 = 14;
+pub const IFLA_IPTUN_ENCAP_TYPE: u16 = 15;
+pub const IFLA_IPTUN_ENCAP_FLAGS: u16 = 16;
+pub const IFLA_IPTUN_ENCAP_SPORT: u16 = 17;
+pub const IFLA_IPTUN_ENCAP_DPORT: u16 = 18;
+pub const IFLA_IPTUN_COLLECT_METADATA: u16 = 19;
+pub const IFLA_IPTUN_FWMARK: u16 = 20;
+
+pub const TUNNEL_ENCAP_NONE: u16 = 0;
+pub const TUNNEL_ENCAP_FOU: u16 = 1;
+pub const TUNNEL_ENCAP_GUE: u16 = 2;
+pub const TUNNEL_ENCAP_MPLS: u16 = 3;
+
+pub const TUNNEL_ENCAP_FLAG_CSUM: u16 = 1;
+pub const TUNNEL_ENCAP_FLAG_CSUM6: u16 = 2;
+pub const TUNNEL_ENCAP_FLAG_REMCSUM: u16 = 4;
+
+pub const SIT_ISATAP: u16 = 1;
+
+// FROM https://elixir.bootlin.com/linux/v5.9.8/source/include/uapi/linux/ip6_tunnel.h
+pub const IP6_TNL_F_IGN_ENCAP_LIMIT: u32 = 0x1;
+pub const IP6_TNL_F_USE_ORIG_TCLASS: u32 = 0x2;
+pub const IP6_TNL_F_USE_ORIG_FLOWLABEL: u32 = 0x4;
+pub const IP6_TNL_F_MIP6_DEV: u32 = 0x8;
+pub const IP6_TNL_F_RCV_DSCP_COPY: u32 = 0x10;
+pub const IP6_TNL_F_USE_ORIG_FWMARK: u32 = 0x20;
+pub const IP6_TNL_F_ALLOW_LOCAL_REMOTE: u32 = 0x40;
+
+pub const IFLA_GRE_UNSPEC: u16 = 0;
+pub const IFLA_GRE_LINK: u16 = 1;
+pub const IFLA_GRE_IFLAGS: u16 = 2;
+pub const IFLA_GRE_OFLAGS: u16 = 3;
+pub const IFLA_GRE_IKEY: u16 = 4;
+pub const IFLA_GRE_OKEY: u16 = 5;
+pub const IFLA_GRE_LOCAL: u16 = 6;
+pub const IFLA_GRE_REMOTE: u16 = 7;
+pub const IFLA_GRE_TTL: u16 = 8;
+pub const IFLA_GRE_TOS: u16 = 9;
+pub const IFLA_GRE_PMTUDISC: u16 = 10;
+pub const IFLA_GRE_ENCAP_LIMIT: u16 = 11;
+pub const IFLA_GRE_FLOWINFO: u16 = 12;
+pub const IFLA_GRE_FLAGS: u16 = 13;
+pub const IFLA_GRE_ENCAP_TYPE: u16 = 14;
+pub const IFLA_GRE_ENCAP_FLAGS: u16 = 15;
+pub const IFLA_GRE_ENCAP_SPORT: u16 = 16;
+pub const IFLA_GRE_ENCAP_DPORT: u16 = 17;
+pub const IFLA_GRE_COLLECT_METADATA: u16 = 18;
+pub const IFLA_GRE_IGNORE_DF: u16 = 19;
+pub const IFLA_GRE_FWMARK: u16 = 20;
+pub const IFLA_GRE_ERSPAN_INDEX: u16 = 21;
+pub const IFLA_GRE_ERSPAN_VER: u16 = 22;
+pub const IFLA_GRE_ERSPAN_DIR: u16 = 23;
+pub const IFLA_GRE_ERSPAN_HWID: u16 = 24;
+
+// Flags of the GRE header, used in `IFLA_GRE_IFLAGS` and `IFLA_GRE_OFLAGS`
+pub const GRE_CSUM: u16 = 0x8000;
+pub const GRE_ROUTING: u16 = 0x4000;
+pub const GRE_KEY: u16 = 0x2000;
+pub const GRE_SEQ: u16 = 0x1000;
+pub const GRE_STRICT: u16 = 0x0800;
+pub const GRE_REC: u16 = 0x0700;
+pub const GRE_ACK: u16 = 0x0080;
+pub const GRE_FLAGS: u16 = 0x0078;
+pub const GRE_VERSION: u16 = 0x0007;
+
+pub const IFLA_VTI_UNSPEC: u16 = 0;
+pub const IFLA_VTI_LINK: u16 = 1;
+pub const IFLA_VTI_IKEY: u16 = 2;
+pub const IFLA_VTI_OKEY: u16 = 3;
+pub const IFLA_VTI_LOCAL: u16 = 4;
+pub const IFLA_VTI_REMOTE: u16 = 5;
+pub const IFLA_VTI_FWMARK: u16 = 6;
 //
 // pub const IFLA_PPP_UNSPEC: int = 0;
 // pub const IFLA_PPP_DEV_FD: int = 1;
@@ -980,52 +1766,6 @@ pub const __IFLA_VXLAN_MAX: u16 = 30;
 // pub const IFLA_GTP_PDP_HASHSIZE: int = 3;
 // pub const IFLA_GTP_ROLE: int = 4;
 //
-// pub const IFLA_BOND_UNSPEC: int = 0;
-// pub const IFLA_BOND_MODE: int = 1;
-// pub const IFLA_BOND_ACTIVE_SLAVE: int = 2;
-// pub const IFLA_BOND_MIIMON: int = 3;
-// pub const IFLA_BOND_UPDELAY: int = 4;
-// pub const IFLA_BOND_DOWNDELAY: int = 5;
-// pub const IFLA_BOND_USE_CARRIER: int = 6;
-// pub const IFLA_BOND_ARP_INTERVAL: int = 7;
-// pub const IFLA_BOND_ARP_IP_TARGET: int = 8;
-// pub const IFLA_BOND_ARP_VALIDATE: int = 9;
-// pub const IFLA_BOND_ARP_ALL_TARGETS: int = 10;
-// pub const IFLA_BOND_PRIMARY: int = 11;
-// pub const IFLA_BOND_PRIMARY_RESELECT: int = 12;
-// pub const IFLA_BOND_FAIL_OVER_MAC: int = 13;
-// pub const IFLA_BOND_XMIT_HASH_POLICY: int = 14;
-// pub const IFLA_BOND_RESEND_IGMP: int = 15;
-// pub const IFLA_BOND_NUM_PEER_NOTIF: int = 16;
-// pub const IFLA_BOND_ALL_SLAVES_ACTIVE: int = 17;
-// pub const IFLA_BOND_MIN_LINKS: int = 18;
-// pub const IFLA_BOND_LP_INTERVAL: int = 19;
-// pub const IFLA_BOND_PACKETS_PER_SLAVE: int = 20;
-// pub const IFLA_BOND_AD_LACP_RATE: int = 21;
-// pub const IFLA_BOND_AD_SELECT: int = 22;
-// pub const IFLA_BOND_AD_INFO: int = 23;
-// pub const IFLA_BOND_AD_ACTOR_SYS_PRIO: int = 24;
-// pub const IFLA_BOND_AD_USER_PORT_KEY: int = 25;
-// pub const IFLA_BOND_AD_ACTOR_SYSTEM: int = 26;
-// pub const IFLA_BOND_TLB_DYNAMIC_LB: int = 27;
-//
-// pub const IFLA_BOND_AD_INFO_UNSPEC: int = 0;
-// pub const IFLA_BOND_AD_INFO_AGGREGATOR: int = 1;
-// pub const IFLA_BOND_AD_INFO_NUM_PORTS: int = 2;
-// pub const IFLA_BOND_AD_INFO_ACTOR_KEY: int = 3;
-// pub const IFLA_BOND_AD_INFO_PARTNER_KEY: int = 4;
-// pub const IFLA_BOND_AD_INFO_PARTNER_MAC: int = 5;
-//
-// pub const IFLA_BOND_SLAVE_UNSPEC: int = 0;
-// pub const IFLA_BOND_SLAVE_STATE: int = 1;
-// pub const IFLA_BOND_SLAVE_MII_STATUS: int = 2;
-// pub const IFLA_BOND_SLAVE_LINK_FAILURE_COUNT: int = 3;
-// pub const IFLA_BOND_SLAVE_PERM_HWADDR: int = 4;
-// pub const IFLA_BOND_SLAVE_QUEUE_ID: int = 5;
-// pub const IFLA_BOND_SLAVE_AD_AGGREGATOR_ID: int = 6;
-// pub const IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE: int = 7;
-// pub const IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE: int = 8;
-//
 // pub const IFLA_VF_INFO_UNSPEC: int = 0;
 // pub const IFLA_VF_INFO: int = 1;
 //
@@ -1081,6 +1821,7 @@ pub const RTNLGRP_NSID: u32 = 28;
 pub const RTNLGRP_MPLS_NETCONF: u32 = 29;
 pub const RTNLGRP_IPV4_MROUTE_R: u32 = 30;
 pub const RTNLGRP_IPV6_MROUTE_R: u32 = 31;
+pub const RTNLGRP_NEXTHOP: u32 = 32;
 //
 // pub const IFLA_VF_LINK_STATE_AUTO: int = 0;
 // pub const IFLA_VF_LINK_STATE_ENABLE: int = 1;
@@ -1143,19 +1884,6 @@ pub const RTNLGRP_IPV6_MROUTE_R: u32 = 31;
 // pub const IFLA_HSR_SEQ_NR: int = 5;
 // pub const IFLA_HSR_VERSION: int = 6;
 //
-// pub const IFLA_STATS_UNSPEC: int = 0;
-// pub const IFLA_STATS_LINK_64: int = 1;
-// pub const IFLA_STATS_LINK_XSTATS: int = 2;
-// pub const IFLA_STATS_LINK_XSTATS_SLAVE: int = 3;
-// pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: int = 4;
-// pub const IFLA_STATS_AF_SPEC: int = 5;
-//
-// pub const LINK_XSTATS_TYPE_UNSPEC: int = 0;
-// pub const LINK_XSTATS_TYPE_BRIDGE: int = 1;
-//
-// pub const IFLA_OFFLOAD_XSTATS_UNSPEC: int = 0;
-// pub const IFLA_OFFLOAD_XSTATS_CPU_HIT: int = 1;
-//
 // pub const XDP_ATTACHED_NONE: int = 0;
 // pub const XDP_ATTACHED_DRV: int = 1;
 // pub const XDP_ATTACHED_SKB: int = 2;
@@ -1167,6 +1895,20 @@ pub const IFLA_XDP_ATTACHED: u32 = 2;
 pub const IFLA_XDP_FLAGS: u32 = 3;
 pub const IFLA_XDP_PROG_ID: u32 = 4;
 
+pub const IFLA_STATS_UNSPEC: u16 = 0;
+pub const IFLA_STATS_LINK_64: u16 = 1;
+pub const IFLA_STATS_LINK_XSTATS: u16 = 2;
+pub const IFLA_STATS_LINK_XSTATS_SLAVE: u16 = 3;
+pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = 4;
+pub const IFLA_STATS_AF_SPEC: u16 = 5;
+
+pub const LINK_XSTATS_TYPE_UNSPEC: u16 = 0;
+pub const LINK_XSTATS_TYPE_BRIDGE: u16 = 1;
+pub const LINK_XSTATS_TYPE_BOND: u16 = 2;
+
+pub const IFLA_OFFLOAD_XSTATS_UNSPEC: u16 = 0;
+pub const IFLA_OFFLOAD_XSTATS_CPU_HIT: u16 = 1;
+
 // pub const IFLA_EVENT_NONE: int = 0;
 // pub const IFLA_EVENT_REBOOT: int = 1;
 // pub const IFLA_EVENT_FEATURES: int = 2;
@@ -1175,26 +1917,6 @@ pub const IFLA_XDP_PROG_ID: u32 = 4;
 // pub const IFLA_EVENT_IGMP_RESEND: int = 5;
 // pub const IFLA_EVENT_BONDING_OPTIONS: int = 6;
 //
-// pub const NDTPA_UNSPEC: int = 0;
-// pub const NDTPA_IFINDEX: int = 1;
-// pub const NDTPA_REFCNT: int = 2;
-// pub const NDTPA_REACHABLE_TIME: int = 3;
-// pub const NDTPA_BASE_REACHABLE_TIME: int = 4;
-// pub const NDTPA_RETRANS_TIME: int = 5;
-// pub const NDTPA_GC_STALETIME: int = 6;
-// pub const NDTPA_DELAY_PROBE_TIME: int = 7;
-// pub const NDTPA_QUEUE_LEN: int = 8;
-// pub const NDTPA_APP_PROBES: int = 9;
-// pub const NDTPA_UCAST_PROBES: int = 10;
-// pub const NDTPA_MCAST_PROBES: int = 11;
-// pub const NDTPA_ANYCAST_DELAY: int = 12;
-// pub const NDTPA_PROXY_DELAY: int = 13;
-// pub const NDTPA_PROXY_QLEN: int = 14;
-// pub const NDTPA_LOCKTIME: int = 15;
-// pub const NDTPA_QUEUE_LENBYTES: int = 16;
-// pub const NDTPA_MCAST_REPROBES: int = 17;
-// pub const NDTPA_PAD: int = 18;
-//
 // #[allow(overflowing_literals)]
 // pub const RT_TABLE_MAX: int = 0xffff_ffff;
 //
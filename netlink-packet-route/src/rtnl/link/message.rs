@@ -3,7 +3,7 @@
 use anyhow::Context;
 
 use crate::{
-    nlas::link::Nla,
+    nlas::link::{Nla, Stats64},
     traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
     LinkHeader,
@@ -16,6 +16,23 @@ pub struct LinkMessage {
     pub nlas: Vec<Nla>,
 }
 
+impl LinkMessage {
+    /// Return the statistics of the link, taken from the `IFLA_STATS64`
+    /// attribute, or from the `IFLA_STATS` attribute when the kernel only
+    /// sent 32 bits counters.
+    pub fn stats(&self) -> Option<Stats64> {
+        let mut stats = None;
+        for nla in self.nlas.iter() {
+            match nla {
+                Nla::Stats64(stats64) => return Some(*stats64),
+                Nla::Stats(stats32) => stats = Some(Stats64::from(*stats32)),
+                _ => {}
+            }
+        }
+        stats
+    }
+}
+
 impl Emitable for LinkMessage {
     fn buffer_len(&self) -> usize {
         self.header.buffer_len() + self.nlas.as_slice().buffer_len()
@@ -59,8 +76,8 @@ impl<'a, T: AsRef<[u8]> + 'a> ParseableParametrized<LinkMessageBuffer<&'a T>, u8
 mod test {
     use crate::{
         constants::*,
-        nlas::link::{Nla, State},
-        traits::{Emitable, ParseableParametrized},
+        nlas::link::{AfSpecBridge, BridgeVlanInfo, InfoBridgePort, Nla, State, Stats, Stats64},
+        traits::{Emitable, Parseable, ParseableParametrized},
         LinkHeader,
         LinkMessage,
         LinkMessageBuffer,
@@ -227,4 +244,125 @@ mod test {
         assert_eq!(packet.buffer_len(), 96);
         packet.emit(&mut buf[..]);
     }
+
+    #[test]
+    fn stats_round_trip() {
+        let stats = Stats {
+            rx_packets: 10,
+            tx_packets: 20,
+            rx_bytes: 1000,
+            tx_bytes: 2000,
+            rx_errors: 1,
+            tx_dropped: 2,
+            ..Default::default()
+        };
+        let stats64 = Stats64 {
+            rx_packets: 10,
+            tx_packets: 20,
+            rx_bytes: 0x1_0000_0000,
+            tx_bytes: 2000,
+            rx_errors: 1,
+            tx_dropped: 2,
+            ..Default::default()
+        };
+        let packet = LinkMessage {
+            header: LinkHeader::default(),
+            nlas: vec![Nla::Stats(stats), Nla::Stats64(stats64)],
+        };
+
+        // 16 bytes header, 4 + 96 bytes for IFLA_STATS, 4 + 192 bytes for
+        // IFLA_STATS64
+        assert_eq!(packet.buffer_len(), 312);
+        let mut buf = vec![0; packet.buffer_len()];
+        packet.emit(&mut buf[..]);
+
+        let parsed = LinkMessage::parse(&LinkMessageBuffer::new(&buf)).unwrap();
+        assert_eq!(parsed, packet);
+        assert_eq!(parsed.stats(), Some(stats64));
+    }
+
+    #[test]
+    fn stats_from_32_bits_counters() {
+        let stats = Stats {
+            rx_packets: 10,
+            tx_packets: 20,
+            rx_bytes: 1000,
+            tx_bytes: 2000,
+            rx_errors: 1,
+            tx_errors: 3,
+            rx_dropped: 4,
+            tx_dropped: 2,
+            ..Default::default()
+        };
+        let packet = LinkMessage {
+            header: LinkHeader::default(),
+            nlas: vec![Nla::IfName("lo".into()), Nla::Stats(stats)],
+        };
+        let stats64 = packet.stats().unwrap();
+        assert_eq!(stats64.rx_packets, 10);
+        assert_eq!(stats64.tx_packets, 20);
+        assert_eq!(stats64.rx_bytes, 1000);
+        assert_eq!(stats64.tx_bytes, 2000);
+        assert_eq!(stats64.rx_errors, 1);
+        assert_eq!(stats64.tx_errors, 3);
+        assert_eq!(stats64.rx_dropped, 4);
+        assert_eq!(stats64.tx_dropped, 2);
+
+        assert_eq!(LinkMessage::default().stats(), None);
+    }
+
+    #[rustfmt::skip]
+    static BRIDGE_PORT: [u8; 72] = [
+        0x07, // interface family AF_BRIDGE
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ethernet
+        0x03, 0x00, 0x00, 0x00, // interface index = 3
+        0x43, 0x10, 0x01, 0x00, // device flags: UP, BROADCAST, RUNNING, MULTICAST, LOWER_UP
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x24, 0x00, 0x0c, 0x80, // L=36,T=IFLA_PROTINFO|NLA_F_NESTED
+            0x05, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00, // State L=5,T=1,V=3 (forwarding)
+            0x08, 0x00, 0x03, 0x00, 0x64, 0x00, 0x00, 0x00, // Cost L=8,T=3,V=100
+            0x05, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, // Learning L=5,T=8,V=1
+            0x05, 0x00, 0x21, 0x00, 0x00, 0x00, 0x00, 0x00, // Isolated L=5,T=33,V=0
+        0x14, 0x00, 0x1a, 0x00, // L=20,T=IFLA_AF_SPEC
+            0x08, 0x00, 0x02, 0x00, 0x06, 0x00, 0x01, 0x00, // VlanInfo: PVID, untagged, vid 1
+            0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0a, 0x00, // VlanInfo: vid 10
+    ];
+
+    #[test]
+    fn bridge_port() {
+        let expected = LinkMessage {
+            header: LinkHeader {
+                interface_family: AF_BRIDGE as u8,
+                index: 3,
+                link_layer_type: ARPHRD_ETHER,
+                flags: IFF_UP | IFF_BROADCAST | IFF_RUNNING | IFF_MULTICAST | IFF_LOWER_UP,
+                change_mask: 0,
+            },
+            nlas: vec![
+                Nla::ProtoInfoBridge(vec![
+                    InfoBridgePort::State(BR_STATE_FORWARDING),
+                    InfoBridgePort::Cost(100),
+                    InfoBridgePort::Learning(1),
+                    InfoBridgePort::Isolated(0),
+                ]),
+                Nla::AfSpecBridge(vec![
+                    AfSpecBridge::VlanInfo(BridgeVlanInfo {
+                        flags: BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED,
+                        vid: 1,
+                    }),
+                    AfSpecBridge::VlanInfo(BridgeVlanInfo { flags: 0, vid: 10 }),
+                ]),
+            ],
+        };
+        let actual = LinkMessage::parse(&LinkMessageBuffer::new(&BRIDGE_PORT)).unwrap();
+        assert_eq!(actual, expected);
+
+        let mut buf = [0; 72];
+        assert_eq!(expected.buffer_len(), 72);
+        expected.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &BRIDGE_PORT[..]);
+    }
 }
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::parse_u16,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Attributes found in `IFLA_AF_SPEC` for messages of the `AF_BRIDGE`
+/// family, used by the `bridge vlan` commands
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum AfSpecBridge {
+    /// Whether the request targets the bridge (`BRIDGE_FLAGS_MASTER`) or the
+    /// port itself (`BRIDGE_FLAGS_SELF`)
+    Flags(u16),
+    /// Forwarding mode, as one of the `BRIDGE_MODE_*` constants
+    Mode(u16),
+    VlanInfo(BridgeVlanInfo),
+    VlanTunnelInfo(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for AfSpecBridge {
+    fn value_len(&self) -> usize {
+        use self::AfSpecBridge::*;
+        match self {
+            Flags(_) | Mode(_) => 2,
+            VlanInfo(info) => info.buffer_len(),
+            VlanTunnelInfo(bytes) => bytes.len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::AfSpecBridge::*;
+        match self {
+            Flags(value) | Mode(value) => NativeEndian::write_u16(buffer, *value),
+            VlanInfo(info) => info.emit(buffer),
+            VlanTunnelInfo(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::AfSpecBridge::*;
+        match self {
+            Flags(_) => IFLA_BRIDGE_FLAGS,
+            Mode(_) => IFLA_BRIDGE_MODE,
+            VlanInfo(_) => IFLA_BRIDGE_VLAN_INFO,
+            VlanTunnelInfo(_) => IFLA_BRIDGE_VLAN_TUNNEL_INFO,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecBridge {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::AfSpecBridge::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_FLAGS => {
+                Flags(parse_u16(payload).context("invalid IFLA_BRIDGE_FLAGS value")?)
+            }
+            IFLA_BRIDGE_MODE => Mode(parse_u16(payload).context("invalid IFLA_BRIDGE_MODE value")?),
+            IFLA_BRIDGE_VLAN_INFO => VlanInfo(
+                BridgeVlanInfo::parse(&BridgeVlanInfoBuffer::new_checked(payload)?)
+                    .context("invalid IFLA_BRIDGE_VLAN_INFO value")?,
+            ),
+            IFLA_BRIDGE_VLAN_TUNNEL_INFO => VlanTunnelInfo(payload.to_vec()),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// A VLAN of a bridge or bridge port (`struct bridge_vlan_info`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct BridgeVlanInfo {
+    /// Combination of the `BRIDGE_VLAN_INFO_*` flags
+    pub flags: u16,
+    pub vid: u16,
+}
+
+pub const BRIDGE_VLAN_INFO_LEN: usize = 4;
+
+buffer!(BridgeVlanInfoBuffer(BRIDGE_VLAN_INFO_LEN) {
+    flags: (u16, 0..2),
+    vid: (u16, 2..4),
+});
+
+impl<T: AsRef<[u8]>> Parseable<BridgeVlanInfoBuffer<T>> for BridgeVlanInfo {
+    fn parse(buf: &BridgeVlanInfoBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            flags: buf.flags(),
+            vid: buf.vid(),
+        })
+    }
+}
+
+impl Emitable for BridgeVlanInfo {
+    fn buffer_len(&self) -> usize {
+        BRIDGE_VLAN_INFO_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = BridgeVlanInfoBuffer::new(buffer);
+        buffer.set_flags(self.flags);
+        buffer.set_vid(self.vid);
+    }
+}
@@ -0,0 +1,312 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_mac, parse_u16, parse_u32, parse_u64, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// Attributes of a bridge port, found in `IFLA_PROTINFO` for messages of the
+/// `AF_BRIDGE` family and used by the `bridge link` commands
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoBridgePort {
+    /// STP state of the port, as one of the `BR_STATE_*` constants
+    State(u8),
+    Priority(u16),
+    Cost(u32),
+    /// Whether packets may be sent back through the port they were received
+    /// on
+    HairpinMode(u8),
+    /// Whether STP BPDUs received on the port are dropped
+    Guard(u8),
+    /// Whether the port is prevented from becoming the root port
+    Protect(u8),
+    FastLeave(u8),
+    /// Whether source MAC addresses are learned on the port
+    Learning(u8),
+    /// Whether unknown unicast traffic is flooded to the port
+    UnicastFlood(u8),
+    ProxyArp(u8),
+    LearningSync(u8),
+    ProxyArpWifi(u8),
+    RootId((u16, [u8; 6])),
+    BridgeId((u16, [u8; 6])),
+    DesignatedPort(u16),
+    DesignatedCost(u16),
+    Id(u16),
+    No(u16),
+    TopologyChangeAck(u8),
+    ConfigPending(u8),
+    MessageAgeTimer(u64),
+    ForwardDelayTimer(u64),
+    HoldTimer(u64),
+    /// Flush the dynamic FDB entries of the port
+    Flush,
+    MulticastRouter(u8),
+    Pad(Vec<u8>),
+    MulticastFlood(u8),
+    MulticastToUnicast(u8),
+    VlanTunnel(u8),
+    BroadcastFlood(u8),
+    GroupFwdMask(u16),
+    /// Whether ARP and ND requests are answered from the neighbour entries
+    /// of the bridge instead of being flooded
+    NeighSuppress(u8),
+    /// Whether the port is isolated, in which case it can only communicate
+    /// with non-isolated ports
+    Isolated(u8),
+    /// Index of the port used when this port goes down
+    BackupPort(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for InfoBridgePort {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoBridgePort::*;
+        match self {
+            Flush => 0,
+            Pad(bytes) => bytes.len(),
+            MessageAgeTimer(_)
+                | ForwardDelayTimer(_)
+                | HoldTimer(_)
+                | RootId(_)
+                | BridgeId(_)
+                => 8,
+            Cost(_) | BackupPort(_) => 4,
+            Priority(_)
+                | DesignatedPort(_)
+                | DesignatedCost(_)
+                | Id(_)
+                | No(_)
+                | GroupFwdMask(_)
+                => 2,
+            State(_)
+                | HairpinMode(_)
+                | Guard(_)
+                | Protect(_)
+                | FastLeave(_)
+                | Learning(_)
+                | UnicastFlood(_)
+                | ProxyArp(_)
+                | LearningSync(_)
+                | ProxyArpWifi(_)
+                | TopologyChangeAck(_)
+                | ConfigPending(_)
+                | MulticastRouter(_)
+                | MulticastFlood(_)
+                | MulticastToUnicast(_)
+                | VlanTunnel(_)
+                | BroadcastFlood(_)
+                | NeighSuppress(_)
+                | Isolated(_)
+                => 1,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoBridgePort::*;
+        match self {
+            Flush => {}
+            Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            MessageAgeTimer(value)
+                | ForwardDelayTimer(value)
+                | HoldTimer(value)
+                => NativeEndian::write_u64(buffer, *value),
+            RootId((priority, address))
+                | BridgeId((priority, address))
+                => {
+                    NativeEndian::write_u16(buffer, *priority);
+                    buffer[2..].copy_from_slice(&address[..]);
+                }
+            Cost(value) | BackupPort(value) => NativeEndian::write_u32(buffer, *value),
+            Priority(value)
+                | DesignatedPort(value)
+                | DesignatedCost(value)
+                | Id(value)
+                | No(value)
+                | GroupFwdMask(value)
+                => NativeEndian::write_u16(buffer, *value),
+            State(value)
+                | HairpinMode(value)
+                | Guard(value)
+                | Protect(value)
+                | FastLeave(value)
+                | Learning(value)
+                | UnicastFlood(value)
+                | ProxyArp(value)
+                | LearningSync(value)
+                | ProxyArpWifi(value)
+                | TopologyChangeAck(value)
+                | ConfigPending(value)
+                | MulticastRouter(value)
+                | MulticastFlood(value)
+                | MulticastToUnicast(value)
+                | VlanTunnel(value)
+                | BroadcastFlood(value)
+                | NeighSuppress(value)
+                | Isolated(value)
+                => buffer[0] = *value,
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoBridgePort::*;
+        match self {
+            State(_) => IFLA_BRPORT_STATE,
+            Priority(_) => IFLA_BRPORT_PRIORITY,
+            Cost(_) => IFLA_BRPORT_COST,
+            HairpinMode(_) => IFLA_BRPORT_MODE,
+            Guard(_) => IFLA_BRPORT_GUARD,
+            Protect(_) => IFLA_BRPORT_PROTECT,
+            FastLeave(_) => IFLA_BRPORT_FAST_LEAVE,
+            Learning(_) => IFLA_BRPORT_LEARNING,
+            UnicastFlood(_) => IFLA_BRPORT_UNICAST_FLOOD,
+            ProxyArp(_) => IFLA_BRPORT_PROXYARP,
+            LearningSync(_) => IFLA_BRPORT_LEARNING_SYNC,
+            ProxyArpWifi(_) => IFLA_BRPORT_PROXYARP_WIFI,
+            RootId(_) => IFLA_BRPORT_ROOT_ID,
+            BridgeId(_) => IFLA_BRPORT_BRIDGE_ID,
+            DesignatedPort(_) => IFLA_BRPORT_DESIGNATED_PORT,
+            DesignatedCost(_) => IFLA_BRPORT_DESIGNATED_COST,
+            Id(_) => IFLA_BRPORT_ID,
+            No(_) => IFLA_BRPORT_NO,
+            TopologyChangeAck(_) => IFLA_BRPORT_TOPOLOGY_CHANGE_ACK,
+            ConfigPending(_) => IFLA_BRPORT_CONFIG_PENDING,
+            MessageAgeTimer(_) => IFLA_BRPORT_MESSAGE_AGE_TIMER,
+            ForwardDelayTimer(_) => IFLA_BRPORT_FORWARD_DELAY_TIMER,
+            HoldTimer(_) => IFLA_BRPORT_HOLD_TIMER,
+            Flush => IFLA_BRPORT_FLUSH,
+            MulticastRouter(_) => IFLA_BRPORT_MULTICAST_ROUTER,
+            Pad(_) => IFLA_BRPORT_PAD,
+            MulticastFlood(_) => IFLA_BRPORT_MCAST_FLOOD,
+            MulticastToUnicast(_) => IFLA_BRPORT_MCAST_TO_UCAST,
+            VlanTunnel(_) => IFLA_BRPORT_VLAN_TUNNEL,
+            BroadcastFlood(_) => IFLA_BRPORT_BCAST_FLOOD,
+            GroupFwdMask(_) => IFLA_BRPORT_GROUP_FWD_MASK,
+            NeighSuppress(_) => IFLA_BRPORT_NEIGH_SUPPRESS,
+            Isolated(_) => IFLA_BRPORT_ISOLATED,
+            BackupPort(_) => IFLA_BRPORT_BACKUP_PORT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBridgePort {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoBridgePort::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRPORT_STATE => {
+                State(parse_u8(payload).context("invalid IFLA_BRPORT_STATE value")?)
+            }
+            IFLA_BRPORT_PRIORITY => {
+                Priority(parse_u16(payload).context("invalid IFLA_BRPORT_PRIORITY value")?)
+            }
+            IFLA_BRPORT_COST => Cost(parse_u32(payload).context("invalid IFLA_BRPORT_COST value")?),
+            IFLA_BRPORT_MODE => {
+                HairpinMode(parse_u8(payload).context("invalid IFLA_BRPORT_MODE value")?)
+            }
+            IFLA_BRPORT_GUARD => {
+                Guard(parse_u8(payload).context("invalid IFLA_BRPORT_GUARD value")?)
+            }
+            IFLA_BRPORT_PROTECT => {
+                Protect(parse_u8(payload).context("invalid IFLA_BRPORT_PROTECT value")?)
+            }
+            IFLA_BRPORT_FAST_LEAVE => {
+                FastLeave(parse_u8(payload).context("invalid IFLA_BRPORT_FAST_LEAVE value")?)
+            }
+            IFLA_BRPORT_LEARNING => {
+                Learning(parse_u8(payload).context("invalid IFLA_BRPORT_LEARNING value")?)
+            }
+            IFLA_BRPORT_UNICAST_FLOOD => {
+                UnicastFlood(parse_u8(payload).context("invalid IFLA_BRPORT_UNICAST_FLOOD value")?)
+            }
+            IFLA_BRPORT_PROXYARP => {
+                ProxyArp(parse_u8(payload).context("invalid IFLA_BRPORT_PROXYARP value")?)
+            }
+            IFLA_BRPORT_LEARNING_SYNC => {
+                LearningSync(parse_u8(payload).context("invalid IFLA_BRPORT_LEARNING_SYNC value")?)
+            }
+            IFLA_BRPORT_PROXYARP_WIFI => {
+                ProxyArpWifi(parse_u8(payload).context("invalid IFLA_BRPORT_PROXYARP_WIFI value")?)
+            }
+            IFLA_BRPORT_ROOT_ID | IFLA_BRPORT_BRIDGE_ID => {
+                if payload.len() != 8 {
+                    return Err("invalid IFLA_BRPORT_ROOT_ID or IFLA_BRPORT_BRIDGE_ID value".into());
+                }
+
+                let priority = NativeEndian::read_u16(&payload[..2]);
+                let address = parse_mac(&payload[2..])
+                    .context("invalid IFLA_BRPORT_ROOT_ID or IFLA_BRPORT_BRIDGE_ID value")?;
+
+                match buf.kind() {
+                    IFLA_BRPORT_ROOT_ID => RootId((priority, address)),
+                    IFLA_BRPORT_BRIDGE_ID => BridgeId((priority, address)),
+                    _ => unreachable!(),
+                }
+            }
+            IFLA_BRPORT_DESIGNATED_PORT => DesignatedPort(
+                parse_u16(payload).context("invalid IFLA_BRPORT_DESIGNATED_PORT value")?,
+            ),
+            IFLA_BRPORT_DESIGNATED_COST => DesignatedCost(
+                parse_u16(payload).context("invalid IFLA_BRPORT_DESIGNATED_COST value")?,
+            ),
+            IFLA_BRPORT_ID => Id(parse_u16(payload).context("invalid IFLA_BRPORT_ID value")?),
+            IFLA_BRPORT_NO => No(parse_u16(payload).context("invalid IFLA_BRPORT_NO value")?),
+            IFLA_BRPORT_TOPOLOGY_CHANGE_ACK => TopologyChangeAck(
+                parse_u8(payload).context("invalid IFLA_BRPORT_TOPOLOGY_CHANGE_ACK value")?,
+            ),
+            IFLA_BRPORT_CONFIG_PENDING => ConfigPending(
+                parse_u8(payload).context("invalid IFLA_BRPORT_CONFIG_PENDING value")?,
+            ),
+            IFLA_BRPORT_MESSAGE_AGE_TIMER => MessageAgeTimer(
+                parse_u64(payload).context("invalid IFLA_BRPORT_MESSAGE_AGE_TIMER value")?,
+            ),
+            IFLA_BRPORT_FORWARD_DELAY_TIMER => ForwardDelayTimer(
+                parse_u64(payload).context("invalid IFLA_BRPORT_FORWARD_DELAY_TIMER value")?,
+            ),
+            IFLA_BRPORT_HOLD_TIMER => {
+                HoldTimer(parse_u64(payload).context("invalid IFLA_BRPORT_HOLD_TIMER value")?)
+            }
+            IFLA_BRPORT_FLUSH => Flush,
+            IFLA_BRPORT_MULTICAST_ROUTER => MulticastRouter(
+                parse_u8(payload).context("invalid IFLA_BRPORT_MULTICAST_ROUTER value")?,
+            ),
+            IFLA_BRPORT_PAD => Pad(payload.to_vec()),
+            IFLA_BRPORT_MCAST_FLOOD => {
+                MulticastFlood(parse_u8(payload).context("invalid IFLA_BRPORT_MCAST_FLOOD value")?)
+            }
+            IFLA_BRPORT_MCAST_TO_UCAST => MulticastToUnicast(
+                parse_u8(payload).context("invalid IFLA_BRPORT_MCAST_TO_UCAST value")?,
+            ),
+            IFLA_BRPORT_VLAN_TUNNEL => {
+                VlanTunnel(parse_u8(payload).context("invalid IFLA_BRPORT_VLAN_TUNNEL value")?)
+            }
+            IFLA_BRPORT_BCAST_FLOOD => {
+                BroadcastFlood(parse_u8(payload).context("invalid IFLA_BRPORT_BCAST_FLOOD value")?)
+            }
+            IFLA_BRPORT_GROUP_FWD_MASK => GroupFwdMask(
+                parse_u16(payload).context("invalid IFLA_BRPORT_GROUP_FWD_MASK value")?,
+            ),
+            IFLA_BRPORT_NEIGH_SUPPRESS => NeighSuppress(
+                parse_u8(payload).context("invalid IFLA_BRPORT_NEIGH_SUPPRESS value")?,
+            ),
+            IFLA_BRPORT_ISOLATED => {
+                Isolated(parse_u8(payload).context("invalid IFLA_BRPORT_ISOLATED value")?)
+            }
+            IFLA_BRPORT_BACKUP_PORT => {
+                BackupPort(parse_u32(payload).context("invalid IFLA_BRPORT_BACKUP_PORT value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
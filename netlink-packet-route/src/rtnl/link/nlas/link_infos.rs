@@ -1,9 +1,25 @@
 // SPDX-License-Identifier: MIT
 
+use super::InfoBridgePort;
 use crate::{
     constants::*,
+    emit_ip,
+    ip_len,
     nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
-    parsers::{parse_mac, parse_string, parse_u16, parse_u16_be, parse_u32, parse_u64, parse_u8},
+    parsers::{
+        parse_i32,
+        parse_ip,
+        parse_ipv6,
+        parse_mac,
+        parse_string,
+        parse_u16,
+        parse_u16_be,
+        parse_u32,
+        parse_u32_be,
+        parse_u64,
+        parse_u64_be,
+        parse_u8,
+    },
     traits::{Emitable, Parseable},
     DecodeError,
     LinkMessage,
@@ -11,6 +27,7 @@ use crate::{
 };
 use anyhow::Context;
 use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 const DUMMY: &str = "dummy";
 const IFB: &str = "ifb";
@@ -35,6 +52,11 @@ const VRF: &str = "vrf";
 const GTP: &str = "gtp";
 const IPOIB: &str = "ipoib";
 const WIREGUARD: &str = "wireguard";
+const GENEVE: &str = "geneve";
+const ERSPAN: &str = "erspan";
+const IP6TNL: &str = "ip6tnl";
+const BAREUDP: &str = "bareudp";
+const MACSEC: &str = "macsec";
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Info {
@@ -42,8 +64,8 @@ pub enum Info {
     Xstats(Vec<u8>),
     Kind(InfoKind),
     Data(InfoData),
-    SlaveKind(Vec<u8>),
-    SlaveData(Vec<u8>),
+    SlaveKind(InfoKind),
+    SlaveData(InfoSlaveData),
 }
 
 impl Nla for Info {
@@ -53,11 +75,11 @@ impl Nla for Info {
         match self {
             Unspec(ref bytes)
                 | Xstats(ref bytes)
-                | SlaveKind(ref bytes)
-                | SlaveData(ref bytes)
                 => bytes.len(),
             Kind(ref nla) => nla.value_len(),
+            SlaveKind(ref nla) => nla.value_len(),
             Data(ref nla) => nla.value_len(),
+            SlaveData(ref nla) => nla.value_len(),
         }
     }
 
@@ -67,11 +89,11 @@ impl Nla for Info {
         match self {
             Unspec(ref bytes)
                 | Xstats(ref bytes)
-                | SlaveKind(ref bytes)
-                | SlaveData(ref bytes)
                 => buffer.copy_from_slice(bytes),
             Kind(ref nla) => nla.emit_value(buffer),
+            SlaveKind(ref nla) => nla.emit_value(buffer),
             Data(ref nla) => nla.emit_value(buffer),
+            SlaveData(ref nla) => nla.emit_value(buffer),
         }
     }
 
@@ -81,7 +103,7 @@ impl Nla for Info {
             Unspec(_) => IFLA_INFO_UNSPEC,
             Xstats(_) => IFLA_INFO_XSTATS,
             SlaveKind(_) => IFLA_INFO_SLAVE_KIND,
-            SlaveData(_) => IFLA_INFO_DATA,
+            SlaveData(_) => IFLA_INFO_SLAVE_DATA,
             Kind(_) => IFLA_INFO_KIND,
             Data(_) => IFLA_INFO_DATA,
         }
@@ -105,13 +127,58 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
         let mut res = Vec::new();
         let nlas = NlasIterator::new(buf.into_inner());
         let mut link_info_kind: Option<InfoKind> = None;
+        let mut link_info_slave_kind: Option<InfoKind> = None;
         for nla in nlas {
             let nla = nla?;
             match nla.kind() {
                 IFLA_INFO_UNSPEC => res.push(Info::Unspec(nla.value().to_vec())),
                 IFLA_INFO_XSTATS => res.push(Info::Xstats(nla.value().to_vec())),
-                IFLA_INFO_SLAVE_KIND => res.push(Info::SlaveKind(nla.value().to_vec())),
-                IFLA_INFO_SLAVE_DATA => res.push(Info::SlaveData(nla.value().to_vec())),
+                IFLA_INFO_SLAVE_KIND => {
+                    let parsed = InfoKind::parse(&nla)?;
+                    res.push(Info::SlaveKind(parsed.clone()));
+                    link_info_slave_kind = Some(parsed);
+                }
+                IFLA_INFO_SLAVE_DATA => {
+                    let payload = nla.value();
+                    let slave_data = match link_info_slave_kind {
+                        Some(InfoKind::Bridge) => {
+                            let mut v = Vec::new();
+                            let err =
+                                "failed to parse IFLA_INFO_SLAVE_DATA (IFLA_INFO_SLAVE_KIND is 'bridge')";
+                            for nla in NlasIterator::new(payload) {
+                                let nla = &nla.context(err)?;
+                                let parsed = InfoBridgePort::parse(nla).context(err)?;
+                                v.push(parsed);
+                            }
+                            InfoSlaveData::Bridge(v)
+                        }
+                        Some(InfoKind::Bond) => {
+                            let mut v = Vec::new();
+                            let err =
+                                "failed to parse IFLA_INFO_SLAVE_DATA (IFLA_INFO_SLAVE_KIND is 'bond')";
+                            for nla in NlasIterator::new(payload) {
+                                let nla = &nla.context(err)?;
+                                let parsed = InfoBondPort::parse(nla).context(err)?;
+                                v.push(parsed);
+                            }
+                            InfoSlaveData::Bond(v)
+                        }
+                        Some(InfoKind::Vrf) => {
+                            let mut v = Vec::new();
+                            let err =
+                                "failed to parse IFLA_INFO_SLAVE_DATA (IFLA_INFO_SLAVE_KIND is 'vrf')";
+                            for nla in NlasIterator::new(payload) {
+                                let nla = &nla.context(err)?;
+                                let parsed = InfoVrfPort::parse(nla).context(err)?;
+                                v.push(parsed);
+                            }
+                            InfoSlaveData::Vrf(v)
+                        }
+                        _ => InfoSlaveData::Other(payload.to_vec()),
+                    };
+                    res.push(Info::SlaveData(slave_data));
+                    link_info_slave_kind = None;
+                }
                 IFLA_INFO_KIND => {
                     let parsed = InfoKind::parse(&nla)?;
                     res.push(Info::Kind(parsed.clone()));
@@ -145,7 +212,17 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
                                 }
                                 InfoData::Vlan(v)
                             }
-                            InfoKind::Tun => InfoData::Tun(payload.to_vec()),
+                            InfoKind::Tun => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'tun')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Tun(v)
+                            }
                             InfoKind::Nlmon => InfoData::Nlmon(payload.to_vec()),
                             InfoKind::Veth => {
                                 let err =
@@ -165,7 +242,17 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
                                 }
                                 InfoData::Vxlan(v)
                             }
-                            InfoKind::Bond => InfoData::Bond(payload.to_vec()),
+                            InfoKind::Bond => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'bond')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoBond::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Bond(v)
+                            }
                             InfoKind::IpVlan => {
                                 let mut v = Vec::new();
                                 let err =
@@ -199,13 +286,83 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
                                 }
                                 InfoData::MacVtap(v)
                             }
-                            InfoKind::GreTap => InfoData::GreTap(payload.to_vec()),
-                            InfoKind::GreTap6 => InfoData::GreTap6(payload.to_vec()),
-                            InfoKind::IpTun => InfoData::IpTun(payload.to_vec()),
-                            InfoKind::SitTun => InfoData::SitTun(payload.to_vec()),
-                            InfoKind::GreTun => InfoData::GreTun(payload.to_vec()),
-                            InfoKind::GreTun6 => InfoData::GreTun6(payload.to_vec()),
-                            InfoKind::Vti => InfoData::Vti(payload.to_vec()),
+                            InfoKind::GreTap => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'gretap')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGreTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::GreTap(v)
+                            }
+                            InfoKind::GreTap6 => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'ip6gretap')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGreTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::GreTap6(v)
+                            }
+                            InfoKind::IpTun => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'ipip')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoIpTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::IpTun(v)
+                            }
+                            InfoKind::SitTun => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'sit')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoIpTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::SitTun(v)
+                            }
+                            InfoKind::GreTun => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'gre')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGreTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::GreTun(v)
+                            }
+                            InfoKind::GreTun6 => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'ip6gre')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGreTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::GreTun6(v)
+                            }
+                            InfoKind::Vti => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'vti')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoVti::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Vti(v)
+                            }
                             InfoKind::Vrf => {
                                 let mut v = Vec::new();
                                 let err =
@@ -230,6 +387,51 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
                                 InfoData::Ipoib(v)
                             }
                             InfoKind::Wireguard => InfoData::Wireguard(payload.to_vec()),
+                            InfoKind::Geneve => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'geneve')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGeneve::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Geneve(v)
+                            }
+                            InfoKind::Erspan => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'erspan')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGreTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Erspan(v)
+                            }
+                            InfoKind::Ip6Tnl => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'ip6tnl')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoIpTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Ip6Tnl(v)
+                            }
+                            InfoKind::BareUdp => InfoData::BareUdp(payload.to_vec()),
+                            InfoKind::Macsec => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'macsec')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoMacsec::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Macsec(v)
+                            }
                             InfoKind::Other(_) => InfoData::Other(payload.to_vec()),
                         };
                         res.push(Info::Data(info_data));
@@ -248,28 +450,33 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum InfoData {
     Bridge(Vec<InfoBridge>),
-    Tun(Vec<u8>),
+    Tun(Vec<InfoTun>),
     Nlmon(Vec<u8>),
     Vlan(Vec<InfoVlan>),
     Dummy(Vec<u8>),
     Ifb(Vec<u8>),
     Veth(VethInfo),
     Vxlan(Vec<InfoVxlan>),
-    Bond(Vec<u8>),
+    Bond(Vec<InfoBond>),
     IpVlan(Vec<InfoIpVlan>),
     MacVlan(Vec<InfoMacVlan>),
     MacVtap(Vec<InfoMacVtap>),
-    GreTap(Vec<u8>),
-    GreTap6(Vec<u8>),
-    IpTun(Vec<u8>),
-    SitTun(Vec<u8>),
-    GreTun(Vec<u8>),
-    GreTun6(Vec<u8>),
-    Vti(Vec<u8>),
+    GreTap(Vec<InfoGreTun>),
+    GreTap6(Vec<InfoGreTun>),
+    IpTun(Vec<InfoIpTun>),
+    SitTun(Vec<InfoIpTun>),
+    GreTun(Vec<InfoGreTun>),
+    GreTun6(Vec<InfoGreTun>),
+    Vti(Vec<InfoVti>),
     Vrf(Vec<InfoVrf>),
     Gtp(Vec<u8>),
     Ipoib(Vec<InfoIpoib>),
     Wireguard(Vec<u8>),
+    Geneve(Vec<InfoGeneve>),
+    Erspan(Vec<InfoGreTun>),
+    Ip6Tnl(Vec<InfoIpTun>),
+    BareUdp(Vec<u8>),
+    Macsec(Vec<InfoMacsec>),
     Other(Vec<u8>),
 }
 
@@ -279,6 +486,7 @@ impl Nla for InfoData {
         use self::InfoData::*;
         match self {
             Bridge(ref nlas) => nlas.as_slice().buffer_len(),
+            Tun(ref nlas) => nlas.as_slice().buffer_len(),
             Vlan(ref nlas) =>  nlas.as_slice().buffer_len(),
             Veth(ref msg) => msg.buffer_len(),
             IpVlan(ref nlas) => nlas.as_slice().buffer_len(),
@@ -287,20 +495,26 @@ impl Nla for InfoData {
             MacVtap(ref nlas) => nlas.as_slice().buffer_len(),
             Vrf(ref nlas) => nlas.as_slice().buffer_len(),
             Vxlan(ref nlas) => nlas.as_slice().buffer_len(),
+            Bond(ref nlas) => nlas.as_slice().buffer_len(),
+            GreTap(ref nlas)
+                | GreTap6(ref nlas)
+                | GreTun(ref nlas)
+                | GreTun6(ref nlas)
+                | Erspan(ref nlas)
+                => nlas.as_slice().buffer_len(),
+            IpTun(ref nlas)
+                | SitTun(ref nlas)
+                | Ip6Tnl(ref nlas)
+                => nlas.as_slice().buffer_len(),
+            Vti(ref nlas) => nlas.as_slice().buffer_len(),
+            Geneve(ref nlas) => nlas.as_slice().buffer_len(),
+            Macsec(ref nlas) => nlas.as_slice().buffer_len(),
             Dummy(ref bytes)
-                | Tun(ref bytes)
                 | Nlmon(ref bytes)
                 | Ifb(ref bytes)
-                | Bond(ref bytes)
-                | GreTap(ref bytes)
-                | GreTap6(ref bytes)
-                | IpTun(ref bytes)
-                | SitTun(ref bytes)
-                | GreTun(ref bytes)
-                | GreTun6(ref bytes)
-                | Vti(ref bytes)
                 | Gtp(ref bytes)
                 | Wireguard(ref bytes)
+                | BareUdp(ref bytes)
                 | Other(ref bytes)
                 => bytes.len(),
         }
@@ -311,6 +525,7 @@ impl Nla for InfoData {
         use self::InfoData::*;
         match self {
             Bridge(ref nlas) => nlas.as_slice().emit(buffer),
+            Tun(ref nlas) => nlas.as_slice().emit(buffer),
             Vlan(ref nlas) => nlas.as_slice().emit(buffer),
             Veth(ref msg) => msg.emit(buffer),
             IpVlan(ref nlas) => nlas.as_slice().emit(buffer),
@@ -319,20 +534,26 @@ impl Nla for InfoData {
             MacVtap(ref nlas) => nlas.as_slice().emit(buffer),
             Vrf(ref nlas) => nlas.as_slice().emit(buffer),
             Vxlan(ref nlas) => nlas.as_slice().emit(buffer),
+            Bond(ref nlas) => nlas.as_slice().emit(buffer),
+            GreTap(ref nlas)
+                | GreTap6(ref nlas)
+                | GreTun(ref nlas)
+                | GreTun6(ref nlas)
+                | Erspan(ref nlas)
+                => nlas.as_slice().emit(buffer),
+            IpTun(ref nlas)
+                | SitTun(ref nlas)
+                | Ip6Tnl(ref nlas)
+                => nlas.as_slice().emit(buffer),
+            Vti(ref nlas) => nlas.as_slice().emit(buffer),
+            Geneve(ref nlas) => nlas.as_slice().emit(buffer),
+            Macsec(ref nlas) => nlas.as_slice().emit(buffer),
             Dummy(ref bytes)
-                | Tun(ref bytes)
                 | Nlmon(ref bytes)
                 | Ifb(ref bytes)
-                | Bond(ref bytes)
-                | GreTap(ref bytes)
-                | GreTap6(ref bytes)
-                | IpTun(ref bytes)
-                | SitTun(ref bytes)
-                | GreTun(ref bytes)
-                | GreTun6(ref bytes)
-                | Vti(ref bytes)
                 | Gtp(ref bytes)
                 | Wireguard(ref bytes)
+                | BareUdp(ref bytes)
                 | Other(ref bytes)
                 => buffer.copy_from_slice(bytes),
         }
@@ -343,6 +564,40 @@ impl Nla for InfoData {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoSlaveData {
+    Bridge(Vec<InfoBridgePort>),
+    Bond(Vec<InfoBondPort>),
+    Vrf(Vec<InfoVrfPort>),
+    Other(Vec<u8>),
+}
+
+impl Nla for InfoSlaveData {
+    fn value_len(&self) -> usize {
+        use self::InfoSlaveData::*;
+        match self {
+            Bridge(ref nlas) => nlas.as_slice().buffer_len(),
+            Bond(ref nlas) => nlas.as_slice().buffer_len(),
+            Vrf(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref bytes) => bytes.len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoSlaveData::*;
+        match self {
+            Bridge(ref nlas) => nlas.as_slice().emit(buffer),
+            Bond(ref nlas) => nlas.as_slice().emit(buffer),
+            Vrf(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref bytes) => buffer.copy_from_slice(bytes),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        IFLA_INFO_SLAVE_DATA
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum InfoKind {
     Dummy,
@@ -368,6 +623,11 @@ pub enum InfoKind {
     Gtp,
     Ipoib,
     Wireguard,
+    Geneve,
+    Erspan,
+    Ip6Tnl,
+    BareUdp,
+    Macsec,
     Other(String),
 }
 
@@ -398,6 +658,11 @@ impl Nla for InfoKind {
             Gtp => GTP.len(),
             Ipoib => IPOIB.len(),
             Wireguard => WIREGUARD.len(),
+            Geneve => GENEVE.len(),
+            Erspan => ERSPAN.len(),
+            Ip6Tnl => IP6TNL.len(),
+            BareUdp => BAREUDP.len(),
+            Macsec => MACSEC.len(),
             Other(ref s) => s.len(),
         };
         len + 1
@@ -429,6 +694,11 @@ impl Nla for InfoKind {
             Gtp => GTP,
             Ipoib => IPOIB,
             Wireguard => WIREGUARD,
+            Geneve => GENEVE,
+            Erspan => ERSPAN,
+            Ip6Tnl => IP6TNL,
+            BareUdp => BAREUDP,
+            Macsec => MACSEC,
             Other(ref s) => s.as_str(),
         };
         buffer[..s.len()].copy_from_slice(s.as_bytes());
@@ -443,7 +713,8 @@ impl Nla for InfoKind {
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<InfoKind, DecodeError> {
         use self::InfoKind::*;
-        if buf.kind() != IFLA_INFO_KIND {
+        // The same strings are used for IFLA_INFO_SLAVE_KIND
+        if buf.kind() != IFLA_INFO_KIND && buf.kind() != IFLA_INFO_SLAVE_KIND {
             return Err(
                 format!("failed to parse IFLA_INFO_KIND: NLA type is {}", buf.kind()).into(),
             );
@@ -473,6 +744,11 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
             GTP => Gtp,
             IPOIB => Ipoib,
             WIREGUARD => Wireguard,
+            GENEVE => Geneve,
+            ERSPAN => Erspan,
+            IP6TNL => Ip6Tnl,
+            BAREUDP => BareUdp,
+            MACSEC => Macsec,
             _ => Other(s),
         })
     }
@@ -714,1397 +990,3523 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVxlan {
     }
 }
 
-// https://elixir.bootlin.com/linux/latest/source/net/8021q/vlan_netlink.c#L21
+// https://elixir.bootlin.com/linux/v5.9.8/source/net/ipv4/ip_gre.c#L1489
+// https://elixir.bootlin.com/linux/v5.9.8/source/net/ipv6/ip6_gre.c#L2104
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum InfoVlan {
+pub enum InfoGreTun {
     Unspec(Vec<u8>),
-    Id(u16),
-    Flags((u32, u32)),
-    EgressQos(Vec<u8>),
-    IngressQos(Vec<u8>),
-    Protocol(u16),
+    Link(u32),
+    /// Combination of the `GRE_*` flags expected in received packets
+    IFlags(u16),
+    /// Combination of the `GRE_*` flags set in sent packets
+    OFlags(u16),
+    /// Key expected in received packets, only used with `GRE_KEY`
+    IKey(u32),
+    /// Key set in sent packets, only used with `GRE_KEY`
+    OKey(u32),
+    Local(IpAddr),
+    Remote(IpAddr),
+    Ttl(u8),
+    Tos(u8),
+    /// Whether path MTU discovery is enabled, for IPv4 tunnels only
+    PMtuDisc(u8),
+    EncapLimit(u8),
+    FlowInfo(u32),
+    /// Combination of the `IP6_TNL_F_*` flags, for IPv6 tunnels only
+    Flags(u32),
+    /// One of the `TUNNEL_ENCAP_*` constants
+    EncapType(u16),
+    /// Combination of the `TUNNEL_ENCAP_FLAG_*` flags
+    EncapFlags(u16),
+    EncapSport(u16),
+    EncapDport(u16),
+    CollectMetadata,
+    IgnoreDf(u8),
+    FwMark(u32),
+    ErspanIndex(u32),
+    ErspanVer(u8),
+    ErspanDir(u8),
+    ErspanHwid(u16),
+    Other(DefaultNla),
 }
 
-impl Nla for InfoVlan {
+impl Nla for InfoGreTun {
     #[rustfmt::skip]
     fn value_len(&self) -> usize {
-        use self::InfoVlan::*;
-        match self {
-            Id(_) | Protocol(_) => 2,
-            Flags(_) => 8,
-            Unspec(bytes)
-                | EgressQos(bytes)
-                | IngressQos(bytes)
-                => bytes.len(),
+        use self::InfoGreTun::*;
+        match *self {
+            CollectMetadata => 0,
+            Ttl(_)
+                | Tos(_)
+                | PMtuDisc(_)
+                | EncapLimit(_)
+                | IgnoreDf(_)
+                | ErspanVer(_)
+                | ErspanDir(_)
+            => 1,
+            IFlags(_)
+                | OFlags(_)
+                | EncapType(_)
+                | EncapFlags(_)
+                | EncapSport(_)
+                | EncapDport(_)
+                | ErspanHwid(_)
+            => 2,
+            Link(_)
+                | IKey(_)
+                | OKey(_)
+                | FlowInfo(_)
+                | Flags(_)
+                | FwMark(_)
+                | ErspanIndex(_)
+            => 4,
+            Local(ref addr) | Remote(ref addr) => ip_len(addr),
+            Unspec(ref bytes) => bytes.len(),
+            Other(ref nla) => nla.value_len(),
         }
     }
 
     #[rustfmt::skip]
     fn emit_value(&self, buffer: &mut [u8]) {
-        use self::InfoVlan::*;
-        match self {
-            Unspec(ref bytes)
-                | EgressQos(ref bytes)
-                | IngressQos(ref bytes)
-                => buffer.copy_from_slice(bytes),
-
-            Id(ref value)
-                | Protocol(ref value)
-                => NativeEndian::write_u16(buffer, *value),
-
-            Flags(ref flags) => {
-                NativeEndian::write_u32(buffer, flags.0);
-                NativeEndian::write_u32(buffer, flags.1)
-            }
+        use self::InfoGreTun::*;
+        match *self {
+            CollectMetadata => {}
+            Ttl(value)
+                | Tos(value)
+                | PMtuDisc(value)
+                | EncapLimit(value)
+                | IgnoreDf(value)
+                | ErspanVer(value)
+                | ErspanDir(value)
+            => buffer[0] = value,
+            EncapType(value)
+                | EncapFlags(value)
+                | ErspanHwid(value)
+            => NativeEndian::write_u16(buffer, value),
+            IFlags(value)
+                | OFlags(value)
+                | EncapSport(value)
+                | EncapDport(value)
+            => BigEndian::write_u16(buffer, value),
+            Link(value)
+                | Flags(value)
+                | FwMark(value)
+                | ErspanIndex(value)
+            => NativeEndian::write_u32(buffer, value),
+            IKey(value)
+                | OKey(value)
+                | FlowInfo(value)
+            => BigEndian::write_u32(buffer, value),
+            Local(ref addr) | Remote(ref addr) => emit_ip(buffer, addr),
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
-        use self::InfoVlan::*;
-        match self {
-            Unspec(_) => IFLA_VLAN_UNSPEC,
-            Id(_) => IFLA_VLAN_ID,
-            Flags(_) => IFLA_VLAN_FLAGS,
-            EgressQos(_) => IFLA_VLAN_EGRESS_QOS,
-            IngressQos(_) => IFLA_VLAN_INGRESS_QOS,
-            Protocol(_) => IFLA_VLAN_PROTOCOL,
+        use self::InfoGreTun::*;
+        match *self {
+            Unspec(_) => IFLA_GRE_UNSPEC,
+            Link(_) => IFLA_GRE_LINK,
+            IFlags(_) => IFLA_GRE_IFLAGS,
+            OFlags(_) => IFLA_GRE_OFLAGS,
+            IKey(_) => IFLA_GRE_IKEY,
+            OKey(_) => IFLA_GRE_OKEY,
+            Local(_) => IFLA_GRE_LOCAL,
+            Remote(_) => IFLA_GRE_REMOTE,
+            Ttl(_) => IFLA_GRE_TTL,
+            Tos(_) => IFLA_GRE_TOS,
+            PMtuDisc(_) => IFLA_GRE_PMTUDISC,
+            EncapLimit(_) => IFLA_GRE_ENCAP_LIMIT,
+            FlowInfo(_) => IFLA_GRE_FLOWINFO,
+            Flags(_) => IFLA_GRE_FLAGS,
+            EncapType(_) => IFLA_GRE_ENCAP_TYPE,
+            EncapFlags(_) => IFLA_GRE_ENCAP_FLAGS,
+            EncapSport(_) => IFLA_GRE_ENCAP_SPORT,
+            EncapDport(_) => IFLA_GRE_ENCAP_DPORT,
+            CollectMetadata => IFLA_GRE_COLLECT_METADATA,
+            IgnoreDf(_) => IFLA_GRE_IGNORE_DF,
+            FwMark(_) => IFLA_GRE_FWMARK,
+            ErspanIndex(_) => IFLA_GRE_ERSPAN_INDEX,
+            ErspanVer(_) => IFLA_GRE_ERSPAN_VER,
+            ErspanDir(_) => IFLA_GRE_ERSPAN_DIR,
+            ErspanHwid(_) => IFLA_GRE_ERSPAN_HWID,
+            Other(ref nla) => nla.kind(),
         }
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVlan {
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGreTun {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        use self::InfoVlan::*;
+        use self::InfoGreTun::*;
         let payload = buf.value();
         Ok(match buf.kind() {
-            IFLA_VLAN_UNSPEC => Unspec(payload.to_vec()),
-            IFLA_VLAN_ID => Id(parse_u16(payload).context("invalid IFLA_VLAN_ID value")?),
-            IFLA_VLAN_FLAGS => {
-                let err = "invalid IFLA_VLAN_FLAGS value";
-                if payload.len() != 8 {
-                    return Err(err.into());
-                }
-                let flags = parse_u32(&payload[0..4]).context(err)?;
-                let mask = parse_u32(&payload[4..]).context(err)?;
-                Flags((flags, mask))
+            IFLA_GRE_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_GRE_LINK => Link(parse_u32(payload).context("invalid IFLA_GRE_LINK value")?),
+            IFLA_GRE_IFLAGS => {
+                IFlags(parse_u16_be(payload).context("invalid IFLA_GRE_IFLAGS value")?)
             }
-            IFLA_VLAN_EGRESS_QOS => EgressQos(payload.to_vec()),
-            IFLA_VLAN_INGRESS_QOS => IngressQos(payload.to_vec()),
-            IFLA_VLAN_PROTOCOL => {
-                Protocol(parse_u16_be(payload).context("invalid IFLA_VLAN_PROTOCOL value")?)
+            IFLA_GRE_OFLAGS => {
+                OFlags(parse_u16_be(payload).context("invalid IFLA_GRE_OFLAGS value")?)
             }
-            _ => return Err(format!("unknown NLA type {}", buf.kind()).into()),
+            IFLA_GRE_IKEY => IKey(parse_u32_be(payload).context("invalid IFLA_GRE_IKEY value")?),
+            IFLA_GRE_OKEY => OKey(parse_u32_be(payload).context("invalid IFLA_GRE_OKEY value")?),
+            IFLA_GRE_LOCAL => Local(parse_ip(payload).context("invalid IFLA_GRE_LOCAL value")?),
+            IFLA_GRE_REMOTE => Remote(parse_ip(payload).context("invalid IFLA_GRE_REMOTE value")?),
+            IFLA_GRE_TTL => Ttl(parse_u8(payload).context("invalid IFLA_GRE_TTL value")?),
+            IFLA_GRE_TOS => Tos(parse_u8(payload).context("invalid IFLA_GRE_TOS value")?),
+            IFLA_GRE_PMTUDISC => {
+                PMtuDisc(parse_u8(payload).context("invalid IFLA_GRE_PMTUDISC value")?)
+            }
+            IFLA_GRE_ENCAP_LIMIT => {
+                EncapLimit(parse_u8(payload).context("invalid IFLA_GRE_ENCAP_LIMIT value")?)
+            }
+            IFLA_GRE_FLOWINFO => {
+                FlowInfo(parse_u32_be(payload).context("invalid IFLA_GRE_FLOWINFO value")?)
+            }
+            IFLA_GRE_FLAGS => Flags(parse_u32(payload).context("invalid IFLA_GRE_FLAGS value")?),
+            IFLA_GRE_ENCAP_TYPE => {
+                EncapType(parse_u16(payload).context("invalid IFLA_GRE_ENCAP_TYPE value")?)
+            }
+            IFLA_GRE_ENCAP_FLAGS => {
+                EncapFlags(parse_u16(payload).context("invalid IFLA_GRE_ENCAP_FLAGS value")?)
+            }
+            IFLA_GRE_ENCAP_SPORT => {
+                EncapSport(parse_u16_be(payload).context("invalid IFLA_GRE_ENCAP_SPORT value")?)
+            }
+            IFLA_GRE_ENCAP_DPORT => {
+                EncapDport(parse_u16_be(payload).context("invalid IFLA_GRE_ENCAP_DPORT value")?)
+            }
+            IFLA_GRE_COLLECT_METADATA => CollectMetadata,
+            IFLA_GRE_IGNORE_DF => {
+                IgnoreDf(parse_u8(payload).context("invalid IFLA_GRE_IGNORE_DF value")?)
+            }
+            IFLA_GRE_FWMARK => FwMark(parse_u32(payload).context("invalid IFLA_GRE_FWMARK value")?),
+            IFLA_GRE_ERSPAN_INDEX => {
+                ErspanIndex(parse_u32(payload).context("invalid IFLA_GRE_ERSPAN_INDEX value")?)
+            }
+            IFLA_GRE_ERSPAN_VER => {
+                ErspanVer(parse_u8(payload).context("invalid IFLA_GRE_ERSPAN_VER value")?)
+            }
+            IFLA_GRE_ERSPAN_DIR => {
+                ErspanDir(parse_u8(payload).context("invalid IFLA_GRE_ERSPAN_DIR value")?)
+            }
+            IFLA_GRE_ERSPAN_HWID => {
+                ErspanHwid(parse_u16(payload).context("invalid IFLA_GRE_ERSPAN_HWID value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
         })
     }
 }
 
+// https://elixir.bootlin.com/linux/v5.9.8/source/net/ipv4/ipip.c#L575
+// https://elixir.bootlin.com/linux/v5.9.8/source/net/ipv6/sit.c#L1727
+// https://elixir.bootlin.com/linux/v5.9.8/source/net/ipv6/ip6_tunnel.c#L2145
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum InfoBridge {
+pub enum InfoIpTun {
     Unspec(Vec<u8>),
-    GroupAddr([u8; 6]),
-    // FIXME: what type is this? putting Vec<u8> for now but it might
-    // be a boolean actually
-    FdbFlush(Vec<u8>),
+    Link(u32),
+    Local(IpAddr),
+    Remote(IpAddr),
+    Ttl(u8),
+    Tos(u8),
+    EncapLimit(u8),
+    FlowInfo(u32),
+    /// Combination of the `SIT_*` flags, for sit tunnels
     Flags(u16),
-    Pad(Vec<u8>),
-    HelloTimer(u64),
-    TcnTimer(u64),
-    TopologyChangeTimer(u64),
-    GcTimer(u64),
-    MulticastMembershipInterval(u64),
-    MulticastQuerierInterval(u64),
-    MulticastQueryInterval(u64),
-    MulticastQueryResponseInterval(u64),
-    MulticastLastMemberInterval(u64),
-    MulticastStartupQueryInterval(u64),
-    ForwardDelay(u32),
-    HelloTime(u32),
-    MaxAge(u32),
-    AgeingTime(u32),
-    StpState(u32),
-    MulticastHashElasticity(u32),
-    MulticastHashMax(u32),
-    MulticastLastMemberCount(u32),
-    MulticastStartupQueryCount(u32),
-    RootPathCost(u32),
-    Priority(u16),
-    VlanProtocol(u16),
-    GroupFwdMask(u16),
-    RootId((u16, [u8; 6])),
-    BridgeId((u16, [u8; 6])),
-    RootPort(u16),
-    VlanDefaultPvid(u16),
-    VlanFiltering(u8),
-    VlanInfo(u16),
-    TopologyChange(u8),
-    TopologyChangeDetected(u8),
-    MulticastRouter(u8),
-    MulticastSnooping(u8),
-    MulticastQueryUseIfaddr(u8),
-    MulticastQuerier(u8),
-    NfCallIpTables(u8),
-    NfCallIp6Tables(u8),
-    NfCallArpTables(u8),
-    VlanStatsEnabled(u8),
-    MulticastStatsEnabled(u8),
-    MulticastIgmpVersion(u8),
-    MulticastMldVersion(u8),
-    VlanStatsPerHost(u8),
-    MultiBoolOpt(u64),
+    /// Combination of the `IP6_TNL_F_*` flags, for ip6tnl tunnels
+    Ip6TnlFlags(u32),
+    /// Inner protocol: one of the `IPPROTO_*` constants, or 0 for any
+    Proto(u8),
+    /// Whether path MTU discovery is enabled, for IPv4 tunnels only
+    PMtuDisc(u8),
+    SixRdPrefix(Ipv6Addr),
+    SixRdRelayPrefix(Ipv4Addr),
+    SixRdPrefixLen(u16),
+    SixRdRelayPrefixLen(u16),
+    /// One of the `TUNNEL_ENCAP_*` constants
+    EncapType(u16),
+    /// Combination of the `TUNNEL_ENCAP_FLAG_*` flags
+    EncapFlags(u16),
+    EncapSport(u16),
+    EncapDport(u16),
+    CollectMetadata,
+    FwMark(u32),
     Other(DefaultNla),
 }
 
-impl Nla for InfoBridge {
+impl Nla for InfoIpTun {
     #[rustfmt::skip]
     fn value_len(&self) -> usize {
-        use self::InfoBridge::*;
-        match self {
-            Unspec(bytes)
-                | FdbFlush(bytes)
-                | Pad(bytes)
-                => bytes.len(),
-            HelloTimer(_)
-                | TcnTimer(_)
-                | TopologyChangeTimer(_)
-                | GcTimer(_)
-                | MulticastMembershipInterval(_)
-                | MulticastQuerierInterval(_)
-                | MulticastQueryInterval(_)
-                | MulticastQueryResponseInterval(_)
-                | MulticastLastMemberInterval(_)
-                | MulticastStartupQueryInterval(_)
-                => 8,
-            ForwardDelay(_)
-                | HelloTime(_)
-                | MaxAge(_)
-                | AgeingTime(_)
-                | StpState(_)
-                | MulticastHashElasticity(_)
-                | MulticastHashMax(_)
-                | MulticastLastMemberCount(_)
-                | MulticastStartupQueryCount(_)
-                | RootPathCost(_)
-                => 4,
-            Priority(_)
-                | VlanInfo(_)
-                | VlanProtocol(_)
-                | GroupFwdMask(_)
-                | RootPort(_)
-                | VlanDefaultPvid(_)
-                | Flags(_)
-                => 2,
-
-            RootId(_)
-                | BridgeId(_)
-                | MultiBoolOpt(_)
-                => 8,
-
-            GroupAddr(_) => 6,
-
-            VlanFiltering(_)
-                | TopologyChange(_)
-                | TopologyChangeDetected(_)
-                | MulticastRouter(_)
-                | MulticastSnooping(_)
-                | MulticastQueryUseIfaddr(_)
-                | MulticastQuerier(_)
-                | NfCallIpTables(_)
-                | NfCallIp6Tables(_)
-                | NfCallArpTables(_)
-                | VlanStatsEnabled(_)
-                | MulticastStatsEnabled(_)
-                | MulticastIgmpVersion(_)
-                | MulticastMldVersion(_)
-                | VlanStatsPerHost(_)
-                => 1,
-            Other(nla)
-                => nla.value_len(),
+        use self::InfoIpTun::*;
+        match *self {
+            CollectMetadata => 0,
+            Ttl(_)
+                | Tos(_)
+                | EncapLimit(_)
+                | Proto(_)
+                | PMtuDisc(_)
+            => 1,
+            Flags(_)
+                | SixRdPrefixLen(_)
+                | SixRdRelayPrefixLen(_)
+                | EncapType(_)
+                | EncapFlags(_)
+                | EncapSport(_)
+                | EncapDport(_)
+            => 2,
+            Link(_)
+                | FlowInfo(_)
+                | Ip6TnlFlags(_)
+                | SixRdRelayPrefix(_)
+                | FwMark(_)
+            => 4,
+            SixRdPrefix(_) => 16,
+            Local(ref addr) | Remote(ref addr) => ip_len(addr),
+            Unspec(ref bytes) => bytes.len(),
+            Other(ref nla) => nla.value_len(),
         }
     }
 
     #[rustfmt::skip]
     fn emit_value(&self, buffer: &mut [u8]) {
-        use self::InfoBridge::*;
-        match self {
-            Flags(value) => NativeEndian::write_u16(buffer, *value),
-            VlanInfo(value) => NativeEndian::write_u16(buffer, *value),
-            Unspec(ref bytes)
-                | FdbFlush(ref bytes)
-                | Pad(ref bytes)
-                => buffer.copy_from_slice(bytes),
-
-            HelloTimer(ref value)
-                | TcnTimer(ref value)
-                | TopologyChangeTimer(ref value)
-                | GcTimer(ref value)
-                | MulticastMembershipInterval(ref value)
-                | MulticastQuerierInterval(ref value)
-                | MulticastQueryInterval(ref value)
-                | MulticastQueryResponseInterval(ref value)
-                | MulticastLastMemberInterval(ref value)
-                | MulticastStartupQueryInterval(ref value)
-                | MultiBoolOpt(ref value)
-                => NativeEndian::write_u64(buffer, *value),
-
-            ForwardDelay(ref value)
-                | HelloTime(ref value)
-                | MaxAge(ref value)
-                | AgeingTime(ref value)
-                | StpState(ref value)
-                | MulticastHashElasticity(ref value)
-                | MulticastHashMax(ref value)
-                | MulticastLastMemberCount(ref value)
-                | MulticastStartupQueryCount(ref value)
-                | RootPathCost(ref value)
-                => NativeEndian::write_u32(buffer, *value),
-
-            Priority(ref value)
-                | GroupFwdMask(ref value)
-                | RootPort(ref value)
-                | VlanDefaultPvid(ref value)
-                => NativeEndian::write_u16(buffer, *value),
-
-            VlanProtocol(ref value)
-                => BigEndian::write_u16(buffer, *value),
-
-            RootId((ref priority, ref address))
-                | BridgeId((ref priority, ref address))
-                => {
-                    NativeEndian::write_u16(buffer, *priority);
-                    buffer[2..].copy_from_slice(&address[..]);
-                }
-
-            GroupAddr(ref value) => buffer.copy_from_slice(&value[..]),
-
-            VlanFiltering(ref value)
-                | TopologyChange(ref value)
-                | TopologyChangeDetected(ref value)
-                | MulticastRouter(ref value)
-                | MulticastSnooping(ref value)
-                | MulticastQueryUseIfaddr(ref value)
-                | MulticastQuerier(ref value)
-                | NfCallIpTables(ref value)
-                | NfCallIp6Tables(ref value)
-                | NfCallArpTables(ref value)
-                | VlanStatsEnabled(ref value)
-                | MulticastStatsEnabled(ref value)
-                | MulticastIgmpVersion(ref value)
-                | MulticastMldVersion(ref value)
-                | VlanStatsPerHost(ref value)
-                => buffer[0] = *value,
-
-            Other(nla)
-                => nla.emit_value(buffer),
+        use self::InfoIpTun::*;
+        match *self {
+            CollectMetadata => {}
+            Ttl(value)
+                | Tos(value)
+                | EncapLimit(value)
+                | Proto(value)
+                | PMtuDisc(value)
+            => buffer[0] = value,
+            Flags(value)
+                | SixRdPrefixLen(value)
+                | SixRdRelayPrefixLen(value)
+                | EncapType(value)
+                | EncapFlags(value)
+            => NativeEndian::write_u16(buffer, value),
+            EncapSport(value) | EncapDport(value) => BigEndian::write_u16(buffer, value),
+            Link(value)
+                | Ip6TnlFlags(value)
+                | FwMark(value)
+            => NativeEndian::write_u32(buffer, value),
+            FlowInfo(value) => BigEndian::write_u32(buffer, value),
+            SixRdPrefix(ref addr) => buffer.copy_from_slice(&addr.octets()),
+            SixRdRelayPrefix(ref addr) => buffer.copy_from_slice(&addr.octets()),
+            Local(ref addr) | Remote(ref addr) => emit_ip(buffer, addr),
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
-        use self::InfoBridge::*;
-        match self {
-            Unspec(_) => IFLA_BR_UNSPEC,
-            GroupAddr(_) => IFLA_BR_GROUP_ADDR,
-            FdbFlush(_) => IFLA_BR_FDB_FLUSH,
-            Flags(_) => IFLA_BRIDGE_FLAGS,
-            Pad(_) => IFLA_BR_PAD,
-            HelloTimer(_) => IFLA_BR_HELLO_TIMER,
-            TcnTimer(_) => IFLA_BR_TCN_TIMER,
-            TopologyChangeTimer(_) => IFLA_BR_TOPOLOGY_CHANGE_TIMER,
-            GcTimer(_) => IFLA_BR_GC_TIMER,
-            MulticastMembershipInterval(_) => IFLA_BR_MCAST_MEMBERSHIP_INTVL,
-            MulticastQuerierInterval(_) => IFLA_BR_MCAST_QUERIER_INTVL,
-            MulticastQueryInterval(_) => IFLA_BR_MCAST_QUERY_INTVL,
-            MulticastQueryResponseInterval(_) => IFLA_BR_MCAST_QUERY_RESPONSE_INTVL,
-            ForwardDelay(_) => IFLA_BR_FORWARD_DELAY,
-            HelloTime(_) => IFLA_BR_HELLO_TIME,
-            MaxAge(_) => IFLA_BR_MAX_AGE,
-            AgeingTime(_) => IFLA_BR_AGEING_TIME,
-            StpState(_) => IFLA_BR_STP_STATE,
-            MulticastHashElasticity(_) => IFLA_BR_MCAST_HASH_ELASTICITY,
-            MulticastHashMax(_) => IFLA_BR_MCAST_HASH_MAX,
-            MulticastLastMemberCount(_) => IFLA_BR_MCAST_LAST_MEMBER_CNT,
-            MulticastStartupQueryCount(_) => IFLA_BR_MCAST_STARTUP_QUERY_CNT,
-            MulticastLastMemberInterval(_) => IFLA_BR_MCAST_LAST_MEMBER_INTVL,
-            MulticastStartupQueryInterval(_) => IFLA_BR_MCAST_STARTUP_QUERY_INTVL,
-            RootPathCost(_) => IFLA_BR_ROOT_PATH_COST,
-            Priority(_) => IFLA_BR_PRIORITY,
-            VlanProtocol(_) => IFLA_BR_VLAN_PROTOCOL,
-            GroupFwdMask(_) => IFLA_BR_GROUP_FWD_MASK,
-            RootId(_) => IFLA_BR_ROOT_ID,
-            BridgeId(_) => IFLA_BR_BRIDGE_ID,
-            RootPort(_) => IFLA_BR_ROOT_PORT,
-            VlanDefaultPvid(_) => IFLA_BR_VLAN_DEFAULT_PVID,
-            VlanFiltering(_) => IFLA_BR_VLAN_FILTERING,
-            VlanInfo(_) => IFLA_BRIDGE_VLAN_INFO,
-            TopologyChange(_) => IFLA_BR_TOPOLOGY_CHANGE,
-            TopologyChangeDetected(_) => IFLA_BR_TOPOLOGY_CHANGE_DETECTED,
-            MulticastRouter(_) => IFLA_BR_MCAST_ROUTER,
-            MulticastSnooping(_) => IFLA_BR_MCAST_SNOOPING,
-            MulticastQueryUseIfaddr(_) => IFLA_BR_MCAST_QUERY_USE_IFADDR,
-            MulticastQuerier(_) => IFLA_BR_MCAST_QUERIER,
-            NfCallIpTables(_) => IFLA_BR_NF_CALL_IPTABLES,
-            NfCallIp6Tables(_) => IFLA_BR_NF_CALL_IP6TABLES,
-            NfCallArpTables(_) => IFLA_BR_NF_CALL_ARPTABLES,
-            VlanStatsEnabled(_) => IFLA_BR_VLAN_STATS_ENABLED,
-            MulticastStatsEnabled(_) => IFLA_BR_MCAST_STATS_ENABLED,
-            MulticastIgmpVersion(_) => IFLA_BR_MCAST_IGMP_VERSION,
-            MulticastMldVersion(_) => IFLA_BR_MCAST_MLD_VERSION,
-            VlanStatsPerHost(_) => IFLA_BR_VLAN_STATS_PER_PORT,
-            MultiBoolOpt(_) => IFLA_BR_MULTI_BOOLOPT,
-            Other(nla) => nla.kind(),
+        use self::InfoIpTun::*;
+        match *self {
+            Unspec(_) => IFLA_IPTUN_UNSPEC,
+            Link(_) => IFLA_IPTUN_LINK,
+            Local(_) => IFLA_IPTUN_LOCAL,
+            Remote(_) => IFLA_IPTUN_REMOTE,
+            Ttl(_) => IFLA_IPTUN_TTL,
+            Tos(_) => IFLA_IPTUN_TOS,
+            EncapLimit(_) => IFLA_IPTUN_ENCAP_LIMIT,
+            FlowInfo(_) => IFLA_IPTUN_FLOWINFO,
+            Flags(_) | Ip6TnlFlags(_) => IFLA_IPTUN_FLAGS,
+            Proto(_) => IFLA_IPTUN_PROTO,
+            PMtuDisc(_) => IFLA_IPTUN_PMTUDISC,
+            SixRdPrefix(_) => IFLA_IPTUN_6RD_PREFIX,
+            SixRdRelayPrefix(_) => IFLA_IPTUN_6RD_RELAY_PREFIX,
+            SixRdPrefixLen(_) => IFLA_IPTUN_6RD_PREFIXLEN,
+            SixRdRelayPrefixLen(_) => IFLA_IPTUN_6RD_RELAY_PREFIXLEN,
+            EncapType(_) => IFLA_IPTUN_ENCAP_TYPE,
+            EncapFlags(_) => IFLA_IPTUN_ENCAP_FLAGS,
+            EncapSport(_) => IFLA_IPTUN_ENCAP_SPORT,
+            EncapDport(_) => IFLA_IPTUN_ENCAP_DPORT,
+            CollectMetadata => IFLA_IPTUN_COLLECT_METADATA,
+            FwMark(_) => IFLA_IPTUN_FWMARK,
+            Other(ref nla) => nla.kind(),
         }
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBridge {
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpTun {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        use self::InfoBridge::*;
+        use self::InfoIpTun::*;
         let payload = buf.value();
         Ok(match buf.kind() {
-            IFLA_BR_UNSPEC => Unspec(payload.to_vec()),
-            IFLA_BR_FDB_FLUSH => FdbFlush(payload.to_vec()),
-            IFLA_BR_PAD => Pad(payload.to_vec()),
-            IFLA_BR_HELLO_TIMER => {
-                HelloTimer(parse_u64(payload).context("invalid IFLA_BR_HELLO_TIMER value")?)
-            }
-            IFLA_BRIDGE_VLAN_INFO => {
-                VlanInfo(parse_u16(payload).context("invalid IFLA_BRIDGE_VLAN_INFO value")?)
-            }
-            IFLA_BR_TCN_TIMER => {
-                TcnTimer(parse_u64(payload).context("invalid IFLA_BR_TCN_TIMER value")?)
+            IFLA_IPTUN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_IPTUN_LINK => Link(parse_u32(payload).context("invalid IFLA_IPTUN_LINK value")?),
+            IFLA_IPTUN_LOCAL => Local(parse_ip(payload).context("invalid IFLA_IPTUN_LOCAL value")?),
+            IFLA_IPTUN_REMOTE => {
+                Remote(parse_ip(payload).context("invalid IFLA_IPTUN_REMOTE value")?)
             }
-            IFLA_BRIDGE_FLAGS => {
-                Flags(parse_u16(payload).context("invalid IFLA_BRIDGE_FLAGS value")?)
-            }
-            IFLA_BR_TOPOLOGY_CHANGE_TIMER => TopologyChangeTimer(
-                parse_u64(payload).context("invalid IFLA_BR_TOPOLOGY_CHANGE_TIMER value")?,
-            ),
-            IFLA_BR_GC_TIMER => {
-                GcTimer(parse_u64(payload).context("invalid IFLA_BR_GC_TIMER value")?)
+            IFLA_IPTUN_TTL => Ttl(parse_u8(payload).context("invalid IFLA_IPTUN_TTL value")?),
+            IFLA_IPTUN_TOS => Tos(parse_u8(payload).context("invalid IFLA_IPTUN_TOS value")?),
+            IFLA_IPTUN_ENCAP_LIMIT => {
+                EncapLimit(parse_u8(payload).context("invalid IFLA_IPTUN_ENCAP_LIMIT value")?)
             }
-            IFLA_BR_MCAST_LAST_MEMBER_INTVL => MulticastLastMemberInterval(
-                parse_u64(payload).context("invalid IFLA_BR_MCAST_LAST_MEMBER_INTVL value")?,
-            ),
-            IFLA_BR_MCAST_MEMBERSHIP_INTVL => MulticastMembershipInterval(
-                parse_u64(payload).context("invalid IFLA_BR_MCAST_MEMBERSHIP_INTVL value")?,
-            ),
-            IFLA_BR_MCAST_QUERIER_INTVL => MulticastQuerierInterval(
-                parse_u64(payload).context("invalid IFLA_BR_MCAST_QUERIER_INTVL value")?,
-            ),
-            IFLA_BR_MCAST_QUERY_INTVL => MulticastQueryInterval(
-                parse_u64(payload).context("invalid IFLA_BR_MCAST_QUERY_INTVL value")?,
-            ),
-            IFLA_BR_MCAST_QUERY_RESPONSE_INTVL => MulticastQueryResponseInterval(
-                parse_u64(payload).context("invalid IFLA_BR_MCAST_QUERY_RESPONSE_INTVL value")?,
-            ),
-            IFLA_BR_MCAST_STARTUP_QUERY_INTVL => MulticastStartupQueryInterval(
-                parse_u64(payload).context("invalid IFLA_BR_MCAST_STARTUP_QUERY_INTVL value")?,
-            ),
-            IFLA_BR_FORWARD_DELAY => {
-                ForwardDelay(parse_u32(payload).context("invalid IFLA_BR_FORWARD_DELAY value")?)
+            IFLA_IPTUN_FLOWINFO => {
+                FlowInfo(parse_u32_be(payload).context("invalid IFLA_IPTUN_FLOWINFO value")?)
             }
-            IFLA_BR_HELLO_TIME => {
-                HelloTime(parse_u32(payload).context("invalid IFLA_BR_HELLO_TIME value")?)
+            // sit uses a 16 bits value, ip6tnl a 32 bits one
+            IFLA_IPTUN_FLAGS if payload.len() == 4 => {
+                Ip6TnlFlags(parse_u32(payload).context("invalid IFLA_IPTUN_FLAGS value")?)
             }
-            IFLA_BR_MAX_AGE => MaxAge(parse_u32(payload).context("invalid IFLA_BR_MAX_AGE value")?),
-            IFLA_BR_AGEING_TIME => {
-                AgeingTime(parse_u32(payload).context("invalid IFLA_BR_AGEING_TIME value")?)
+            IFLA_IPTUN_FLAGS => {
+                Flags(parse_u16(payload).context("invalid IFLA_IPTUN_FLAGS value")?)
             }
-            IFLA_BR_STP_STATE => {
-                StpState(parse_u32(payload).context("invalid IFLA_BR_STP_STATE value")?)
+            IFLA_IPTUN_PROTO => Proto(parse_u8(payload).context("invalid IFLA_IPTUN_PROTO value")?),
+            IFLA_IPTUN_PMTUDISC => {
+                PMtuDisc(parse_u8(payload).context("invalid IFLA_IPTUN_PMTUDISC value")?)
             }
-            IFLA_BR_MCAST_HASH_ELASTICITY => MulticastHashElasticity(
-                parse_u32(payload).context("invalid IFLA_BR_MCAST_HASH_ELASTICITY value")?,
+            IFLA_IPTUN_6RD_PREFIX => SixRdPrefix(
+                parse_ipv6(payload)
+                    .context("invalid IFLA_IPTUN_6RD_PREFIX value")?
+                    .into(),
             ),
-            IFLA_BR_MCAST_HASH_MAX => MulticastHashMax(
-                parse_u32(payload).context("invalid IFLA_BR_MCAST_HASH_MAX value")?,
-            ),
-            IFLA_BR_MCAST_LAST_MEMBER_CNT => MulticastLastMemberCount(
-                parse_u32(payload).context("invalid IFLA_BR_MCAST_LAST_MEMBER_CNT value")?,
+            IFLA_IPTUN_6RD_RELAY_PREFIX => {
+                let err = "invalid IFLA_IPTUN_6RD_RELAY_PREFIX value";
+                if payload.len() != 4 {
+                    return Err(err.into());
+                }
+                SixRdRelayPrefix(Ipv4Addr::new(
+                    payload[0], payload[1], payload[2], payload[3],
+                ))
+            }
+            IFLA_IPTUN_6RD_PREFIXLEN => SixRdPrefixLen(
+                parse_u16(payload).context("invalid IFLA_IPTUN_6RD_PREFIXLEN value")?,
             ),
-            IFLA_BR_MCAST_STARTUP_QUERY_CNT => MulticastStartupQueryCount(
-                parse_u32(payload).context("invalid IFLA_BR_MCAST_STARTUP_QUERY_CNT value")?,
+            IFLA_IPTUN_6RD_RELAY_PREFIXLEN => SixRdRelayPrefixLen(
+                parse_u16(payload).context("invalid IFLA_IPTUN_6RD_RELAY_PREFIXLEN value")?,
             ),
-            IFLA_BR_ROOT_PATH_COST => {
-                RootPathCost(parse_u32(payload).context("invalid IFLA_BR_ROOT_PATH_COST value")?)
+            IFLA_IPTUN_ENCAP_TYPE => {
+                EncapType(parse_u16(payload).context("invalid IFLA_IPTUN_ENCAP_TYPE value")?)
             }
-            IFLA_BR_PRIORITY => {
-                Priority(parse_u16(payload).context("invalid IFLA_BR_PRIORITY value")?)
+            IFLA_IPTUN_ENCAP_FLAGS => {
+                EncapFlags(parse_u16(payload).context("invalid IFLA_IPTUN_ENCAP_FLAGS value")?)
             }
-            IFLA_BR_VLAN_PROTOCOL => {
-                VlanProtocol(parse_u16_be(payload).context("invalid IFLA_BR_VLAN_PROTOCOL value")?)
+            IFLA_IPTUN_ENCAP_SPORT => {
+                EncapSport(parse_u16_be(payload).context("invalid IFLA_IPTUN_ENCAP_SPORT value")?)
             }
-            IFLA_BR_GROUP_FWD_MASK => {
-                GroupFwdMask(parse_u16(payload).context("invalid IFLA_BR_GROUP_FWD_MASK value")?)
+            IFLA_IPTUN_ENCAP_DPORT => {
+                EncapDport(parse_u16_be(payload).context("invalid IFLA_IPTUN_ENCAP_DPORT value")?)
             }
-            IFLA_BR_ROOT_ID | IFLA_BR_BRIDGE_ID => {
-                if payload.len() != 8 {
-                    return Err("invalid IFLA_BR_ROOT_ID or IFLA_BR_BRIDGE_ID value".into());
-                }
-
-                let priority = NativeEndian::read_u16(&payload[..2]);
-                let address = parse_mac(&payload[2..])
-                    .context("invalid IFLA_BR_ROOT_ID or IFLA_BR_BRIDGE_ID value")?;
-
-                match buf.kind() {
-                    IFLA_BR_ROOT_ID => RootId((priority, address)),
-                    IFLA_BR_BRIDGE_ID => BridgeId((priority, address)),
-                    _ => unreachable!(),
-                }
-            }
-            IFLA_BR_GROUP_ADDR => {
-                GroupAddr(parse_mac(payload).context("invalid IFLA_BR_GROUP_ADDR value")?)
-            }
-            IFLA_BR_ROOT_PORT => {
-                RootPort(parse_u16(payload).context("invalid IFLA_BR_ROOT_PORT value")?)
-            }
-            IFLA_BR_VLAN_DEFAULT_PVID => VlanDefaultPvid(
-                parse_u16(payload).context("invalid IFLA_BR_VLAN_DEFAULT_PVID value")?,
-            ),
-            IFLA_BR_VLAN_FILTERING => {
-                VlanFiltering(parse_u8(payload).context("invalid IFLA_BR_VLAN_FILTERING value")?)
-            }
-            IFLA_BR_TOPOLOGY_CHANGE => {
-                TopologyChange(parse_u8(payload).context("invalid IFLA_BR_TOPOLOGY_CHANGE value")?)
-            }
-            IFLA_BR_TOPOLOGY_CHANGE_DETECTED => TopologyChangeDetected(
-                parse_u8(payload).context("invalid IFLA_BR_TOPOLOGY_CHANGE_DETECTED value")?,
-            ),
-            IFLA_BR_MCAST_ROUTER => {
-                MulticastRouter(parse_u8(payload).context("invalid IFLA_BR_MCAST_ROUTER value")?)
-            }
-            IFLA_BR_MCAST_SNOOPING => MulticastSnooping(
-                parse_u8(payload).context("invalid IFLA_BR_MCAST_SNOOPING value")?,
-            ),
-            IFLA_BR_MCAST_QUERY_USE_IFADDR => MulticastQueryUseIfaddr(
-                parse_u8(payload).context("invalid IFLA_BR_MCAST_QUERY_USE_IFADDR value")?,
-            ),
-            IFLA_BR_MCAST_QUERIER => {
-                MulticastQuerier(parse_u8(payload).context("invalid IFLA_BR_MCAST_QUERIER value")?)
-            }
-            IFLA_BR_NF_CALL_IPTABLES => {
-                NfCallIpTables(parse_u8(payload).context("invalid IFLA_BR_NF_CALL_IPTABLES value")?)
-            }
-            IFLA_BR_NF_CALL_IP6TABLES => NfCallIp6Tables(
-                parse_u8(payload).context("invalid IFLA_BR_NF_CALL_IP6TABLES value")?,
-            ),
-            IFLA_BR_NF_CALL_ARPTABLES => NfCallArpTables(
-                parse_u8(payload).context("invalid IFLA_BR_NF_CALL_ARPTABLES value")?,
-            ),
-            IFLA_BR_VLAN_STATS_ENABLED => VlanStatsEnabled(
-                parse_u8(payload).context("invalid IFLA_BR_VLAN_STATS_ENABLED value")?,
-            ),
-            IFLA_BR_MCAST_STATS_ENABLED => MulticastStatsEnabled(
-                parse_u8(payload).context("invalid IFLA_BR_MCAST_STATS_ENABLED value")?,
-            ),
-            IFLA_BR_MCAST_IGMP_VERSION => MulticastIgmpVersion(
-                parse_u8(payload).context("invalid IFLA_BR_MCAST_IGMP_VERSION value")?,
-            ),
-            IFLA_BR_MCAST_MLD_VERSION => MulticastMldVersion(
-                parse_u8(payload).context("invalid IFLA_BR_MCAST_MLD_VERSION value")?,
-            ),
-            IFLA_BR_VLAN_STATS_PER_PORT => VlanStatsPerHost(
-                parse_u8(payload).context("invalid IFLA_BR_VLAN_STATS_PER_PORT value")?,
-            ),
-            IFLA_BR_MULTI_BOOLOPT => {
-                MultiBoolOpt(parse_u64(payload).context("invalid IFLA_BR_MULTI_BOOLOPT value")?)
+            IFLA_IPTUN_COLLECT_METADATA => CollectMetadata,
+            IFLA_IPTUN_FWMARK => {
+                FwMark(parse_u32(payload).context("invalid IFLA_IPTUN_FWMARK value")?)
             }
-            _ => Other(
-                DefaultNla::parse(buf)
-                    .context("invalid link info bridge NLA value (unknown type)")?,
-            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
         })
     }
 }
 
+// https://elixir.bootlin.com/linux/v5.9.8/source/net/ipv4/ip_vti.c#L622
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum InfoIpoib {
+pub enum InfoVti {
     Unspec(Vec<u8>),
-    Pkey(u16),
-    Mode(u16),
-    UmCast(u16),
+    Link(u32),
+    IKey(u32),
+    OKey(u32),
+    Local(IpAddr),
+    Remote(IpAddr),
+    FwMark(u32),
     Other(DefaultNla),
 }
 
-impl Nla for InfoIpoib {
+impl Nla for InfoVti {
     fn value_len(&self) -> usize {
-        use self::InfoIpoib::*;
-        match self {
-            Unspec(bytes) => bytes.len(),
-            Pkey(_) | Mode(_) | UmCast(_) => 2,
-            Other(nla) => nla.value_len(),
+        use self::InfoVti::*;
+        match *self {
+            Link(_) | IKey(_) | OKey(_) | FwMark(_) => 4,
+            Local(ref addr) | Remote(ref addr) => ip_len(addr),
+            Unspec(ref bytes) => bytes.len(),
+            Other(ref nla) => nla.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
-        use self::InfoIpoib::*;
-        match self {
-            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
-            Pkey(value) => NativeEndian::write_u16(buffer, *value),
-            Mode(value) => NativeEndian::write_u16(buffer, *value),
-            UmCast(value) => NativeEndian::write_u16(buffer, *value),
-            Other(nla) => nla.emit_value(buffer),
+        use self::InfoVti::*;
+        match *self {
+            Link(value) | FwMark(value) => NativeEndian::write_u32(buffer, value),
+            IKey(value) | OKey(value) => BigEndian::write_u32(buffer, value),
+            Local(ref addr) | Remote(ref addr) => emit_ip(buffer, addr),
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
-        use self::InfoIpoib::*;
-        match self {
-            Unspec(_) => IFLA_IPOIB_UNSPEC,
-            Pkey(_) => IFLA_IPOIB_PKEY,
-            Mode(_) => IFLA_IPOIB_MODE,
-            UmCast(_) => IFLA_IPOIB_UMCAST,
-            Other(nla) => nla.kind(),
+        use self::InfoVti::*;
+        match *self {
+            Unspec(_) => IFLA_VTI_UNSPEC,
+            Link(_) => IFLA_VTI_LINK,
+            IKey(_) => IFLA_VTI_IKEY,
+            OKey(_) => IFLA_VTI_OKEY,
+            Local(_) => IFLA_VTI_LOCAL,
+            Remote(_) => IFLA_VTI_REMOTE,
+            FwMark(_) => IFLA_VTI_FWMARK,
+            Other(ref nla) => nla.kind(),
         }
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpoib {
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVti {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        use self::InfoIpoib::*;
+        use self::InfoVti::*;
         let payload = buf.value();
         Ok(match buf.kind() {
-            IFLA_IPOIB_UNSPEC => Unspec(payload.to_vec()),
-            IFLA_IPOIB_PKEY => Pkey(parse_u16(payload).context("invalid IFLA_IPOIB_PKEY value")?),
-            IFLA_IPOIB_MODE => Mode(parse_u16(payload).context("invalid IFLA_IPOIB_MODE value")?),
-            IFLA_IPOIB_UMCAST => {
-                UmCast(parse_u16(payload).context("invalid IFLA_IPOIB_UMCAST value")?)
-            }
+            IFLA_VTI_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VTI_LINK => Link(parse_u32(payload).context("invalid IFLA_VTI_LINK value")?),
+            IFLA_VTI_IKEY => IKey(parse_u32_be(payload).context("invalid IFLA_VTI_IKEY value")?),
+            IFLA_VTI_OKEY => OKey(parse_u32_be(payload).context("invalid IFLA_VTI_OKEY value")?),
+            IFLA_VTI_LOCAL => Local(parse_ip(payload).context("invalid IFLA_VTI_LOCAL value")?),
+            IFLA_VTI_REMOTE => Remote(parse_ip(payload).context("invalid IFLA_VTI_REMOTE value")?),
+            IFLA_VTI_FWMARK => FwMark(parse_u32(payload).context("invalid IFLA_VTI_FWMARK value")?),
             kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
         })
     }
 }
 
+// https://elixir.bootlin.com/linux/v5.9.8/source/drivers/net/geneve.c#L1712
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum VethInfo {
+pub enum InfoGeneve {
     Unspec(Vec<u8>),
-    Peer(LinkMessage),
+    Id(u32),
+    Remote(Ipv4Addr),
+    Remote6(Ipv6Addr),
+    Ttl(u8),
+    Tos(u8),
+    Port(u16),
+    CollectMetadata,
+    UDPCsum(u8),
+    UDPZeroCsumTX(u8),
+    UDPZeroCsumRX(u8),
+    Label(u32),
+    TtlInherit(u8),
+    /// One of the `GENEVE_DF_*` constants
+    Df(u8),
+    InnerProtoInherit,
     Other(DefaultNla),
 }
 
-impl Nla for VethInfo {
+impl Nla for InfoGeneve {
+    #[rustfmt::skip]
     fn value_len(&self) -> usize {
-        use self::VethInfo::*;
+        use self::InfoGeneve::*;
         match *self {
+            CollectMetadata | InnerProtoInherit => 0,
+            Ttl(_)
+                | Tos(_)
+                | UDPCsum(_)
+                | UDPZeroCsumTX(_)
+                | UDPZeroCsumRX(_)
+                | TtlInherit(_)
+                | Df(_)
+            => 1,
+            Port(_) => 2,
+            Id(_) | Remote(_) | Label(_) => 4,
+            Remote6(_) => 16,
             Unspec(ref bytes) => bytes.len(),
-            Peer(ref message) => message.buffer_len(),
-            Other(ref attr) => attr.value_len(),
+            Other(ref nla) => nla.value_len(),
         }
     }
 
+    #[rustfmt::skip]
     fn emit_value(&self, buffer: &mut [u8]) {
-        use self::VethInfo::*;
+        use self::InfoGeneve::*;
         match *self {
+            CollectMetadata | InnerProtoInherit => {}
+            Ttl(value)
+                | Tos(value)
+                | UDPCsum(value)
+                | UDPZeroCsumTX(value)
+                | UDPZeroCsumRX(value)
+                | TtlInherit(value)
+                | Df(value)
+            => buffer[0] = value,
+            Port(value) => BigEndian::write_u16(buffer, value),
+            Id(value) => NativeEndian::write_u32(buffer, value),
+            Label(value) => BigEndian::write_u32(buffer, value),
+            Remote(ref addr) => buffer.copy_from_slice(&addr.octets()),
+            Remote6(ref addr) => buffer.copy_from_slice(&addr.octets()),
             Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
-            Peer(ref message) => message.emit(buffer),
-            Other(ref attr) => attr.emit_value(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
-        use self::VethInfo::*;
+        use self::InfoGeneve::*;
         match *self {
-            Unspec(_) => VETH_INFO_UNSPEC,
-            Peer(_) => VETH_INFO_PEER,
-            Other(ref attr) => attr.kind(),
+            Unspec(_) => IFLA_GENEVE_UNSPEC,
+            Id(_) => IFLA_GENEVE_ID,
+            Remote(_) => IFLA_GENEVE_REMOTE,
+            Remote6(_) => IFLA_GENEVE_REMOTE6,
+            Ttl(_) => IFLA_GENEVE_TTL,
+            Tos(_) => IFLA_GENEVE_TOS,
+            Port(_) => IFLA_GENEVE_PORT,
+            CollectMetadata => IFLA_GENEVE_COLLECT_METADATA,
+            UDPCsum(_) => IFLA_GENEVE_UDP_CSUM,
+            UDPZeroCsumTX(_) => IFLA_GENEVE_UDP_ZERO_CSUM6_TX,
+            UDPZeroCsumRX(_) => IFLA_GENEVE_UDP_ZERO_CSUM6_RX,
+            Label(_) => IFLA_GENEVE_LABEL,
+            TtlInherit(_) => IFLA_GENEVE_TTL_INHERIT,
+            Df(_) => IFLA_GENEVE_DF,
+            InnerProtoInherit => IFLA_GENEVE_INNER_PROTO_INHERIT,
+            Other(ref nla) => nla.kind(),
         }
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VethInfo {
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGeneve {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        use self::VethInfo::*;
+        use self::InfoGeneve::*;
         let payload = buf.value();
         Ok(match buf.kind() {
-            VETH_INFO_UNSPEC => Unspec(payload.to_vec()),
-            VETH_INFO_PEER => {
-                let err = "failed to parse veth link info";
-                let buffer = LinkMessageBuffer::new_checked(&payload).context(err)?;
-                Peer(LinkMessage::parse(&buffer).context(err)?)
+            IFLA_GENEVE_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_GENEVE_ID => Id(parse_u32(payload).context("invalid IFLA_GENEVE_ID value")?),
+            IFLA_GENEVE_REMOTE => {
+                let err = "invalid IFLA_GENEVE_REMOTE value";
+                if payload.len() != 4 {
+                    return Err(err.into());
+                }
+                Remote(Ipv4Addr::new(
+                    payload[0], payload[1], payload[2], payload[3],
+                ))
+            }
+            IFLA_GENEVE_REMOTE6 => Remote6(
+                parse_ipv6(payload)
+                    .context("invalid IFLA_GENEVE_REMOTE6 value")?
+                    .into(),
+            ),
+            IFLA_GENEVE_TTL => Ttl(parse_u8(payload).context("invalid IFLA_GENEVE_TTL value")?),
+            IFLA_GENEVE_TOS => Tos(parse_u8(payload).context("invalid IFLA_GENEVE_TOS value")?),
+            IFLA_GENEVE_PORT => {
+                Port(parse_u16_be(payload).context("invalid IFLA_GENEVE_PORT value")?)
+            }
+            IFLA_GENEVE_COLLECT_METADATA => CollectMetadata,
+            IFLA_GENEVE_UDP_CSUM => {
+                UDPCsum(parse_u8(payload).context("invalid IFLA_GENEVE_UDP_CSUM value")?)
+            }
+            IFLA_GENEVE_UDP_ZERO_CSUM6_TX => UDPZeroCsumTX(
+                parse_u8(payload).context("invalid IFLA_GENEVE_UDP_ZERO_CSUM6_TX value")?,
+            ),
+            IFLA_GENEVE_UDP_ZERO_CSUM6_RX => UDPZeroCsumRX(
+                parse_u8(payload).context("invalid IFLA_GENEVE_UDP_ZERO_CSUM6_RX value")?,
+            ),
+            IFLA_GENEVE_LABEL => {
+                Label(parse_u32_be(payload).context("invalid IFLA_GENEVE_LABEL value")?)
+            }
+            IFLA_GENEVE_TTL_INHERIT => {
+                TtlInherit(parse_u8(payload).context("invalid IFLA_GENEVE_TTL_INHERIT value")?)
             }
+            IFLA_GENEVE_DF => Df(parse_u8(payload).context("invalid IFLA_GENEVE_DF value")?),
+            IFLA_GENEVE_INNER_PROTO_INHERIT => InnerProtoInherit,
             kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
         })
     }
 }
 
+// https://elixir.bootlin.com/linux/v5.9.8/source/drivers/net/macsec.c#L4174
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum InfoIpVlan {
+pub enum InfoMacsec {
     Unspec(Vec<u8>),
-    Mode(u16),
-    Flags(u16),
+    /// Secure channel identifier: the MAC address of the link followed by
+    /// the port number
+    Sci(u64),
+    Port(u16),
+    IcvLen(u8),
+    /// One of the `MACSEC_CIPHER_ID_*` constants
+    CipherSuite(u64),
+    Window(u32),
+    EncodingSa(u8),
+    Encrypt(u8),
+    Protect(u8),
+    IncSci(u8),
+    Es(u8),
+    Scb(u8),
+    ReplayProtect(u8),
+    /// One of the `MACSEC_VALIDATE_*` constants
+    Validation(u8),
+    /// One of the `MACSEC_OFFLOAD_*` constants
+    Offload(u8),
     Other(DefaultNla),
 }
 
-impl Nla for InfoIpVlan {
+impl Nla for InfoMacsec {
+    #[rustfmt::skip]
     fn value_len(&self) -> usize {
-        use self::InfoIpVlan::*;
-        match self {
-            Unspec(bytes) => bytes.len(),
-            Mode(_) | Flags(_) => 2,
-            Other(nla) => nla.value_len(),
+        use self::InfoMacsec::*;
+        match *self {
+            IcvLen(_)
+                | EncodingSa(_)
+                | Encrypt(_)
+                | Protect(_)
+                | IncSci(_)
+                | Es(_)
+                | Scb(_)
+                | ReplayProtect(_)
+                | Validation(_)
+                | Offload(_)
+            => 1,
+            Port(_) => 2,
+            Window(_) => 4,
+            Sci(_) | CipherSuite(_) => 8,
+            Unspec(ref bytes) => bytes.len(),
+            Other(ref nla) => nla.value_len(),
         }
     }
 
+    #[rustfmt::skip]
     fn emit_value(&self, buffer: &mut [u8]) {
-        use self::InfoIpVlan::*;
-        match self {
-            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
-            Mode(value) => NativeEndian::write_u16(buffer, *value),
-            Flags(value) => NativeEndian::write_u16(buffer, *value),
-            Other(nla) => nla.emit_value(buffer),
+        use self::InfoMacsec::*;
+        match *self {
+            IcvLen(value)
+                | EncodingSa(value)
+                | Encrypt(value)
+                | Protect(value)
+                | IncSci(value)
+                | Es(value)
+                | Scb(value)
+                | ReplayProtect(value)
+                | Validation(value)
+                | Offload(value)
+            => buffer[0] = value,
+            Port(value) => BigEndian::write_u16(buffer, value),
+            Window(value) => NativeEndian::write_u32(buffer, value),
+            Sci(value) => BigEndian::write_u64(buffer, value),
+            CipherSuite(value) => NativeEndian::write_u64(buffer, value),
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
-        use self::InfoIpVlan::*;
-        match self {
-            Unspec(_) => IFLA_IPVLAN_UNSPEC,
-            Mode(_) => IFLA_IPVLAN_MODE,
-            Flags(_) => IFLA_IPVLAN_FLAGS,
-            Other(nla) => nla.kind(),
+        use self::InfoMacsec::*;
+        match *self {
+            Unspec(_) => IFLA_MACSEC_UNSPEC,
+            Sci(_) => IFLA_MACSEC_SCI,
+            Port(_) => IFLA_MACSEC_PORT,
+            IcvLen(_) => IFLA_MACSEC_ICV_LEN,
+            CipherSuite(_) => IFLA_MACSEC_CIPHER_SUITE,
+            Window(_) => IFLA_MACSEC_WINDOW,
+            EncodingSa(_) => IFLA_MACSEC_ENCODING_SA,
+            Encrypt(_) => IFLA_MACSEC_ENCRYPT,
+            Protect(_) => IFLA_MACSEC_PROTECT,
+            IncSci(_) => IFLA_MACSEC_INC_SCI,
+            Es(_) => IFLA_MACSEC_ES,
+            Scb(_) => IFLA_MACSEC_SCB,
+            ReplayProtect(_) => IFLA_MACSEC_REPLAY_PROTECT,
+            Validation(_) => IFLA_MACSEC_VALIDATION,
+            Offload(_) => IFLA_MACSEC_OFFLOAD,
+            Other(ref nla) => nla.kind(),
         }
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpVlan {
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoMacsec {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        use self::InfoIpVlan::*;
+        use self::InfoMacsec::*;
         let payload = buf.value();
         Ok(match buf.kind() {
-            IFLA_IPVLAN_UNSPEC => Unspec(payload.to_vec()),
-            IFLA_IPVLAN_MODE => Mode(parse_u16(payload).context("invalid IFLA_IPVLAN_MODE value")?),
-            IFLA_IPVLAN_FLAGS => {
-                Flags(parse_u16(payload).context("invalid IFLA_IPVLAN_FLAGS value")?)
+            IFLA_MACSEC_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_MACSEC_SCI => Sci(parse_u64_be(payload).context("invalid IFLA_MACSEC_SCI value")?),
+            IFLA_MACSEC_PORT => {
+                Port(parse_u16_be(payload).context("invalid IFLA_MACSEC_PORT value")?)
+            }
+            IFLA_MACSEC_ICV_LEN => {
+                IcvLen(parse_u8(payload).context("invalid IFLA_MACSEC_ICV_LEN value")?)
+            }
+            IFLA_MACSEC_CIPHER_SUITE => {
+                CipherSuite(parse_u64(payload).context("invalid IFLA_MACSEC_CIPHER_SUITE value")?)
+            }
+            IFLA_MACSEC_WINDOW => {
+                Window(parse_u32(payload).context("invalid IFLA_MACSEC_WINDOW value")?)
+            }
+            IFLA_MACSEC_ENCODING_SA => {
+                EncodingSa(parse_u8(payload).context("invalid IFLA_MACSEC_ENCODING_SA value")?)
+            }
+            IFLA_MACSEC_ENCRYPT => {
+                Encrypt(parse_u8(payload).context("invalid IFLA_MACSEC_ENCRYPT value")?)
+            }
+            IFLA_MACSEC_PROTECT => {
+                Protect(parse_u8(payload).context("invalid IFLA_MACSEC_PROTECT value")?)
+            }
+            IFLA_MACSEC_INC_SCI => {
+                IncSci(parse_u8(payload).context("invalid IFLA_MACSEC_INC_SCI value")?)
+            }
+            IFLA_MACSEC_ES => Es(parse_u8(payload).context("invalid IFLA_MACSEC_ES value")?),
+            IFLA_MACSEC_SCB => Scb(parse_u8(payload).context("invalid IFLA_MACSEC_SCB value")?),
+            IFLA_MACSEC_REPLAY_PROTECT => ReplayProtect(
+                parse_u8(payload).context("invalid IFLA_MACSEC_REPLAY_PROTECT value")?,
+            ),
+            IFLA_MACSEC_VALIDATION => {
+                Validation(parse_u8(payload).context("invalid IFLA_MACSEC_VALIDATION value")?)
+            }
+            IFLA_MACSEC_OFFLOAD => {
+                Offload(parse_u8(payload).context("invalid IFLA_MACSEC_OFFLOAD value")?)
             }
             kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
         })
     }
 }
 
+// https://elixir.bootlin.com/linux/latest/source/net/8021q/vlan_netlink.c#L21
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum InfoVrf {
-    TableId(u32),
-    Other(DefaultNla),
+pub enum InfoVlan {
+    Unspec(Vec<u8>),
+    Id(u16),
+    Flags((u32, u32)),
+    EgressQos(Vec<u8>),
+    IngressQos(Vec<u8>),
+    Protocol(u16),
 }
 
-impl Nla for InfoVrf {
+impl Nla for InfoVlan {
+    #[rustfmt::skip]
     fn value_len(&self) -> usize {
-        use self::InfoVrf::*;
+        use self::InfoVlan::*;
         match self {
-            TableId(_) => 4,
-            Other(nla) => nla.value_len(),
+            Id(_) | Protocol(_) => 2,
+            Flags(_) => 8,
+            Unspec(bytes)
+                | EgressQos(bytes)
+                | IngressQos(bytes)
+                => bytes.len(),
         }
     }
 
+    #[rustfmt::skip]
     fn emit_value(&self, buffer: &mut [u8]) {
-        use self::InfoVrf::*;
+        use self::InfoVlan::*;
         match self {
-            TableId(value) => NativeEndian::write_u32(buffer, *value),
-            Other(nla) => nla.emit_value(buffer),
+            Unspec(ref bytes)
+                | EgressQos(ref bytes)
+                | IngressQos(ref bytes)
+                => buffer.copy_from_slice(bytes),
+
+            Id(ref value)
+                | Protocol(ref value)
+                => NativeEndian::write_u16(buffer, *value),
+
+            Flags(ref flags) => {
+                NativeEndian::write_u32(buffer, flags.0);
+                NativeEndian::write_u32(buffer, flags.1)
+            }
         }
     }
 
     fn kind(&self) -> u16 {
-        use self::InfoVrf::*;
+        use self::InfoVlan::*;
         match self {
-            TableId(_) => IFLA_VRF_TABLE,
-            Other(nla) => nla.kind(),
-        }
+            Unspec(_) => IFLA_VLAN_UNSPEC,
+            Id(_) => IFLA_VLAN_ID,
+            Flags(_) => IFLA_VLAN_FLAGS,
+            EgressQos(_) => IFLA_VLAN_EGRESS_QOS,
+            IngressQos(_) => IFLA_VLAN_INGRESS_QOS,
+            Protocol(_) => IFLA_VLAN_PROTOCOL,
+        }
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVrf {
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVlan {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        use self::InfoVrf::*;
+        use self::InfoVlan::*;
         let payload = buf.value();
         Ok(match buf.kind() {
-            IFLA_VRF_TABLE => TableId(parse_u32(payload).context("invalid IFLA_VRF_TABLE value")?),
-            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+            IFLA_VLAN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VLAN_ID => Id(parse_u16(payload).context("invalid IFLA_VLAN_ID value")?),
+            IFLA_VLAN_FLAGS => {
+                let err = "invalid IFLA_VLAN_FLAGS value";
+                if payload.len() != 8 {
+                    return Err(err.into());
+                }
+                let flags = parse_u32(&payload[0..4]).context(err)?;
+                let mask = parse_u32(&payload[4..]).context(err)?;
+                Flags((flags, mask))
+            }
+            IFLA_VLAN_EGRESS_QOS => EgressQos(payload.to_vec()),
+            IFLA_VLAN_INGRESS_QOS => IngressQos(payload.to_vec()),
+            IFLA_VLAN_PROTOCOL => {
+                Protocol(parse_u16_be(payload).context("invalid IFLA_VLAN_PROTOCOL value")?)
+            }
+            _ => return Err(format!("unknown NLA type {}", buf.kind()).into()),
         })
     }
 }
 
+// `IFLA_BRIDGE_FLAGS` and `IFLA_BRIDGE_VLAN_INFO` used to be defined with these
+// values, which are not bridge device attributes. They are only kept for the
+// deprecated `InfoBridge::Flags` and `InfoBridge::VlanInfo` variants.
+const DEPRECATED_IFLA_BRIDGE_FLAGS: u16 = 47;
+const DEPRECATED_IFLA_BRIDGE_VLAN_INFO: u16 = 48;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum InfoMacVlan {
+pub enum InfoBridge {
     Unspec(Vec<u8>),
-    Mode(u32),
+    GroupAddr([u8; 6]),
+    // FIXME: what type is this? putting Vec<u8> for now but it might
+    // be a boolean actually
+    FdbFlush(Vec<u8>),
+    #[deprecated(note = "not a bridge device attribute, use `AfSpecBridge::Flags` instead")]
     Flags(u16),
-    MacAddrMode(u32),
-    MacAddr([u8; 6]),
-    MacAddrData(Vec<InfoMacVlan>),
-    MacAddrCount(u32),
+    Pad(Vec<u8>),
+    HelloTimer(u64),
+    TcnTimer(u64),
+    TopologyChangeTimer(u64),
+    GcTimer(u64),
+    MulticastMembershipInterval(u64),
+    MulticastQuerierInterval(u64),
+    MulticastQueryInterval(u64),
+    MulticastQueryResponseInterval(u64),
+    MulticastLastMemberInterval(u64),
+    MulticastStartupQueryInterval(u64),
+    ForwardDelay(u32),
+    HelloTime(u32),
+    MaxAge(u32),
+    AgeingTime(u32),
+    StpState(u32),
+    MulticastHashElasticity(u32),
+    MulticastHashMax(u32),
+    MulticastLastMemberCount(u32),
+    MulticastStartupQueryCount(u32),
+    RootPathCost(u32),
+    Priority(u16),
+    VlanProtocol(u16),
+    GroupFwdMask(u16),
+    RootId((u16, [u8; 6])),
+    BridgeId((u16, [u8; 6])),
+    RootPort(u16),
+    VlanDefaultPvid(u16),
+    VlanFiltering(u8),
+    #[deprecated(note = "not a bridge device attribute, use `AfSpecBridge::VlanInfo` instead")]
+    VlanInfo(u16),
+    TopologyChange(u8),
+    TopologyChangeDetected(u8),
+    MulticastRouter(u8),
+    MulticastSnooping(u8),
+    MulticastQueryUseIfaddr(u8),
+    MulticastQuerier(u8),
+    NfCallIpTables(u8),
+    NfCallIp6Tables(u8),
+    NfCallArpTables(u8),
+    VlanStatsEnabled(u8),
+    MulticastStatsEnabled(u8),
+    MulticastIgmpVersion(u8),
+    MulticastMldVersion(u8),
+    VlanStatsPerHost(u8),
+    MultiBoolOpt(u64),
     Other(DefaultNla),
 }
 
-impl Nla for InfoMacVlan {
+#[allow(deprecated)]
+impl Nla for InfoBridge {
+    #[rustfmt::skip]
     fn value_len(&self) -> usize {
-        use self::InfoMacVlan::*;
+        use self::InfoBridge::*;
         match self {
-            Unspec(bytes) => bytes.len(),
-            Mode(_) => 4,
-            Flags(_) => 2,
-            MacAddrMode(_) => 4,
-            MacAddr(_) => 6,
-            MacAddrData(ref nlas) => nlas.as_slice().buffer_len(),
-            MacAddrCount(_) => 4,
-            Other(nla) => nla.value_len(),
+            Unspec(bytes)
+                | FdbFlush(bytes)
+                | Pad(bytes)
+                => bytes.len(),
+            HelloTimer(_)
+                | TcnTimer(_)
+                | TopologyChangeTimer(_)
+                | GcTimer(_)
+                | MulticastMembershipInterval(_)
+                | MulticastQuerierInterval(_)
+                | MulticastQueryInterval(_)
+                | MulticastQueryResponseInterval(_)
+                | MulticastLastMemberInterval(_)
+                | MulticastStartupQueryInterval(_)
+                => 8,
+            ForwardDelay(_)
+                | HelloTime(_)
+                | MaxAge(_)
+                | AgeingTime(_)
+                | StpState(_)
+                | MulticastHashElasticity(_)
+                | MulticastHashMax(_)
+                | MulticastLastMemberCount(_)
+                | MulticastStartupQueryCount(_)
+                | RootPathCost(_)
+                => 4,
+            Priority(_)
+                | VlanInfo(_)
+                | VlanProtocol(_)
+                | GroupFwdMask(_)
+                | RootPort(_)
+                | VlanDefaultPvid(_)
+                | Flags(_)
+                => 2,
+
+            RootId(_)
+                | BridgeId(_)
+                | MultiBoolOpt(_)
+                => 8,
+
+            GroupAddr(_) => 6,
+
+            VlanFiltering(_)
+                | TopologyChange(_)
+                | TopologyChangeDetected(_)
+                | MulticastRouter(_)
+                | MulticastSnooping(_)
+                | MulticastQueryUseIfaddr(_)
+                | MulticastQuerier(_)
+                | NfCallIpTables(_)
+                | NfCallIp6Tables(_)
+                | NfCallArpTables(_)
+                | VlanStatsEnabled(_)
+                | MulticastStatsEnabled(_)
+                | MulticastIgmpVersion(_)
+                | MulticastMldVersion(_)
+                | VlanStatsPerHost(_)
+                => 1,
+            Other(nla)
+                => nla.value_len(),
         }
     }
 
+    #[rustfmt::skip]
     fn emit_value(&self, buffer: &mut [u8]) {
-        use self::InfoMacVlan::*;
+        use self::InfoBridge::*;
         match self {
-            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
-            Mode(value) => NativeEndian::write_u32(buffer, *value),
             Flags(value) => NativeEndian::write_u16(buffer, *value),
-            MacAddrMode(value) => NativeEndian::write_u32(buffer, *value),
-            MacAddr(bytes) => buffer.copy_from_slice(bytes),
-            MacAddrData(ref nlas) => nlas.as_slice().emit(buffer),
-            MacAddrCount(value) => NativeEndian::write_u32(buffer, *value),
-            Other(nla) => nla.emit_value(buffer),
+            VlanInfo(value) => NativeEndian::write_u16(buffer, *value),
+            Unspec(ref bytes)
+                | FdbFlush(ref bytes)
+                | Pad(ref bytes)
+                => buffer.copy_from_slice(bytes),
+
+            HelloTimer(ref value)
+                | TcnTimer(ref value)
+                | TopologyChangeTimer(ref value)
+                | GcTimer(ref value)
+                | MulticastMembershipInterval(ref value)
+                | MulticastQuerierInterval(ref value)
+                | MulticastQueryInterval(ref value)
+                | MulticastQueryResponseInterval(ref value)
+                | MulticastLastMemberInterval(ref value)
+                | MulticastStartupQueryInterval(ref value)
+                | MultiBoolOpt(ref value)
+                => NativeEndian::write_u64(buffer, *value),
+
+            ForwardDelay(ref value)
+                | HelloTime(ref value)
+                | MaxAge(ref value)
+                | AgeingTime(ref value)
+                | StpState(ref value)
+                | MulticastHashElasticity(ref value)
+                | MulticastHashMax(ref value)
+                | MulticastLastMemberCount(ref value)
+                | MulticastStartupQueryCount(ref value)
+                | RootPathCost(ref value)
+                => NativeEndian::write_u32(buffer, *value),
+
+            Priority(ref value)
+                | GroupFwdMask(ref value)
+                | RootPort(ref value)
+                | VlanDefaultPvid(ref value)
+                => NativeEndian::write_u16(buffer, *value),
+
+            VlanProtocol(ref value)
+                => BigEndian::write_u16(buffer, *value),
+
+            RootId((ref priority, ref address))
+                | BridgeId((ref priority, ref address))
+                => {
+                    NativeEndian::write_u16(buffer, *priority);
+                    buffer[2..].copy_from_slice(&address[..]);
+                }
+
+            GroupAddr(ref value) => buffer.copy_from_slice(&value[..]),
+
+            VlanFiltering(ref value)
+                | TopologyChange(ref value)
+                | TopologyChangeDetected(ref value)
+                | MulticastRouter(ref value)
+                | MulticastSnooping(ref value)
+                | MulticastQueryUseIfaddr(ref value)
+                | MulticastQuerier(ref value)
+                | NfCallIpTables(ref value)
+                | NfCallIp6Tables(ref value)
+                | NfCallArpTables(ref value)
+                | VlanStatsEnabled(ref value)
+                | MulticastStatsEnabled(ref value)
+                | MulticastIgmpVersion(ref value)
+                | MulticastMldVersion(ref value)
+                | VlanStatsPerHost(ref value)
+                => buffer[0] = *value,
+
+            Other(nla)
+                => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
-        use self::InfoMacVlan::*;
+        use self::InfoBridge::*;
         match self {
-            Unspec(_) => IFLA_MACVLAN_UNSPEC,
-            Mode(_) => IFLA_MACVLAN_MODE,
-            Flags(_) => IFLA_MACVLAN_FLAGS,
-            MacAddrMode(_) => IFLA_MACVLAN_MACADDR_MODE,
-            MacAddr(_) => IFLA_MACVLAN_MACADDR,
-            MacAddrData(_) => IFLA_MACVLAN_MACADDR_DATA,
-            MacAddrCount(_) => IFLA_MACVLAN_MACADDR_COUNT,
-            Other(nla) => nla.kind(),
-        }
-    }
-}
-
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoMacVlan {
-    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        use self::InfoMacVlan::*;
-        let payload = buf.value();
+            Unspec(_) => IFLA_BR_UNSPEC,
+            GroupAddr(_) => IFLA_BR_GROUP_ADDR,
+            FdbFlush(_) => IFLA_BR_FDB_FLUSH,
+            Flags(_) => DEPRECATED_IFLA_BRIDGE_FLAGS,
+            Pad(_) => IFLA_BR_PAD,
+            HelloTimer(_) => IFLA_BR_HELLO_TIMER,
+            TcnTimer(_) => IFLA_BR_TCN_TIMER,
+            TopologyChangeTimer(_) => IFLA_BR_TOPOLOGY_CHANGE_TIMER,
+            GcTimer(_) => IFLA_BR_GC_TIMER,
+            MulticastMembershipInterval(_) => IFLA_BR_MCAST_MEMBERSHIP_INTVL,
+            MulticastQuerierInterval(_) => IFLA_BR_MCAST_QUERIER_INTVL,
+            MulticastQueryInterval(_) => IFLA_BR_MCAST_QUERY_INTVL,
+            MulticastQueryResponseInterval(_) => IFLA_BR_MCAST_QUERY_RESPONSE_INTVL,
+            ForwardDelay(_) => IFLA_BR_FORWARD_DELAY,
+            HelloTime(_) => IFLA_BR_HELLO_TIME,
+            MaxAge(_) => IFLA_BR_MAX_AGE,
+            AgeingTime(_) => IFLA_BR_AGEING_TIME,
+            StpState(_) => IFLA_BR_STP_STATE,
+            MulticastHashElasticity(_) => IFLA_BR_MCAST_HASH_ELASTICITY,
+            MulticastHashMax(_) => IFLA_BR_MCAST_HASH_MAX,
+            MulticastLastMemberCount(_) => IFLA_BR_MCAST_LAST_MEMBER_CNT,
+            MulticastStartupQueryCount(_) => IFLA_BR_MCAST_STARTUP_QUERY_CNT,
+            MulticastLastMemberInterval(_) => IFLA_BR_MCAST_LAST_MEMBER_INTVL,
+            MulticastStartupQueryInterval(_) => IFLA_BR_MCAST_STARTUP_QUERY_INTVL,
+            RootPathCost(_) => IFLA_BR_ROOT_PATH_COST,
+            Priority(_) => IFLA_BR_PRIORITY,
+            VlanProtocol(_) => IFLA_BR_VLAN_PROTOCOL,
+            GroupFwdMask(_) => IFLA_BR_GROUP_FWD_MASK,
+            RootId(_) => IFLA_BR_ROOT_ID,
+            BridgeId(_) => IFLA_BR_BRIDGE_ID,
+            RootPort(_) => IFLA_BR_ROOT_PORT,
+            VlanDefaultPvid(_) => IFLA_BR_VLAN_DEFAULT_PVID,
+            VlanFiltering(_) => IFLA_BR_VLAN_FILTERING,
+            VlanInfo(_) => DEPRECATED_IFLA_BRIDGE_VLAN_INFO,
+            TopologyChange(_) => IFLA_BR_TOPOLOGY_CHANGE,
+            TopologyChangeDetected(_) => IFLA_BR_TOPOLOGY_CHANGE_DETECTED,
+            MulticastRouter(_) => IFLA_BR_MCAST_ROUTER,
+            MulticastSnooping(_) => IFLA_BR_MCAST_SNOOPING,
+            MulticastQueryUseIfaddr(_) => IFLA_BR_MCAST_QUERY_USE_IFADDR,
+            MulticastQuerier(_) => IFLA_BR_MCAST_QUERIER,
+            NfCallIpTables(_) => IFLA_BR_NF_CALL_IPTABLES,
+            NfCallIp6Tables(_) => IFLA_BR_NF_CALL_IP6TABLES,
+            NfCallArpTables(_) => IFLA_BR_NF_CALL_ARPTABLES,
+            VlanStatsEnabled(_) => IFLA_BR_VLAN_STATS_ENABLED,
+            MulticastStatsEnabled(_) => IFLA_BR_MCAST_STATS_ENABLED,
+            MulticastIgmpVersion(_) => IFLA_BR_MCAST_IGMP_VERSION,
+            MulticastMldVersion(_) => IFLA_BR_MCAST_MLD_VERSION,
+            VlanStatsPerHost(_) => IFLA_BR_VLAN_STATS_PER_PORT,
+            MultiBoolOpt(_) => IFLA_BR_MULTI_BOOLOPT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBridge {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoBridge::*;
+        let payload = buf.value();
         Ok(match buf.kind() {
-            IFLA_MACVLAN_UNSPEC => Unspec(payload.to_vec()),
-            IFLA_MACVLAN_MODE => {
-                Mode(parse_u32(payload).context("invalid IFLA_MACVLAN_MODE value")?)
+            IFLA_BR_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_BR_FDB_FLUSH => FdbFlush(payload.to_vec()),
+            IFLA_BR_PAD => Pad(payload.to_vec()),
+            IFLA_BR_HELLO_TIMER => {
+                HelloTimer(parse_u64(payload).context("invalid IFLA_BR_HELLO_TIMER value")?)
             }
-            IFLA_MACVLAN_FLAGS => {
-                Flags(parse_u16(payload).context("invalid IFLA_MACVLAN_FLAGS value")?)
+            DEPRECATED_IFLA_BRIDGE_VLAN_INFO => {
+                VlanInfo(parse_u16(payload).context("invalid IFLA_BRIDGE_VLAN_INFO value")?)
             }
-            IFLA_MACVLAN_MACADDR_MODE => {
-                MacAddrMode(parse_u32(payload).context("invalid IFLA_MACVLAN_MACADDR_MODE value")?)
+            IFLA_BR_TCN_TIMER => {
+                TcnTimer(parse_u64(payload).context("invalid IFLA_BR_TCN_TIMER value")?)
             }
-            IFLA_MACVLAN_MACADDR => {
-                MacAddr(parse_mac(payload).context("invalid IFLA_MACVLAN_MACADDR value")?)
+            DEPRECATED_IFLA_BRIDGE_FLAGS => {
+                Flags(parse_u16(payload).context("invalid IFLA_BRIDGE_FLAGS value")?)
             }
-            IFLA_MACVLAN_MACADDR_DATA => {
-                let mut mac_data = Vec::new();
-                let err = "failed to parse IFLA_MACVLAN_MACADDR_DATA";
-                for nla in NlasIterator::new(payload) {
-                    let nla = &nla.context(err)?;
-                    let parsed = InfoMacVlan::parse(nla).context(err)?;
-                    mac_data.push(parsed);
+            IFLA_BR_TOPOLOGY_CHANGE_TIMER => TopologyChangeTimer(
+                parse_u64(payload).context("invalid IFLA_BR_TOPOLOGY_CHANGE_TIMER value")?,
+            ),
+            IFLA_BR_GC_TIMER => {
+                GcTimer(parse_u64(payload).context("invalid IFLA_BR_GC_TIMER value")?)
+            }
+            IFLA_BR_MCAST_LAST_MEMBER_INTVL => MulticastLastMemberInterval(
+                parse_u64(payload).context("invalid IFLA_BR_MCAST_LAST_MEMBER_INTVL value")?,
+            ),
+            IFLA_BR_MCAST_MEMBERSHIP_INTVL => MulticastMembershipInterval(
+                parse_u64(payload).context("invalid IFLA_BR_MCAST_MEMBERSHIP_INTVL value")?,
+            ),
+            IFLA_BR_MCAST_QUERIER_INTVL => MulticastQuerierInterval(
+                parse_u64(payload).context("invalid IFLA_BR_MCAST_QUERIER_INTVL value")?,
+            ),
+            IFLA_BR_MCAST_QUERY_INTVL => MulticastQueryInterval(
+                parse_u64(payload).context("invalid IFLA_BR_MCAST_QUERY_INTVL value")?,
+            ),
+            IFLA_BR_MCAST_QUERY_RESPONSE_INTVL => MulticastQueryResponseInterval(
+                parse_u64(payload).context("invalid IFLA_BR_MCAST_QUERY_RESPONSE_INTVL value")?,
+            ),
+            IFLA_BR_MCAST_STARTUP_QUERY_INTVL => MulticastStartupQueryInterval(
+                parse_u64(payload).context("invalid IFLA_BR_MCAST_STARTUP_QUERY_INTVL value")?,
+            ),
+            IFLA_BR_FORWARD_DELAY => {
+                ForwardDelay(parse_u32(payload).context("invalid IFLA_BR_FORWARD_DELAY value")?)
+            }
+            IFLA_BR_HELLO_TIME => {
+                HelloTime(parse_u32(payload).context("invalid IFLA_BR_HELLO_TIME value")?)
+            }
+            IFLA_BR_MAX_AGE => MaxAge(parse_u32(payload).context("invalid IFLA_BR_MAX_AGE value")?),
+            IFLA_BR_AGEING_TIME => {
+                AgeingTime(parse_u32(payload).context("invalid IFLA_BR_AGEING_TIME value")?)
+            }
+            IFLA_BR_STP_STATE => {
+                StpState(parse_u32(payload).context("invalid IFLA_BR_STP_STATE value")?)
+            }
+            IFLA_BR_MCAST_HASH_ELASTICITY => MulticastHashElasticity(
+                parse_u32(payload).context("invalid IFLA_BR_MCAST_HASH_ELASTICITY value")?,
+            ),
+            IFLA_BR_MCAST_HASH_MAX => MulticastHashMax(
+                parse_u32(payload).context("invalid IFLA_BR_MCAST_HASH_MAX value")?,
+            ),
+            IFLA_BR_MCAST_LAST_MEMBER_CNT => MulticastLastMemberCount(
+                parse_u32(payload).context("invalid IFLA_BR_MCAST_LAST_MEMBER_CNT value")?,
+            ),
+            IFLA_BR_MCAST_STARTUP_QUERY_CNT => MulticastStartupQueryCount(
+                parse_u32(payload).context("invalid IFLA_BR_MCAST_STARTUP_QUERY_CNT value")?,
+            ),
+            IFLA_BR_ROOT_PATH_COST => {
+                RootPathCost(parse_u32(payload).context("invalid IFLA_BR_ROOT_PATH_COST value")?)
+            }
+            IFLA_BR_PRIORITY => {
+                Priority(parse_u16(payload).context("invalid IFLA_BR_PRIORITY value")?)
+            }
+            IFLA_BR_VLAN_PROTOCOL => {
+                VlanProtocol(parse_u16_be(payload).context("invalid IFLA_BR_VLAN_PROTOCOL value")?)
+            }
+            IFLA_BR_GROUP_FWD_MASK => {
+                GroupFwdMask(parse_u16(payload).context("invalid IFLA_BR_GROUP_FWD_MASK value")?)
+            }
+            IFLA_BR_ROOT_ID | IFLA_BR_BRIDGE_ID => {
+                if payload.len() != 8 {
+                    return Err("invalid IFLA_BR_ROOT_ID or IFLA_BR_BRIDGE_ID value".into());
+                }
+
+                let priority = NativeEndian::read_u16(&payload[..2]);
+                let address = parse_mac(&payload[2..])
+                    .context("invalid IFLA_BR_ROOT_ID or IFLA_BR_BRIDGE_ID value")?;
+
+                match buf.kind() {
+                    IFLA_BR_ROOT_ID => RootId((priority, address)),
+                    IFLA_BR_BRIDGE_ID => BridgeId((priority, address)),
+                    _ => unreachable!(),
                 }
-                MacAddrData(mac_data)
             }
-            IFLA_MACVLAN_MACADDR_COUNT => MacAddrCount(
-                parse_u32(payload).context("invalid IFLA_MACVLAN_MACADDR_COUNT value")?,
+            IFLA_BR_GROUP_ADDR => {
+                GroupAddr(parse_mac(payload).context("invalid IFLA_BR_GROUP_ADDR value")?)
+            }
+            IFLA_BR_ROOT_PORT => {
+                RootPort(parse_u16(payload).context("invalid IFLA_BR_ROOT_PORT value")?)
+            }
+            IFLA_BR_VLAN_DEFAULT_PVID => VlanDefaultPvid(
+                parse_u16(payload).context("invalid IFLA_BR_VLAN_DEFAULT_PVID value")?,
+            ),
+            IFLA_BR_VLAN_FILTERING => {
+                VlanFiltering(parse_u8(payload).context("invalid IFLA_BR_VLAN_FILTERING value")?)
+            }
+            IFLA_BR_TOPOLOGY_CHANGE => {
+                TopologyChange(parse_u8(payload).context("invalid IFLA_BR_TOPOLOGY_CHANGE value")?)
+            }
+            IFLA_BR_TOPOLOGY_CHANGE_DETECTED => TopologyChangeDetected(
+                parse_u8(payload).context("invalid IFLA_BR_TOPOLOGY_CHANGE_DETECTED value")?,
+            ),
+            IFLA_BR_MCAST_ROUTER => {
+                MulticastRouter(parse_u8(payload).context("invalid IFLA_BR_MCAST_ROUTER value")?)
+            }
+            IFLA_BR_MCAST_SNOOPING => MulticastSnooping(
+                parse_u8(payload).context("invalid IFLA_BR_MCAST_SNOOPING value")?,
+            ),
+            IFLA_BR_MCAST_QUERY_USE_IFADDR => MulticastQueryUseIfaddr(
+                parse_u8(payload).context("invalid IFLA_BR_MCAST_QUERY_USE_IFADDR value")?,
+            ),
+            IFLA_BR_MCAST_QUERIER => {
+                MulticastQuerier(parse_u8(payload).context("invalid IFLA_BR_MCAST_QUERIER value")?)
+            }
+            IFLA_BR_NF_CALL_IPTABLES => {
+                NfCallIpTables(parse_u8(payload).context("invalid IFLA_BR_NF_CALL_IPTABLES value")?)
+            }
+            IFLA_BR_NF_CALL_IP6TABLES => NfCallIp6Tables(
+                parse_u8(payload).context("invalid IFLA_BR_NF_CALL_IP6TABLES value")?,
+            ),
+            IFLA_BR_NF_CALL_ARPTABLES => NfCallArpTables(
+                parse_u8(payload).context("invalid IFLA_BR_NF_CALL_ARPTABLES value")?,
+            ),
+            IFLA_BR_VLAN_STATS_ENABLED => VlanStatsEnabled(
+                parse_u8(payload).context("invalid IFLA_BR_VLAN_STATS_ENABLED value")?,
+            ),
+            IFLA_BR_MCAST_STATS_ENABLED => MulticastStatsEnabled(
+                parse_u8(payload).context("invalid IFLA_BR_MCAST_STATS_ENABLED value")?,
+            ),
+            IFLA_BR_MCAST_IGMP_VERSION => MulticastIgmpVersion(
+                parse_u8(payload).context("invalid IFLA_BR_MCAST_IGMP_VERSION value")?,
+            ),
+            IFLA_BR_MCAST_MLD_VERSION => MulticastMldVersion(
+                parse_u8(payload).context("invalid IFLA_BR_MCAST_MLD_VERSION value")?,
+            ),
+            IFLA_BR_VLAN_STATS_PER_PORT => VlanStatsPerHost(
+                parse_u8(payload).context("invalid IFLA_BR_VLAN_STATS_PER_PORT value")?,
+            ),
+            IFLA_BR_MULTI_BOOLOPT => {
+                MultiBoolOpt(parse_u64(payload).context("invalid IFLA_BR_MULTI_BOOLOPT value")?)
+            }
+            _ => Other(
+                DefaultNla::parse(buf)
+                    .context("invalid link info bridge NLA value (unknown type)")?,
             ),
-            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
         })
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum InfoMacVtap {
+pub enum InfoIpoib {
     Unspec(Vec<u8>),
-    Mode(u32),
-    Flags(u16),
-    MacAddrMode(u32),
-    MacAddr([u8; 6]),
-    MacAddrData(Vec<InfoMacVtap>),
-    MacAddrCount(u32),
+    Pkey(u16),
+    Mode(u16),
+    UmCast(u16),
     Other(DefaultNla),
 }
 
-impl Nla for InfoMacVtap {
+impl Nla for InfoIpoib {
     fn value_len(&self) -> usize {
-        use self::InfoMacVtap::*;
+        use self::InfoIpoib::*;
         match self {
             Unspec(bytes) => bytes.len(),
-            Mode(_) => 4,
-            Flags(_) => 2,
-            MacAddrMode(_) => 4,
-            MacAddr(_) => 6,
-            MacAddrData(ref nlas) => nlas.as_slice().buffer_len(),
-            MacAddrCount(_) => 4,
+            Pkey(_) | Mode(_) | UmCast(_) => 2,
             Other(nla) => nla.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
-        use self::InfoMacVtap::*;
+        use self::InfoIpoib::*;
         match self {
             Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
-            Mode(value) => NativeEndian::write_u32(buffer, *value),
-            Flags(value) => NativeEndian::write_u16(buffer, *value),
-            MacAddrMode(value) => NativeEndian::write_u32(buffer, *value),
-            MacAddr(bytes) => buffer.copy_from_slice(bytes),
-            MacAddrData(ref nlas) => nlas.as_slice().emit(buffer),
-            MacAddrCount(value) => NativeEndian::write_u32(buffer, *value),
+            Pkey(value) => NativeEndian::write_u16(buffer, *value),
+            Mode(value) => NativeEndian::write_u16(buffer, *value),
+            UmCast(value) => NativeEndian::write_u16(buffer, *value),
             Other(nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
-        use self::InfoMacVtap::*;
+        use self::InfoIpoib::*;
         match self {
-            Unspec(_) => IFLA_MACVLAN_UNSPEC,
-            Mode(_) => IFLA_MACVLAN_MODE,
-            Flags(_) => IFLA_MACVLAN_FLAGS,
-            MacAddrMode(_) => IFLA_MACVLAN_MACADDR_MODE,
-            MacAddr(_) => IFLA_MACVLAN_MACADDR,
-            MacAddrData(_) => IFLA_MACVLAN_MACADDR_DATA,
-            MacAddrCount(_) => IFLA_MACVLAN_MACADDR_COUNT,
+            Unspec(_) => IFLA_IPOIB_UNSPEC,
+            Pkey(_) => IFLA_IPOIB_PKEY,
+            Mode(_) => IFLA_IPOIB_MODE,
+            UmCast(_) => IFLA_IPOIB_UMCAST,
             Other(nla) => nla.kind(),
         }
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoMacVtap {
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpoib {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        use self::InfoMacVtap::*;
+        use self::InfoIpoib::*;
         let payload = buf.value();
         Ok(match buf.kind() {
-            IFLA_MACVLAN_UNSPEC => Unspec(payload.to_vec()),
-            IFLA_MACVLAN_MODE => {
-                Mode(parse_u32(payload).context("invalid IFLA_MACVLAN_MODE value")?)
-            }
-            IFLA_MACVLAN_FLAGS => {
-                Flags(parse_u16(payload).context("invalid IFLA_MACVLAN_FLAGS value")?)
-            }
-            IFLA_MACVLAN_MACADDR_MODE => {
-                MacAddrMode(parse_u32(payload).context("invalid IFLA_MACVLAN_MACADDR_MODE value")?)
-            }
-            IFLA_MACVLAN_MACADDR => {
-                MacAddr(parse_mac(payload).context("invalid IFLA_MACVLAN_MACADDR value")?)
-            }
-            IFLA_MACVLAN_MACADDR_DATA => {
-                let mut mac_data = Vec::new();
-                let err = "failed to parse IFLA_MACVLAN_MACADDR_DATA";
-                for nla in NlasIterator::new(payload) {
-                    let nla = &nla.context(err)?;
-                    let parsed = InfoMacVtap::parse(nla).context(err)?;
-                    mac_data.push(parsed);
-                }
-                MacAddrData(mac_data)
+            IFLA_IPOIB_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_IPOIB_PKEY => Pkey(parse_u16(payload).context("invalid IFLA_IPOIB_PKEY value")?),
+            IFLA_IPOIB_MODE => Mode(parse_u16(payload).context("invalid IFLA_IPOIB_MODE value")?),
+            IFLA_IPOIB_UMCAST => {
+                UmCast(parse_u16(payload).context("invalid IFLA_IPOIB_UMCAST value")?)
             }
-            IFLA_MACVLAN_MACADDR_COUNT => MacAddrCount(
-                parse_u32(payload).context("invalid IFLA_MACVLAN_MACADDR_COUNT value")?,
-            ),
             kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
         })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{nlas::link::Nla, traits::Emitable, LinkHeader, LinkMessage};
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VethInfo {
+    Unspec(Vec<u8>),
+    Peer(LinkMessage),
+    Other(DefaultNla),
+}
 
-    #[rustfmt::skip]
-    static BRIDGE: [u8; 424] = [
-        0x0b, 0x00, // L = 11
-        0x01, 0x00, // T = 1 (IFLA_INFO_KIND)
-        0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x00, // V = "bridge"
-        0x00, // padding
+impl Nla for VethInfo {
+    fn value_len(&self) -> usize {
+        use self::VethInfo::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Peer(ref message) => message.buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
 
-        0x9c, 0x01, // L = 412
-        0x02, 0x00, // T = 2 (IFLA_INFO_DATA)
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VethInfo::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Peer(ref message) => message.emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
 
-            0x0c, 0x00, // L = 12
-            0x10, 0x00, // T = 16 (IFLA_BR_HELLO_TIMER)
-            0x23, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 35
+    fn kind(&self) -> u16 {
+        use self::VethInfo::*;
+        match *self {
+            Unspec(_) => VETH_INFO_UNSPEC,
+            Peer(_) => VETH_INFO_PEER,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
 
-            0x0c, 0x00, // L = 12
-            0x11, 0x00, // T = 17 (IFLA_BR_TCN_TIMER)
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 0
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VethInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::VethInfo::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            VETH_INFO_UNSPEC => Unspec(payload.to_vec()),
+            VETH_INFO_PEER => {
+                let err = "failed to parse veth link info";
+                let buffer = LinkMessageBuffer::new_checked(&payload).context(err)?;
+                Peer(LinkMessage::parse(&buffer).context(err)?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
 
-            0x0c, 0x00, // L = 12
-            0x12, 0x00, // T = 18 (IFLA_BR_TOPOLOGY_CHANGE_TIMER)
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 0
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoIpVlan {
+    Unspec(Vec<u8>),
+    Mode(u16),
+    Flags(u16),
+    Other(DefaultNla),
+}
 
-            0x0c, 0x00, //  L = 12
-            0x13, 0x00, // T = 19 (IFLA_BR_GC_TIMER)
-            0xb5, 0x37, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 14261 (0x37b5)
+impl Nla for InfoIpVlan {
+    fn value_len(&self) -> usize {
+        use self::InfoIpVlan::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Mode(_) | Flags(_) => 2,
+            Other(nla) => nla.value_len(),
+        }
+    }
 
-            0x08, 0x00, // L = 8
-            0x01, 0x00, // T = 1 (IFLA_BR_FORWARD_DELAY)
-            0xc7, 0x00, 0x00, 0x00, // V = 199
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoIpVlan::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Mode(value) => NativeEndian::write_u16(buffer, *value),
+            Flags(value) => NativeEndian::write_u16(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
 
-            0x08, 0x00, // L = 8
-            0x02, 0x00, // T = 2 (IFLA_BR_HELLO_TIME)
-            0xc7, 0x00, 0x00, 0x00, // V = 199
+    fn kind(&self) -> u16 {
+        use self::InfoIpVlan::*;
+        match self {
+            Unspec(_) => IFLA_IPVLAN_UNSPEC,
+            Mode(_) => IFLA_IPVLAN_MODE,
+            Flags(_) => IFLA_IPVLAN_FLAGS,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
 
-            0x08, 0x00, // L = 8
-            0x03, 0x00, // T = 3 (IFLA_BR_MAX_AGE)
-            0xcf, 0x07, 0x00, 0x00, // V = 1999 (0x07cf)
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpVlan {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoIpVlan::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_IPVLAN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_IPVLAN_MODE => Mode(parse_u16(payload).context("invalid IFLA_IPVLAN_MODE value")?),
+            IFLA_IPVLAN_FLAGS => {
+                Flags(parse_u16(payload).context("invalid IFLA_IPVLAN_FLAGS value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
 
-            0x08, 0x00, // L = 8
-            0x04, 0x00, // T = 4 (IFLA_BR_AGEING_TIME)
-            0x2f, 0x75, 0x00, 0x00, // V = 29999 (0x752f)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoVrf {
+    TableId(u32),
+    Other(DefaultNla),
+}
 
-            0x08, 0x00, // L = 8
-            0x05, 0x00, // T = 5 (IFLA_BR_STP_STATE)
-            0x01, 0x00, 0x00, 0x00, // V = 1
+impl Nla for InfoVrf {
+    fn value_len(&self) -> usize {
+        use self::InfoVrf::*;
+        match self {
+            TableId(_) => 4,
+            Other(nla) => nla.value_len(),
+        }
+    }
 
-            0x06, 0x00, // L = 6
-            0x06, 0x00, // T = 6 (IFLA_BR_PRIORITY)
-            0x00, 0x80, // V =  32768 (0x8000)
-            0x00, 0x00, // Padding
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoVrf::*;
+        match self {
+            TableId(value) => NativeEndian::write_u32(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
 
-            0x05, 0x00, // L = 5
-            0x07, 0x00, // T = 7 (IFLA_BR_VLAN_FILTERING)
-            0x00, // V = 0
-            0x00, 0x00, 0x00, // Padding
+    fn kind(&self) -> u16 {
+        use self::InfoVrf::*;
+        match self {
+            TableId(_) => IFLA_VRF_TABLE,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
 
-            0x06, 0x00, // L = 6
-            0x09, 0x00, // T = 9 (IFLA_BR_GROUP_FWD_MASK)
-            0x00, 0x00, // V = 0
-            0x00, 0x00, // Padding
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVrf {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoVrf::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_VRF_TABLE => TableId(parse_u32(payload).context("invalid IFLA_VRF_TABLE value")?),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
 
-            0x0c, 0x00, // L = 12
-            0x0b, 0x00, // T = 11 (IFLA_BR_BRIDGE_ID)
-            0x80, 0x00, // V (priority) = 128 (0x80)
-            0x52, 0x54, 0x00, 0xd7, 0x19, 0x3e, // V (address) = 52:54:00:d7:19:3e
+/// Attributes of a VRF port, found in `IFLA_INFO_SLAVE_DATA`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoVrfPort {
+    /// Routing table of the VRF device the port belongs to
+    TableId(u32),
+    Other(DefaultNla),
+}
 
-            0x0c, 0x00, // L = 12
-            0x0a, 0x00, // T = 10 (IFLA_BR_ROOT_ID)
-            0x80, 0x00, // V (priority) = 128 (0x80)
-            0x52, 0x54, 0x00, 0xd7, 0x19, 0x3e, // V (address) = 52:54:00:d7:19:3e
+impl Nla for InfoVrfPort {
+    fn value_len(&self) -> usize {
+        use self::InfoVrfPort::*;
+        match self {
+            TableId(_) => 4,
+            Other(nla) => nla.value_len(),
+        }
+    }
 
-            0x06, 0x00, // L = 6
-            0x0c, 0x00, // T = 12 (IFLA_BR_ROOT_PORT)
-            0x00, 0x00, // V = 0
-            0x00, 0x00, // Padding
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoVrfPort::*;
+        match self {
+            TableId(value) => NativeEndian::write_u32(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoVrfPort::*;
+        match self {
+            TableId(_) => IFLA_VRF_PORT_TABLE,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVrfPort {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoVrfPort::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_VRF_PORT_TABLE => {
+                TableId(parse_u32(payload).context("invalid IFLA_VRF_PORT_TABLE value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of TUN/TAP devices (`IFLA_TUN_*`). These devices cannot be
+/// created with netlink, the kernel only sends these attributes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoTun {
+    Unspec(Vec<u8>),
+    /// User ID of the owner of the device
+    Owner(u32),
+    /// Group ID of the owner of the device
+    Group(u32),
+    /// `IFF_TUN` or `IFF_TAP`
+    Type(u8),
+    /// Whether packets are prefixed with the packet information header
+    Pi(u8),
+    VnetHdr(u8),
+    Persist(u8),
+    MultiQueue(u8),
+    NumQueues(u32),
+    NumDisabledQueues(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoTun {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoTun::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Owner(_)
+                | Group(_)
+                | NumQueues(_)
+                | NumDisabledQueues(_)
+                => 4,
+            Type(_)
+                | Pi(_)
+                | VnetHdr(_)
+                | Persist(_)
+                | MultiQueue(_)
+                => 1,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoTun::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Owner(value)
+                | Group(value)
+                | NumQueues(value)
+                | NumDisabledQueues(value)
+                => NativeEndian::write_u32(buffer, *value),
+            Type(value)
+                | Pi(value)
+                | VnetHdr(value)
+                | Persist(value)
+                | MultiQueue(value)
+                => buffer[0] = *value,
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoTun::*;
+        match self {
+            Unspec(_) => IFLA_TUN_UNSPEC,
+            Owner(_) => IFLA_TUN_OWNER,
+            Group(_) => IFLA_TUN_GROUP,
+            Type(_) => IFLA_TUN_TYPE,
+            Pi(_) => IFLA_TUN_PI,
+            VnetHdr(_) => IFLA_TUN_VNET_HDR,
+            Persist(_) => IFLA_TUN_PERSIST,
+            MultiQueue(_) => IFLA_TUN_MULTI_QUEUE,
+            NumQueues(_) => IFLA_TUN_NUM_QUEUES,
+            NumDisabledQueues(_) => IFLA_TUN_NUM_DISABLED_QUEUES,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoTun {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoTun::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_TUN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_TUN_OWNER => Owner(parse_u32(payload).context("invalid IFLA_TUN_OWNER value")?),
+            IFLA_TUN_GROUP => Group(parse_u32(payload).context("invalid IFLA_TUN_GROUP value")?),
+            IFLA_TUN_TYPE => Type(parse_u8(payload).context("invalid IFLA_TUN_TYPE value")?),
+            IFLA_TUN_PI => Pi(parse_u8(payload).context("invalid IFLA_TUN_PI value")?),
+            IFLA_TUN_VNET_HDR => {
+                VnetHdr(parse_u8(payload).context("invalid IFLA_TUN_VNET_HDR value")?)
+            }
+            IFLA_TUN_PERSIST => {
+                Persist(parse_u8(payload).context("invalid IFLA_TUN_PERSIST value")?)
+            }
+            IFLA_TUN_MULTI_QUEUE => {
+                MultiQueue(parse_u8(payload).context("invalid IFLA_TUN_MULTI_QUEUE value")?)
+            }
+            IFLA_TUN_NUM_QUEUES => {
+                NumQueues(parse_u32(payload).context("invalid IFLA_TUN_NUM_QUEUES value")?)
+            }
+            IFLA_TUN_NUM_DISABLED_QUEUES => NumDisabledQueues(
+                parse_u32(payload).context("invalid IFLA_TUN_NUM_DISABLED_QUEUES value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoMacVlan {
+    Unspec(Vec<u8>),
+    Mode(u32),
+    Flags(u16),
+    MacAddrMode(u32),
+    MacAddr([u8; 6]),
+    MacAddrData(Vec<InfoMacVlan>),
+    MacAddrCount(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoMacVlan {
+    fn value_len(&self) -> usize {
+        use self::InfoMacVlan::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Mode(_) => 4,
+            Flags(_) => 2,
+            MacAddrMode(_) => 4,
+            MacAddr(_) => 6,
+            MacAddrData(ref nlas) => nlas.as_slice().buffer_len(),
+            MacAddrCount(_) => 4,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoMacVlan::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Mode(value) => NativeEndian::write_u32(buffer, *value),
+            Flags(value) => NativeEndian::write_u16(buffer, *value),
+            MacAddrMode(value) => NativeEndian::write_u32(buffer, *value),
+            MacAddr(bytes) => buffer.copy_from_slice(bytes),
+            MacAddrData(ref nlas) => nlas.as_slice().emit(buffer),
+            MacAddrCount(value) => NativeEndian::write_u32(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoMacVlan::*;
+        match self {
+            Unspec(_) => IFLA_MACVLAN_UNSPEC,
+            Mode(_) => IFLA_MACVLAN_MODE,
+            Flags(_) => IFLA_MACVLAN_FLAGS,
+            MacAddrMode(_) => IFLA_MACVLAN_MACADDR_MODE,
+            MacAddr(_) => IFLA_MACVLAN_MACADDR,
+            MacAddrData(_) => IFLA_MACVLAN_MACADDR_DATA,
+            MacAddrCount(_) => IFLA_MACVLAN_MACADDR_COUNT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoMacVlan {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoMacVlan::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_MACVLAN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_MACVLAN_MODE => {
+                Mode(parse_u32(payload).context("invalid IFLA_MACVLAN_MODE value")?)
+            }
+            IFLA_MACVLAN_FLAGS => {
+                Flags(parse_u16(payload).context("invalid IFLA_MACVLAN_FLAGS value")?)
+            }
+            IFLA_MACVLAN_MACADDR_MODE => {
+                MacAddrMode(parse_u32(payload).context("invalid IFLA_MACVLAN_MACADDR_MODE value")?)
+            }
+            IFLA_MACVLAN_MACADDR => {
+                MacAddr(parse_mac(payload).context("invalid IFLA_MACVLAN_MACADDR value")?)
+            }
+            IFLA_MACVLAN_MACADDR_DATA => {
+                let mut mac_data = Vec::new();
+                let err = "failed to parse IFLA_MACVLAN_MACADDR_DATA";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err)?;
+                    let parsed = InfoMacVlan::parse(nla).context(err)?;
+                    mac_data.push(parsed);
+                }
+                MacAddrData(mac_data)
+            }
+            IFLA_MACVLAN_MACADDR_COUNT => MacAddrCount(
+                parse_u32(payload).context("invalid IFLA_MACVLAN_MACADDR_COUNT value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoMacVtap {
+    Unspec(Vec<u8>),
+    Mode(u32),
+    Flags(u16),
+    MacAddrMode(u32),
+    MacAddr([u8; 6]),
+    MacAddrData(Vec<InfoMacVtap>),
+    MacAddrCount(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoMacVtap {
+    fn value_len(&self) -> usize {
+        use self::InfoMacVtap::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Mode(_) => 4,
+            Flags(_) => 2,
+            MacAddrMode(_) => 4,
+            MacAddr(_) => 6,
+            MacAddrData(ref nlas) => nlas.as_slice().buffer_len(),
+            MacAddrCount(_) => 4,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoMacVtap::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Mode(value) => NativeEndian::write_u32(buffer, *value),
+            Flags(value) => NativeEndian::write_u16(buffer, *value),
+            MacAddrMode(value) => NativeEndian::write_u32(buffer, *value),
+            MacAddr(bytes) => buffer.copy_from_slice(bytes),
+            MacAddrData(ref nlas) => nlas.as_slice().emit(buffer),
+            MacAddrCount(value) => NativeEndian::write_u32(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoMacVtap::*;
+        match self {
+            Unspec(_) => IFLA_MACVLAN_UNSPEC,
+            Mode(_) => IFLA_MACVLAN_MODE,
+            Flags(_) => IFLA_MACVLAN_FLAGS,
+            MacAddrMode(_) => IFLA_MACVLAN_MACADDR_MODE,
+            MacAddr(_) => IFLA_MACVLAN_MACADDR,
+            MacAddrData(_) => IFLA_MACVLAN_MACADDR_DATA,
+            MacAddrCount(_) => IFLA_MACVLAN_MACADDR_COUNT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoMacVtap {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoMacVtap::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_MACVLAN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_MACVLAN_MODE => {
+                Mode(parse_u32(payload).context("invalid IFLA_MACVLAN_MODE value")?)
+            }
+            IFLA_MACVLAN_FLAGS => {
+                Flags(parse_u16(payload).context("invalid IFLA_MACVLAN_FLAGS value")?)
+            }
+            IFLA_MACVLAN_MACADDR_MODE => {
+                MacAddrMode(parse_u32(payload).context("invalid IFLA_MACVLAN_MACADDR_MODE value")?)
+            }
+            IFLA_MACVLAN_MACADDR => {
+                MacAddr(parse_mac(payload).context("invalid IFLA_MACVLAN_MACADDR value")?)
+            }
+            IFLA_MACVLAN_MACADDR_DATA => {
+                let mut mac_data = Vec::new();
+                let err = "failed to parse IFLA_MACVLAN_MACADDR_DATA";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err)?;
+                    let parsed = InfoMacVtap::parse(nla).context(err)?;
+                    mac_data.push(parsed);
+                }
+                MacAddrData(mac_data)
+            }
+            IFLA_MACVLAN_MACADDR_COUNT => MacAddrCount(
+                parse_u32(payload).context("invalid IFLA_MACVLAN_MACADDR_COUNT value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+// An address in the IFLA_BOND_ARP_IP_TARGET and IFLA_BOND_NS_IP6_TARGET
+// nested lists. The kernel uses the position of the address in the list as
+// the attribute type.
+struct BondIpAddrNla {
+    index: u16,
+    addr: IpAddr,
+}
+
+impl Nla for BondIpAddrNla {
+    fn value_len(&self) -> usize {
+        match self.addr {
+            IpAddr::V4(_) => 4,
+            IpAddr::V6(_) => 16,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self.addr {
+            IpAddr::V4(addr) => buffer.copy_from_slice(&addr.octets()),
+            IpAddr::V6(addr) => buffer.copy_from_slice(&addr.octets()),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        self.index
+    }
+}
+
+fn bond_ip_addr_nlas<I: Iterator<Item = IpAddr>>(addrs: I) -> Vec<BondIpAddrNla> {
+    addrs
+        .enumerate()
+        .map(|(index, addr)| BondIpAddrNla {
+            index: index as u16,
+            addr,
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BondAdInfo {
+    Aggregator(u16),
+    NumPorts(u16),
+    ActorKey(u16),
+    PartnerKey(u16),
+    PartnerMac([u8; 6]),
+    Other(DefaultNla),
+}
+
+impl Nla for BondAdInfo {
+    fn value_len(&self) -> usize {
+        use self::BondAdInfo::*;
+        match self {
+            Aggregator(_) | NumPorts(_) | ActorKey(_) | PartnerKey(_) => 2,
+            PartnerMac(_) => 6,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BondAdInfo::*;
+        match self {
+            Aggregator(value) | NumPorts(value) | ActorKey(value) | PartnerKey(value) => {
+                NativeEndian::write_u16(buffer, *value)
+            }
+            PartnerMac(bytes) => buffer.copy_from_slice(bytes),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BondAdInfo::*;
+        match self {
+            Aggregator(_) => IFLA_BOND_AD_INFO_AGGREGATOR,
+            NumPorts(_) => IFLA_BOND_AD_INFO_NUM_PORTS,
+            ActorKey(_) => IFLA_BOND_AD_INFO_ACTOR_KEY,
+            PartnerKey(_) => IFLA_BOND_AD_INFO_PARTNER_KEY,
+            PartnerMac(_) => IFLA_BOND_AD_INFO_PARTNER_MAC,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BondAdInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::BondAdInfo::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BOND_AD_INFO_AGGREGATOR => Aggregator(
+                parse_u16(payload).context("invalid IFLA_BOND_AD_INFO_AGGREGATOR value")?,
+            ),
+            IFLA_BOND_AD_INFO_NUM_PORTS => {
+                NumPorts(parse_u16(payload).context("invalid IFLA_BOND_AD_INFO_NUM_PORTS value")?)
+            }
+            IFLA_BOND_AD_INFO_ACTOR_KEY => {
+                ActorKey(parse_u16(payload).context("invalid IFLA_BOND_AD_INFO_ACTOR_KEY value")?)
+            }
+            IFLA_BOND_AD_INFO_PARTNER_KEY => PartnerKey(
+                parse_u16(payload).context("invalid IFLA_BOND_AD_INFO_PARTNER_KEY value")?,
+            ),
+            IFLA_BOND_AD_INFO_PARTNER_MAC => PartnerMac(
+                parse_mac(payload).context("invalid IFLA_BOND_AD_INFO_PARTNER_MAC value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/drivers/net/bonding/bond_netlink.c
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoBond {
+    /// One of the `BOND_MODE_*` constants
+    Mode(u8),
+    ActiveSlave(u32),
+    MiiMon(u32),
+    UpDelay(u32),
+    DownDelay(u32),
+    UseCarrier(u8),
+    ArpInterval(u32),
+    ArpIpTarget(Vec<Ipv4Addr>),
+    /// One of the `BOND_ARP_VALIDATE_*` or `BOND_ARP_FILTER*` constants
+    ArpValidate(u32),
+    /// One of the `BOND_OPT_ARP_ALL_TARGETS_*` constants
+    ArpAllTargets(u32),
+    Primary(u32),
+    /// One of the `BOND_PRI_RESELECT_*` constants
+    PrimaryReselect(u8),
+    /// One of the `BOND_FOM_*` constants
+    FailOverMac(u8),
+    /// One of the `BOND_XMIT_POLICY_*` constants
+    XmitHashPolicy(u8),
+    ResendIgmp(u32),
+    NumPeerNotif(u8),
+    AllSlavesActive(u8),
+    MinLinks(u32),
+    LpInterval(u32),
+    PacketsPerSlave(u32),
+    /// `AD_LACP_SLOW` or `AD_LACP_FAST`
+    AdLacpRate(u8),
+    /// One of the `BOND_AD_*` constants
+    AdSelect(u8),
+    AdInfo(Vec<BondAdInfo>),
+    AdActorSysPrio(u16),
+    AdUserPortKey(u16),
+    AdActorSystem([u8; 6]),
+    TlbDynamicLb(u8),
+    PeerNotifDelay(u32),
+    AdLacpActive(u8),
+    MissedMax(u8),
+    NsIp6Target(Vec<Ipv6Addr>),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBond {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoBond::*;
+        match self {
+            Mode(_)
+                | UseCarrier(_)
+                | PrimaryReselect(_)
+                | FailOverMac(_)
+                | XmitHashPolicy(_)
+                | NumPeerNotif(_)
+                | AllSlavesActive(_)
+                | AdLacpRate(_)
+                | AdSelect(_)
+                | TlbDynamicLb(_)
+                | AdLacpActive(_)
+                | MissedMax(_)
+            => 1,
+            AdActorSysPrio(_)
+                | AdUserPortKey(_)
+            => 2,
+            ActiveSlave(_)
+                | MiiMon(_)
+                | UpDelay(_)
+                | DownDelay(_)
+                | ArpInterval(_)
+                | ArpValidate(_)
+                | ArpAllTargets(_)
+                | Primary(_)
+                | ResendIgmp(_)
+                | MinLinks(_)
+                | LpInterval(_)
+                | PacketsPerSlave(_)
+                | PeerNotifDelay(_)
+            => 4,
+            AdActorSystem(_) => 6,
+            ArpIpTarget(ref addrs) => {
+                bond_ip_addr_nlas(addrs.iter().map(|addr| IpAddr::V4(*addr))).as_slice().buffer_len()
+            }
+            NsIp6Target(ref addrs) => {
+                bond_ip_addr_nlas(addrs.iter().map(|addr| IpAddr::V6(*addr))).as_slice().buffer_len()
+            }
+            AdInfo(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoBond::*;
+        match self {
+            Mode(value)
+                | UseCarrier(value)
+                | PrimaryReselect(value)
+                | FailOverMac(value)
+                | XmitHashPolicy(value)
+                | NumPeerNotif(value)
+                | AllSlavesActive(value)
+                | AdLacpRate(value)
+                | AdSelect(value)
+                | TlbDynamicLb(value)
+                | AdLacpActive(value)
+                | MissedMax(value)
+            => buffer[0] = *value,
+            AdActorSysPrio(value)
+                | AdUserPortKey(value)
+            => NativeEndian::write_u16(buffer, *value),
+            ActiveSlave(value)
+                | MiiMon(value)
+                | UpDelay(value)
+                | DownDelay(value)
+                | ArpInterval(value)
+                | ArpValidate(value)
+                | ArpAllTargets(value)
+                | Primary(value)
+                | ResendIgmp(value)
+                | MinLinks(value)
+                | LpInterval(value)
+                | PacketsPerSlave(value)
+                | PeerNotifDelay(value)
+            => NativeEndian::write_u32(buffer, *value),
+            AdActorSystem(bytes) => buffer.copy_from_slice(bytes),
+            ArpIpTarget(ref addrs) => {
+                bond_ip_addr_nlas(addrs.iter().map(|addr| IpAddr::V4(*addr))).as_slice().emit(buffer)
+            }
+            NsIp6Target(ref addrs) => {
+                bond_ip_addr_nlas(addrs.iter().map(|addr| IpAddr::V6(*addr))).as_slice().emit(buffer)
+            }
+            AdInfo(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoBond::*;
+        match self {
+            Mode(_) => IFLA_BOND_MODE,
+            ActiveSlave(_) => IFLA_BOND_ACTIVE_SLAVE,
+            MiiMon(_) => IFLA_BOND_MIIMON,
+            UpDelay(_) => IFLA_BOND_UPDELAY,
+            DownDelay(_) => IFLA_BOND_DOWNDELAY,
+            UseCarrier(_) => IFLA_BOND_USE_CARRIER,
+            ArpInterval(_) => IFLA_BOND_ARP_INTERVAL,
+            ArpIpTarget(_) => IFLA_BOND_ARP_IP_TARGET,
+            ArpValidate(_) => IFLA_BOND_ARP_VALIDATE,
+            ArpAllTargets(_) => IFLA_BOND_ARP_ALL_TARGETS,
+            Primary(_) => IFLA_BOND_PRIMARY,
+            PrimaryReselect(_) => IFLA_BOND_PRIMARY_RESELECT,
+            FailOverMac(_) => IFLA_BOND_FAIL_OVER_MAC,
+            XmitHashPolicy(_) => IFLA_BOND_XMIT_HASH_POLICY,
+            ResendIgmp(_) => IFLA_BOND_RESEND_IGMP,
+            NumPeerNotif(_) => IFLA_BOND_NUM_PEER_NOTIF,
+            AllSlavesActive(_) => IFLA_BOND_ALL_SLAVES_ACTIVE,
+            MinLinks(_) => IFLA_BOND_MIN_LINKS,
+            LpInterval(_) => IFLA_BOND_LP_INTERVAL,
+            PacketsPerSlave(_) => IFLA_BOND_PACKETS_PER_SLAVE,
+            AdLacpRate(_) => IFLA_BOND_AD_LACP_RATE,
+            AdSelect(_) => IFLA_BOND_AD_SELECT,
+            AdInfo(_) => IFLA_BOND_AD_INFO,
+            AdActorSysPrio(_) => IFLA_BOND_AD_ACTOR_SYS_PRIO,
+            AdUserPortKey(_) => IFLA_BOND_AD_USER_PORT_KEY,
+            AdActorSystem(_) => IFLA_BOND_AD_ACTOR_SYSTEM,
+            TlbDynamicLb(_) => IFLA_BOND_TLB_DYNAMIC_LB,
+            PeerNotifDelay(_) => IFLA_BOND_PEER_NOTIF_DELAY,
+            AdLacpActive(_) => IFLA_BOND_AD_LACP_ACTIVE,
+            MissedMax(_) => IFLA_BOND_MISSED_MAX,
+            NsIp6Target(_) => IFLA_BOND_NS_IP6_TARGET,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBond {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoBond::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BOND_MODE => Mode(parse_u8(payload).context("invalid IFLA_BOND_MODE value")?),
+            IFLA_BOND_ACTIVE_SLAVE => {
+                ActiveSlave(parse_u32(payload).context("invalid IFLA_BOND_ACTIVE_SLAVE value")?)
+            }
+            IFLA_BOND_MIIMON => {
+                MiiMon(parse_u32(payload).context("invalid IFLA_BOND_MIIMON value")?)
+            }
+            IFLA_BOND_UPDELAY => {
+                UpDelay(parse_u32(payload).context("invalid IFLA_BOND_UPDELAY value")?)
+            }
+            IFLA_BOND_DOWNDELAY => {
+                DownDelay(parse_u32(payload).context("invalid IFLA_BOND_DOWNDELAY value")?)
+            }
+            IFLA_BOND_USE_CARRIER => {
+                UseCarrier(parse_u8(payload).context("invalid IFLA_BOND_USE_CARRIER value")?)
+            }
+            IFLA_BOND_ARP_INTERVAL => {
+                ArpInterval(parse_u32(payload).context("invalid IFLA_BOND_ARP_INTERVAL value")?)
+            }
+            IFLA_BOND_ARP_IP_TARGET => {
+                let err = "invalid IFLA_BOND_ARP_IP_TARGET value";
+                let mut addrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err)?;
+                    if let IpAddr::V4(addr) = parse_ip(nla.value()).context(err)? {
+                        addrs.push(addr);
+                    } else {
+                        return Err(err.into());
+                    }
+                }
+                ArpIpTarget(addrs)
+            }
+            IFLA_BOND_ARP_VALIDATE => {
+                ArpValidate(parse_u32(payload).context("invalid IFLA_BOND_ARP_VALIDATE value")?)
+            }
+            IFLA_BOND_ARP_ALL_TARGETS => ArpAllTargets(
+                parse_u32(payload).context("invalid IFLA_BOND_ARP_ALL_TARGETS value")?,
+            ),
+            IFLA_BOND_PRIMARY => {
+                Primary(parse_u32(payload).context("invalid IFLA_BOND_PRIMARY value")?)
+            }
+            IFLA_BOND_PRIMARY_RESELECT => PrimaryReselect(
+                parse_u8(payload).context("invalid IFLA_BOND_PRIMARY_RESELECT value")?,
+            ),
+            IFLA_BOND_FAIL_OVER_MAC => {
+                FailOverMac(parse_u8(payload).context("invalid IFLA_BOND_FAIL_OVER_MAC value")?)
+            }
+            IFLA_BOND_XMIT_HASH_POLICY => XmitHashPolicy(
+                parse_u8(payload).context("invalid IFLA_BOND_XMIT_HASH_POLICY value")?,
+            ),
+            IFLA_BOND_RESEND_IGMP => {
+                ResendIgmp(parse_u32(payload).context("invalid IFLA_BOND_RESEND_IGMP value")?)
+            }
+            IFLA_BOND_NUM_PEER_NOTIF => {
+                NumPeerNotif(parse_u8(payload).context("invalid IFLA_BOND_NUM_PEER_NOTIF value")?)
+            }
+            IFLA_BOND_ALL_SLAVES_ACTIVE => AllSlavesActive(
+                parse_u8(payload).context("invalid IFLA_BOND_ALL_SLAVES_ACTIVE value")?,
+            ),
+            IFLA_BOND_MIN_LINKS => {
+                MinLinks(parse_u32(payload).context("invalid IFLA_BOND_MIN_LINKS value")?)
+            }
+            IFLA_BOND_LP_INTERVAL => {
+                LpInterval(parse_u32(payload).context("invalid IFLA_BOND_LP_INTERVAL value")?)
+            }
+            IFLA_BOND_PACKETS_PER_SLAVE => PacketsPerSlave(
+                parse_u32(payload).context("invalid IFLA_BOND_PACKETS_PER_SLAVE value")?,
+            ),
+            IFLA_BOND_AD_LACP_RATE => {
+                AdLacpRate(parse_u8(payload).context("invalid IFLA_BOND_AD_LACP_RATE value")?)
+            }
+            IFLA_BOND_AD_SELECT => {
+                AdSelect(parse_u8(payload).context("invalid IFLA_BOND_AD_SELECT value")?)
+            }
+            IFLA_BOND_AD_INFO => {
+                let err = "invalid IFLA_BOND_AD_INFO value";
+                let mut infos = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err)?;
+                    infos.push(BondAdInfo::parse(nla).context(err)?);
+                }
+                AdInfo(infos)
+            }
+            IFLA_BOND_AD_ACTOR_SYS_PRIO => AdActorSysPrio(
+                parse_u16(payload).context("invalid IFLA_BOND_AD_ACTOR_SYS_PRIO value")?,
+            ),
+            IFLA_BOND_AD_USER_PORT_KEY => AdUserPortKey(
+                parse_u16(payload).context("invalid IFLA_BOND_AD_USER_PORT_KEY value")?,
+            ),
+            IFLA_BOND_AD_ACTOR_SYSTEM => AdActorSystem(
+                parse_mac(payload).context("invalid IFLA_BOND_AD_ACTOR_SYSTEM value")?,
+            ),
+            IFLA_BOND_TLB_DYNAMIC_LB => {
+                TlbDynamicLb(parse_u8(payload).context("invalid IFLA_BOND_TLB_DYNAMIC_LB value")?)
+            }
+            IFLA_BOND_PEER_NOTIF_DELAY => PeerNotifDelay(
+                parse_u32(payload).context("invalid IFLA_BOND_PEER_NOTIF_DELAY value")?,
+            ),
+            IFLA_BOND_AD_LACP_ACTIVE => {
+                AdLacpActive(parse_u8(payload).context("invalid IFLA_BOND_AD_LACP_ACTIVE value")?)
+            }
+            IFLA_BOND_MISSED_MAX => {
+                MissedMax(parse_u8(payload).context("invalid IFLA_BOND_MISSED_MAX value")?)
+            }
+            IFLA_BOND_NS_IP6_TARGET => {
+                let err = "invalid IFLA_BOND_NS_IP6_TARGET value";
+                let mut addrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err)?;
+                    addrs.push(Ipv6Addr::from(parse_ipv6(nla.value()).context(err)?));
+                }
+                NsIp6Target(addrs)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/drivers/net/bonding/bond_netlink.c
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoBondPort {
+    /// `BOND_STATE_ACTIVE` or `BOND_STATE_BACKUP`
+    State(u8),
+    /// One of the `BOND_LINK_*` constants
+    MiiStatus(u8),
+    LinkFailureCount(u32),
+    PermHwaddr(Vec<u8>),
+    QueueId(u16),
+    AdAggregatorId(u16),
+    AdActorOperPortState(u8),
+    AdPartnerOperPortState(u16),
+    Prio(i32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBondPort {
+    fn value_len(&self) -> usize {
+        use self::InfoBondPort::*;
+        match self {
+            State(_) | MiiStatus(_) | AdActorOperPortState(_) => 1,
+            QueueId(_) | AdAggregatorId(_) | AdPartnerOperPortState(_) => 2,
+            LinkFailureCount(_) | Prio(_) => 4,
+            PermHwaddr(bytes) => bytes.len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoBondPort::*;
+        match self {
+            State(value) | MiiStatus(value) | AdActorOperPortState(value) => buffer[0] = *value,
+            QueueId(value) | AdAggregatorId(value) | AdPartnerOperPortState(value) => {
+                NativeEndian::write_u16(buffer, *value)
+            }
+            LinkFailureCount(value) => NativeEndian::write_u32(buffer, *value),
+            Prio(value) => NativeEndian::write_i32(buffer, *value),
+            PermHwaddr(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoBondPort::*;
+        match self {
+            State(_) => IFLA_BOND_SLAVE_STATE,
+            MiiStatus(_) => IFLA_BOND_SLAVE_MII_STATUS,
+            LinkFailureCount(_) => IFLA_BOND_SLAVE_LINK_FAILURE_COUNT,
+            PermHwaddr(_) => IFLA_BOND_SLAVE_PERM_HWADDR,
+            QueueId(_) => IFLA_BOND_SLAVE_QUEUE_ID,
+            AdAggregatorId(_) => IFLA_BOND_SLAVE_AD_AGGREGATOR_ID,
+            AdActorOperPortState(_) => IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE,
+            AdPartnerOperPortState(_) => IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE,
+            Prio(_) => IFLA_BOND_SLAVE_PRIO,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBondPort {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoBondPort::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BOND_SLAVE_STATE => {
+                State(parse_u8(payload).context("invalid IFLA_BOND_SLAVE_STATE value")?)
+            }
+            IFLA_BOND_SLAVE_MII_STATUS => {
+                MiiStatus(parse_u8(payload).context("invalid IFLA_BOND_SLAVE_MII_STATUS value")?)
+            }
+            IFLA_BOND_SLAVE_LINK_FAILURE_COUNT => LinkFailureCount(
+                parse_u32(payload).context("invalid IFLA_BOND_SLAVE_LINK_FAILURE_COUNT value")?,
+            ),
+            IFLA_BOND_SLAVE_PERM_HWADDR => PermHwaddr(payload.to_vec()),
+            IFLA_BOND_SLAVE_QUEUE_ID => {
+                QueueId(parse_u16(payload).context("invalid IFLA_BOND_SLAVE_QUEUE_ID value")?)
+            }
+            IFLA_BOND_SLAVE_AD_AGGREGATOR_ID => AdAggregatorId(
+                parse_u16(payload).context("invalid IFLA_BOND_SLAVE_AD_AGGREGATOR_ID value")?,
+            ),
+            IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE => AdActorOperPortState(
+                parse_u8(payload)
+                    .context("invalid IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE value")?,
+            ),
+            IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE => AdPartnerOperPortState(
+                parse_u16(payload)
+                    .context("invalid IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE value")?,
+            ),
+            IFLA_BOND_SLAVE_PRIO => {
+                Prio(parse_i32(payload).context("invalid IFLA_BOND_SLAVE_PRIO value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nlas::link::Nla, traits::Emitable, LinkHeader, LinkMessage};
+
+    #[rustfmt::skip]
+    static BRIDGE: [u8; 424] = [
+        0x0b, 0x00, // L = 11
+        0x01, 0x00, // T = 1 (IFLA_INFO_KIND)
+        0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x00, // V = "bridge"
+        0x00, // padding
+
+        0x9c, 0x01, // L = 412
+        0x02, 0x00, // T = 2 (IFLA_INFO_DATA)
+
+            0x0c, 0x00, // L = 12
+            0x10, 0x00, // T = 16 (IFLA_BR_HELLO_TIMER)
+            0x23, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 35
+
+            0x0c, 0x00, // L = 12
+            0x11, 0x00, // T = 17 (IFLA_BR_TCN_TIMER)
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 0
+
+            0x0c, 0x00, // L = 12
+            0x12, 0x00, // T = 18 (IFLA_BR_TOPOLOGY_CHANGE_TIMER)
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 0
+
+            0x0c, 0x00, //  L = 12
+            0x13, 0x00, // T = 19 (IFLA_BR_GC_TIMER)
+            0xb5, 0x37, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 14261 (0x37b5)
+
+            0x08, 0x00, // L = 8
+            0x01, 0x00, // T = 1 (IFLA_BR_FORWARD_DELAY)
+            0xc7, 0x00, 0x00, 0x00, // V = 199
+
+            0x08, 0x00, // L = 8
+            0x02, 0x00, // T = 2 (IFLA_BR_HELLO_TIME)
+            0xc7, 0x00, 0x00, 0x00, // V = 199
+
+            0x08, 0x00, // L = 8
+            0x03, 0x00, // T = 3 (IFLA_BR_MAX_AGE)
+            0xcf, 0x07, 0x00, 0x00, // V = 1999 (0x07cf)
+
+            0x08, 0x00, // L = 8
+            0x04, 0x00, // T = 4 (IFLA_BR_AGEING_TIME)
+            0x2f, 0x75, 0x00, 0x00, // V = 29999 (0x752f)
+
+            0x08, 0x00, // L = 8
+            0x05, 0x00, // T = 5 (IFLA_BR_STP_STATE)
+            0x01, 0x00, 0x00, 0x00, // V = 1
+
+            0x06, 0x00, // L = 6
+            0x06, 0x00, // T = 6 (IFLA_BR_PRIORITY)
+            0x00, 0x80, // V =  32768 (0x8000)
+            0x00, 0x00, // Padding
+
+            0x05, 0x00, // L = 5
+            0x07, 0x00, // T = 7 (IFLA_BR_VLAN_FILTERING)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // Padding
+
+            0x06, 0x00, // L = 6
+            0x09, 0x00, // T = 9 (IFLA_BR_GROUP_FWD_MASK)
+            0x00, 0x00, // V = 0
+            0x00, 0x00, // Padding
+
+            0x0c, 0x00, // L = 12
+            0x0b, 0x00, // T = 11 (IFLA_BR_BRIDGE_ID)
+            0x80, 0x00, // V (priority) = 128 (0x80)
+            0x52, 0x54, 0x00, 0xd7, 0x19, 0x3e, // V (address) = 52:54:00:d7:19:3e
+
+            0x0c, 0x00, // L = 12
+            0x0a, 0x00, // T = 10 (IFLA_BR_ROOT_ID)
+            0x80, 0x00, // V (priority) = 128 (0x80)
+            0x52, 0x54, 0x00, 0xd7, 0x19, 0x3e, // V (address) = 52:54:00:d7:19:3e
+
+            0x06, 0x00, // L = 6
+            0x0c, 0x00, // T = 12 (IFLA_BR_ROOT_PORT)
+            0x00, 0x00, // V = 0
+            0x00, 0x00, // Padding
 
             0x08, 0x00, // L = 8
             0x0d, 0x00, // T = 13 (IFLA_BR_ROOT_PATH_COST)
             0x00, 0x00, 0x00, 0x00, // V = 0
 
             0x05, 0x00, // L = 5
-            0x0e, 0x00, // T = 14 (IFLA_BR_TOPOLOGY_CHANGE)
+            0x0e, 0x00, // T = 14 (IFLA_BR_TOPOLOGY_CHANGE)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // Padding
+
+            0x05, 0x00, // L = 5
+            0x0f, 0x00, // T = 15 (IFLA_BR_TOPOLOGY_CHANGE_DETECTED)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // Padding
+
+            0x0a, 0x00, // L = 10
+            0x14, 0x00, // T = 20 (IFLA_BR_GROUP_ADDR)
+            0x01, 0x80, 0xc2, 0x00, 0x00, 0x00, // V = 01:80:c2:00:00:00
+            0x00, 0x00, // Padding
+
+            0x06, 0x00, // L = 6
+            0x08, 0x00, // T = 8 (IFLA_BR_VLAN_PROTOCOL)
+            0x81, 0x00, // V = 33024 (big-endian)
+            0x00, 0x00, // Padding
+
+            0x06, 0x00, // L = 6
+            0x27, 0x00, // T = 39 (IFLA_BR_VLAN_DEFAULT_PVID)
+            0x01, 0x00, // V = 1
+            0x00, 0x00, // Padding
+
+            0x05, 0x00, // L = 5
+            0x29, 0x00, // T = 41 (IFLA_BR_VLAN_STATS_ENABLED)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // Padding
+
+            0x05, 0x00, // L = 5
+            0x16, 0x00, // T = 22 (IFLA_BR_MCAST_ROUTER)
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // Padding
+
+            0x05, 0x00, // L = 5
+            0x17, 0x00, // T = 23 (IFLA_BR_MCAST_SNOOPING)
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // Padding
+
+            0x05, 0x00, // L = 5
+            0x18, 0x00, // T = 24 (IFLA_BR_MCAST_QUERY_USE_IFADDR)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // Padding
+
+            0x05, 0x00, // L = 5
+            0x19, 0x00, // T = 25 (IFLA_BR_MCAST_QUERIER)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // Padding
+
+            0x05, 0x00, // L = 5
+            0x2a, 0x00, // T = 42 (IFLA_BR_MCAST_STATS_ENABLED)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // Padding
+
+            0x08, 0x00, // L = 8
+            0x1a, 0x00, // T = 26 (IFLA_BR_MCAST_HASH_ELASTICITY)
+            0x04, 0x00, 0x00, 0x00, // V = 4
+
+            0x08, 0x00, // L = 8
+            0x1b, 0x00, // T = 27 (IFLA_BR_MCAST_HASH_MAX)
+            0x00, 0x02, 0x00, 0x00, // V = 512 (0x0200)
+
+            0x08, 0x00, // L = 8
+            0x1c, 0x00, // T = 28 (IFLA_BR_MCAST_LAST_MEMBER_CNT)
+            0x02, 0x00, 0x00, 0x00, // V = 2
+
+            0x08, 0x00, // L = 8
+            0x1d, 0x00, // T = 29 (IFLA_BR_MCAST_STARTUP_QUERY_CNT)
+            0x02, 0x00, 0x00, 0x00, // V = 2
+
+            0x05, 0x00, // L = 5
+            0x2b, 0x00, // T = 43 (IFLA_BR_MCAST_IGMP_VERSION)
+            0x02, // V = 2
+            0x00, 0x00, 0x00, // Padding
+
+            0x05, 0x00, // L = 5
+            0x2c, 0x00, // T = 44 (IFLA_BR_MCAST_MLD_VERSION)
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // Padding
+
+            0x0c, 0x00, // L = 12
+            0x1e, 0x00, // T = 30 (IFLA_BR_MCAST_LAST_MEMBER_INTVL)
+            0x63, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 99
+
+            0x0c, 0x00, // L = 12
+            0x1f, 0x00, // T = 31 (IFLA_BR_MCAST_MEMBERSHIP_INTVL)
+            0x8f, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 25999 (0x658f)
+
+            0x0c, 0x00, // L = 12
+            0x20, 0x00, // T = 32 (IFLA_BR_MCAST_QUERIER_INTVL)
+            0x9b, 0x63, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 25499 (0x639b)
+
+            0x0c, 0x00, // L = 12
+            0x21, 0x00, // T = 33 (IFLA_BR_MCAST_QUERY_INTVL)
+            0xd3, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 12499 (0x30d3)
+
+            0x0c, 0x00, // L = 12
+            0x22, 0x00, // T = 34 (IFLA_BR_MCAST_QUERY_RESPONSE_INTVL)
+            0xe7, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 999 (0x03e7)
+
+            0x0c, 0x00, // L = 12
+            0x23, 0x00, // T = 35 (IFLA_BR_MCAST_STARTUP_QUERY_INTVL)
+            0x34, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 3124 (0x0c34)
+
+            0x05, 0x00, // L = 5
+            0x24, 0x00, // T = 36 (IFLA_BR_NF_CALL_IPTABLES)
             0x00, // V = 0
             0x00, 0x00, 0x00, // Padding
 
             0x05, 0x00, // L = 5
-            0x0f, 0x00, // T = 15 (IFLA_BR_TOPOLOGY_CHANGE_DETECTED)
+            0x25, 0x00, // T = 37 (IFLA_BR_NF_CALL_IP6TABLES)
             0x00, // V = 0
             0x00, 0x00, 0x00, // Padding
 
-            0x0a, 0x00, // L = 10
-            0x14, 0x00, // T = 20 (IFLA_BR_GROUP_ADDR)
-            0x01, 0x80, 0xc2, 0x00, 0x00, 0x00, // V = 01:80:c2:00:00:00
-            0x00, 0x00, // Padding
+            0x05, 0x00, // L = 5
+            0x26, 0x00, // T = 38 (IFLA_BR_NF_CALL_ARPTABLES)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // Padding
+
+            0x05, 0x00, // L = 5
+            0x2d, 0x00, // T = 45 (IFLA_BR_VLAN_STATS_PER_PORT)
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // Padding
+
+            0x0c, 0x00, // L = 12
+            0x2e, 0x00, // T = 46 (IFLA_BR_MULTI_BOOLOPT)
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,  // V = 0
+
+    ];
+
+    lazy_static! {
+        static ref BRIDGE_INFO: Vec<InfoBridge> = vec![
+            InfoBridge::HelloTimer(35),
+            InfoBridge::TcnTimer(0),
+            InfoBridge::TopologyChangeTimer(0),
+            InfoBridge::GcTimer(14261),
+            InfoBridge::ForwardDelay(199),
+            InfoBridge::HelloTime(199),
+            InfoBridge::MaxAge(1999),
+            InfoBridge::AgeingTime(29999),
+            InfoBridge::StpState(1),
+            InfoBridge::Priority(0x8000),
+            InfoBridge::VlanFiltering(0),
+            InfoBridge::GroupFwdMask(0),
+            InfoBridge::BridgeId((128, [0x52, 0x54, 0x00, 0xd7, 0x19, 0x3e])),
+            InfoBridge::RootId((128, [0x52, 0x54, 0x00, 0xd7, 0x19, 0x3e])),
+            InfoBridge::RootPort(0),
+            InfoBridge::RootPathCost(0),
+            InfoBridge::TopologyChange(0),
+            InfoBridge::TopologyChangeDetected(0),
+            InfoBridge::GroupAddr([0x01, 0x80, 0xc2, 0x00, 0x00, 0x00]),
+            InfoBridge::VlanProtocol(33024),
+            InfoBridge::VlanDefaultPvid(1),
+            InfoBridge::VlanStatsEnabled(0),
+            InfoBridge::MulticastRouter(1),
+            InfoBridge::MulticastSnooping(1),
+            InfoBridge::MulticastQueryUseIfaddr(0),
+            InfoBridge::MulticastQuerier(0),
+            InfoBridge::MulticastStatsEnabled(0),
+            InfoBridge::MulticastHashElasticity(4),
+            InfoBridge::MulticastHashMax(512),
+            InfoBridge::MulticastLastMemberCount(2),
+            InfoBridge::MulticastStartupQueryCount(2),
+            InfoBridge::MulticastIgmpVersion(2),
+            InfoBridge::MulticastMldVersion(1),
+            InfoBridge::MulticastLastMemberInterval(99),
+            InfoBridge::MulticastMembershipInterval(25999),
+            InfoBridge::MulticastQuerierInterval(25499),
+            InfoBridge::MulticastQueryInterval(12499),
+            InfoBridge::MulticastQueryResponseInterval(999),
+            InfoBridge::MulticastStartupQueryInterval(3124),
+            InfoBridge::NfCallIpTables(0),
+            InfoBridge::NfCallIp6Tables(0),
+            InfoBridge::NfCallArpTables(0),
+            InfoBridge::VlanStatsPerHost(1),
+            InfoBridge::MultiBoolOpt(0),
+        ];
+    }
+
+    #[test]
+    fn parse_info_kind() {
+        let info_kind_nla = NlaBuffer::new_checked(&BRIDGE[..12]).unwrap();
+        let parsed = InfoKind::parse(&info_kind_nla).unwrap();
+        assert_eq!(parsed, InfoKind::Bridge);
+    }
+
+    #[test]
+    fn parse_info_bridge() {
+        let nlas = NlasIterator::new(&BRIDGE[16..]);
+        for nla in nlas.map(|nla| nla.unwrap()) {
+            InfoBridge::parse(&nla).unwrap();
+        }
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn parse_veth_info() {
+        let data = vec![
+            0x08, 0x00, // length = 8
+            0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+            0x76, 0x65, 0x74, 0x68, // VETH
+
+            0x30, 0x00, // length = 48
+            0x02, 0x00, // type = IFLA_INFO_DATA
+
+                0x2c, 0x00, // length = 44
+                0x01, 0x00, // type = VETH_INFO_PEER
+                // The data a NEWLINK message
+                0x00, // interface family
+                0x00, // padding
+                0x00, 0x00, // link layer type
+                0x00, 0x00, 0x00, 0x00, // link index
+                0x00, 0x00, 0x00, 0x00, // flags
+                0x00, 0x00, 0x00, 0x00, // flags change mask
+                    // NLA
+                    0x10, 0x00, // length = 16
+                    0x03, 0x00, // type = IFLA_IFNAME
+                    0x76, 0x65, 0x74, 0x68, 0x63, 0x30, 0x65, 0x36, 0x30, 0x64, 0x36, 0x00,
+                    // NLA
+                    0x08, 0x00, // length = 8
+                    0x0d, 0x00, // type = IFLA_TXQLEN
+                    0x00, 0x00, 0x00, 0x00,
+        ];
+        let nla = NlaBuffer::new_checked(&data[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::Veth),
+            Info::Data(InfoData::Veth(VethInfo::Peer(LinkMessage {
+                header: LinkHeader {
+                    interface_family: 0,
+                    index: 0,
+                    link_layer_type: ARPHRD_NETROM,
+                    flags: 0,
+                    change_mask: 0,
+                },
+                nlas: vec![
+                    Nla::IfName("vethc0e60d6".to_string()),
+                    Nla::TxQueueLen(0),
+                ],
+            }))),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[rustfmt::skip]
+    static IPVLAN: [u8; 32] = [
+        0x0b, 0x00, // length = 11
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x69, 0x70, 0x76, 0x6c, 0x61, 0x6e, 0x00, // V = "ipvlan\0"
+        0x00, // padding
+
+        0x14, 0x00, // length = 20
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x06, 0x00, // length = 6
+            0x01, 0x00, // type = 1 = IFLA_IPVLAN_MODE
+            0x01, 0x00, // l3
+            0x00, 0x00, // padding
+
+            0x06, 0x00, // length = 6
+            0x02, 0x00, // type = 2 = IFLA_IPVLAN_FLAGS
+            0x02, 0x00, // vepa flag
+            0x00, 0x00, // padding
+    ];
+
+    lazy_static! {
+        static ref IPVLAN_INFO: Vec<InfoIpVlan> = vec![
+            InfoIpVlan::Mode(1), // L3
+            InfoIpVlan::Flags(2), // vepa flag
+        ];
+    }
+
+    #[test]
+    fn parse_info_ipvlan() {
+        let nla = NlaBuffer::new_checked(&IPVLAN[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::IpVlan),
+            Info::Data(InfoData::IpVlan(IPVLAN_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_ipvlan() {
+        let nlas = vec![
+            Info::Kind(InfoKind::IpVlan),
+            Info::Data(InfoData::IpVlan(IPVLAN_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 32);
+
+        let mut vec = vec![0xff; 32];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &IPVLAN[..]);
+    }
+
+    #[rustfmt::skip]
+    static MACVLAN: [u8; 24] = [
+        0x0c, 0x00, // length = 12
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x6d, 0x61, 0x63, 0x76, 0x6c, 0x61, 0x6e, 0x00, // V = "macvlan\0"
+        0x0c, 0x00, // length = 12
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, // length = 8
+            0x01, 0x00, // type = IFLA_MACVLAN_MODE
+            0x04, 0x00, 0x00, 0x00, // V = 4 = bridge
+    ];
+
+    lazy_static! {
+        static ref MACVLAN_INFO: Vec<InfoMacVlan> = vec![
+            InfoMacVlan::Mode(4), // bridge
+        ];
+    }
+
+    #[test]
+    fn parse_info_macvlan() {
+        let nla = NlaBuffer::new_checked(&MACVLAN[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::MacVlan),
+            Info::Data(InfoData::MacVlan(MACVLAN_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_macvlan() {
+        let nlas = vec![
+            Info::Kind(InfoKind::MacVlan),
+            Info::Data(InfoData::MacVlan(MACVLAN_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 24);
+
+        let mut vec = vec![0xff; 24];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &MACVLAN[..]);
+    }
+
+    #[rustfmt::skip]
+    static MACVLAN_SOURCE_SET: [u8; 84] = [
+        0x0c, 0x00, // length = 12
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x6d, 0x61, 0x63, 0x76, 0x6c, 0x61, 0x6e, 0x00, // V = "macvlan\0"
+        0x48, 0x00, // length = 72
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, // length = 8
+            0x03, 0x00, // type = 3 = IFLA_MACVLAN_MACADDR_MODE
+            0x03, 0x00, 0x00, 0x00, // V = 3 = set
 
-            0x06, 0x00, // L = 6
-            0x08, 0x00, // T = 8 (IFLA_BR_VLAN_PROTOCOL)
-            0x81, 0x00, // V = 33024 (big-endian)
-            0x00, 0x00, // Padding
+            0x34, 0x00, // length = 52
+            0x05, 0x00, // type = 5 = IFLA_MACVLAN_MACADDR_DATA
+                0x0a, 0x00, // length = 10
+                0x04, 0x00, // type = 4 = IFLA_MACVLAN_MACADDR
+                0x22, 0xf5, 0x54, 0x09, 0x88, 0xd7, // V = mac address
+                0x00, 0x00, // padding
 
-            0x06, 0x00, // L = 6
-            0x27, 0x00, // T = 39 (IFLA_BR_VLAN_DEFAULT_PVID)
-            0x01, 0x00, // V = 1
-            0x00, 0x00, // Padding
+                0x0a, 0x00, // length = 10
+                0x04, 0x00, // type = 4 = IFLA_MACVLAN_MACADDR
+                0x22, 0xf5, 0x54, 0x09, 0x99, 0x32, // V = mac address
+                0x00, 0x00, // padding
 
-            0x05, 0x00, // L = 5
-            0x29, 0x00, // T = 41 (IFLA_BR_VLAN_STATS_ENABLED)
-            0x00, // V = 0
-            0x00, 0x00, 0x00, // Padding
+                0x0a, 0x00, // length = 10
+                0x04, 0x00, // type = 4 = IFLA_MACVLAN_MACADDR
+                0x22, 0xf5, 0x54, 0x09, 0x87, 0x45, // V = mac address
+                0x00, 0x00, // padding
 
-            0x05, 0x00, // L = 5
-            0x16, 0x00, // T = 22 (IFLA_BR_MCAST_ROUTER)
-            0x01, // V = 1
-            0x00, 0x00, 0x00, // Padding
+                0x0a, 0x00, // length = 10
+                0x04, 0x00, // type = 4 = IFLA_MACVLAN_MACADDR
+                0x22, 0xf5, 0x54, 0x09, 0x11, 0x45, // V = mac address
+                0x00, 0x00, // padding
+            0x08, 0x00, // length = 8
+            0x01, 0x00, // Type = 1 = IFLA_MACVLAN_MODE
+            0x10, 0x00, 0x00, 0x00, // V = 16 = source
+    ];
 
-            0x05, 0x00, // L = 5
-            0x17, 0x00, // T = 23 (IFLA_BR_MCAST_SNOOPING)
-            0x01, // V = 1
-            0x00, 0x00, 0x00, // Padding
+    lazy_static! {
+        static ref MACVLAN_SOURCE_SET_INFO: Vec<InfoMacVlan> = vec![
+            InfoMacVlan::MacAddrMode(3), // set
+            InfoMacVlan::MacAddrData(vec![
+                                 InfoMacVlan::MacAddr([0x22, 0xf5, 0x54, 0x09, 0x88, 0xd7,]),
+                                 InfoMacVlan::MacAddr([0x22, 0xf5, 0x54, 0x09, 0x99, 0x32,]),
+                                 InfoMacVlan::MacAddr([0x22, 0xf5, 0x54, 0x09, 0x87, 0x45,]),
+                                 InfoMacVlan::MacAddr([0x22, 0xf5, 0x54, 0x09, 0x11, 0x45,]),
+            ]),
+            InfoMacVlan::Mode(16), // source
+        ];
+    }
 
-            0x05, 0x00, // L = 5
-            0x18, 0x00, // T = 24 (IFLA_BR_MCAST_QUERY_USE_IFADDR)
-            0x00, // V = 0
-            0x00, 0x00, 0x00, // Padding
+    #[test]
+    fn parse_info_macvlan_source_set() {
+        let nla = NlaBuffer::new_checked(&MACVLAN_SOURCE_SET[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::MacVlan),
+            Info::Data(InfoData::MacVlan(MACVLAN_SOURCE_SET_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
 
-            0x05, 0x00, // L = 5
-            0x19, 0x00, // T = 25 (IFLA_BR_MCAST_QUERIER)
-            0x00, // V = 0
-            0x00, 0x00, 0x00, // Padding
+    #[test]
+    fn emit_info_macvlan_source_set() {
+        let nlas = vec![
+            Info::Kind(InfoKind::MacVlan),
+            Info::Data(InfoData::MacVlan(MACVLAN_SOURCE_SET_INFO.clone())),
+        ];
 
-            0x05, 0x00, // L = 5
-            0x2a, 0x00, // T = 42 (IFLA_BR_MCAST_STATS_ENABLED)
-            0x00, // V = 0
-            0x00, 0x00, 0x00, // Padding
+        assert_eq!(nlas.as_slice().buffer_len(), 84);
 
-            0x08, 0x00, // L = 8
-            0x1a, 0x00, // T = 26 (IFLA_BR_MCAST_HASH_ELASTICITY)
-            0x04, 0x00, 0x00, 0x00, // V = 4
+        let mut vec = vec![0xff; 84];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &MACVLAN_SOURCE_SET[..]);
+    }
 
-            0x08, 0x00, // L = 8
-            0x1b, 0x00, // T = 27 (IFLA_BR_MCAST_HASH_MAX)
-            0x00, 0x02, 0x00, 0x00, // V = 512 (0x0200)
+    #[rustfmt::skip]
+    static BOND: [u8; 84] = [
+        0x09, 0x00, // length = 9
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x62, 0x6f, 0x6e, 0x64, 0x00, // V = "bond\0"
+        0x00, 0x00, 0x00, // padding
 
-            0x08, 0x00, // L = 8
-            0x1c, 0x00, // T = 28 (IFLA_BR_MCAST_LAST_MEMBER_CNT)
-            0x02, 0x00, 0x00, 0x00, // V = 2
+        0x48, 0x00, // length = 72
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x05, 0x00, // length = 5
+            0x01, 0x00, // type = 1 = IFLA_BOND_MODE
+            0x04, // V = 4 = 802.3ad
+            0x00, 0x00, 0x00, // padding
 
-            0x08, 0x00, // L = 8
-            0x1d, 0x00, // T = 29 (IFLA_BR_MCAST_STARTUP_QUERY_CNT)
-            0x02, 0x00, 0x00, 0x00, // V = 2
+            0x08, 0x00, // length = 8
+            0x03, 0x00, // type = 3 = IFLA_BOND_MIIMON
+            0x64, 0x00, 0x00, 0x00, // V = 100
+
+            0x14, 0x00, // length = 20
+            0x08, 0x00, // type = 8 = IFLA_BOND_ARP_IP_TARGET
+                0x08, 0x00, // length = 8
+                0x00, 0x00, // type = 0
+                0x0a, 0x00, 0x00, 0x01, // V = 10.0.0.1
+                0x08, 0x00, // length = 8
+                0x01, 0x00, // type = 1
+                0x0a, 0x00, 0x00, 0x02, // V = 10.0.0.2
+
+            0x05, 0x00, // length = 5
+            0x0e, 0x00, // type = 14 = IFLA_BOND_XMIT_HASH_POLICY
+            0x01, // V = 1 = layer3+4
+            0x00, 0x00, 0x00, // padding
+
+            0x18, 0x00, // length = 24
+            0x17, 0x00, // type = 23 = IFLA_BOND_AD_INFO
+                0x06, 0x00, // length = 6
+                0x01, 0x00, // type = 1 = IFLA_BOND_AD_INFO_AGGREGATOR
+                0x01, 0x00, // V = 1
+                0x00, 0x00, // padding
+                0x0a, 0x00, // length = 10
+                0x05, 0x00, // type = 5 = IFLA_BOND_AD_INFO_PARTNER_MAC
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // V = 00:11:22:33:44:55
+                0x00, 0x00, // padding
+    ];
 
-            0x05, 0x00, // L = 5
-            0x2b, 0x00, // T = 43 (IFLA_BR_MCAST_IGMP_VERSION)
-            0x02, // V = 2
-            0x00, 0x00, 0x00, // Padding
+    lazy_static! {
+        static ref BOND_INFO: Vec<InfoBond> = vec![
+            InfoBond::Mode(BOND_MODE_8023AD),
+            InfoBond::MiiMon(100),
+            InfoBond::ArpIpTarget(vec![Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 2),]),
+            InfoBond::XmitHashPolicy(BOND_XMIT_POLICY_LAYER34),
+            InfoBond::AdInfo(vec![
+                BondAdInfo::Aggregator(1),
+                BondAdInfo::PartnerMac([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            ]),
+        ];
+    }
 
-            0x05, 0x00, // L = 5
-            0x2c, 0x00, // T = 44 (IFLA_BR_MCAST_MLD_VERSION)
-            0x01, // V = 1
-            0x00, 0x00, 0x00, // Padding
+    #[test]
+    fn parse_info_bond() {
+        let nla = NlaBuffer::new_checked(&BOND[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::Bond),
+            Info::Data(InfoData::Bond(BOND_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
 
-            0x0c, 0x00, // L = 12
-            0x1e, 0x00, // T = 30 (IFLA_BR_MCAST_LAST_MEMBER_INTVL)
-            0x63, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 99
+    #[test]
+    fn emit_info_bond() {
+        let nlas = vec![
+            Info::Kind(InfoKind::Bond),
+            Info::Data(InfoData::Bond(BOND_INFO.clone())),
+        ];
 
-            0x0c, 0x00, // L = 12
-            0x1f, 0x00, // T = 31 (IFLA_BR_MCAST_MEMBERSHIP_INTVL)
-            0x8f, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 25999 (0x658f)
+        assert_eq!(nlas.as_slice().buffer_len(), 84);
 
-            0x0c, 0x00, // L = 12
-            0x20, 0x00, // T = 32 (IFLA_BR_MCAST_QUERIER_INTVL)
-            0x9b, 0x63, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 25499 (0x639b)
+        let mut vec = vec![0xff; 84];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &BOND[..]);
+    }
 
-            0x0c, 0x00, // L = 12
-            0x21, 0x00, // T = 33 (IFLA_BR_MCAST_QUERY_INTVL)
-            0xd3, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 12499 (0x30d3)
+    #[rustfmt::skip]
+    static BOND_PORT: [u8; 60] = [
+        0x09, 0x00, // length = 9
+        0x04, 0x00, // type = 4 = IFLA_INFO_SLAVE_KIND
+        0x62, 0x6f, 0x6e, 0x64, 0x00, // V = "bond\0"
+        0x00, 0x00, 0x00, // padding
+
+        0x30, 0x00, // length = 48
+        0x05, 0x00, // type = 5 = IFLA_INFO_SLAVE_DATA
+            0x05, 0x00, // length = 5
+            0x01, 0x00, // type = 1 = IFLA_BOND_SLAVE_STATE
+            0x00, // V = 0 = active
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // length = 5
+            0x02, 0x00, // type = 2 = IFLA_BOND_SLAVE_MII_STATUS
+            0x00, // V = 0 = up
+            0x00, 0x00, 0x00, // padding
 
-            0x0c, 0x00, // L = 12
-            0x22, 0x00, // T = 34 (IFLA_BR_MCAST_QUERY_RESPONSE_INTVL)
-            0xe7, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 999 (0x03e7)
+            0x08, 0x00, // length = 8
+            0x03, 0x00, // type = 3 = IFLA_BOND_SLAVE_LINK_FAILURE_COUNT
+            0x02, 0x00, 0x00, 0x00, // V = 2
 
-            0x0c, 0x00, // L = 12
-            0x23, 0x00, // T = 35 (IFLA_BR_MCAST_STARTUP_QUERY_INTVL)
-            0x34, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 3124 (0x0c34)
+            0x0a, 0x00, // length = 10
+            0x04, 0x00, // type = 4 = IFLA_BOND_SLAVE_PERM_HWADDR
+            0x52, 0x54, 0x00, 0x12, 0x34, 0x56, // V = 52:54:00:12:34:56
+            0x00, 0x00, // padding
 
-            0x05, 0x00, // L = 5
-            0x24, 0x00, // T = 36 (IFLA_BR_NF_CALL_IPTABLES)
-            0x00, // V = 0
-            0x00, 0x00, 0x00, // Padding
+            0x06, 0x00, // length = 6
+            0x05, 0x00, // type = 5 = IFLA_BOND_SLAVE_QUEUE_ID
+            0x00, 0x00, // V = 0
+            0x00, 0x00, // padding
+    ];
 
-            0x05, 0x00, // L = 5
-            0x25, 0x00, // T = 37 (IFLA_BR_NF_CALL_IP6TABLES)
-            0x00, // V = 0
-            0x00, 0x00, 0x00, // Padding
+    lazy_static! {
+        static ref BOND_PORT_INFO: Vec<InfoBondPort> = vec![
+            InfoBondPort::State(BOND_STATE_ACTIVE),
+            InfoBondPort::MiiStatus(BOND_LINK_UP),
+            InfoBondPort::LinkFailureCount(2),
+            InfoBondPort::PermHwaddr(vec![0x52, 0x54, 0x00, 0x12, 0x34, 0x56]),
+            InfoBondPort::QueueId(0),
+        ];
+    }
 
-            0x05, 0x00, // L = 5
-            0x26, 0x00, // T = 38 (IFLA_BR_NF_CALL_ARPTABLES)
-            0x00, // V = 0
-            0x00, 0x00, 0x00, // Padding
+    #[test]
+    fn parse_info_bond_port() {
+        let nla = NlaBuffer::new_checked(&BOND_PORT[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::SlaveKind(InfoKind::Bond),
+            Info::SlaveData(InfoSlaveData::Bond(BOND_PORT_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
 
-            0x05, 0x00, // L = 5
-            0x2d, 0x00, // T = 45 (IFLA_BR_VLAN_STATS_PER_PORT)
-            0x01, // V = 1
-            0x00, 0x00, 0x00, // Padding
+    #[test]
+    fn emit_info_bond_port() {
+        let nlas = vec![
+            Info::SlaveKind(InfoKind::Bond),
+            Info::SlaveData(InfoSlaveData::Bond(BOND_PORT_INFO.clone())),
+        ];
 
-            0x0c, 0x00, // L = 12
-            0x2e, 0x00, // T = 46 (IFLA_BR_MULTI_BOOLOPT)
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,  // V = 0
+        assert_eq!(nlas.as_slice().buffer_len(), 60);
 
-    ];
+        let mut vec = vec![0xff; 60];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &BOND_PORT[..]);
+    }
 
-    lazy_static! {
-        static ref BRIDGE_INFO: Vec<InfoBridge> = vec![
-            InfoBridge::HelloTimer(35),
-            InfoBridge::TcnTimer(0),
-            InfoBridge::TopologyChangeTimer(0),
-            InfoBridge::GcTimer(14261),
-            InfoBridge::ForwardDelay(199),
-            InfoBridge::HelloTime(199),
-            InfoBridge::MaxAge(1999),
-            InfoBridge::AgeingTime(29999),
-            InfoBridge::StpState(1),
-            InfoBridge::Priority(0x8000),
-            InfoBridge::VlanFiltering(0),
-            InfoBridge::GroupFwdMask(0),
-            InfoBridge::BridgeId((128, [0x52, 0x54, 0x00, 0xd7, 0x19, 0x3e])),
-            InfoBridge::RootId((128, [0x52, 0x54, 0x00, 0xd7, 0x19, 0x3e])),
-            InfoBridge::RootPort(0),
-            InfoBridge::RootPathCost(0),
-            InfoBridge::TopologyChange(0),
-            InfoBridge::TopologyChangeDetected(0),
-            InfoBridge::GroupAddr([0x01, 0x80, 0xc2, 0x00, 0x00, 0x00]),
-            InfoBridge::VlanProtocol(33024),
-            InfoBridge::VlanDefaultPvid(1),
-            InfoBridge::VlanStatsEnabled(0),
-            InfoBridge::MulticastRouter(1),
-            InfoBridge::MulticastSnooping(1),
-            InfoBridge::MulticastQueryUseIfaddr(0),
-            InfoBridge::MulticastQuerier(0),
-            InfoBridge::MulticastStatsEnabled(0),
-            InfoBridge::MulticastHashElasticity(4),
-            InfoBridge::MulticastHashMax(512),
-            InfoBridge::MulticastLastMemberCount(2),
-            InfoBridge::MulticastStartupQueryCount(2),
-            InfoBridge::MulticastIgmpVersion(2),
-            InfoBridge::MulticastMldVersion(1),
-            InfoBridge::MulticastLastMemberInterval(99),
-            InfoBridge::MulticastMembershipInterval(25999),
-            InfoBridge::MulticastQuerierInterval(25499),
-            InfoBridge::MulticastQueryInterval(12499),
-            InfoBridge::MulticastQueryResponseInterval(999),
-            InfoBridge::MulticastStartupQueryInterval(3124),
-            InfoBridge::NfCallIpTables(0),
-            InfoBridge::NfCallIp6Tables(0),
-            InfoBridge::NfCallArpTables(0),
-            InfoBridge::VlanStatsPerHost(1),
-            InfoBridge::MultiBoolOpt(0),
+    #[rustfmt::skip]
+    static GRE: [u8; 104] = [
+        0x08, 0x00, // length = 8
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x67, 0x72, 0x65, 0x00, // V = "gre\0"
+        0x60, 0x00, // length = 96
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, // length = 8
+            0x01, 0x00, // type = IFLA_GRE_LINK
+            0x00, 0x00, 0x00, 0x00, // V = 0
+            0x06, 0x00, // length = 6
+            0x02, 0x00, // type = IFLA_GRE_IFLAGS
+            0x20, 0x00, // V = GRE_KEY
+            0x00, 0x00, // padding
+            0x06, 0x00, // length = 6
+            0x03, 0x00, // type = IFLA_GRE_OFLAGS
+            0x20, 0x00, // V = GRE_KEY
+            0x00, 0x00, // padding
+            0x08, 0x00, // length = 8
+            0x04, 0x00, // type = IFLA_GRE_IKEY
+            0x00, 0x00, 0x00, 0x2a, // V = 42
+            0x08, 0x00, // length = 8
+            0x05, 0x00, // type = IFLA_GRE_OKEY
+            0x00, 0x00, 0x00, 0x2a, // V = 42
+            0x08, 0x00, // length = 8
+            0x06, 0x00, // type = IFLA_GRE_LOCAL
+            0x0a, 0x00, 0x00, 0x01, // V = 10.0.0.1
+            0x08, 0x00, // length = 8
+            0x07, 0x00, // type = IFLA_GRE_REMOTE
+            0x0a, 0x00, 0x00, 0x02, // V = 10.0.0.2
+            0x05, 0x00, // length = 5
+            0x08, 0x00, // type = IFLA_GRE_TTL
+            0x40, // V = 64
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x0a, 0x00, // type = IFLA_GRE_PMTUDISC
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+            0x06, 0x00, // length = 6
+            0x0e, 0x00, // type = IFLA_GRE_ENCAP_TYPE
+            0x01, 0x00, // V = TUNNEL_ENCAP_FOU
+            0x00, 0x00, // padding
+            0x06, 0x00, // length = 6
+            0x11, 0x00, // type = IFLA_GRE_ENCAP_DPORT
+            0x15, 0xb3, // V = 5555
+            0x00, 0x00, // padding
+            0x04, 0x00, // length = 4
+            0x12, 0x00, // type = IFLA_GRE_COLLECT_METADATA
+    ];
+
+    lazy_static! {
+        static ref GRE_INFO: Vec<InfoGreTun> = vec![
+            InfoGreTun::Link(0),
+            InfoGreTun::IFlags(GRE_KEY),
+            InfoGreTun::OFlags(GRE_KEY),
+            InfoGreTun::IKey(42),
+            InfoGreTun::OKey(42),
+            InfoGreTun::Local(Ipv4Addr::new(10, 0, 0, 1).into()),
+            InfoGreTun::Remote(Ipv4Addr::new(10, 0, 0, 2).into()),
+            InfoGreTun::Ttl(64),
+            InfoGreTun::PMtuDisc(1),
+            InfoGreTun::EncapType(TUNNEL_ENCAP_FOU),
+            InfoGreTun::EncapDport(5555),
+            InfoGreTun::CollectMetadata,
         ];
     }
 
     #[test]
-    fn parse_info_kind() {
-        let info_kind_nla = NlaBuffer::new_checked(&BRIDGE[..12]).unwrap();
-        let parsed = InfoKind::parse(&info_kind_nla).unwrap();
-        assert_eq!(parsed, InfoKind::Bridge);
+    fn parse_info_gre() {
+        let nla = NlaBuffer::new_checked(&GRE[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::GreTun),
+            Info::Data(InfoData::GreTun(GRE_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
     }
 
     #[test]
-    fn parse_info_bridge() {
-        let nlas = NlasIterator::new(&BRIDGE[16..]);
-        for nla in nlas.map(|nla| nla.unwrap()) {
-            InfoBridge::parse(&nla).unwrap();
-        }
+    fn emit_info_gre() {
+        let nlas = vec![
+            Info::Kind(InfoKind::GreTun),
+            Info::Data(InfoData::GreTun(GRE_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 104);
+
+        let mut vec = vec![0xff; 104];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &GRE[..]);
     }
 
     #[rustfmt::skip]
-    #[test]
-    fn parse_veth_info() {
-        let data = vec![
+    static SIT: [u8; 120] = [
+        0x08, 0x00, // length = 8
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x73, 0x69, 0x74, 0x00, // V = "sit\0"
+        0x70, 0x00, // length = 112
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
             0x08, 0x00, // length = 8
-            0x01, 0x00, // type = 1 = IFLA_INFO_KIND
-            0x76, 0x65, 0x74, 0x68, // VETH
+            0x01, 0x00, // type = IFLA_IPTUN_LINK
+            0x00, 0x00, 0x00, 0x00, // V = 0
+            0x08, 0x00, // length = 8
+            0x02, 0x00, // type = IFLA_IPTUN_LOCAL
+            0xc0, 0x00, 0x02, 0x01, // V = 192.0.2.1
+            0x08, 0x00, // length = 8
+            0x03, 0x00, // type = IFLA_IPTUN_REMOTE
+            0x00, 0x00, 0x00, 0x00, // V = 0.0.0.0
+            0x05, 0x00, // length = 5
+            0x04, 0x00, // type = IFLA_IPTUN_TTL
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x05, 0x00, // type = IFLA_IPTUN_TOS
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x0a, 0x00, // type = IFLA_IPTUN_PMTUDISC
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+            0x06, 0x00, // length = 6
+            0x08, 0x00, // type = IFLA_IPTUN_FLAGS
+            0x01, 0x00, // V = SIT_ISATAP
+            0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x09, 0x00, // type = IFLA_IPTUN_PROTO
+            0x29, // V = 41 = IPPROTO_IPV6
+            0x00, 0x00, 0x00, // padding
+            0x14, 0x00, // length = 20
+            0x0b, 0x00, // type = IFLA_IPTUN_6RD_PREFIX
+            0x20, 0x02, 0x00, 0x00, // V = 2002::
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+            0x08, 0x00, // length = 8
+            0x0c, 0x00, // type = IFLA_IPTUN_6RD_RELAY_PREFIX
+            0x00, 0x00, 0x00, 0x00, // V = 0.0.0.0
+            0x06, 0x00, // length = 6
+            0x0d, 0x00, // type = IFLA_IPTUN_6RD_PREFIXLEN
+            0x10, 0x00, // V = 16
+            0x00, 0x00, // padding
+            0x06, 0x00, // length = 6
+            0x0e, 0x00, // type = IFLA_IPTUN_6RD_RELAY_PREFIXLEN
+            0x00, 0x00, // V = 0
+            0x00, 0x00, // padding
+    ];
 
-            0x30, 0x00, // length = 48
-            0x02, 0x00, // type = IFLA_INFO_DATA
+    lazy_static! {
+        static ref SIT_INFO: Vec<InfoIpTun> = vec![
+            InfoIpTun::Link(0),
+            InfoIpTun::Local(Ipv4Addr::new(192, 0, 2, 1).into()),
+            InfoIpTun::Remote(Ipv4Addr::UNSPECIFIED.into()),
+            InfoIpTun::Ttl(0),
+            InfoIpTun::Tos(0),
+            InfoIpTun::PMtuDisc(1),
+            InfoIpTun::Flags(SIT_ISATAP),
+            InfoIpTun::Proto(41),
+            InfoIpTun::SixRdPrefix(Ipv6Addr::new(0x2002, 0, 0, 0, 0, 0, 0, 0)),
+            InfoIpTun::SixRdRelayPrefix(Ipv4Addr::UNSPECIFIED),
+            InfoIpTun::SixRdPrefixLen(16),
+            InfoIpTun::SixRdRelayPrefixLen(0),
+        ];
+    }
 
-                0x2c, 0x00, // length = 44
-                0x01, 0x00, // type = VETH_INFO_PEER
-                // The data a NEWLINK message
-                0x00, // interface family
-                0x00, // padding
-                0x00, 0x00, // link layer type
-                0x00, 0x00, 0x00, 0x00, // link index
-                0x00, 0x00, 0x00, 0x00, // flags
-                0x00, 0x00, 0x00, 0x00, // flags change mask
-                    // NLA
-                    0x10, 0x00, // length = 16
-                    0x03, 0x00, // type = IFLA_IFNAME
-                    0x76, 0x65, 0x74, 0x68, 0x63, 0x30, 0x65, 0x36, 0x30, 0x64, 0x36, 0x00,
-                    // NLA
-                    0x08, 0x00, // length = 8
-                    0x0d, 0x00, // type = IFLA_TXQLEN
-                    0x00, 0x00, 0x00, 0x00,
+    #[test]
+    fn parse_info_sit() {
+        let nla = NlaBuffer::new_checked(&SIT[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::SitTun),
+            Info::Data(InfoData::SitTun(SIT_INFO.clone())),
         ];
-        let nla = NlaBuffer::new_checked(&data[..]).unwrap();
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_sit() {
+        let nlas = vec![
+            Info::Kind(InfoKind::SitTun),
+            Info::Data(InfoData::SitTun(SIT_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 120);
+
+        let mut vec = vec![0xff; 120];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &SIT[..]);
+    }
+
+    #[rustfmt::skip]
+    static VTI: [u8; 60] = [
+        0x08, 0x00, // length = 8
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x76, 0x74, 0x69, 0x00, // V = "vti\0"
+        0x34, 0x00, // length = 52
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, // length = 8
+            0x01, 0x00, // type = IFLA_VTI_LINK
+            0x00, 0x00, 0x00, 0x00, // V = 0
+            0x08, 0x00, // length = 8
+            0x02, 0x00, // type = IFLA_VTI_IKEY
+            0x00, 0x00, 0x00, 0x0a, // V = 10
+            0x08, 0x00, // length = 8
+            0x03, 0x00, // type = IFLA_VTI_OKEY
+            0x00, 0x00, 0x00, 0x0b, // V = 11
+            0x08, 0x00, // length = 8
+            0x04, 0x00, // type = IFLA_VTI_LOCAL
+            0x0a, 0x00, 0x00, 0x01, // V = 10.0.0.1
+            0x08, 0x00, // length = 8
+            0x05, 0x00, // type = IFLA_VTI_REMOTE
+            0x0a, 0x00, 0x00, 0x02, // V = 10.0.0.2
+            0x08, 0x00, // length = 8
+            0x06, 0x00, // type = IFLA_VTI_FWMARK
+            0x00, 0x00, 0x00, 0x00, // V = 0
+    ];
+
+    lazy_static! {
+        static ref VTI_INFO: Vec<InfoVti> = vec![
+            InfoVti::Link(0),
+            InfoVti::IKey(10),
+            InfoVti::OKey(11),
+            InfoVti::Local(Ipv4Addr::new(10, 0, 0, 1).into()),
+            InfoVti::Remote(Ipv4Addr::new(10, 0, 0, 2).into()),
+            InfoVti::FwMark(0),
+        ];
+    }
+
+    #[test]
+    fn parse_info_vti() {
+        let nla = NlaBuffer::new_checked(&VTI[..]).unwrap();
         let parsed = VecInfo::parse(&nla).unwrap().0;
         let expected = vec![
-            Info::Kind(InfoKind::Veth),
-            Info::Data(InfoData::Veth(VethInfo::Peer(LinkMessage {
-                header: LinkHeader {
-                    interface_family: 0,
-                    index: 0,
-                    link_layer_type: ARPHRD_NETROM,
-                    flags: 0,
-                    change_mask: 0,
-                },
-                nlas: vec![
-                    Nla::IfName("vethc0e60d6".to_string()),
-                    Nla::TxQueueLen(0),
-                ],
-            }))),
+            Info::Kind(InfoKind::Vti),
+            Info::Data(InfoData::Vti(VTI_INFO.clone())),
         ];
         assert_eq!(expected, parsed);
     }
 
+    #[test]
+    fn emit_info_vti() {
+        let nlas = vec![
+            Info::Kind(InfoKind::Vti),
+            Info::Data(InfoData::Vti(VTI_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 60);
+
+        let mut vec = vec![0xff; 60];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &VTI[..]);
+    }
+
     #[rustfmt::skip]
-    static IPVLAN: [u8; 32] = [
+    static GENEVE: [u8; 88] = [
         0x0b, 0x00, // length = 11
         0x01, 0x00, // type = 1 = IFLA_INFO_KIND
-        0x69, 0x70, 0x76, 0x6c, 0x61, 0x6e, 0x00, // V = "ipvlan\0"
+        0x67, 0x65, 0x6e, 0x65, 0x76, 0x65, 0x00, // V = "geneve\0"
         0x00, // padding
-
-        0x14, 0x00, // length = 20
+        0x4c, 0x00, // length = 76
         0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, // length = 8
+            0x01, 0x00, // type = IFLA_GENEVE_ID
+            0x0a, 0x00, 0x00, 0x00, // V = 10
+            0x08, 0x00, // length = 8
+            0x02, 0x00, // type = IFLA_GENEVE_REMOTE
+            0x0a, 0x00, 0x00, 0x02, // V = 10.0.0.2
+            0x05, 0x00, // length = 5
+            0x03, 0x00, // type = IFLA_GENEVE_TTL
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x04, 0x00, // type = IFLA_GENEVE_TOS
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
             0x06, 0x00, // length = 6
-            0x01, 0x00, // type = 1 = IFLA_IPVLAN_MODE
-            0x01, 0x00, // l3
-            0x00, 0x00, // padding
-
-            0x06, 0x00, // length = 6
-            0x02, 0x00, // type = 2 = IFLA_IPVLAN_FLAGS
-            0x02, 0x00, // vepa flag
+            0x05, 0x00, // type = IFLA_GENEVE_PORT
+            0x17, 0xc1, // V = 6081
             0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x08, 0x00, // type = IFLA_GENEVE_UDP_CSUM
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+            0x08, 0x00, // length = 8
+            0x0b, 0x00, // type = IFLA_GENEVE_LABEL
+            0x00, 0x00, 0x00, 0x00, // V = 0
+            0x05, 0x00, // length = 5
+            0x0c, 0x00, // type = IFLA_GENEVE_TTL_INHERIT
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x0d, 0x00, // type = IFLA_GENEVE_DF
+            0x00, // V = GENEVE_DF_UNSET
+            0x00, 0x00, 0x00, // padding
     ];
 
     lazy_static! {
-        static ref IPVLAN_INFO: Vec<InfoIpVlan> = vec![
-            InfoIpVlan::Mode(1), // L3
-            InfoIpVlan::Flags(2), // vepa flag
+        static ref GENEVE_INFO: Vec<InfoGeneve> = vec![
+            InfoGeneve::Id(10),
+            InfoGeneve::Remote(Ipv4Addr::new(10, 0, 0, 2)),
+            InfoGeneve::Ttl(0),
+            InfoGeneve::Tos(0),
+            InfoGeneve::Port(6081),
+            InfoGeneve::UDPCsum(0),
+            InfoGeneve::Label(0),
+            InfoGeneve::TtlInherit(0),
+            InfoGeneve::Df(GENEVE_DF_UNSET),
         ];
     }
 
     #[test]
-    fn parse_info_ipvlan() {
-        let nla = NlaBuffer::new_checked(&IPVLAN[..]).unwrap();
+    fn parse_info_geneve() {
+        let nla = NlaBuffer::new_checked(&GENEVE[..]).unwrap();
         let parsed = VecInfo::parse(&nla).unwrap().0;
         let expected = vec![
-            Info::Kind(InfoKind::IpVlan),
-            Info::Data(InfoData::IpVlan(IPVLAN_INFO.clone())),
+            Info::Kind(InfoKind::Geneve),
+            Info::Data(InfoData::Geneve(GENEVE_INFO.clone())),
         ];
         assert_eq!(expected, parsed);
     }
 
     #[test]
-    fn emit_info_ipvlan() {
+    fn emit_info_geneve() {
         let nlas = vec![
-            Info::Kind(InfoKind::IpVlan),
-            Info::Data(InfoData::IpVlan(IPVLAN_INFO.clone())),
+            Info::Kind(InfoKind::Geneve),
+            Info::Data(InfoData::Geneve(GENEVE_INFO.clone())),
         ];
 
-        assert_eq!(nlas.as_slice().buffer_len(), 32);
+        assert_eq!(nlas.as_slice().buffer_len(), 88);
 
-        let mut vec = vec![0xff; 32];
+        let mut vec = vec![0xff; 88];
         nlas.as_slice().emit(&mut vec);
-        assert_eq!(&vec[..], &IPVLAN[..]);
+        assert_eq!(&vec[..], &GENEVE[..]);
     }
 
     #[rustfmt::skip]
-    static MACVLAN: [u8; 24] = [
-        0x0c, 0x00, // length = 12
+    static MACSEC: [u8; 128] = [
+        0x0b, 0x00, // length = 11
         0x01, 0x00, // type = 1 = IFLA_INFO_KIND
-        0x6d, 0x61, 0x63, 0x76, 0x6c, 0x61, 0x6e, 0x00, // V = "macvlan\0"
-        0x0c, 0x00, // length = 12
+        0x6d, 0x61, 0x63, 0x73, 0x65, 0x63, 0x00, // V = "macsec\0"
+        0x00, // padding
+        0x74, 0x00, // length = 116
         0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x0c, 0x00, // length = 12
+            0x01, 0x00, // type = IFLA_MACSEC_SCI
+            0x52, 0x54, 0x00, 0x12, 0x34, 0x56, 0x00, 0x01, // V = 52:54:00:12:34:56, port 1
+            0x05, 0x00, // length = 5
+            0x03, 0x00, // type = IFLA_MACSEC_ICV_LEN
+            0x10, // V = 16
+            0x00, 0x00, 0x00, // padding
+            0x0c, 0x00, // length = 12
+            0x04, 0x00, // type = IFLA_MACSEC_CIPHER_SUITE
+            0x01, 0x00, 0x00, 0x01, 0x00, 0xc2, 0x80, 0x00, // V = MACSEC_CIPHER_ID_GCM_AES_128
+            0x05, 0x00, // length = 5
+            0x06, 0x00, // type = IFLA_MACSEC_ENCODING_SA
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x07, 0x00, // type = IFLA_MACSEC_ENCRYPT
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x08, 0x00, // type = IFLA_MACSEC_PROTECT
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x09, 0x00, // type = IFLA_MACSEC_INC_SCI
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x0a, 0x00, // type = IFLA_MACSEC_ES
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x0b, 0x00, // type = IFLA_MACSEC_SCB
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x0c, 0x00, // type = IFLA_MACSEC_REPLAY_PROTECT
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x0d, 0x00, // type = IFLA_MACSEC_VALIDATION
+            0x02, // V = MACSEC_VALIDATE_STRICT
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x0f, 0x00, // type = IFLA_MACSEC_OFFLOAD
+            0x00, // V = MACSEC_OFFLOAD_OFF
+            0x00, 0x00, 0x00, // padding
             0x08, 0x00, // length = 8
-            0x01, 0x00, // type = IFLA_MACVLAN_MODE
-            0x04, 0x00, 0x00, 0x00, // V = 4 = bridge
+            0x05, 0x00, // type = IFLA_MACSEC_WINDOW
+            0x20, 0x00, 0x00, 0x00, // V = 32
     ];
 
     lazy_static! {
-        static ref MACVLAN_INFO: Vec<InfoMacVlan> = vec![
-            InfoMacVlan::Mode(4), // bridge
+        static ref MACSEC_INFO: Vec<InfoMacsec> = vec![
+            InfoMacsec::Sci(0x5254_0012_3456_0001),
+            InfoMacsec::IcvLen(16),
+            InfoMacsec::CipherSuite(MACSEC_CIPHER_ID_GCM_AES_128),
+            InfoMacsec::EncodingSa(0),
+            InfoMacsec::Encrypt(1),
+            InfoMacsec::Protect(1),
+            InfoMacsec::IncSci(1),
+            InfoMacsec::Es(0),
+            InfoMacsec::Scb(0),
+            InfoMacsec::ReplayProtect(1),
+            InfoMacsec::Validation(MACSEC_VALIDATE_STRICT),
+            InfoMacsec::Offload(MACSEC_OFFLOAD_OFF),
+            InfoMacsec::Window(32),
         ];
     }
 
     #[test]
-    fn parse_info_macvlan() {
-        let nla = NlaBuffer::new_checked(&MACVLAN[..]).unwrap();
+    fn parse_info_macsec() {
+        let nla = NlaBuffer::new_checked(&MACSEC[..]).unwrap();
         let parsed = VecInfo::parse(&nla).unwrap().0;
         let expected = vec![
-            Info::Kind(InfoKind::MacVlan),
-            Info::Data(InfoData::MacVlan(MACVLAN_INFO.clone())),
+            Info::Kind(InfoKind::Macsec),
+            Info::Data(InfoData::Macsec(MACSEC_INFO.clone())),
         ];
         assert_eq!(expected, parsed);
     }
 
     #[test]
-    fn emit_info_macvlan() {
+    fn emit_info_macsec() {
         let nlas = vec![
-            Info::Kind(InfoKind::MacVlan),
-            Info::Data(InfoData::MacVlan(MACVLAN_INFO.clone())),
+            Info::Kind(InfoKind::Macsec),
+            Info::Data(InfoData::Macsec(MACSEC_INFO.clone())),
         ];
 
-        assert_eq!(nlas.as_slice().buffer_len(), 24);
+        assert_eq!(nlas.as_slice().buffer_len(), 128);
 
-        let mut vec = vec![0xff; 24];
+        let mut vec = vec![0xff; 128];
         nlas.as_slice().emit(&mut vec);
-        assert_eq!(&vec[..], &MACVLAN[..]);
+        assert_eq!(&vec[..], &MACSEC[..]);
     }
 
     #[rustfmt::skip]
-    static MACVLAN_SOURCE_SET: [u8; 84] = [
-        0x0c, 0x00, // length = 12
+    static TUN: [u8; 68] = [
+        0x08, 0x00, // length = 8
         0x01, 0x00, // type = 1 = IFLA_INFO_KIND
-        0x6d, 0x61, 0x63, 0x76, 0x6c, 0x61, 0x6e, 0x00, // V = "macvlan\0"
-        0x48, 0x00, // length = 72
+        0x74, 0x75, 0x6e, 0x00, // V = "tun\0"
+        0x3c, 0x00, // length = 60
         0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x05, 0x00, // length = 5
+            0x03, 0x00, // type = IFLA_TUN_TYPE
+            0x02, // V = IFF_TAP
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x04, 0x00, // type = IFLA_TUN_PI
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x05, 0x00, // type = IFLA_TUN_VNET_HDR
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x06, 0x00, // type = IFLA_TUN_PERSIST
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, // length = 5
+            0x07, 0x00, // type = IFLA_TUN_MULTI_QUEUE
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
             0x08, 0x00, // length = 8
-            0x03, 0x00, // type = 3 = IFLA_MACVLAN_MACADDR_MODE
-            0x03, 0x00, 0x00, 0x00, // V = 3 = set
-
-            0x34, 0x00, // length = 52
-            0x05, 0x00, // type = 5 = IFLA_MACVLAN_MACADDR_DATA
-                0x0a, 0x00, // length = 10
-                0x04, 0x00, // type = 4 = IFLA_MACVLAN_MACADDR
-                0x22, 0xf5, 0x54, 0x09, 0x88, 0xd7, // V = mac address
-                0x00, 0x00, // padding
-
-                0x0a, 0x00, // length = 10
-                0x04, 0x00, // type = 4 = IFLA_MACVLAN_MACADDR
-                0x22, 0xf5, 0x54, 0x09, 0x99, 0x32, // V = mac address
-                0x00, 0x00, // padding
-
-                0x0a, 0x00, // length = 10
-                0x04, 0x00, // type = 4 = IFLA_MACVLAN_MACADDR
-                0x22, 0xf5, 0x54, 0x09, 0x87, 0x45, // V = mac address
-                0x00, 0x00, // padding
-
-                0x0a, 0x00, // length = 10
-                0x04, 0x00, // type = 4 = IFLA_MACVLAN_MACADDR
-                0x22, 0xf5, 0x54, 0x09, 0x11, 0x45, // V = mac address
-                0x00, 0x00, // padding
+            0x08, 0x00, // type = IFLA_TUN_NUM_QUEUES
+            0x00, 0x00, 0x00, 0x00, // V = 0
             0x08, 0x00, // length = 8
-            0x01, 0x00, // Type = 1 = IFLA_MACVLAN_MODE
-            0x10, 0x00, 0x00, 0x00, // V = 16 = source
+            0x09, 0x00, // type = IFLA_TUN_NUM_DISABLED_QUEUES
+            0x00, 0x00, 0x00, 0x00, // V = 0
     ];
 
     lazy_static! {
-        static ref MACVLAN_SOURCE_SET_INFO: Vec<InfoMacVlan> = vec![
-            InfoMacVlan::MacAddrMode(3), // set
-            InfoMacVlan::MacAddrData(vec![
-                                 InfoMacVlan::MacAddr([0x22, 0xf5, 0x54, 0x09, 0x88, 0xd7,]),
-                                 InfoMacVlan::MacAddr([0x22, 0xf5, 0x54, 0x09, 0x99, 0x32,]),
-                                 InfoMacVlan::MacAddr([0x22, 0xf5, 0x54, 0x09, 0x87, 0x45,]),
-                                 InfoMacVlan::MacAddr([0x22, 0xf5, 0x54, 0x09, 0x11, 0x45,]),
-            ]),
-            InfoMacVlan::Mode(16), // source
+        static ref TUN_INFO: Vec<InfoTun> = vec![
+            InfoTun::Type(IFF_TAP),
+            InfoTun::Pi(0),
+            InfoTun::VnetHdr(0),
+            InfoTun::Persist(1),
+            InfoTun::MultiQueue(1),
+            InfoTun::NumQueues(0),
+            InfoTun::NumDisabledQueues(0),
         ];
     }
 
     #[test]
-    fn parse_info_macvlan_source_set() {
-        let nla = NlaBuffer::new_checked(&MACVLAN_SOURCE_SET[..]).unwrap();
+    fn parse_info_tun() {
+        let nla = NlaBuffer::new_checked(&TUN[..]).unwrap();
         let parsed = VecInfo::parse(&nla).unwrap().0;
         let expected = vec![
-            Info::Kind(InfoKind::MacVlan),
-            Info::Data(InfoData::MacVlan(MACVLAN_SOURCE_SET_INFO.clone())),
+            Info::Kind(InfoKind::Tun),
+            Info::Data(InfoData::Tun(TUN_INFO.clone())),
         ];
         assert_eq!(expected, parsed);
     }
 
     #[test]
-    fn emit_info_macvlan_source_set() {
+    fn emit_info_tun() {
         let nlas = vec![
-            Info::Kind(InfoKind::MacVlan),
-            Info::Data(InfoData::MacVlan(MACVLAN_SOURCE_SET_INFO.clone())),
+            Info::Kind(InfoKind::Tun),
+            Info::Data(InfoData::Tun(TUN_INFO.clone())),
         ];
 
-        assert_eq!(nlas.as_slice().buffer_len(), 84);
+        assert_eq!(nlas.as_slice().buffer_len(), 68);
 
-        let mut vec = vec![0xff; 84];
+        let mut vec = vec![0xff; 68];
         nlas.as_slice().emit(&mut vec);
-        assert_eq!(&vec[..], &MACVLAN_SOURCE_SET[..]);
+        assert_eq!(&vec[..], &TUN[..]);
+    }
+
+    #[rustfmt::skip]
+    static VRF_PORT: [u8; 20] = [
+        0x08, 0x00, // length = 8
+        0x04, 0x00, // type = 4 = IFLA_INFO_SLAVE_KIND
+        0x76, 0x72, 0x66, 0x00, // V = "vrf\0"
+        0x0c, 0x00, // length = 12
+        0x05, 0x00, // type = 5 = IFLA_INFO_SLAVE_DATA
+            0x08, 0x00, // length = 8
+            0x01, 0x00, // type = IFLA_VRF_PORT_TABLE
+            0x0a, 0x00, 0x00, 0x00, // V = 10
+    ];
+
+    #[test]
+    fn parse_info_vrf_port() {
+        let nla = NlaBuffer::new_checked(&VRF_PORT[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::SlaveKind(InfoKind::Vrf),
+            Info::SlaveData(InfoSlaveData::Vrf(vec![InfoVrfPort::TableId(10)])),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_vrf_port() {
+        let nlas = vec![
+            Info::SlaveKind(InfoKind::Vrf),
+            Info::SlaveData(InfoSlaveData::Vrf(vec![InfoVrfPort::TableId(10)])),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 20);
+
+        let mut vec = vec![0xff; 20];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &VRF_PORT[..]);
     }
 
     #[test]
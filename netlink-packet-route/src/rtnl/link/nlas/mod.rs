@@ -9,6 +9,12 @@ pub use self::inet6::*;
 mod af_spec_inet;
 pub use self::af_spec_inet::*;
 
+mod af_spec_bridge;
+pub use self::af_spec_bridge::*;
+
+mod bridge_port;
+pub use self::bridge_port::*;
+
 mod link_infos;
 pub use self::link_infos::*;
 
@@ -66,6 +72,9 @@ pub enum Nla {
     Info(Vec<Info>),
     Wireless(Vec<u8>),
     ProtoInfo(Vec<u8>),
+    /// Attributes of a bridge port, sent as `IFLA_PROTINFO` in messages of
+    /// the `AF_BRIDGE` family
+    ProtoInfoBridge(Vec<InfoBridgePort>),
     /// A list of properties for the device. For additional context see the related linux kernel
     /// threads<sup>[1][1],[2][2]</sup>. In particular see [this message][defining message] from
     /// the first thread describing the design.
@@ -137,13 +146,12 @@ pub enum Nla {
     NetnsId(i32),
     // custom
     OperState(State),
-    Stats(Vec<u8>),
-    Stats64(Vec<u8>),
+    Stats(Stats),
+    Stats64(Stats64),
     Map(Vec<u8>),
     // AF_SPEC (the type of af_spec depends on the interface family of the message)
     AfSpecInet(Vec<AfSpecInet>),
-    // AfSpecBridge(Vec<AfSpecBridgeNla>),
-    AfSpecBridge(Vec<u8>),
+    AfSpecBridge(Vec<AfSpecBridge>),
     AfSpecUnknown(Vec<u8>),
     Other(DefaultNla),
 }
@@ -177,7 +185,6 @@ impl nlas::Nla for Nla {
                 | Broadcast(ref bytes)
                 | PermAddress(ref bytes)
                 | AfSpecUnknown(ref bytes)
-                | AfSpecBridge(ref bytes)
                 | Map(ref bytes)
                 | ProtoDownReason(ref bytes)
                 => bytes.len(),
@@ -218,12 +225,13 @@ impl nlas::Nla for Nla {
 
             // Defaults
             OperState(_) => 1,
-            Stats(_) => LINK_STATS_LEN,
-            Stats64(_) => LINK_STATS64_LEN,
+            Stats(ref stats) => stats.buffer_len(),
+            Stats64(ref stats) => stats.buffer_len(),
             Info(ref nlas) => nlas.as_slice().buffer_len(),
             PropList(ref nlas) => nlas.as_slice().buffer_len(),
             AfSpecInet(ref nlas) => nlas.as_slice().buffer_len(),
-            // AfSpecBridge(ref nlas) => nlas.as_slice().buffer_len(),
+            AfSpecBridge(ref nlas) => nlas.as_slice().buffer_len(),
+            ProtoInfoBridge(ref nlas) => nlas.as_slice().buffer_len(),
             Other(ref attr)  => attr.value_len(),
         }
     }
@@ -258,9 +266,6 @@ impl nlas::Nla for Nla {
                 | Broadcast(ref bytes)
                 | PermAddress(ref bytes)
                 | AfSpecUnknown(ref bytes)
-                | AfSpecBridge(ref bytes)
-                | Stats(ref bytes)
-                | Stats64(ref bytes)
                 | Map(ref bytes)
                 | ProtoDownReason(ref bytes)
                 => buffer.copy_from_slice(bytes.as_slice()),
@@ -306,10 +311,13 @@ impl nlas::Nla for Nla {
                 => NativeEndian::write_i32(buffer, *value),
 
             OperState(state) => buffer[0] = state.into(),
+            Stats(ref stats) => stats.emit(buffer),
+            Stats64(ref stats) => stats.emit(buffer),
             Info(ref nlas) => nlas.as_slice().emit(buffer),
             PropList(ref nlas) => nlas.as_slice().emit(buffer),
             AfSpecInet(ref nlas) => nlas.as_slice().emit(buffer),
-            // AfSpecBridge(ref nlas) => nlas.as_slice().emit(buffer),
+            AfSpecBridge(ref nlas) => nlas.as_slice().emit(buffer),
+            ProtoInfoBridge(ref nlas) => nlas.as_slice().emit(buffer),
             // default nlas
             Other(ref attr) => attr.emit_value(buffer),
         }
@@ -331,6 +339,7 @@ impl nlas::Nla for Nla {
             Info(_) => IFLA_LINKINFO,
             Wireless(_) => IFLA_WIRELESS,
             ProtoInfo(_) => IFLA_PROTINFO,
+            ProtoInfoBridge(_) => IFLA_PROTINFO | NLA_F_NESTED,
             Pad(_) => IFLA_PAD,
             Xdp(_) => IFLA_XDP,
             Event(_) => IFLA_EVENT,
@@ -405,7 +414,18 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
             IFLA_PHYS_PORT_ID => PhysPortId(payload.to_vec()),
             IFLA_PHYS_SWITCH_ID => PhysSwitchId(payload.to_vec()),
             IFLA_WIRELESS => Wireless(payload.to_vec()),
-            IFLA_PROTINFO => ProtoInfo(payload.to_vec()),
+            IFLA_PROTINFO => match interface_family {
+                AF_BRIDGE => {
+                    let mut nlas = vec![];
+                    let err = "invalid IFLA_PROTINFO value";
+                    for nla in NlasIterator::new(payload) {
+                        let nla = nla.context(err)?;
+                        nlas.push(InfoBridgePort::parse(&nla).context(err)?);
+                    }
+                    ProtoInfoBridge(nlas)
+                }
+                _ => ProtoInfo(payload.to_vec()),
+            },
             IFLA_PAD => Pad(payload.to_vec()),
             IFLA_XDP => Xdp(payload.to_vec()),
             IFLA_EVENT => Event(payload.to_vec()),
@@ -488,8 +508,18 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
                     .into(),
             ),
             IFLA_MAP => Map(payload.to_vec()),
-            IFLA_STATS => Stats(payload.to_vec()),
-            IFLA_STATS64 => Stats64(payload.to_vec()),
+            IFLA_STATS => Stats(
+                self::Stats::parse(
+                    &StatsBuffer::new_checked(payload).context("invalid IFLA_STATS value")?,
+                )
+                .context("invalid IFLA_STATS value")?,
+            ),
+            IFLA_STATS64 => Stats64(
+                self::Stats64::parse(
+                    &Stats64Buffer::new_checked(payload).context("invalid IFLA_STATS64 value")?,
+                )
+                .context("invalid IFLA_STATS64 value")?,
+            ),
             IFLA_AF_SPEC => match interface_family as u16 {
                 AF_INET | AF_INET6 | AF_UNSPEC => {
                     let mut nlas = vec![];
@@ -500,7 +530,15 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
                     }
                     AfSpecInet(nlas)
                 }
-                AF_BRIDGE => AfSpecBridge(payload.to_vec()),
+                AF_BRIDGE => {
+                    let mut nlas = vec![];
+                    let err = "invalid IFLA_AF_SPEC value";
+                    for nla in NlasIterator::new(payload) {
+                        let nla = nla.context(err)?;
+                        nlas.push(self::AfSpecBridge::parse(&nla).context(err)?);
+                    }
+                    AfSpecBridge(nlas)
+                }
                 _ => AfSpecUnknown(payload.to_vec()),
             },
             IFLA_LINKINFO => {
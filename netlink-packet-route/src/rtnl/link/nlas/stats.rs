@@ -5,7 +5,7 @@ use crate::{
     DecodeError,
 };
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct Stats {
     /// total packets received
     pub rx_packets: u32,
@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: MIT
 
+use super::Stats;
 use crate::{
     traits::{Emitable, Parseable},
     DecodeError,
@@ -33,7 +34,7 @@ buffer!(Stats64Buffer(LINK_STATS64_LEN) {
     rx_nohandler: (u64, 184..192),
 });
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct Stats64 {
     /// total packets received
     pub rx_packets: u64,
@@ -83,6 +84,37 @@ pub struct Stats64 {
     pub rx_nohandler: u64,
 }
 
+impl From<Stats> for Stats64 {
+    fn from(stats: Stats) -> Self {
+        Self {
+            rx_packets: stats.rx_packets.into(),
+            tx_packets: stats.tx_packets.into(),
+            rx_bytes: stats.rx_bytes.into(),
+            tx_bytes: stats.tx_bytes.into(),
+            rx_errors: stats.rx_errors.into(),
+            tx_errors: stats.tx_errors.into(),
+            rx_dropped: stats.rx_dropped.into(),
+            tx_dropped: stats.tx_dropped.into(),
+            multicast: stats.multicast.into(),
+            collisions: stats.collisions.into(),
+            rx_length_errors: stats.rx_length_errors.into(),
+            rx_over_errors: stats.rx_over_errors.into(),
+            rx_crc_errors: stats.rx_crc_errors.into(),
+            rx_frame_errors: stats.rx_frame_errors.into(),
+            rx_fifo_errors: stats.rx_fifo_errors.into(),
+            rx_missed_errors: stats.rx_missed_errors.into(),
+            tx_aborted_errors: stats.tx_aborted_errors.into(),
+            tx_carrier_errors: stats.tx_carrier_errors.into(),
+            tx_fifo_errors: stats.tx_fifo_errors.into(),
+            tx_heartbeat_errors: stats.tx_heartbeat_errors.into(),
+            tx_window_errors: stats.tx_window_errors.into(),
+            rx_compressed: stats.rx_compressed.into(),
+            tx_compressed: stats.tx_compressed.into(),
+            rx_nohandler: stats.rx_nohandler.into(),
+        }
+    }
+}
+
 impl<T: AsRef<[u8]>> Parseable<Stats64Buffer<T>> for Stats64 {
     fn parse(buf: &Stats64Buffer<T>) -> Result<Self, DecodeError> {
         Ok(Self {
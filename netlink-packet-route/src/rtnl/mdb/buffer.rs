@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nlas::{NlaBuffer, NlasIterator},
+    DecodeError,
+};
+
+pub const MDB_HEADER_LEN: usize = 8;
+
+buffer!(MdbMessageBuffer(MDB_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..4),
+    ifindex: (u32, 4..MDB_HEADER_LEN),
+    payload: (slice, MDB_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> MdbMessageBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
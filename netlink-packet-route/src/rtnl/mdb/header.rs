@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+
+use super::{MdbMessageBuffer, MDB_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Bridge multicast database message header (`struct br_port_msg`).
+///
+/// ```no_rust
+/// 0                8                16              24               32
+/// +----------------+----------------+----------------+----------------+
+/// | address family |    padding     |             padding             |
+/// +----------------+----------------+----------------+----------------+
+/// |                        bridge interface index                     |
+/// +----------------+----------------+----------------+----------------+
+/// ```
+// see https://github.com/torvalds/linux/blob/master/include/uapi/linux/if_bridge.h
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct MdbHeader {
+    /// Address family: `AF_BRIDGE`.
+    pub family: u8,
+    /// Index of the bridge the entries belong to.
+    pub ifindex: u32,
+}
+
+impl Emitable for MdbHeader {
+    fn buffer_len(&self) -> usize {
+        MDB_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = MdbMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+        packet.set_ifindex(self.ifindex);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<MdbMessageBuffer<T>> for MdbHeader {
+    fn parse(buf: &MdbMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(MdbHeader {
+            family: buf.family(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
@@ -0,0 +1,308 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::mdb::Nla,
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+    MdbHeader,
+    MdbMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct MdbMessage {
+    pub header: MdbHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<MdbMessageBuffer<&'a T>> for MdbMessage {
+    fn parse(buf: &MdbMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: MdbHeader::parse(buf).context("failed to parse MDB message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse MDB message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<MdbMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &MdbMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        // Requests start with a `MDBA_SET_ENTRY` attribute. The attribute
+        // that follows it shares its type with `MDBA_ROUTER`.
+        let mut request = false;
+        for nla_buf in buf.nlas() {
+            let nla = Nla::parse_with_param(&nla_buf?, request)?;
+            if let Nla::SetEntry(_) = nla {
+                request = true;
+            }
+            nlas.push(nla);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for MdbMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use crate::{
+        nlas::mdb::{
+            MdbEntry,
+            MdbEntryAttr,
+            MdbEntryInfo,
+            MdbGroup,
+            MdbNla,
+            MdbSrcAttr,
+            MdbSrcListEntry,
+            Nla,
+            RouterPort,
+            RouterPortAttr,
+            SetEntryAttr,
+        },
+        traits::{Emitable, ParseableParametrized},
+        MdbHeader,
+        MdbMessage,
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        AF_BRIDGE,
+        MCAST_INCLUDE,
+        MDB_PERMANENT,
+        MDB_RTR_TYPE_PERM,
+        MDB_TEMPORARY,
+        RTM_NEWMDB,
+        RTPROT_STATIC,
+    };
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_mdb() {
+        let data = vec![
+            0x8c, 0x00, 0x00, 0x00, // length = 140
+            0x54, 0x00, // message type = 84 = RTM_NEWMDB
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // br_port_msg
+            0x07, // family = AF_BRIDGE
+            0x00, 0x00, 0x00, // padding
+            0x03, 0x00, 0x00, 0x00, // bridge index = 3
+
+            // NLAs
+            0x58, 0x00, 0x01, 0x00, // length = 88, type = MDBA_MDB
+            0x54, 0x00, 0x01, 0x00, // length = 84, type = MDBA_MDB_ENTRY
+            0x50, 0x00, 0x01, 0x00, // length = 80, type = MDBA_MDB_ENTRY_INFO
+            // br_mdb_entry
+            0x04, 0x00, 0x00, 0x00, // port index = 4
+            0x01, // state = MDB_PERMANENT
+            0x00, // flags
+            0x00, 0x00, // vid = 0
+            0xef, 0x01, 0x01, 0x01, // group = 239.1.1.1
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+            0x08, 0x00, // proto = ETH_P_IP
+            0x00, 0x00, // padding
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = MDBA_MDB_EATTR_TIMER
+            0x00, 0x00, 0x00, 0x00, // 0
+            0x18, 0x00, 0x02, 0x80, // length = 24, type = MDBA_MDB_EATTR_SRC_LIST | NLA_F_NESTED
+            0x14, 0x00, 0x01, 0x80, // length = 20, type = MDBA_MDB_SRCLIST_ENTRY | NLA_F_NESTED
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = MDBA_MDB_SRCATTR_ADDRESS
+            0x0a, 0x00, 0x00, 0x01, // 10.0.0.1
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = MDBA_MDB_SRCATTR_TIMER
+            0x64, 0x00, 0x00, 0x00, // 100
+            0x05, 0x00, 0x03, 0x00, // length = 5, type = MDBA_MDB_EATTR_GROUP_MODE
+            0x01, 0x00, 0x00, 0x00, // MCAST_INCLUDE + padding
+            0x05, 0x00, 0x05, 0x00, // length = 5, type = MDBA_MDB_EATTR_RTPROT
+            0x04, 0x00, 0x00, 0x00, // RTPROT_STATIC + padding
+
+            0x1c, 0x00, 0x02, 0x00, // length = 28, type = MDBA_ROUTER
+            0x18, 0x00, 0x01, 0x00, // length = 24, type = MDBA_ROUTER_PORT
+            0x05, 0x00, 0x00, 0x00, // port index = 5
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = MDBA_ROUTER_PATTR_TIMER
+            0x00, 0x00, 0x00, 0x00, // 0
+            0x05, 0x00, 0x02, 0x00, // length = 5, type = MDBA_ROUTER_PATTR_TYPE
+            0x02, 0x00, 0x00, 0x00, // MDB_RTR_TYPE_PERM + padding
+        ];
+        let expected = RtnlMessage::NewMdb(MdbMessage {
+            header: MdbHeader {
+                family: AF_BRIDGE as u8,
+                ifindex: 3,
+            },
+            nlas: vec![
+                Nla::Mdb(vec![MdbNla::Entry(vec![MdbEntryInfo {
+                    entry: MdbEntry {
+                        ifindex: 4,
+                        state: MDB_PERMANENT,
+                        flags: 0,
+                        vid: 0,
+                        group: MdbGroup::Ipv4(Ipv4Addr::new(239, 1, 1, 1)),
+                    },
+                    nlas: vec![
+                        MdbEntryAttr::Timer(0),
+                        MdbEntryAttr::SrcList(vec![MdbSrcListEntry {
+                            nlas: vec![
+                                MdbSrcAttr::Address(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))),
+                                MdbSrcAttr::Timer(100),
+                            ],
+                        }]),
+                        MdbEntryAttr::GroupMode(MCAST_INCLUDE),
+                        MdbEntryAttr::RtProt(RTPROT_STATIC),
+                    ],
+                }])]),
+                Nla::Router(vec![RouterPort {
+                    ifindex: 5,
+                    nlas: vec![
+                        RouterPortAttr::Timer(0),
+                        RouterPortAttr::Type(MDB_RTR_TYPE_PERM),
+                    ],
+                }]),
+            ],
+        });
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWMDB).unwrap();
+        assert_eq!(expected, actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_mdb_request() {
+        let data = vec![
+            0x50, 0x00, 0x00, 0x00, // length = 80
+            0x54, 0x00, // message type = 84 = RTM_NEWMDB
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // br_port_msg
+            0x07, // family = AF_BRIDGE
+            0x00, 0x00, 0x00, // padding
+            0x03, 0x00, 0x00, 0x00, // bridge index = 3
+
+            // NLAs
+            0x20, 0x00, 0x01, 0x00, // length = 32, type = MDBA_SET_ENTRY
+            0x04, 0x00, 0x00, 0x00, // port index = 4
+            0x01, // state = MDB_PERMANENT
+            0x00, // flags
+            0x0a, 0x00, // vid = 10
+            0xff, 0x0e, 0x00, 0x00, // group = ff0e::1
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x01,
+            0x86, 0xdd, // proto = ETH_P_IPV6
+            0x00, 0x00, // padding
+            0x18, 0x00, 0x02, 0x80, // length = 24, type = MDBA_SET_ENTRY_ATTRS | NLA_F_NESTED
+            0x14, 0x00, 0x01, 0x00, // length = 20, type = MDBE_ATTR_SOURCE
+            0x20, 0x01, 0x0d, 0xb8, // 2001:db8::1
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x01,
+        ];
+        let expected = RtnlMessage::NewMdb(MdbMessage {
+            header: MdbHeader {
+                family: AF_BRIDGE as u8,
+                ifindex: 3,
+            },
+            nlas: vec![
+                Nla::SetEntry(MdbEntry {
+                    ifindex: 4,
+                    state: MDB_PERMANENT,
+                    flags: 0,
+                    vid: 10,
+                    group: MdbGroup::Ipv6(Ipv6Addr::new(0xff0e, 0, 0, 0, 0, 0, 0, 1)),
+                }),
+                Nla::SetEntryAttrs(vec![SetEntryAttr::Source(IpAddr::V6(Ipv6Addr::new(
+                    0x2001, 0xdb8, 0, 0, 0, 0, 0, 1,
+                )))]),
+            ],
+        });
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWMDB).unwrap();
+        assert_eq!(expected, actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_mdb_unknown_protocol() {
+        let data = vec![
+            0x40, 0x00, 0x00, 0x00, // length = 64
+            0x54, 0x00, // message type = 84 = RTM_NEWMDB
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // br_port_msg
+            0x07, // family = AF_BRIDGE
+            0x00, 0x00, 0x00, // padding
+            0x03, 0x00, 0x00, 0x00, // bridge index = 3
+
+            // NLAs
+            0x28, 0x00, 0x01, 0x00, // length = 40, type = MDBA_MDB
+            0x24, 0x00, 0x01, 0x00, // length = 36, type = MDBA_MDB_ENTRY
+            0x20, 0x00, 0x01, 0x00, // length = 32, type = MDBA_MDB_ENTRY_INFO
+            // br_mdb_entry
+            0x04, 0x00, 0x00, 0x00, // port index = 4
+            0x00, // state = MDB_TEMPORARY
+            0x00, // flags
+            0x0a, 0x00, // vid = 10
+            0x01, 0x02, 0x03, 0x04, // group
+            0x05, 0x06, 0x07, 0x08,
+            0x09, 0x0a, 0x0b, 0x0c,
+            0x0d, 0x0e, 0x0f, 0x10,
+            0x88, 0xb5, // proto = 0x88b5 (unknown)
+            0x00, 0x00, // padding
+        ];
+        let expected = RtnlMessage::NewMdb(MdbMessage {
+            header: MdbHeader {
+                family: AF_BRIDGE as u8,
+                ifindex: 3,
+            },
+            nlas: vec![Nla::Mdb(vec![MdbNla::Entry(vec![MdbEntryInfo {
+                entry: MdbEntry {
+                    ifindex: 4,
+                    state: MDB_TEMPORARY,
+                    flags: 0,
+                    vid: 10,
+                    group: MdbGroup::Other(0x88b5, [
+                        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+                        0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10,
+                    ]),
+                },
+                nlas: vec![],
+            }])])],
+        });
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWMDB).unwrap();
+        assert_eq!(expected, actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::{
+    constants::*,
+    parsers::{parse_ipv6, parse_mac},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Multicast group of a MDB entry
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MdbGroup {
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr),
+    /// Layer 2 multicast address
+    Mac([u8; 6]),
+    /// Group of an unknown protocol, with its raw address
+    Other(u16, [u8; 16]),
+}
+
+impl Default for MdbGroup {
+    fn default() -> Self {
+        MdbGroup::Ipv4(Ipv4Addr::UNSPECIFIED)
+    }
+}
+
+/// A bridge port member of a multicast group (`struct br_mdb_entry`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct MdbEntry {
+    /// Index of the bridge port
+    pub ifindex: u32,
+    /// `MDB_TEMPORARY` or `MDB_PERMANENT`
+    pub state: u8,
+    /// Combination of the `MDB_FLAGS_*` constants
+    pub flags: u8,
+    /// VLAN of the group, or 0
+    pub vid: u16,
+    pub group: MdbGroup,
+}
+
+pub const MDB_ENTRY_LEN: usize = 28;
+
+buffer!(MdbEntryBuffer(MDB_ENTRY_LEN) {
+    ifindex: (u32, 0..4),
+    state: (u8, 4),
+    flags: (u8, 5),
+    vid: (u16, 6..8),
+    addr: (slice, 8..24),
+    proto: (slice, 24..26),
+    pad: (slice, 26..MDB_ENTRY_LEN),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<MdbEntryBuffer<&'a T>> for MdbEntry {
+    fn parse(buf: &MdbEntryBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let addr = buf.addr();
+        let group = match BigEndian::read_u16(buf.proto()) {
+            ETH_P_IP => MdbGroup::Ipv4(Ipv4Addr::new(addr[0], addr[1], addr[2], addr[3])),
+            ETH_P_IPV6 => MdbGroup::Ipv6(parse_ipv6(addr)?.into()),
+            0 => MdbGroup::Mac(parse_mac(&addr[..6])?),
+            proto => {
+                let mut raw = [0; 16];
+                raw.copy_from_slice(addr);
+                MdbGroup::Other(proto, raw)
+            }
+        };
+        Ok(Self {
+            ifindex: buf.ifindex(),
+            state: buf.state(),
+            flags: buf.flags(),
+            vid: buf.vid(),
+            group,
+        })
+    }
+}
+
+impl Emitable for MdbEntry {
+    fn buffer_len(&self) -> usize {
+        MDB_ENTRY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = MdbEntryBuffer::new(buffer);
+        buffer.set_ifindex(self.ifindex);
+        buffer.set_state(self.state);
+        buffer.set_flags(self.flags);
+        buffer.set_vid(self.vid);
+        let addr = buffer.addr_mut();
+        for byte in addr.iter_mut() {
+            *byte = 0;
+        }
+        let proto = match self.group {
+            MdbGroup::Ipv4(ip) => {
+                addr[..4].copy_from_slice(&ip.octets());
+                ETH_P_IP
+            }
+            MdbGroup::Ipv6(ip) => {
+                addr.copy_from_slice(&ip.octets());
+                ETH_P_IPV6
+            }
+            MdbGroup::Mac(mac) => {
+                addr[..6].copy_from_slice(&mac);
+                0
+            }
+            MdbGroup::Other(proto, raw) => {
+                addr.copy_from_slice(&raw);
+                proto
+            }
+        };
+        BigEndian::write_u16(buffer.proto_mut(), proto);
+        buffer.pad_mut().copy_from_slice(&[0; 2]);
+    }
+}
@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use super::{MdbEntry, MdbEntryBuffer, MDB_ENTRY_LEN};
+use crate::{
+    constants::*,
+    emit_ip,
+    ip_len,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_ip, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// A bridge port member of a multicast group, as sent by the kernel
+/// (`MDBA_MDB_ENTRY_INFO`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MdbEntryInfo {
+    pub entry: MdbEntry,
+    pub nlas: Vec<MdbEntryAttr>,
+}
+
+impl nlas::Nla for MdbEntryInfo {
+    fn value_len(&self) -> usize {
+        self.entry.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.entry.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.entry.buffer_len()..]);
+    }
+
+    fn kind(&self) -> u16 {
+        MDBA_MDB_ENTRY_INFO
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbEntryInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let entry = MdbEntry::parse(&MdbEntryBuffer::new_checked(payload)?)
+            .context("invalid MDBA_MDB_ENTRY_INFO value")?;
+        let mut nlas = vec![];
+        for nla in NlasIterator::new(&payload[MDB_ENTRY_LEN..]) {
+            let nla = nla.context("invalid MDBA_MDB_ENTRY_INFO value")?;
+            nlas.push(MdbEntryAttr::parse(&nla)?);
+        }
+        Ok(Self { entry, nlas })
+    }
+}
+
+/// Attributes of a MDB entry sent by the kernel (`MDBA_MDB_EATTR_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbEntryAttr {
+    /// Time left before the entry expires, in hundredths of second
+    Timer(u32),
+    /// Sources of the group, with IGMPv3 and MLDv2
+    SrcList(Vec<MdbSrcListEntry>),
+    /// `MCAST_INCLUDE` or `MCAST_EXCLUDE`
+    GroupMode(u8),
+    /// Source of a (S, G) entry
+    Source(IpAddr),
+    /// Protocol that installed the entry: one of the `RTPROT_*` constants
+    RtProt(u8),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for MdbEntryAttr {
+    fn value_len(&self) -> usize {
+        use self::MdbEntryAttr::*;
+        match self {
+            Timer(_) => 4,
+            SrcList(nlas) => nlas.as_slice().buffer_len(),
+            GroupMode(_) | RtProt(_) => 1,
+            Source(addr) => ip_len(addr),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MdbEntryAttr::*;
+        match self {
+            Timer(value) => NativeEndian::write_u32(buffer, *value),
+            SrcList(nlas) => nlas.as_slice().emit(buffer),
+            GroupMode(value) | RtProt(value) => buffer[0] = *value,
+            Source(addr) => emit_ip(buffer, addr),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MdbEntryAttr::*;
+        match self {
+            Timer(_) => MDBA_MDB_EATTR_TIMER,
+            SrcList(_) => MDBA_MDB_EATTR_SRC_LIST | NLA_F_NESTED,
+            GroupMode(_) => MDBA_MDB_EATTR_GROUP_MODE,
+            Source(_) => MDBA_MDB_EATTR_SOURCE,
+            RtProt(_) => MDBA_MDB_EATTR_RTPROT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbEntryAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MdbEntryAttr::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_EATTR_TIMER => {
+                Timer(parse_u32(payload).context("invalid MDBA_MDB_EATTR_TIMER value")?)
+            }
+            MDBA_MDB_EATTR_SRC_LIST => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid MDBA_MDB_EATTR_SRC_LIST value")?;
+                    nlas.push(MdbSrcListEntry::parse(&nla)?);
+                }
+                SrcList(nlas)
+            }
+            MDBA_MDB_EATTR_GROUP_MODE => {
+                GroupMode(parse_u8(payload).context("invalid MDBA_MDB_EATTR_GROUP_MODE value")?)
+            }
+            MDBA_MDB_EATTR_SOURCE => {
+                Source(parse_ip(payload).context("invalid MDBA_MDB_EATTR_SOURCE value")?)
+            }
+            MDBA_MDB_EATTR_RTPROT => {
+                RtProt(parse_u8(payload).context("invalid MDBA_MDB_EATTR_RTPROT value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// A source of a multicast group (`MDBA_MDB_SRCLIST_ENTRY` or
+/// `MDBE_SRC_LIST_ENTRY`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MdbSrcListEntry {
+    pub nlas: Vec<MdbSrcAttr>,
+}
+
+impl nlas::Nla for MdbSrcListEntry {
+    fn value_len(&self) -> usize {
+        self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.nlas.as_slice().emit(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        MDBA_MDB_SRCLIST_ENTRY | NLA_F_NESTED
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbSrcListEntry {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = nla.context("invalid MDBA_MDB_SRCLIST_ENTRY value")?;
+            nlas.push(MdbSrcAttr::parse(&nla)?);
+        }
+        Ok(Self { nlas })
+    }
+}
+
+/// Attributes of a source of a multicast group (`MDBA_MDB_SRCATTR_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbSrcAttr {
+    Address(IpAddr),
+    /// Time left before the source expires, in hundredths of second
+    Timer(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for MdbSrcAttr {
+    fn value_len(&self) -> usize {
+        use self::MdbSrcAttr::*;
+        match self {
+            Address(addr) => ip_len(addr),
+            Timer(_) => 4,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MdbSrcAttr::*;
+        match self {
+            Address(addr) => emit_ip(buffer, addr),
+            Timer(value) => NativeEndian::write_u32(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MdbSrcAttr::*;
+        match self {
+            Address(_) => MDBA_MDB_SRCATTR_ADDRESS,
+            Timer(_) => MDBA_MDB_SRCATTR_TIMER,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbSrcAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MdbSrcAttr::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_SRCATTR_ADDRESS => {
+                Address(parse_ip(payload).context("invalid MDBA_MDB_SRCATTR_ADDRESS value")?)
+            }
+            MDBA_MDB_SRCATTR_TIMER => {
+                Timer(parse_u32(payload).context("invalid MDBA_MDB_SRCATTR_TIMER value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
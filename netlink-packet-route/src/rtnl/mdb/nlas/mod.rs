@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MIT
+
+mod entry;
+pub use self::entry::*;
+
+mod entry_info;
+pub use self::entry_info::*;
+
+mod router;
+pub use self::router::*;
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    emit_ip,
+    ip_len,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_ip, parse_u8},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Multicast groups of the bridge, as sent by the kernel
+    Mdb(Vec<MdbNla>),
+    /// Multicast router ports of the bridge, as sent by the kernel
+    Router(Vec<RouterPort>),
+    /// Entry to add or remove, in requests
+    SetEntry(MdbEntry),
+    /// Additional attributes of the entry to add or remove, in requests
+    SetEntryAttrs(Vec<SetEntryAttr>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Mdb(nlas) => nlas.as_slice().buffer_len(),
+            Router(nlas) => nlas.as_slice().buffer_len(),
+            SetEntry(entry) => entry.buffer_len(),
+            SetEntryAttrs(nlas) => nlas.as_slice().buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Mdb(nlas) => nlas.as_slice().emit(buffer),
+            Router(nlas) => nlas.as_slice().emit(buffer),
+            SetEntry(entry) => entry.emit(buffer),
+            SetEntryAttrs(nlas) => nlas.as_slice().emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => MDBA_UNSPEC,
+            Mdb(_) => MDBA_MDB,
+            Router(_) => MDBA_ROUTER,
+            SetEntry(_) => MDBA_SET_ENTRY,
+            SetEntryAttrs(_) => MDBA_SET_ENTRY_ATTRS | NLA_F_NESTED,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+// Requests and kernel messages use the same attribute types with different
+// meanings. `request` tells whether a `MDBA_SET_ENTRY` attribute was found
+// before this one. `MDBA_SET_ENTRY` itself is recognized by its size, since
+// a `MDBA_MDB` attribute holding an entry is always bigger.
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, bool> for Nla {
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, request: bool) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_UNSPEC => Unspec(payload.to_vec()),
+            MDBA_SET_ENTRY if payload.len() == MDB_ENTRY_LEN => SetEntry(
+                MdbEntry::parse(&MdbEntryBuffer::new(payload))
+                    .context("invalid MDBA_SET_ENTRY value")?,
+            ),
+            MDBA_MDB => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid MDBA_MDB value")?;
+                    nlas.push(MdbNla::parse(&nla)?);
+                }
+                Mdb(nlas)
+            }
+            MDBA_SET_ENTRY_ATTRS if request => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid MDBA_SET_ENTRY_ATTRS value")?;
+                    nlas.push(SetEntryAttr::parse(&nla)?);
+                }
+                SetEntryAttrs(nlas)
+            }
+            MDBA_ROUTER => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid MDBA_ROUTER value")?;
+                    nlas.push(RouterPort::parse(&nla)?);
+                }
+                Router(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes found in `MDBA_MDB`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbNla {
+    /// Bridge ports that are members of a multicast group
+    Entry(Vec<MdbEntryInfo>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for MdbNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => MDBA_MDB_ENTRY,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            MDBA_MDB_ENTRY => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(buf.value()) {
+                    let nla = nla.context("invalid MDBA_MDB_ENTRY value")?;
+                    nlas.push(MdbEntryInfo::parse(&nla)?);
+                }
+                Self::Entry(nlas)
+            }
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
+
+/// Additional attributes of an entry to add or remove (`MDBE_ATTR_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SetEntryAttr {
+    /// Source of a (S, G) entry
+    Source(IpAddr),
+    /// Sources of a (*, G) entry. The `MdbSrcListEntry` attributes must only
+    /// hold addresses.
+    SrcList(Vec<MdbSrcListEntry>),
+    /// `MCAST_INCLUDE` or `MCAST_EXCLUDE`
+    GroupMode(u8),
+    /// Protocol installing the entry: one of the `RTPROT_*` constants
+    RtProt(u8),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for SetEntryAttr {
+    fn value_len(&self) -> usize {
+        use self::SetEntryAttr::*;
+        match self {
+            Source(addr) => ip_len(addr),
+            SrcList(nlas) => nlas.as_slice().buffer_len(),
+            GroupMode(_) | RtProt(_) => 1,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::SetEntryAttr::*;
+        match self {
+            Source(addr) => emit_ip(buffer, addr),
+            SrcList(nlas) => nlas.as_slice().emit(buffer),
+            GroupMode(value) | RtProt(value) => buffer[0] = *value,
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::SetEntryAttr::*;
+        match self {
+            Source(_) => MDBE_ATTR_SOURCE,
+            SrcList(_) => MDBE_ATTR_SRC_LIST | NLA_F_NESTED,
+            GroupMode(_) => MDBE_ATTR_GROUP_MODE,
+            RtProt(_) => MDBE_ATTR_RTPROT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for SetEntryAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::SetEntryAttr::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBE_ATTR_SOURCE => {
+                Source(parse_ip(payload).context("invalid MDBE_ATTR_SOURCE value")?)
+            }
+            MDBE_ATTR_SRC_LIST => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid MDBE_ATTR_SRC_LIST value")?;
+                    nlas.push(MdbSrcListEntry::parse(&nla)?);
+                }
+                SrcList(nlas)
+            }
+            MDBE_ATTR_GROUP_MODE => {
+                GroupMode(parse_u8(payload).context("invalid MDBE_ATTR_GROUP_MODE value")?)
+            }
+            MDBE_ATTR_RTPROT => {
+                RtProt(parse_u8(payload).context("invalid MDBE_ATTR_RTPROT value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// A multicast router port of a bridge (`MDBA_ROUTER_PORT`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RouterPort {
+    /// Index of the bridge port
+    pub ifindex: u32,
+    pub nlas: Vec<RouterPortAttr>,
+}
+
+impl nlas::Nla for RouterPort {
+    fn value_len(&self) -> usize {
+        4 + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(buffer, self.ifindex);
+        self.nlas.as_slice().emit(&mut buffer[4..]);
+    }
+
+    fn kind(&self) -> u16 {
+        MDBA_ROUTER_PORT
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RouterPort {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        if payload.len() < 4 {
+            return Err(format!("invalid MDBA_ROUTER_PORT value: {:?}", payload).into());
+        }
+        let mut nlas = vec![];
+        for nla in NlasIterator::new(&payload[4..]) {
+            let nla = nla.context("invalid MDBA_ROUTER_PORT value")?;
+            nlas.push(RouterPortAttr::parse(&nla)?);
+        }
+        Ok(Self {
+            ifindex: NativeEndian::read_u32(payload),
+            nlas,
+        })
+    }
+}
+
+/// Attributes of a multicast router port (`MDBA_ROUTER_PATTR_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RouterPortAttr {
+    /// Time left before the port stops being a router port, in hundredths
+    /// of second
+    Timer(u32),
+    /// One of the `MDB_RTR_TYPE_*` constants
+    Type(u8),
+    InetTimer(u32),
+    Inet6Timer(u32),
+    Vid(u16),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for RouterPortAttr {
+    fn value_len(&self) -> usize {
+        use self::RouterPortAttr::*;
+        match self {
+            Timer(_) | InetTimer(_) | Inet6Timer(_) => 4,
+            Type(_) => 1,
+            Vid(_) => 2,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::RouterPortAttr::*;
+        match self {
+            Timer(value) | InetTimer(value) | Inet6Timer(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Type(value) => buffer[0] = *value,
+            Vid(value) => NativeEndian::write_u16(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::RouterPortAttr::*;
+        match self {
+            Timer(_) => MDBA_ROUTER_PATTR_TIMER,
+            Type(_) => MDBA_ROUTER_PATTR_TYPE,
+            InetTimer(_) => MDBA_ROUTER_PATTR_INET_TIMER,
+            Inet6Timer(_) => MDBA_ROUTER_PATTR_INET6_TIMER,
+            Vid(_) => MDBA_ROUTER_PATTR_VID,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RouterPortAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::RouterPortAttr::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_ROUTER_PATTR_TIMER => {
+                Timer(parse_u32(payload).context("invalid MDBA_ROUTER_PATTR_TIMER value")?)
+            }
+            MDBA_ROUTER_PATTR_TYPE => {
+                Type(parse_u8(payload).context("invalid MDBA_ROUTER_PATTR_TYPE value")?)
+            }
+            MDBA_ROUTER_PATTR_INET_TIMER => {
+                InetTimer(parse_u32(payload).context("invalid MDBA_ROUTER_PATTR_INET_TIMER value")?)
+            }
+            MDBA_ROUTER_PATTR_INET6_TIMER => Inet6Timer(
+                parse_u32(payload).context("invalid MDBA_ROUTER_PATTR_INET6_TIMER value")?,
+            ),
+            MDBA_ROUTER_PATTR_VID => {
+                Vid(parse_u16(payload).context("invalid MDBA_ROUTER_PATTR_VID value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
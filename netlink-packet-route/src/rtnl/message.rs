@@ -3,19 +3,25 @@
 use crate::{
     constants::*,
     traits::{Emitable, ParseableParametrized},
+    AddressLabelMessage,
     AddressMessage,
     DecodeError,
     LinkMessage,
+    MdbMessage,
     NeighbourMessage,
     NeighbourTableMessage,
+    NetconfMessage,
     NetlinkDeserializable,
     NetlinkHeader,
     NetlinkPayload,
     NetlinkSerializable,
+    NexthopMessage,
     NsidMessage,
     RouteMessage,
     RtnlMessageBuffer,
     RuleMessage,
+    StatsMessage,
+    TcActionMessage,
     TcMessage,
 };
 
@@ -51,12 +57,29 @@ pub enum RtnlMessage {
     NewTrafficChain(TcMessage),
     DelTrafficChain(TcMessage),
     GetTrafficChain(TcMessage),
+    NewTrafficAction(TcActionMessage),
+    DelTrafficAction(TcActionMessage),
+    GetTrafficAction(TcActionMessage),
     NewNsId(NsidMessage),
     DelNsId(NsidMessage),
     GetNsId(NsidMessage),
     NewRule(RuleMessage),
     DelRule(RuleMessage),
     GetRule(RuleMessage),
+    NewNexthop(NexthopMessage),
+    DelNexthop(NexthopMessage),
+    GetNexthop(NexthopMessage),
+    NewStats(StatsMessage),
+    GetStats(StatsMessage),
+    NewMdb(MdbMessage),
+    DelMdb(MdbMessage),
+    GetMdb(MdbMessage),
+    NewNetconf(NetconfMessage),
+    DelNetconf(NetconfMessage),
+    GetNetconf(NetconfMessage),
+    NewAddressLabel(AddressLabelMessage),
+    DelAddressLabel(AddressLabelMessage),
+    GetAddressLabel(AddressLabelMessage),
 }
 
 impl RtnlMessage {
@@ -172,6 +195,18 @@ impl RtnlMessage {
         matches!(self, RtnlMessage::GetTrafficChain(_))
     }
 
+    pub fn is_new_action(&self) -> bool {
+        matches!(self, RtnlMessage::NewTrafficAction(_))
+    }
+
+    pub fn is_del_action(&self) -> bool {
+        matches!(self, RtnlMessage::DelTrafficAction(_))
+    }
+
+    pub fn is_get_action(&self) -> bool {
+        matches!(self, RtnlMessage::GetTrafficAction(_))
+    }
+
     pub fn is_new_nsid(&self) -> bool {
         matches!(self, RtnlMessage::NewNsId(_))
     }
@@ -196,6 +231,62 @@ impl RtnlMessage {
         matches!(self, RtnlMessage::DelRule(_))
     }
 
+    pub fn is_new_nexthop(&self) -> bool {
+        matches!(self, RtnlMessage::NewNexthop(_))
+    }
+
+    pub fn is_del_nexthop(&self) -> bool {
+        matches!(self, RtnlMessage::DelNexthop(_))
+    }
+
+    pub fn is_get_nexthop(&self) -> bool {
+        matches!(self, RtnlMessage::GetNexthop(_))
+    }
+
+    pub fn is_new_stats(&self) -> bool {
+        matches!(self, RtnlMessage::NewStats(_))
+    }
+
+    pub fn is_get_stats(&self) -> bool {
+        matches!(self, RtnlMessage::GetStats(_))
+    }
+
+    pub fn is_new_mdb(&self) -> bool {
+        matches!(self, RtnlMessage::NewMdb(_))
+    }
+
+    pub fn is_del_mdb(&self) -> bool {
+        matches!(self, RtnlMessage::DelMdb(_))
+    }
+
+    pub fn is_get_mdb(&self) -> bool {
+        matches!(self, RtnlMessage::GetMdb(_))
+    }
+
+    pub fn is_new_netconf(&self) -> bool {
+        matches!(self, RtnlMessage::NewNetconf(_))
+    }
+
+    pub fn is_del_netconf(&self) -> bool {
+        matches!(self, RtnlMessage::DelNetconf(_))
+    }
+
+    pub fn is_get_netconf(&self) -> bool {
+        matches!(self, RtnlMessage::GetNetconf(_))
+    }
+
+    pub fn is_new_address_label(&self) -> bool {
+        matches!(self, RtnlMessage::NewAddressLabel(_))
+    }
+
+    pub fn is_del_address_label(&self) -> bool {
+        matches!(self, RtnlMessage::DelAddressLabel(_))
+    }
+
+    pub fn is_get_address_label(&self) -> bool {
+        matches!(self, RtnlMessage::GetAddressLabel(_))
+    }
+
     pub fn message_type(&self) -> u16 {
         use self::RtnlMessage::*;
 
@@ -230,12 +321,29 @@ impl RtnlMessage {
             NewTrafficChain(_) => RTM_NEWCHAIN,
             DelTrafficChain(_) => RTM_DELCHAIN,
             GetTrafficChain(_) => RTM_GETCHAIN,
+            NewTrafficAction(_) => RTM_NEWACTION,
+            DelTrafficAction(_) => RTM_DELACTION,
+            GetTrafficAction(_) => RTM_GETACTION,
             GetNsId(_) => RTM_GETNSID,
             NewNsId(_) => RTM_NEWNSID,
             DelNsId(_) => RTM_DELNSID,
             GetRule(_) => RTM_GETRULE,
             NewRule(_) => RTM_NEWRULE,
             DelRule(_) => RTM_DELRULE,
+            NewNexthop(_) => RTM_NEWNEXTHOP,
+            DelNexthop(_) => RTM_DELNEXTHOP,
+            GetNexthop(_) => RTM_GETNEXTHOP,
+            NewStats(_) => RTM_NEWSTATS,
+            GetStats(_) => RTM_GETSTATS,
+            NewMdb(_) => RTM_NEWMDB,
+            DelMdb(_) => RTM_DELMDB,
+            GetMdb(_) => RTM_GETMDB,
+            NewNetconf(_) => RTM_NEWNETCONF,
+            DelNetconf(_) => RTM_DELNETCONF,
+            GetNetconf(_) => RTM_GETNETCONF,
+            NewAddressLabel(_) => RTM_NEWADDRLABEL,
+            DelAddressLabel(_) => RTM_DELADDRLABEL,
+            GetAddressLabel(_) => RTM_GETADDRLABEL,
         }
     }
 }
@@ -287,6 +395,11 @@ impl Emitable for RtnlMessage {
             | GetTrafficChain(ref msg)
             => msg.buffer_len(),
 
+            | NewTrafficAction(ref msg)
+            | DelTrafficAction(ref msg)
+            | GetTrafficAction(ref msg)
+            => msg.buffer_len(),
+
             | NewNsId(ref msg)
             | DelNsId(ref msg)
             | GetNsId(ref msg)
@@ -295,6 +408,30 @@ impl Emitable for RtnlMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
+            => msg.buffer_len(),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            => msg.buffer_len(),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.buffer_len(),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.buffer_len(),
+
+            | NewNetconf(ref msg)
+            | DelNetconf(ref msg)
+            | GetNetconf(ref msg)
+            => msg.buffer_len(),
+
+            | NewAddressLabel(ref msg)
+            | DelAddressLabel(ref msg)
+            | GetAddressLabel(ref msg)
             => msg.buffer_len()
         }
     }
@@ -345,6 +482,11 @@ impl Emitable for RtnlMessage {
             | GetTrafficChain(ref msg)
             => msg.emit(buffer),
 
+            | NewTrafficAction(ref msg)
+            | DelTrafficAction(ref msg)
+            | GetTrafficAction(ref msg)
+            => msg.emit(buffer),
+
             | NewNsId(ref msg)
             | DelNsId(ref msg)
             | GetNsId(ref msg)
@@ -353,6 +495,30 @@ impl Emitable for RtnlMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
+            => msg.emit(buffer),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            => msg.emit(buffer),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.emit(buffer),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.emit(buffer),
+
+            | NewNetconf(ref msg)
+            | DelNetconf(ref msg)
+            | GetNetconf(ref msg)
+            => msg.emit(buffer),
+
+            | NewAddressLabel(ref msg)
+            | DelAddressLabel(ref msg)
+            | GetAddressLabel(ref msg)
             => msg.emit(buffer)
         }
     }
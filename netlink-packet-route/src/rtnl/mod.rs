@@ -3,9 +3,20 @@
 pub mod address;
 pub use address::{AddressHeader, AddressMessage, AddressMessageBuffer, ADDRESS_HEADER_LEN};
 
+pub mod address_label;
+pub use address_label::{
+    AddressLabelHeader,
+    AddressLabelMessage,
+    AddressLabelMessageBuffer,
+    ADDRESS_LABEL_HEADER_LEN,
+};
+
 pub mod link;
 pub use link::{LinkHeader, LinkMessage, LinkMessageBuffer, LINK_HEADER_LEN};
 
+pub mod mdb;
+pub use mdb::{MdbHeader, MdbMessage, MdbMessageBuffer, MDB_HEADER_LEN};
+
 pub mod neighbour;
 pub use neighbour::{
     NeighbourHeader,
@@ -22,6 +33,12 @@ pub use neighbour_table::{
     NEIGHBOUR_TABLE_HEADER_LEN,
 };
 
+pub mod netconf;
+pub use netconf::{NetconfHeader, NetconfMessage, NetconfMessageBuffer, NETCONF_HEADER_LEN};
+
+pub mod nexthop;
+pub use nexthop::{NexthopHeader, NexthopMessage, NexthopMessageBuffer, NEXTHOP_HEADER_LEN};
+
 pub mod nsid;
 pub use nsid::{NsidHeader, NsidMessage, NsidMessageBuffer, NSID_HEADER_LEN};
 
@@ -31,9 +48,15 @@ pub use route::{RouteFlags, RouteHeader, RouteMessage, RouteMessageBuffer, ROUTE
 pub mod rule;
 pub use rule::{RuleHeader, RuleMessage, RuleMessageBuffer, RULE_HEADER_LEN};
 
+pub mod stats;
+pub use stats::{StatsHeader, StatsMessage, StatsMessageBuffer, STATS_HEADER_LEN};
+
 pub mod tc;
 pub use tc::{TcHeader, TcMessage, TcMessageBuffer, TC_HEADER_LEN};
 
+pub mod tc_action;
+pub use tc_action::{TcActionHeader, TcActionMessage, TcActionMessageBuffer, TC_ACTION_HEADER_LEN};
+
 pub mod constants;
 pub use self::constants::*;
 
@@ -46,13 +69,19 @@ pub use self::message::*;
 pub mod nlas {
     pub use super::{
         address::nlas as address,
+        address_label::nlas as address_label,
         link::nlas as link,
+        mdb::nlas as mdb,
         neighbour::nlas as neighbour,
         neighbour_table::nlas as neighbour_table,
+        netconf::nlas as netconf,
+        nexthop::nlas as nexthop,
         nsid::nlas as nsid,
         route::nlas as route,
         rule::nlas as rule,
+        stats::nlas as stats,
         tc::nlas as tc,
+        tc_action::nlas as tc_action,
     };
     pub use crate::utils::nla::*;
 }
@@ -53,10 +53,14 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<NeighbourMessageBuffer<&'a T>> for Vec<N
 mod test {
     use crate::{
         constants::*,
-        traits::Emitable,
+        nlas::neighbour::{CacheInfo, FdbExtAttr, Nla},
+        traits::{Emitable, ParseableParametrized},
         NeighbourHeader,
         NeighbourMessage,
         NeighbourMessageBuffer,
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
     };
 
     // 0020   0a 00 00 00 02 00 00 00 02 00 80 01 14 00 01 00
@@ -119,4 +123,131 @@ mod test {
         assert_eq!(packet.buffer_len(), 12);
         packet.emit(&mut buf[..]);
     }
+
+    #[rustfmt::skip]
+    #[test]
+    fn fdb_vxlan_entry() {
+        let data = vec![
+            0x64, 0x00, 0x00, 0x00, // length = 100
+            0x1c, 0x00, // message type = 28 = RTM_NEWNEIGH
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // ndmsg
+            0x07, // family = AF_BRIDGE
+            0x00, 0x00, 0x00, // padding
+            0x05, 0x00, 0x00, 0x00, // interface index = 5
+            0x80, 0x00, // state = NUD_PERMANENT
+            0x02, // flags = NTF_SELF
+            0x00, // ntype
+
+            // NLAs
+            0x0a, 0x00, 0x02, 0x00, // length = 10, type = NDA_LLADDR
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x00, 0x00,
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = NDA_DST
+            0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+            0x06, 0x00, 0x06, 0x00, // length = 6, type = NDA_PORT
+            0x12, 0xb5, 0x00, 0x00, // 4789 (big endian) + padding
+            0x08, 0x00, 0x07, 0x00, // length = 8, type = NDA_VNI
+            0x64, 0x00, 0x00, 0x00, // 100
+            0x08, 0x00, 0x08, 0x00, // length = 8, type = NDA_IFINDEX
+            0x02, 0x00, 0x00, 0x00, // 2
+            0x14, 0x00, 0x03, 0x00, // length = 20, type = NDA_CACHEINFO
+            0x01, 0x00, 0x00, 0x00, // confirmed = 1
+            0x02, 0x00, 0x00, 0x00, // used = 2
+            0x03, 0x00, 0x00, 0x00, // updated = 3
+            0x00, 0x00, 0x00, 0x00, // refcnt = 0
+            0x05, 0x00, 0x0c, 0x00, // length = 5, type = NDA_PROTOCOL
+            0x04, 0x00, 0x00, 0x00, // RTPROT_STATIC + padding
+        ];
+        let expected = RtnlMessage::NewNeighbour(NeighbourMessage {
+            header: NeighbourHeader {
+                family: AF_BRIDGE as u8,
+                ifindex: 5,
+                state: NUD_PERMANENT,
+                flags: NTF_SELF,
+                ntype: 0,
+            },
+            nlas: vec![
+                Nla::LinkLocalAddress(vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+                Nla::Destination(vec![192, 0, 2, 1]),
+                Nla::Port(4789),
+                Nla::Vni(100),
+                Nla::IfIndex(2),
+                Nla::CacheInfo(CacheInfo {
+                    confirmed: 1,
+                    used: 2,
+                    updated: 3,
+                    refcnt: 0,
+                }),
+                Nla::Protocol(RTPROT_STATIC),
+            ],
+        });
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWNEIGH).unwrap();
+        assert_eq!(expected, actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn fdb_bridge_entry() {
+        let data = vec![
+            0x4c, 0x00, 0x00, 0x00, // length = 76
+            0x1c, 0x00, // message type = 28 = RTM_NEWNEIGH
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // ndmsg
+            0x07, // family = AF_BRIDGE
+            0x00, 0x00, 0x00, // padding
+            0x04, 0x00, 0x00, 0x00, // interface index = 4
+            0x02, 0x00, // state = NUD_REACHABLE
+            0x14, // flags = NTF_MASTER | NTF_EXT_LEARNED
+            0x00, // ntype
+
+            // NLAs
+            0x0a, 0x00, 0x02, 0x00, // length = 10, type = NDA_LLADDR
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x00, 0x00,
+            0x06, 0x00, 0x05, 0x00, // length = 6, type = NDA_VLAN
+            0x0a, 0x00, 0x00, 0x00, // 10 + padding
+            0x08, 0x00, 0x09, 0x00, // length = 8, type = NDA_MASTER
+            0x03, 0x00, 0x00, 0x00, // 3
+            0x0c, 0x00, 0x0e, 0x80, // length = 12, type = NDA_FDB_EXT_ATTRS | NLA_F_NESTED
+            0x05, 0x00, 0x01, 0x00, // length = 5, type = NFEA_ACTIVITY_NOTIFY
+            0x01, 0x00, 0x00, 0x00, // FDB_NOTIFY_BIT + padding
+            0x08, 0x00, 0x0f, 0x00, // length = 8, type = NDA_FLAGS_EXT
+            0x02, 0x00, 0x00, 0x00, // NTF_EXT_LOCKED
+        ];
+        let expected = RtnlMessage::NewNeighbour(NeighbourMessage {
+            header: NeighbourHeader {
+                family: AF_BRIDGE as u8,
+                ifindex: 4,
+                state: NUD_REACHABLE,
+                flags: NTF_MASTER | NTF_EXT_LEARNED,
+                ntype: 0,
+            },
+            nlas: vec![
+                Nla::LinkLocalAddress(vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+                Nla::Vlan(10),
+                Nla::Master(3),
+                Nla::FdbExtAttrs(vec![FdbExtAttr::ActivityNotify(FDB_NOTIFY_BIT)]),
+                Nla::FlagsExt(NTF_EXT_LOCKED),
+            ],
+        });
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWNEIGH).unwrap();
+        assert_eq!(expected, actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
 }
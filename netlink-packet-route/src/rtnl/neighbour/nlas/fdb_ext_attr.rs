@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::parse_u8,
+    traits::Parseable,
+    DecodeError,
+};
+
+/// Attributes found in `NDA_FDB_EXT_ATTRS`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FdbExtAttr {
+    /// Activity notification state of the entry: a combination of
+    /// `FDB_NOTIFY_BIT` and `FDB_NOTIFY_INACTIVE_BIT`
+    ActivityNotify(u8),
+    /// Do not refresh the entry when this request changes it
+    DontRefresh,
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for FdbExtAttr {
+    fn value_len(&self) -> usize {
+        use self::FdbExtAttr::*;
+        match *self {
+            ActivityNotify(_) => 1,
+            DontRefresh => 0,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::FdbExtAttr::*;
+        match *self {
+            ActivityNotify(value) => buffer[0] = value,
+            DontRefresh => {}
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::FdbExtAttr::*;
+        match *self {
+            ActivityNotify(_) => NFEA_ACTIVITY_NOTIFY,
+            DontRefresh => NFEA_DONT_REFRESH,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for FdbExtAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::FdbExtAttr::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NFEA_ACTIVITY_NOTIFY => {
+                ActivityNotify(parse_u8(payload).context("invalid NFEA_ACTIVITY_NOTIFY value")?)
+            }
+            NFEA_DONT_REFRESH => DontRefresh,
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -3,14 +3,17 @@
 mod cache_info;
 pub use self::cache_info::*;
 
+mod fdb_ext_attr;
+pub use self::fdb_ext_attr::*;
+
 use anyhow::Context;
-use byteorder::{ByteOrder, NativeEndian};
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 
 use crate::{
     constants::*,
-    nlas::{self, DefaultNla, NlaBuffer},
-    parsers::{parse_u16, parse_u32},
-    traits::Parseable,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_i32, parse_u16, parse_u16_be, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
     DecodeError,
 };
 
@@ -19,15 +22,26 @@ pub enum Nla {
     Unspec(Vec<u8>),
     Destination(Vec<u8>),
     LinkLocalAddress(Vec<u8>),
-    CacheInfo(Vec<u8>),
-    Probes(Vec<u8>),
+    CacheInfo(CacheInfo),
+    Probes(u32),
     Vlan(u16),
-    Port(Vec<u8>),
+    /// Destination UDP port of a VXLAN FDB entry
+    Port(u16),
     Vni(u32),
     IfIndex(u32),
-    Master(Vec<u8>),
-    LinkNetNsId(Vec<u8>),
+    /// Index of the master device, e.g. the bridge of a FDB entry
+    Master(u32),
+    LinkNetNsId(i32),
     SourceVni(u32),
+    /// Protocol that installed the entry: one of the `RTPROT_*` constants
+    Protocol(u8),
+    /// Nexthop object (or group) of a VXLAN FDB entry
+    NhId(u32),
+    FdbExtAttrs(Vec<FdbExtAttr>),
+    /// Extended flags: a combination of the `NTF_EXT_*` constants
+    FlagsExt(u32),
+    NdmStateMask(u16),
+    NdmFlagsMask(u8),
     Other(DefaultNla),
 }
 
@@ -38,16 +52,22 @@ impl nlas::Nla for Nla {
         match *self {
             Unspec(ref bytes)
             | Destination(ref bytes)
-            | LinkLocalAddress(ref bytes)
-            | Probes(ref bytes)
-            | Port(ref bytes)
-            | Master(ref bytes)
-            | CacheInfo(ref bytes)
-            | LinkNetNsId(ref bytes) => bytes.len(),
-            Vlan(_) => 2,
-            Vni(_)
+            | LinkLocalAddress(ref bytes) => bytes.len(),
+            CacheInfo(ref info) => info.buffer_len(),
+            FdbExtAttrs(ref nlas) => nlas.as_slice().buffer_len(),
+            Protocol(_)
+            | NdmFlagsMask(_) => 1,
+            Vlan(_)
+            | Port(_)
+            | NdmStateMask(_) => 2,
+            Probes(_)
+            | Vni(_)
             | IfIndex(_)
-            | SourceVni(_) => 4,
+            | Master(_)
+            | LinkNetNsId(_)
+            | SourceVni(_)
+            | NhId(_)
+            | FlagsExt(_) => 4,
             Other(ref attr) => attr.value_len(),
         }
     }
@@ -58,16 +78,22 @@ impl nlas::Nla for Nla {
         match *self {
             Unspec(ref bytes)
             | Destination(ref bytes)
-            | LinkLocalAddress(ref bytes)
-            | Probes(ref bytes)
-            | Port(ref bytes)
-            | Master(ref bytes)
-            | CacheInfo(ref bytes)
-            | LinkNetNsId(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
-            Vlan(ref value) => NativeEndian::write_u16(buffer, *value),
-            Vni(ref value)
+            | LinkLocalAddress(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            CacheInfo(ref info) => info.emit(buffer),
+            FdbExtAttrs(ref nlas) => nlas.as_slice().emit(buffer),
+            Protocol(ref value)
+            | NdmFlagsMask(ref value) => buffer[0] = *value,
+            Vlan(ref value)
+            | NdmStateMask(ref value) => NativeEndian::write_u16(buffer, *value),
+            Port(ref value) => BigEndian::write_u16(buffer, *value),
+            Probes(ref value)
+            | Vni(ref value)
             | IfIndex(ref value)
-            | SourceVni(ref value) => NativeEndian::write_u32(buffer, *value),
+            | Master(ref value)
+            | SourceVni(ref value)
+            | NhId(ref value)
+            | FlagsExt(ref value) => NativeEndian::write_u32(buffer, *value),
+            LinkNetNsId(ref value) => NativeEndian::write_i32(buffer, *value),
             Other(ref attr) => attr.emit_value(buffer),
         }
     }
@@ -87,6 +113,12 @@ impl nlas::Nla for Nla {
             Master(_) => NDA_MASTER,
             LinkNetNsId(_) => NDA_LINK_NETNSID,
             SourceVni(_) => NDA_SRC_VNI,
+            Protocol(_) => NDA_PROTOCOL,
+            NhId(_) => NDA_NH_ID,
+            FdbExtAttrs(_) => NDA_FDB_EXT_ATTRS | NLA_F_NESTED,
+            FlagsExt(_) => NDA_FLAGS_EXT,
+            NdmStateMask(_) => NDA_NDM_STATE_MASK,
+            NdmFlagsMask(_) => NDA_NDM_FLAGS_MASK,
             Other(ref nla) => nla.kind(),
         }
     }
@@ -101,15 +133,37 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             NDA_UNSPEC => Unspec(payload.to_vec()),
             NDA_DST => Destination(payload.to_vec()),
             NDA_LLADDR => LinkLocalAddress(payload.to_vec()),
-            NDA_CACHEINFO => CacheInfo(payload.to_vec()),
-            NDA_PROBES => Probes(payload.to_vec()),
+            NDA_CACHEINFO => CacheInfo(
+                super::CacheInfo::parse(&CacheInfoBuffer::new_checked(payload)?)
+                    .context("invalid NDA_CACHEINFO value")?,
+            ),
+            NDA_PROBES => Probes(parse_u32(payload).context("invalid NDA_PROBES value")?),
             NDA_VLAN => Vlan(parse_u16(payload)?),
-            NDA_PORT => Port(payload.to_vec()),
+            NDA_PORT => Port(parse_u16_be(payload).context("invalid NDA_PORT value")?),
             NDA_VNI => Vni(parse_u32(payload)?),
             NDA_IFINDEX => IfIndex(parse_u32(payload)?),
-            NDA_MASTER => Master(payload.to_vec()),
-            NDA_LINK_NETNSID => LinkNetNsId(payload.to_vec()),
+            NDA_MASTER => Master(parse_u32(payload).context("invalid NDA_MASTER value")?),
+            NDA_LINK_NETNSID => {
+                LinkNetNsId(parse_i32(payload).context("invalid NDA_LINK_NETNSID value")?)
+            }
             NDA_SRC_VNI => SourceVni(parse_u32(payload)?),
+            NDA_PROTOCOL => Protocol(parse_u8(payload).context("invalid NDA_PROTOCOL value")?),
+            NDA_NH_ID => NhId(parse_u32(payload).context("invalid NDA_NH_ID value")?),
+            NDA_FDB_EXT_ATTRS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid NDA_FDB_EXT_ATTRS value")?;
+                    nlas.push(FdbExtAttr::parse(&nla)?);
+                }
+                FdbExtAttrs(nlas)
+            }
+            NDA_FLAGS_EXT => FlagsExt(parse_u32(payload).context("invalid NDA_FLAGS_EXT value")?),
+            NDA_NDM_STATE_MASK => {
+                NdmStateMask(parse_u16(payload).context("invalid NDA_NDM_STATE_MASK value")?)
+            }
+            NDA_NDM_FLAGS_MASK => {
+                NdmFlagsMask(parse_u8(payload).context("invalid NDA_NDM_FLAGS_MASK value")?)
+            }
             _ => Other(DefaultNla::parse(buf).context("invalid link NLA value (unknown type)")?),
         })
     }
@@ -9,7 +9,7 @@ use crate::{
 };
 use anyhow::Context;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct NeighbourTableMessage {
     pub header: NeighbourTableHeader,
     pub nlas: Vec<Nla>,
@@ -22,7 +22,9 @@ impl Emitable for NeighbourTableMessage {
 
     fn emit(&self, buffer: &mut [u8]) {
         self.header.emit(buffer);
-        self.nlas.as_slice().emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
     }
 }
 
@@ -47,3 +49,79 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<NeighbourTableMessageBuffer<&'a T>> for
         Ok(nlas)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        constants::*,
+        nlas::neighbour_table::{NeighbourTableParameter, Nla},
+        traits::{Emitable, ParseableParametrized},
+        NeighbourTableHeader,
+        NeighbourTableMessage,
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
+    };
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_neighbour_table() {
+        let data = vec![
+            0x6c, 0x00, 0x00, 0x00, // length = 108
+            0x40, 0x00, // message type = 64 = RTM_NEWNEIGHTBL
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // ndtmsg
+            0x02, // family = AF_INET
+            0x00, 0x00, 0x00, // padding
+
+            // NLAs
+            0x0e, 0x00, 0x01, 0x00, // length = 14, type = NDTA_NAME
+            0x61, 0x72, 0x70, 0x5f, // "arp_"
+            0x63, 0x61, 0x63, 0x68, // "cach"
+            0x65, 0x00, 0x00, 0x00, // "e\0" + padding
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = NDTA_THRESH1
+            0x80, 0x00, 0x00, 0x00, // 128
+            0x0c, 0x00, 0x08, 0x00, // length = 12, type = NDTA_GC_INTERVAL
+            0x30, 0x75, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 30000
+            0x34, 0x00, 0x06, 0x00, // length = 52, type = NDTA_PARMS
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = NDTPA_REFCNT
+            0x01, 0x00, 0x00, 0x00, // 1
+            0x0c, 0x00, 0x04, 0x00, // length = 12, type = NDTPA_BASE_REACHABLE_TIME
+            0x30, 0x75, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 30000
+            0x0c, 0x00, 0x06, 0x00, // length = 12, type = NDTPA_GC_STALETIME
+            0x60, 0xea, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 60000
+            0x08, 0x00, 0x10, 0x00, // length = 8, type = NDTPA_QUEUE_LENBYTES
+            0x00, 0x40, 0x03, 0x00, // 212992
+            0x08, 0x00, 0x0a, 0x00, // length = 8, type = NDTPA_UCAST_PROBES
+            0x03, 0x00, 0x00, 0x00, // 3
+        ];
+        let expected = RtnlMessage::NewNeighbourTable(NeighbourTableMessage {
+            header: NeighbourTableHeader {
+                family: AF_INET as u8,
+            },
+            nlas: vec![
+                Nla::Name("arp_cache".to_string()),
+                Nla::Threshold1(128),
+                Nla::GcInterval(30000),
+                Nla::Parms(vec![
+                    NeighbourTableParameter::RefCount(1),
+                    NeighbourTableParameter::BaseReachableTime(30000),
+                    NeighbourTableParameter::GcStaleTime(60000),
+                    NeighbourTableParameter::QueueLenBytes(212992),
+                    NeighbourTableParameter::UcastProbes(3),
+                ]),
+            ],
+        });
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWNEIGHTBL).unwrap();
+        assert_eq!(expected, actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+}
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// Parameters of a neighbour table, or of the neighbour table of an interface (`NDTPA_*`).
+/// Times are in milliseconds.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum NeighbourTableParameter {
+    /// Interface these parameters apply to. Missing for the default parameters of the table.
+    IfIndex(u32),
+    RefCount(u32),
+    /// Current reachable time, randomly chosen around the base reachable time. Read only.
+    ReachableTime(u64),
+    BaseReachableTime(u64),
+    RetransTime(u64),
+    GcStaleTime(u64),
+    DelayProbeTime(u64),
+    /// Maximum number of packets queued for an unresolved neighbour. Obsoleted by
+    /// `QueueLenBytes` on the kernel side.
+    QueueLen(u32),
+    AppProbes(u32),
+    UcastProbes(u32),
+    McastProbes(u32),
+    AnycastDelay(u64),
+    ProxyDelay(u64),
+    ProxyQueueLen(u32),
+    LockTime(u64),
+    QueueLenBytes(u32),
+    McastReprobes(u32),
+    IntervalProbeTime(u64),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for NeighbourTableParameter {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::NeighbourTableParameter::*;
+        match *self {
+            IfIndex(_)
+            | RefCount(_)
+            | QueueLen(_)
+            | AppProbes(_)
+            | UcastProbes(_)
+            | McastProbes(_)
+            | ProxyQueueLen(_)
+            | QueueLenBytes(_)
+            | McastReprobes(_) => 4,
+            ReachableTime(_)
+            | BaseReachableTime(_)
+            | RetransTime(_)
+            | GcStaleTime(_)
+            | DelayProbeTime(_)
+            | AnycastDelay(_)
+            | ProxyDelay(_)
+            | LockTime(_)
+            | IntervalProbeTime(_) => 8,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::NeighbourTableParameter::*;
+        match *self {
+            IfIndex(ref value)
+            | RefCount(ref value)
+            | QueueLen(ref value)
+            | AppProbes(ref value)
+            | UcastProbes(ref value)
+            | McastProbes(ref value)
+            | ProxyQueueLen(ref value)
+            | QueueLenBytes(ref value)
+            | McastReprobes(ref value) => NativeEndian::write_u32(buffer, *value),
+            ReachableTime(ref value)
+            | BaseReachableTime(ref value)
+            | RetransTime(ref value)
+            | GcStaleTime(ref value)
+            | DelayProbeTime(ref value)
+            | AnycastDelay(ref value)
+            | ProxyDelay(ref value)
+            | LockTime(ref value)
+            | IntervalProbeTime(ref value) => NativeEndian::write_u64(buffer, *value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::NeighbourTableParameter::*;
+        match *self {
+            IfIndex(_) => NDTPA_IFINDEX,
+            RefCount(_) => NDTPA_REFCNT,
+            ReachableTime(_) => NDTPA_REACHABLE_TIME,
+            BaseReachableTime(_) => NDTPA_BASE_REACHABLE_TIME,
+            RetransTime(_) => NDTPA_RETRANS_TIME,
+            GcStaleTime(_) => NDTPA_GC_STALETIME,
+            DelayProbeTime(_) => NDTPA_DELAY_PROBE_TIME,
+            QueueLen(_) => NDTPA_QUEUE_LEN,
+            AppProbes(_) => NDTPA_APP_PROBES,
+            UcastProbes(_) => NDTPA_UCAST_PROBES,
+            McastProbes(_) => NDTPA_MCAST_PROBES,
+            AnycastDelay(_) => NDTPA_ANYCAST_DELAY,
+            ProxyDelay(_) => NDTPA_PROXY_DELAY,
+            ProxyQueueLen(_) => NDTPA_PROXY_QLEN,
+            LockTime(_) => NDTPA_LOCKTIME,
+            QueueLenBytes(_) => NDTPA_QUEUE_LENBYTES,
+            McastReprobes(_) => NDTPA_MCAST_REPROBES,
+            IntervalProbeTime(_) => NDTPA_INTERVAL_PROBE_TIME_MS,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for NeighbourTableParameter {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::NeighbourTableParameter::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NDTPA_IFINDEX => IfIndex(parse_u32(payload).context("invalid NDTPA_IFINDEX value")?),
+            NDTPA_REFCNT => RefCount(parse_u32(payload).context("invalid NDTPA_REFCNT value")?),
+            NDTPA_REACHABLE_TIME => {
+                ReachableTime(parse_u64(payload).context("invalid NDTPA_REACHABLE_TIME value")?)
+            }
+            NDTPA_BASE_REACHABLE_TIME => BaseReachableTime(
+                parse_u64(payload).context("invalid NDTPA_BASE_REACHABLE_TIME value")?,
+            ),
+            NDTPA_RETRANS_TIME => {
+                RetransTime(parse_u64(payload).context("invalid NDTPA_RETRANS_TIME value")?)
+            }
+            NDTPA_GC_STALETIME => {
+                GcStaleTime(parse_u64(payload).context("invalid NDTPA_GC_STALETIME value")?)
+            }
+            NDTPA_DELAY_PROBE_TIME => {
+                DelayProbeTime(parse_u64(payload).context("invalid NDTPA_DELAY_PROBE_TIME value")?)
+            }
+            NDTPA_QUEUE_LEN => {
+                QueueLen(parse_u32(payload).context("invalid NDTPA_QUEUE_LEN value")?)
+            }
+            NDTPA_APP_PROBES => {
+                AppProbes(parse_u32(payload).context("invalid NDTPA_APP_PROBES value")?)
+            }
+            NDTPA_UCAST_PROBES => {
+                UcastProbes(parse_u32(payload).context("invalid NDTPA_UCAST_PROBES value")?)
+            }
+            NDTPA_MCAST_PROBES => {
+                McastProbes(parse_u32(payload).context("invalid NDTPA_MCAST_PROBES value")?)
+            }
+            NDTPA_ANYCAST_DELAY => {
+                AnycastDelay(parse_u64(payload).context("invalid NDTPA_ANYCAST_DELAY value")?)
+            }
+            NDTPA_PROXY_DELAY => {
+                ProxyDelay(parse_u64(payload).context("invalid NDTPA_PROXY_DELAY value")?)
+            }
+            NDTPA_PROXY_QLEN => {
+                ProxyQueueLen(parse_u32(payload).context("invalid NDTPA_PROXY_QLEN value")?)
+            }
+            NDTPA_LOCKTIME => LockTime(parse_u64(payload).context("invalid NDTPA_LOCKTIME value")?),
+            NDTPA_QUEUE_LENBYTES => {
+                QueueLenBytes(parse_u32(payload).context("invalid NDTPA_QUEUE_LENBYTES value")?)
+            }
+            NDTPA_MCAST_REPROBES => {
+                McastReprobes(parse_u32(payload).context("invalid NDTPA_MCAST_REPROBES value")?)
+            }
+            NDTPA_INTERVAL_PROBE_TIME_MS => IntervalProbeTime(
+                parse_u64(payload).context("invalid NDTPA_INTERVAL_PROBE_TIME_MS value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nlas::{NlaBuffer, NlasIterator},
+    DecodeError,
+};
+
+pub const NETCONF_HEADER_LEN: usize = 4;
+
+buffer!(NetconfMessageBuffer(NETCONF_HEADER_LEN) {
+    family: (u8, 0),
+    pad: (slice, 1..NETCONF_HEADER_LEN),
+    payload: (slice, NETCONF_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NetconfMessageBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+
+use super::{NetconfMessageBuffer, NETCONF_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Netconf message header (`struct netconfmsg`), padded to 4 bytes.
+// see https://github.com/torvalds/linux/blob/master/include/uapi/linux/netconf.h
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NetconfHeader {
+    /// Address family: `AF_INET`, `AF_INET6` or `AF_MPLS`.
+    pub family: u8,
+}
+
+impl Emitable for NetconfHeader {
+    fn buffer_len(&self) -> usize {
+        NETCONF_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NetconfMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.pad_mut().copy_from_slice(&[0; 3]);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NetconfMessageBuffer<T>> for NetconfHeader {
+    fn parse(buf: &NetconfMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(NetconfHeader {
+            family: buf.family(),
+        })
+    }
+}
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::netconf::Nla,
+    traits::{Emitable, Parseable},
+    DecodeError,
+    NetconfHeader,
+    NetconfMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NetconfMessage {
+    pub header: NetconfHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NetconfMessageBuffer<&'a T>> for NetconfMessage {
+    fn parse(buf: &NetconfMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: NetconfHeader::parse(buf).context("failed to parse netconf message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse netconf message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NetconfMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &NetconfMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for NetconfMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        constants::*,
+        nlas::netconf::Nla,
+        traits::{Emitable, ParseableParametrized},
+        NetconfHeader,
+        NetconfMessage,
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
+    };
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_netconf() {
+        let data = vec![
+            0x3c, 0x00, 0x00, 0x00, // length = 60
+            0x50, 0x00, // message type = 80 = RTM_NEWNETCONF
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // netconfmsg
+            0x02, // family = AF_INET
+            0x00, 0x00, 0x00, // padding
+
+            // NLAs
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = NETCONFA_IFINDEX
+            0xff, 0xff, 0xff, 0xff, // NETCONFA_IFINDEX_ALL
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = NETCONFA_FORWARDING
+            0x01, 0x00, 0x00, 0x00, // 1
+            0x08, 0x00, 0x03, 0x00, // length = 8, type = NETCONFA_RP_FILTER
+            0x02, 0x00, 0x00, 0x00, // 2
+            0x08, 0x00, 0x04, 0x00, // length = 8, type = NETCONFA_MC_FORWARDING
+            0x00, 0x00, 0x00, 0x00, // 0
+            0x08, 0x00, 0x06, 0x00, // length = 8, type = NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN
+            0x00, 0x00, 0x00, 0x00, // 0
+        ];
+        let expected = RtnlMessage::NewNetconf(NetconfMessage {
+            header: NetconfHeader {
+                family: AF_INET as u8,
+            },
+            nlas: vec![
+                Nla::IfIndex(NETCONFA_IFINDEX_ALL),
+                Nla::Forwarding(1),
+                Nla::RpFilter(2),
+                Nla::McForwarding(0),
+                Nla::IgnoreRoutesWithLinkdown(0),
+            ],
+        });
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWNETCONF).unwrap();
+        assert_eq!(expected, actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::parse_i32,
+    traits::Parseable,
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Interface the configuration applies to, or `NETCONFA_IFINDEX_ALL` or
+    /// `NETCONFA_IFINDEX_DEFAULT`
+    IfIndex(i32),
+    Forwarding(i32),
+    RpFilter(i32),
+    McForwarding(i32),
+    ProxyNeigh(i32),
+    IgnoreRoutesWithLinkdown(i32),
+    /// Whether MPLS input is enabled
+    Input(i32),
+    BcForwarding(i32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            IfIndex(_)
+            | Forwarding(_)
+            | RpFilter(_)
+            | McForwarding(_)
+            | ProxyNeigh(_)
+            | IgnoreRoutesWithLinkdown(_)
+            | Input(_)
+            | BcForwarding(_) => 4,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            IfIndex(value)
+            | Forwarding(value)
+            | RpFilter(value)
+            | McForwarding(value)
+            | ProxyNeigh(value)
+            | IgnoreRoutesWithLinkdown(value)
+            | Input(value)
+            | BcForwarding(value) => NativeEndian::write_i32(buffer, value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match *self {
+            Unspec(_) => NETCONFA_UNSPEC,
+            IfIndex(_) => NETCONFA_IFINDEX,
+            Forwarding(_) => NETCONFA_FORWARDING,
+            RpFilter(_) => NETCONFA_RP_FILTER,
+            McForwarding(_) => NETCONFA_MC_FORWARDING,
+            ProxyNeigh(_) => NETCONFA_PROXY_NEIGH,
+            IgnoreRoutesWithLinkdown(_) => NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN,
+            Input(_) => NETCONFA_INPUT,
+            BcForwarding(_) => NETCONFA_BC_FORWARDING,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NETCONFA_UNSPEC => Unspec(payload.to_vec()),
+            NETCONFA_IFINDEX => {
+                IfIndex(parse_i32(payload).context("invalid NETCONFA_IFINDEX value")?)
+            }
+            NETCONFA_FORWARDING => {
+                Forwarding(parse_i32(payload).context("invalid NETCONFA_FORWARDING value")?)
+            }
+            NETCONFA_RP_FILTER => {
+                RpFilter(parse_i32(payload).context("invalid NETCONFA_RP_FILTER value")?)
+            }
+            NETCONFA_MC_FORWARDING => {
+                McForwarding(parse_i32(payload).context("invalid NETCONFA_MC_FORWARDING value")?)
+            }
+            NETCONFA_PROXY_NEIGH => {
+                ProxyNeigh(parse_i32(payload).context("invalid NETCONFA_PROXY_NEIGH value")?)
+            }
+            NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN => IgnoreRoutesWithLinkdown(
+                parse_i32(payload).context("invalid NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN value")?,
+            ),
+            NETCONFA_INPUT => Input(parse_i32(payload).context("invalid NETCONFA_INPUT value")?),
+            NETCONFA_BC_FORWARDING => {
+                BcForwarding(parse_i32(payload).context("invalid NETCONFA_BC_FORWARDING value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
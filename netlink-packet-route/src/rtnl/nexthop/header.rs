@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+use super::{NexthopMessageBuffer, NEXTHOP_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Nexthop message header (`struct nhmsg`).
+///
+/// ```no_rust
+/// 0                8                16              24               32
+/// +----------------+----------------+----------------+----------------+
+/// | address family |     scope      |    protocol    |    reserved    |
+/// +----------------+----------------+----------------+----------------+
+/// |                               flags                               |
+/// +----------------+----------------+----------------+----------------+
+/// ```
+// see https://github.com/torvalds/linux/blob/master/include/uapi/linux/nexthop.h
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NexthopHeader {
+    /// Address family: `AF_INET`, `AF_INET6`, or `AF_UNSPEC` for
+    /// groups and blackhole nexthops.
+    pub family: u8,
+    /// Scope of the nexthop: one of the `RT_SCOPE_*` constants.
+    pub scope: u8,
+    /// Protocol that installed the nexthop: one of the `RTPROT_*`
+    /// constants.
+    pub protocol: u8,
+    /// Nexthop flags: a combination of the `RTNH_F_*` constants.
+    pub flags: u32,
+}
+
+impl Emitable for NexthopHeader {
+    fn buffer_len(&self) -> usize {
+        NEXTHOP_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NexthopMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_scope(self.scope);
+        packet.set_protocol(self.protocol);
+        packet.set_reserved(0);
+        packet.set_flags(self.flags);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NexthopMessageBuffer<T>> for NexthopHeader {
+    fn parse(buf: &NexthopMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(NexthopHeader {
+            family: buf.family(),
+            scope: buf.scope(),
+            protocol: buf.protocol(),
+            flags: buf.flags(),
+        })
+    }
+}
@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::nexthop::Nla,
+    traits::{Emitable, Parseable},
+    DecodeError,
+    NexthopHeader,
+    NexthopMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NexthopMessage {
+    pub header: NexthopHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NexthopMessageBuffer<&'a T>> for NexthopMessage {
+    fn parse(buf: &NexthopMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: NexthopHeader::parse(buf).context("failed to parse nexthop message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse nexthop message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NexthopMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &NexthopMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for NexthopMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        nlas::nexthop::{NexthopGroup, Nla, ResGroupNla},
+        traits::{Emitable, ParseableParametrized},
+        NetlinkBuffer,
+        NexthopHeader,
+        NexthopMessage,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        AF_INET,
+        NEXTHOP_GRP_TYPE_RES,
+        RTM_NEWNEXTHOP,
+        RTPROT_UNSPEC,
+        RT_SCOPE_UNIVERSE,
+    };
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_nexthop_gateway() {
+        let data = vec![
+            0x30, 0x00, 0x00, 0x00, // length = 48
+            0x68, 0x00, // message type = 104 = RTM_NEWNEXTHOP
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // nhmsg
+            0x02, // family = AF_INET
+            0x00, // scope = RT_SCOPE_UNIVERSE
+            0x00, // protocol = RTPROT_UNSPEC
+            0x00, // reserved
+            0x00, 0x00, 0x00, 0x00, // flags
+
+            // NLAs
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = NHA_ID
+            0x01, 0x00, 0x00, 0x00, // 1
+            0x08, 0x00, 0x06, 0x00, // length = 8, type = NHA_GATEWAY
+            0xc0, 0xa8, 0x01, 0x01, // 192.168.1.1
+            0x08, 0x00, 0x05, 0x00, // length = 8, type = NHA_OIF
+            0x02, 0x00, 0x00, 0x00, // 2
+        ];
+        let expected = RtnlMessage::NewNexthop(NexthopMessage {
+            header: NexthopHeader {
+                family: AF_INET as u8,
+                scope: RT_SCOPE_UNIVERSE,
+                protocol: RTPROT_UNSPEC,
+                flags: 0,
+            },
+            nlas: vec![
+                Nla::Id(1),
+                Nla::Gateway(vec![192, 168, 1, 1]),
+                Nla::Oif(2),
+            ],
+        });
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWNEXTHOP).unwrap();
+        assert_eq!(expected, actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_nexthop_resilient_group() {
+        let data = vec![
+            0x48, 0x00, 0x00, 0x00, // length = 72
+            0x68, 0x00, // message type = 104 = RTM_NEWNEXTHOP
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // nhmsg
+            0x00, // family = AF_UNSPEC
+            0x00, // scope
+            0x00, // protocol
+            0x00, // reserved
+            0x00, 0x00, 0x00, 0x00, // flags
+
+            // NLAs
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = NHA_ID
+            0x0a, 0x00, 0x00, 0x00, // 10
+            0x14, 0x00, 0x02, 0x00, // length = 20, type = NHA_GROUP
+            0x01, 0x00, 0x00, 0x00, // id = 1
+            0x00, 0x00, 0x00, 0x00, // weight = 0, reserved
+            0x02, 0x00, 0x00, 0x00, // id = 2
+            0x02, 0x00, 0x00, 0x00, // weight = 2, reserved
+            0x06, 0x00, 0x03, 0x00, // length = 6, type = NHA_GROUP_TYPE
+            0x01, 0x00, 0x00, 0x00, // NEXTHOP_GRP_TYPE_RES, padding
+            0x0c, 0x00, 0x0c, 0x80, // length = 12, type = NHA_RES_GROUP | NLA_F_NESTED
+            0x06, 0x00, 0x01, 0x00, // length = 6, type = NHA_RES_GROUP_BUCKETS
+            0x40, 0x00, 0x00, 0x00, // 64, padding
+        ];
+        let expected = NexthopMessage {
+            header: NexthopHeader::default(),
+            nlas: vec![
+                Nla::Id(10),
+                Nla::Group(vec![
+                    NexthopGroup { id: 1, weight: 0 },
+                    NexthopGroup { id: 2, weight: 2 },
+                ]),
+                Nla::GroupType(NEXTHOP_GRP_TYPE_RES),
+                Nla::ResGroup(vec![ResGroupNla::Buckets(64)]),
+            ],
+        };
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWNEXTHOP).unwrap();
+        assert_eq!(RtnlMessage::NewNexthop(expected.clone()), actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const NEXTHOP_GROUP_LEN: usize = 8;
+
+buffer!(NexthopGroupBuffer(NEXTHOP_GROUP_LEN) {
+    id: (u32, 0..4),
+    weight: (u8, 4),
+    reserved_1: (u8, 5),
+    reserved_2: (u16, 6..8),
+});
+
+/// A member of a nexthop group (`struct nexthop_grp`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct NexthopGroup {
+    /// Id of the member nexthop.
+    pub id: u32,
+    /// Weight of the member nexthop, minus one: a weight of `0` on the
+    /// wire is a weight of `1`.
+    pub weight: u8,
+}
+
+impl Emitable for NexthopGroup {
+    fn buffer_len(&self) -> usize {
+        NEXTHOP_GROUP_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NexthopGroupBuffer::new(buffer);
+        buffer.set_id(self.id);
+        buffer.set_weight(self.weight);
+        buffer.set_reserved_1(0);
+        buffer.set_reserved_2(0);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NexthopGroupBuffer<T>> for NexthopGroup {
+    fn parse(buf: &NexthopGroupBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(NexthopGroup {
+            id: buf.id(),
+            weight: buf.weight(),
+        })
+    }
+}
@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: MIT
+
+mod group;
+pub use self::group::*;
+
+mod res_bucket;
+pub use self::res_bucket::*;
+
+mod res_group;
+pub use self::res_group::*;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Id of the nexthop
+    Id(u32),
+    /// Members of a nexthop group
+    Group(Vec<NexthopGroup>),
+    /// Type of a nexthop group: `NEXTHOP_GRP_TYPE_MPATH` or
+    /// `NEXTHOP_GRP_TYPE_RES`
+    GroupType(u16),
+    /// The nexthop is a blackhole
+    Blackhole,
+    /// Output interface index
+    Oif(u32),
+    /// Gateway address
+    Gateway(Vec<u8>),
+    /// Lightweight tunnel encapsulation type: one of the
+    /// `LWTUNNEL_ENCAP_*` constants
+    EncapType(u16),
+    /// Lightweight tunnel encapsulation attributes
+    Encap(Vec<u8>),
+    /// Dump filter: only return nexthop groups
+    Groups,
+    /// Dump filter: only return nexthops whose output interface is
+    /// enslaved to the given master device
+    Master(u32),
+    /// The nexthop is used by FDB entries, or, in a dump request, only
+    /// return such nexthops
+    Fdb,
+    /// Resilient nexthop group parameters
+    ResGroup(Vec<ResGroupNla>),
+    /// Resilient nexthop group bucket
+    ResBucket(Vec<ResBucketNla>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes)
+                | Gateway(ref bytes)
+                | Encap(ref bytes)
+                => bytes.len(),
+            Group(ref entries) => entries.iter().map(|e| e.buffer_len()).sum(),
+            ResGroup(ref nlas) => nlas.as_slice().buffer_len(),
+            ResBucket(ref nlas) => nlas.as_slice().buffer_len(),
+            Blackhole | Groups | Fdb => 0,
+            GroupType(_) | EncapType(_) => 2,
+            Id(_) | Oif(_) | Master(_) => 4,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes)
+                | Gateway(ref bytes)
+                | Encap(ref bytes)
+                => buffer.copy_from_slice(bytes.as_slice()),
+            Group(ref entries) => {
+                let mut offset = 0;
+                for entry in entries {
+                    let len = entry.buffer_len();
+                    entry.emit(&mut buffer[offset..offset+len]);
+                    offset += len
+                }
+            }
+            ResGroup(ref nlas) => nlas.as_slice().emit(buffer),
+            ResBucket(ref nlas) => nlas.as_slice().emit(buffer),
+            Blackhole | Groups | Fdb => {}
+            GroupType(value) | EncapType(value) => NativeEndian::write_u16(buffer, value),
+            Id(value)
+                | Oif(value)
+                | Master(value)
+                => NativeEndian::write_u32(buffer, value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match *self {
+            Unspec(_) => NHA_UNSPEC,
+            Id(_) => NHA_ID,
+            Group(_) => NHA_GROUP,
+            GroupType(_) => NHA_GROUP_TYPE,
+            Blackhole => NHA_BLACKHOLE,
+            Oif(_) => NHA_OIF,
+            Gateway(_) => NHA_GATEWAY,
+            EncapType(_) => NHA_ENCAP_TYPE,
+            Encap(_) => NHA_ENCAP,
+            Groups => NHA_GROUPS,
+            Master(_) => NHA_MASTER,
+            Fdb => NHA_FDB,
+            ResGroup(_) => NHA_RES_GROUP | NLA_F_NESTED,
+            ResBucket(_) => NHA_RES_BUCKET | NLA_F_NESTED,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_UNSPEC => Unspec(payload.to_vec()),
+            NHA_ID => Id(parse_u32(payload).context("invalid NHA_ID value")?),
+            NHA_GROUP => {
+                let chunks = payload.chunks_exact(NEXTHOP_GROUP_LEN);
+                if !chunks.remainder().is_empty() {
+                    return Err(format!(
+                        "invalid NHA_GROUP value: length {} is not a multiple of {}",
+                        payload.len(),
+                        NEXTHOP_GROUP_LEN
+                    )
+                    .into());
+                }
+                let mut entries = Vec::new();
+                for chunk in chunks {
+                    let entry = NexthopGroup::parse(&NexthopGroupBuffer::new(chunk))
+                        .context("invalid NHA_GROUP value")?;
+                    entries.push(entry);
+                }
+                Group(entries)
+            }
+            NHA_GROUP_TYPE => {
+                GroupType(parse_u16(payload).context("invalid NHA_GROUP_TYPE value")?)
+            }
+            NHA_BLACKHOLE => Blackhole,
+            NHA_OIF => Oif(parse_u32(payload).context("invalid NHA_OIF value")?),
+            NHA_GATEWAY => Gateway(payload.to_vec()),
+            NHA_ENCAP_TYPE => {
+                EncapType(parse_u16(payload).context("invalid NHA_ENCAP_TYPE value")?)
+            }
+            NHA_ENCAP => Encap(payload.to_vec()),
+            NHA_GROUPS => Groups,
+            NHA_MASTER => Master(parse_u32(payload).context("invalid NHA_MASTER value")?),
+            NHA_FDB => Fdb,
+            NHA_RES_GROUP => {
+                let err = "invalid NHA_RES_GROUP value";
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err)?;
+                    nlas.push(ResGroupNla::parse(nla).context(err)?);
+                }
+                ResGroup(nlas)
+            }
+            NHA_RES_BUCKET => {
+                let err = "invalid NHA_RES_BUCKET value";
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err)?;
+                    nlas.push(ResBucketNla::parse(nla).context(err)?);
+                }
+                ResBucket(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
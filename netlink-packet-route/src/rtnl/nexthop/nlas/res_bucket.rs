@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// Attributes of a resilient nexthop group bucket, nested in
+/// `NHA_RES_BUCKET`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ResBucketNla {
+    Pad(Vec<u8>),
+    /// Index of the bucket in the group.
+    Index(u16),
+    /// Time the bucket has been idle, in clock ticks.
+    IdleTime(u64),
+    /// Id of the nexthop the bucket is assigned to.
+    NhId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for ResBucketNla {
+    fn value_len(&self) -> usize {
+        use self::ResBucketNla::*;
+        match *self {
+            Pad(ref bytes) => bytes.len(),
+            Index(_) => 2,
+            NhId(_) => 4,
+            IdleTime(_) => 8,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::ResBucketNla::*;
+        match *self {
+            Pad(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Index(value) => NativeEndian::write_u16(buffer, value),
+            NhId(value) => NativeEndian::write_u32(buffer, value),
+            IdleTime(value) => NativeEndian::write_u64(buffer, value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::ResBucketNla::*;
+        match *self {
+            Pad(_) => NHA_RES_BUCKET_PAD,
+            Index(_) => NHA_RES_BUCKET_INDEX,
+            IdleTime(_) => NHA_RES_BUCKET_IDLE_TIME,
+            NhId(_) => NHA_RES_BUCKET_NH_ID,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for ResBucketNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::ResBucketNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_BUCKET_PAD => Pad(payload.to_vec()),
+            NHA_RES_BUCKET_INDEX => {
+                Index(parse_u16(payload).context("invalid NHA_RES_BUCKET_INDEX value")?)
+            }
+            NHA_RES_BUCKET_IDLE_TIME => {
+                IdleTime(parse_u64(payload).context("invalid NHA_RES_BUCKET_IDLE_TIME value")?)
+            }
+            NHA_RES_BUCKET_NH_ID => {
+                NhId(parse_u32(payload).context("invalid NHA_RES_BUCKET_NH_ID value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
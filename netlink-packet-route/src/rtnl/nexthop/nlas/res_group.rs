@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// Attributes of a resilient nexthop group, nested in `NHA_RES_GROUP`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ResGroupNla {
+    Pad(Vec<u8>),
+    /// Number of hash buckets.
+    Buckets(u16),
+    /// Idle timer, in seconds.
+    IdleTimer(u32),
+    /// Unbalanced timer, in seconds.
+    UnbalancedTimer(u32),
+    /// Time the group has been unbalanced, in clock ticks.
+    UnbalancedTime(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for ResGroupNla {
+    fn value_len(&self) -> usize {
+        use self::ResGroupNla::*;
+        match *self {
+            Pad(ref bytes) => bytes.len(),
+            Buckets(_) => 2,
+            IdleTimer(_) | UnbalancedTimer(_) => 4,
+            UnbalancedTime(_) => 8,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::ResGroupNla::*;
+        match *self {
+            Pad(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Buckets(value) => NativeEndian::write_u16(buffer, value),
+            IdleTimer(value) | UnbalancedTimer(value) => NativeEndian::write_u32(buffer, value),
+            UnbalancedTime(value) => NativeEndian::write_u64(buffer, value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::ResGroupNla::*;
+        match *self {
+            Pad(_) => NHA_RES_GROUP_PAD,
+            Buckets(_) => NHA_RES_GROUP_BUCKETS,
+            IdleTimer(_) => NHA_RES_GROUP_IDLE_TIMER,
+            UnbalancedTimer(_) => NHA_RES_GROUP_UNBALANCED_TIMER,
+            UnbalancedTime(_) => NHA_RES_GROUP_UNBALANCED_TIME,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for ResGroupNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::ResGroupNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_GROUP_PAD => Pad(payload.to_vec()),
+            NHA_RES_GROUP_BUCKETS => {
+                Buckets(parse_u16(payload).context("invalid NHA_RES_GROUP_BUCKETS value")?)
+            }
+            NHA_RES_GROUP_IDLE_TIMER => {
+                IdleTimer(parse_u32(payload).context("invalid NHA_RES_GROUP_IDLE_TIMER value")?)
+            }
+            NHA_RES_GROUP_UNBALANCED_TIMER => UnbalancedTimer(
+                parse_u32(payload).context("invalid NHA_RES_GROUP_UNBALANCED_TIMER value")?,
+            ),
+            NHA_RES_GROUP_UNBALANCED_TIME => UnbalancedTime(
+                parse_u64(payload).context("invalid NHA_RES_GROUP_UNBALANCED_TIME value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -24,4 +24,15 @@ impl<'a, T: AsRef<[u8]> + ?Sized> RouteMessageBuffer<&'a T> {
     pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
         NlasIterator::new(self.payload())
     }
+
+    /// Returns the undecoded value of the first attribute of the given
+    /// `RTA_*` type, like `RTA_METRICS` or `RTA_CACHEINFO`, for attributes
+    /// whose typed representation in [`Nla`](crate::nlas::route::Nla) is
+    /// not enough.
+    pub fn nla_value(&self, kind: u16) -> Option<Vec<u8>> {
+        self.nlas()
+            .filter_map(Result::ok)
+            .find(|nla| nla.kind() == kind)
+            .map(|nla| nla.value().to_vec())
+    }
 }
@@ -1,8 +1,10 @@
 // SPDX-License-Identifier: MIT
 
 use crate::{
+    constants::{LWTUNNEL_ENCAP_NONE, RTA_ENCAP_TYPE},
     nlas::route::Nla,
-    traits::{Emitable, Parseable},
+    parsers::parse_u16,
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
     RouteHeader,
     RouteMessageBuffer,
@@ -40,9 +42,18 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<RouteMessageBuffer<&'a T>> for RouteMess
 
 impl<'a, T: AsRef<[u8]> + 'a> Parseable<RouteMessageBuffer<&'a T>> for Vec<Nla> {
     fn parse(buf: &RouteMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        // The kernel sends RTA_ENCAP before RTA_ENCAP_TYPE, which gives its
+        // format, so look for the latter first.
+        let mut encap_type = LWTUNNEL_ENCAP_NONE;
+        for nla_buf in buf.nlas() {
+            let nla_buf = nla_buf?;
+            if nla_buf.kind() == RTA_ENCAP_TYPE {
+                encap_type = parse_u16(nla_buf.value()).context("invalid RTA_ENCAP_TYPE value")?;
+            }
+        }
         let mut nlas = vec![];
         for nla_buf in buf.nlas() {
-            nlas.push(Nla::parse(&nla_buf?)?);
+            nlas.push(Nla::parse_with_param(&nla_buf?, encap_type)?);
         }
         Ok(nlas)
     }
@@ -0,0 +1,347 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use super::{MplsLabel, Seg6IpTunnel, Seg6Local, MPLS_LABEL_LEN};
+use crate::{
+    constants::*,
+    emit_ip,
+    ip_len,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_ip, parse_string, parse_u16_be, parse_u32, parse_u64_be, parse_u8},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+/// Lightweight tunnel encapsulation of a route (`RTA_ENCAP`). Its format
+/// depends on the encapsulation type found in `RTA_ENCAP_TYPE`, so
+/// encapsulations of unknown types are kept as raw bytes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Encap {
+    Mpls(Vec<MplsIpTunnel>),
+    Ip(Vec<IpTunnel>),
+    Ip6(Vec<IpTunnel>),
+    Seg6(Vec<Seg6IpTunnel>),
+    Seg6Local(Vec<Seg6Local>),
+    Bpf(Vec<BpfEncap>),
+    Other(Vec<u8>),
+}
+
+impl Encap {
+    /// Return the `LWTUNNEL_ENCAP_*` type of this encapsulation, if known
+    pub fn kind(&self) -> Option<u16> {
+        use self::Encap::*;
+        match self {
+            Mpls(_) => Some(LWTUNNEL_ENCAP_MPLS),
+            Ip(_) => Some(LWTUNNEL_ENCAP_IP),
+            Ip6(_) => Some(LWTUNNEL_ENCAP_IP6),
+            Seg6(_) => Some(LWTUNNEL_ENCAP_SEG6),
+            Seg6Local(_) => Some(LWTUNNEL_ENCAP_SEG6_LOCAL),
+            Bpf(_) => Some(LWTUNNEL_ENCAP_BPF),
+            Other(_) => None,
+        }
+    }
+}
+
+impl Emitable for Encap {
+    fn buffer_len(&self) -> usize {
+        use self::Encap::*;
+        match self {
+            Mpls(nlas) => nlas.as_slice().buffer_len(),
+            Ip(nlas) | Ip6(nlas) => nlas.as_slice().buffer_len(),
+            Seg6(nlas) => nlas.as_slice().buffer_len(),
+            Seg6Local(nlas) => nlas.as_slice().buffer_len(),
+            Bpf(nlas) => nlas.as_slice().buffer_len(),
+            Other(bytes) => bytes.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        use self::Encap::*;
+        match self {
+            Mpls(nlas) => nlas.as_slice().emit(buffer),
+            Ip(nlas) | Ip6(nlas) => nlas.as_slice().emit(buffer),
+            Seg6(nlas) => nlas.as_slice().emit(buffer),
+            Seg6Local(nlas) => nlas.as_slice().emit(buffer),
+            Bpf(nlas) => nlas.as_slice().emit(buffer),
+            Other(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+        }
+    }
+}
+
+fn parse_nlas<'a, N>(payload: &'a [u8]) -> Result<Vec<N>, DecodeError>
+where
+    N: Parseable<NlaBuffer<&'a [u8]>>,
+{
+    let mut nlas = vec![];
+    for nla in NlasIterator::new(payload) {
+        nlas.push(N::parse(&nla?)?);
+    }
+    Ok(nlas)
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> for Encap {
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, kind: u16) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match kind {
+            LWTUNNEL_ENCAP_MPLS => Self::Mpls(parse_nlas(payload).context("invalid mpls encap")?),
+            LWTUNNEL_ENCAP_IP => Self::Ip(parse_nlas(payload).context("invalid ip encap")?),
+            LWTUNNEL_ENCAP_IP6 => Self::Ip6(parse_nlas(payload).context("invalid ip6 encap")?),
+            LWTUNNEL_ENCAP_SEG6 => Self::Seg6(parse_nlas(payload).context("invalid seg6 encap")?),
+            LWTUNNEL_ENCAP_SEG6_LOCAL => {
+                Self::Seg6Local(parse_nlas(payload).context("invalid seg6local encap")?)
+            }
+            LWTUNNEL_ENCAP_BPF => Self::Bpf(parse_nlas(payload).context("invalid bpf encap")?),
+            _ => Self::Other(payload.to_vec()),
+        })
+    }
+}
+
+/// Attributes of a `LWTUNNEL_ENCAP_MPLS` encapsulation
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MplsIpTunnel {
+    /// Labels to push, the outermost one first
+    Destination(Vec<MplsLabel>),
+    Ttl(u8),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for MplsIpTunnel {
+    fn value_len(&self) -> usize {
+        use self::MplsIpTunnel::*;
+        match self {
+            Destination(labels) => labels.len() * MPLS_LABEL_LEN,
+            Ttl(_) => 1,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MplsIpTunnel::*;
+        match self {
+            Destination(labels) => MplsLabel::emit_stack(labels, buffer),
+            Ttl(value) => buffer[0] = *value,
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MplsIpTunnel::*;
+        match self {
+            Destination(_) => MPLS_IPTUNNEL_DST,
+            Ttl(_) => MPLS_IPTUNNEL_TTL,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MplsIpTunnel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MplsIpTunnel::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MPLS_IPTUNNEL_DST => Destination(
+                MplsLabel::parse_stack(payload).context("invalid MPLS_IPTUNNEL_DST value")?,
+            ),
+            MPLS_IPTUNNEL_TTL => Ttl(parse_u8(payload).context("invalid MPLS_IPTUNNEL_TTL value")?),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of a `LWTUNNEL_ENCAP_IP` or `LWTUNNEL_ENCAP_IP6` encapsulation,
+/// which hold the metadata of a collect mode tunnel
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum IpTunnel {
+    /// Tunnel key
+    Id(u64),
+    Destination(IpAddr),
+    Source(IpAddr),
+    /// TTL, or hop limit for IPv6
+    Ttl(u8),
+    /// TOS, or traffic class for IPv6
+    Tos(u8),
+    /// `TUNNEL_*` flags
+    Flags(u16),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for IpTunnel {
+    fn value_len(&self) -> usize {
+        use self::IpTunnel::*;
+        match self {
+            Id(_) => 8,
+            Destination(addr) | Source(addr) => ip_len(addr),
+            Ttl(_) | Tos(_) => 1,
+            Flags(_) => 2,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::IpTunnel::*;
+        match self {
+            Id(value) => BigEndian::write_u64(buffer, *value),
+            Destination(addr) | Source(addr) => emit_ip(buffer, addr),
+            Ttl(value) | Tos(value) => buffer[0] = *value,
+            Flags(value) => BigEndian::write_u16(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    // `LWTUNNEL_IP6_*` attributes have the same types as their
+    // `LWTUNNEL_IP_*` counterparts.
+    fn kind(&self) -> u16 {
+        use self::IpTunnel::*;
+        match self {
+            Id(_) => LWTUNNEL_IP_ID,
+            Destination(_) => LWTUNNEL_IP_DST,
+            Source(_) => LWTUNNEL_IP_SRC,
+            Ttl(_) => LWTUNNEL_IP_TTL,
+            Tos(_) => LWTUNNEL_IP_TOS,
+            Flags(_) => LWTUNNEL_IP_FLAGS,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for IpTunnel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::IpTunnel::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWTUNNEL_IP_ID => Id(parse_u64_be(payload).context("invalid LWTUNNEL_IP_ID value")?),
+            LWTUNNEL_IP_DST => {
+                Destination(parse_ip(payload).context("invalid LWTUNNEL_IP_DST value")?)
+            }
+            LWTUNNEL_IP_SRC => Source(parse_ip(payload).context("invalid LWTUNNEL_IP_SRC value")?),
+            LWTUNNEL_IP_TTL => Ttl(parse_u8(payload).context("invalid LWTUNNEL_IP_TTL value")?),
+            LWTUNNEL_IP_TOS => Tos(parse_u8(payload).context("invalid LWTUNNEL_IP_TOS value")?),
+            LWTUNNEL_IP_FLAGS => {
+                Flags(parse_u16_be(payload).context("invalid LWTUNNEL_IP_FLAGS value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of a `LWTUNNEL_ENCAP_BPF` encapsulation
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BpfEncap {
+    /// Program run on packets received through the route
+    In(Vec<BpfProg>),
+    /// Program run on packets sent through the route
+    Out(Vec<BpfProg>),
+    /// Program run on packets transmitted through the route, which can
+    /// modify them
+    Xmit(Vec<BpfProg>),
+    /// Headroom reserved for the headers pushed by the `Xmit` program
+    XmitHeadroom(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for BpfEncap {
+    fn value_len(&self) -> usize {
+        use self::BpfEncap::*;
+        match self {
+            In(nlas) | Out(nlas) | Xmit(nlas) => nlas.as_slice().buffer_len(),
+            XmitHeadroom(_) => 4,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BpfEncap::*;
+        match self {
+            In(nlas) | Out(nlas) | Xmit(nlas) => nlas.as_slice().emit(buffer),
+            XmitHeadroom(value) => NativeEndian::write_u32(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BpfEncap::*;
+        match self {
+            In(_) => LWT_BPF_IN,
+            Out(_) => LWT_BPF_OUT,
+            Xmit(_) => LWT_BPF_XMIT,
+            XmitHeadroom(_) => LWT_BPF_XMIT_HEADROOM,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BpfEncap {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::BpfEncap::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWT_BPF_IN => In(parse_nlas(payload).context("invalid LWT_BPF_IN value")?),
+            LWT_BPF_OUT => Out(parse_nlas(payload).context("invalid LWT_BPF_OUT value")?),
+            LWT_BPF_XMIT => Xmit(parse_nlas(payload).context("invalid LWT_BPF_XMIT value")?),
+            LWT_BPF_XMIT_HEADROOM => {
+                XmitHeadroom(parse_u32(payload).context("invalid LWT_BPF_XMIT_HEADROOM value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of a BPF program attached to a route. Requests must give both
+/// the file descriptor and the name of the program, while the kernel only
+/// sends back its name.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BpfProg {
+    Fd(u32),
+    Name(String),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for BpfProg {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Fd(_) => 4,
+            Self::Name(name) => name.len() + 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Fd(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Name(name) => {
+                buffer[..name.len()].copy_from_slice(name.as_bytes());
+                buffer[name.len()] = 0;
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Fd(_) => LWT_BPF_PROG_FD,
+            Self::Name(_) => LWT_BPF_PROG_NAME,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BpfProg {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWT_BPF_PROG_FD => {
+                Self::Fd(parse_u32(payload).context("invalid LWT_BPF_PROG_FD value")?)
+            }
+            LWT_BPF_PROG_NAME => {
+                Self::Name(parse_string(payload).context("invalid LWT_BPF_PROG_NAME value")?)
+            }
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
@@ -7,14 +7,17 @@ use std::mem::size_of;
 use crate::{
     constants::*,
     nlas::{DefaultNla, Nla, NlaBuffer},
-    parsers::parse_u32,
+    parsers::{parse_string, parse_u32},
     traits::Parseable,
     DecodeError,
 };
 
+/// Route metrics, found in the `RTA_METRICS` attribute of a route (`RTAX_*`)
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Metrics {
     Unspec(Vec<u8>),
+    /// Bitmask of the metrics the kernel must not change, like
+    /// `1 << RTAX_MTU` for a locked MTU
     Lock(u32),
     Mtu(u32),
     Window(u32),
@@ -26,11 +29,13 @@ pub enum Metrics {
     Reordering(u32),
     Hoplimit(u32),
     InitCwnd(u32),
+    /// Bitmask of `RTAX_FEATURE_*` flags
     Features(u32),
     RtoMin(u32),
     InitRwnd(u32),
     QuickAck(u32),
-    CcAlgo(u32),
+    /// Name of the congestion control algorithm, like `"cubic"`
+    CcAlgo(String),
     FastopenNoCookie(u32),
     Other(DefaultNla),
 }
@@ -56,9 +61,10 @@ impl Nla for Metrics {
                 | RtoMin(_)
                 | InitRwnd(_)
                 | QuickAck(_)
-                | CcAlgo(_)
                 | FastopenNoCookie(_)
                 => size_of::<u32>(),
+            // +1 because we need to append a nul byte
+            CcAlgo(ref name) => name.len() + 1,
             Other(ref attr) => attr.value_len(),
         }
     }
@@ -84,10 +90,14 @@ impl Nla for Metrics {
                 | RtoMin(value)
                 | InitRwnd(value)
                 | QuickAck(value)
-                | CcAlgo(value)
                 | FastopenNoCookie(value)
                 => NativeEndian::write_u32(buffer, value),
 
+            CcAlgo(ref name) => {
+                buffer[..name.len()].copy_from_slice(name.as_bytes());
+                buffer[name.len()] = 0;
+            }
+
             Other(ref attr) => attr.emit_value(buffer),
         }
     }
@@ -141,7 +151,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Metrics {
             RTAX_RTO_MIN => RtoMin(parse_u32(payload).context("invalid RTAX_RTO_MIN value")?),
             RTAX_INITRWND => InitRwnd(parse_u32(payload).context("invalid RTAX_INITRWND value")?),
             RTAX_QUICKACK => QuickAck(parse_u32(payload).context("invalid RTAX_QUICKACK value")?),
-            RTAX_CC_ALGO => CcAlgo(parse_u32(payload).context("invalid RTAX_CC_ALGO value")?),
+            RTAX_CC_ALGO => CcAlgo(parse_string(payload).context("invalid RTAX_CC_ALGO value")?),
             RTAX_FASTOPEN_NO_COOKIE => FastopenNoCookie(
                 parse_u32(payload).context("invalid RTAX_FASTOPEN_NO_COOKIE value")?,
             ),
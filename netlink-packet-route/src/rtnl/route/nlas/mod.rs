@@ -3,49 +3,46 @@
 mod cache_info;
 pub use self::cache_info::*;
 
+mod lwtunnel;
+pub use self::lwtunnel::*;
+
 mod metrics;
 pub use self::metrics::*;
 
 mod mfc_stats;
 pub use self::mfc_stats::*;
 
+mod mpls_label;
+pub use self::mpls_label::*;
+
 mod next_hops;
 pub use self::next_hops::*;
 
+mod seg6;
+pub use self::seg6::*;
+
+mod via;
+pub use self::via::*;
+
 use anyhow::Context;
-use byteorder::{ByteOrder, NativeEndian};
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 
 use crate::{
     constants::*,
-    nlas::{self, DefaultNla, NlaBuffer},
-    parsers::{parse_u16, parse_u32},
-    traits::Parseable,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u8},
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
-#[cfg(feature = "rich_nlas")]
-use crate::traits::Emitable;
-
 /// Netlink attributes for `RTM_NEWROUTE`, `RTM_DELROUTE`,
 /// `RTM_GETROUTE` messages.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Nla {
-    #[cfg(not(feature = "rich_nlas"))]
-    Metrics(Vec<u8>),
-    #[cfg(feature = "rich_nlas")]
-    Metrics(Metrics),
-    #[cfg(not(feature = "rich_nlas"))]
-    MfcStats(Vec<u8>),
-    #[cfg(feature = "rich_nlas")]
+    Metrics(Vec<Metrics>),
     MfcStats(MfcStats),
-    #[cfg(not(feature = "rich_nlas"))]
-    MultiPath(Vec<u8>),
-    #[cfg(feature = "rich_nlas")]
     // See: https://codecave.cc/multipath-routing-in-linux-part-1.html
     MultiPath(Vec<NextHop>),
-    #[cfg(not(feature = "rich_nlas"))]
-    CacheInfo(Vec<u8>),
-    #[cfg(feature = "rich_nlas")]
     CacheInfo(CacheInfo),
     Unspec(Vec<u8>),
     Destination(Vec<u8>),
@@ -54,14 +51,28 @@ pub enum Nla {
     PrefSource(Vec<u8>),
     Session(Vec<u8>),
     MpAlgo(Vec<u8>),
-    Via(Vec<u8>),
-    NewDestination(Vec<u8>),
+    /// Gateway, possibly of another family than the route
+    Via(RouteVia),
+    /// Labels pushed by a MPLS route, the outermost one first. An empty stack
+    /// pops the label.
+    NewDestination(Vec<MplsLabel>),
     Pref(Vec<u8>),
-    Encap(Vec<u8>),
+    /// Lightweight tunnel encapsulation, whose type is given by the
+    /// `EncapType` attribute
+    Encap(Encap),
     Expires(Vec<u8>),
     Pad(Vec<u8>),
-    Uid(Vec<u8>),
-    TtlPropagate(Vec<u8>),
+    /// User the route lookup is done for
+    Uid(u32),
+    /// IP protocol of the route lookup, like `IPPROTO_TCP`
+    IpProto(u8),
+    /// Source port of the route lookup
+    SourcePort(u16),
+    /// Destination port of the route lookup
+    DestinationPort(u16),
+    /// Whether MPLS routes propagate the TTL of the label to the IP header
+    /// when popping it (1), or not (0)
+    TtlPropagate(u8),
     EncapType(u16),
     Iif(u32),
     Oif(u32),
@@ -70,6 +81,7 @@ pub enum Nla {
     Flow(u32),
     Table(u32),
     Mark(u32),
+    NhId(u32),
     Other(DefaultNla),
 }
 
@@ -85,33 +97,22 @@ impl nlas::Nla for Nla {
                 | PrefSource(ref bytes)
                 | Session(ref bytes)
                 | MpAlgo(ref bytes)
-                | Via(ref bytes)
-                | NewDestination(ref bytes)
                 | Pref(ref bytes)
-                | Encap(ref bytes)
                 | Expires(ref bytes)
                 | Pad(ref bytes)
-                | Uid(ref bytes)
-                | TtlPropagate(ref bytes)
                 => bytes.len(),
 
-            #[cfg(not(feature = "rich_nlas"))]
-            CacheInfo(ref bytes)
-                | MfcStats(ref bytes)
-                | Metrics(ref bytes)
-                | MultiPath(ref bytes)
-                => bytes.len(),
+            Encap(ref encap) => encap.buffer_len(),
+            Via(ref via) => via.buffer_len(),
+            NewDestination(ref labels) => labels.len() * MPLS_LABEL_LEN,
 
-            #[cfg(feature = "rich_nlas")]
             CacheInfo(ref cache_info) => cache_info.buffer_len(),
-            #[cfg(feature = "rich_nlas")]
             MfcStats(ref stats) => stats.buffer_len(),
-            #[cfg(feature = "rich_nlas")]
-            Metrics(ref metrics) => metrics.buffer_len(),
-            #[cfg(feature = "rich_nlas")]
+            Metrics(ref metrics) => metrics.as_slice().buffer_len(),
             MultiPath(ref next_hops) => next_hops.iter().map(|nh| nh.buffer_len()).sum(),
 
-            EncapType(_) => 2,
+            TtlPropagate(_) | IpProto(_) => 1,
+            EncapType(_) | SourcePort(_) | DestinationPort(_) => 2,
             Iif(_)
                 | Oif(_)
                 | Priority(_)
@@ -119,6 +120,8 @@ impl nlas::Nla for Nla {
                 | Flow(_)
                 | Table(_)
                 | Mark(_)
+                | NhId(_)
+                | Uid(_)
                 => 4,
 
             Other(ref attr) => attr.value_len(),
@@ -136,30 +139,18 @@ impl nlas::Nla for Nla {
                 | PrefSource(ref bytes)
                 | Session(ref bytes)
                 | MpAlgo(ref bytes)
-                | Via(ref bytes)
-                | NewDestination(ref bytes)
                 | Pref(ref bytes)
-                | Encap(ref bytes)
                 | Expires(ref bytes)
                 | Pad(ref bytes)
-                | Uid(ref bytes)
-                | TtlPropagate(ref bytes)
                 => buffer.copy_from_slice(bytes.as_slice()),
 
-            #[cfg(not(feature = "rich_nlas"))]
-                MultiPath(ref bytes)
-                | CacheInfo(ref bytes)
-                | MfcStats(ref bytes)
-                | Metrics(ref bytes)
-                => buffer.copy_from_slice(bytes.as_slice()),
+            Encap(ref encap) => encap.emit(buffer),
+            Via(ref via) => via.emit(buffer),
+            NewDestination(ref labels) => MplsLabel::emit_stack(labels, buffer),
 
-            #[cfg(feature = "rich_nlas")]
             CacheInfo(ref cache_info) => cache_info.emit(buffer),
-            #[cfg(feature = "rich_nlas")]
             MfcStats(ref stats) => stats.emit(buffer),
-            #[cfg(feature = "rich_nlas")]
-            Metrics(ref metrics) => metrics.emit(buffer),
-            #[cfg(feature = "rich_nlas")]
+            Metrics(ref metrics) => metrics.as_slice().emit(buffer),
             MultiPath(ref next_hops) => {
                 let mut offset = 0;
                 for nh in next_hops {
@@ -169,7 +160,11 @@ impl nlas::Nla for Nla {
                 }
             }
 
+            TtlPropagate(value) | IpProto(value) => buffer[0] = value,
             EncapType(value) => NativeEndian::write_u16(buffer, value),
+            SourcePort(value)
+                | DestinationPort(value)
+                => BigEndian::write_u16(buffer, value),
             Iif(value)
                 | Oif(value)
                 | Priority(value)
@@ -177,6 +172,8 @@ impl nlas::Nla for Nla {
                 | Flow(value)
                 | Table(value)
                 | Mark(value)
+                | NhId(value)
+                | Uid(value)
                 => NativeEndian::write_u32(buffer, value),
             Other(ref attr) => attr.emit_value(buffer),
         }
@@ -211,7 +208,11 @@ impl nlas::Nla for Nla {
             Expires(_) => RTA_EXPIRES,
             Pad(_) => RTA_PAD,
             Uid(_) => RTA_UID,
+            IpProto(_) => RTA_IP_PROTO,
+            SourcePort(_) => RTA_SPORT,
+            DestinationPort(_) => RTA_DPORT,
             TtlPropagate(_) => RTA_TTL_PROPAGATE,
+            NhId(_) => RTA_NH_ID,
             Other(ref attr) => attr.kind(),
         }
     }
@@ -219,6 +220,14 @@ impl nlas::Nla for Nla {
 
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Self::parse_with_param(buf, LWTUNNEL_ENCAP_NONE)
+    }
+}
+
+// The format of `RTA_ENCAP` depends on the `LWTUNNEL_ENCAP_*` type found in
+// `RTA_ENCAP_TYPE`, passed as parameter.
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> for Nla {
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, encap_type: u16) -> Result<Self, DecodeError> {
         use self::Nla::*;
 
         let payload = buf.value();
@@ -230,14 +239,24 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             RTA_PREFSRC => PrefSource(payload.to_vec()),
             RTA_SESSION => Session(payload.to_vec()),
             RTA_MP_ALGO => MpAlgo(payload.to_vec()),
-            RTA_VIA => Via(payload.to_vec()),
-            RTA_NEWDST => NewDestination(payload.to_vec()),
+            RTA_VIA => Via(RouteVia::parse(payload).context("invalid RTA_VIA value")?),
+            RTA_NEWDST => {
+                NewDestination(MplsLabel::parse_stack(payload).context("invalid RTA_NEWDST value")?)
+            }
             RTA_PREF => Pref(payload.to_vec()),
-            RTA_ENCAP => Encap(payload.to_vec()),
+            RTA_ENCAP => Encap(
+                lwtunnel::Encap::parse_with_param(buf, encap_type)
+                    .context("invalid RTA_ENCAP value")?,
+            ),
             RTA_EXPIRES => Expires(payload.to_vec()),
             RTA_PAD => Pad(payload.to_vec()),
-            RTA_UID => Uid(payload.to_vec()),
-            RTA_TTL_PROPAGATE => TtlPropagate(payload.to_vec()),
+            RTA_UID => Uid(parse_u32(payload).context("invalid RTA_UID value")?),
+            RTA_IP_PROTO => IpProto(parse_u8(payload).context("invalid RTA_IP_PROTO value")?),
+            RTA_SPORT => SourcePort(parse_u16_be(payload).context("invalid RTA_SPORT value")?),
+            RTA_DPORT => DestinationPort(parse_u16_be(payload).context("invalid RTA_DPORT value")?),
+            RTA_TTL_PROPAGATE => {
+                TtlPropagate(parse_u8(payload).context("invalid RTA_TTL_PROPAGATE value")?)
+            }
             RTA_ENCAP_TYPE => {
                 EncapType(parse_u16(payload).context("invalid RTA_ENCAP_TYPE value")?)
             }
@@ -250,10 +269,8 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             RTA_FLOW => Flow(parse_u32(payload).context("invalid RTA_FLOW value")?),
             RTA_TABLE => Table(parse_u32(payload).context("invalid RTA_TABLE value")?),
             RTA_MARK => Mark(parse_u32(payload).context("invalid RTA_MARK value")?),
+            RTA_NH_ID => NhId(parse_u32(payload).context("invalid RTA_NH_ID value")?),
 
-            #[cfg(not(feature = "rich_nlas"))]
-            RTA_CACHEINFO => CacheInfo(payload.to_vec()),
-            #[cfg(feature = "rich_nlas")]
             RTA_CACHEINFO => CacheInfo(
                 cache_info::CacheInfo::parse(
                     &CacheInfoBuffer::new_checked(payload)
@@ -261,39 +278,30 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
                 )
                 .context("invalid RTA_CACHEINFO value")?,
             ),
-            #[cfg(not(feature = "rich_nlas"))]
-            RTA_MFC_STATS => MfcStats(payload.to_vec()),
-            #[cfg(feature = "rich_nlas")]
             RTA_MFC_STATS => MfcStats(
                 mfc_stats::MfcStats::parse(
                     &MfcStatsBuffer::new_checked(payload).context("invalid RTA_MFC_STATS value")?,
                 )
                 .context("invalid RTA_MFC_STATS value")?,
             ),
-            #[cfg(not(feature = "rich_nlas"))]
-            RTA_METRICS => Metrics(payload.to_vec()),
-            #[cfg(feature = "rich_nlas")]
-            RTA_METRICS => Metrics(
-                metrics::Metrics::parse(
-                    &NlaBuffer::new_checked(payload).context("invalid RTA_METRICS value")?,
-                )
-                .context("invalid RTA_METRICS value")?,
-            ),
-            #[cfg(not(feature = "rich_nlas"))]
-            RTA_MULTIPATH => MultiPath(payload.to_vec()),
-            #[cfg(feature = "rich_nlas")]
+            RTA_METRICS => {
+                let err = "invalid RTA_METRICS value";
+                let mut metrics = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err)?;
+                    metrics.push(metrics::Metrics::parse(nla).context(err)?);
+                }
+                Metrics(metrics)
+            }
             RTA_MULTIPATH => {
                 let mut next_hops = vec![];
                 let mut buf = payload;
-                loop {
+                while !buf.is_empty() {
                     let nh_buf =
                         NextHopBuffer::new_checked(&buf).context("invalid RTA_MULTIPATH value")?;
                     let len = nh_buf.length() as usize;
                     let nh = NextHop::parse(&nh_buf).context("invalid RTA_MULTIPATH value")?;
                     next_hops.push(nh);
-                    if buf.len() == len {
-                        break;
-                    }
                     buf = &buf[len..];
                 }
                 MultiPath(next_hops)
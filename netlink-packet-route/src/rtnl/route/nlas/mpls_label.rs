@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::{traits::Emitable, DecodeError};
+
+pub const MPLS_LABEL_LEN: usize = 4;
+
+/// A MPLS label stack entry. Label stacks are found in `MPLS_IPTUNNEL_DST`
+/// encapsulation attributes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct MplsLabel {
+    /// Label value (20 bits)
+    pub label: u32,
+    /// Traffic class (3 bits)
+    pub traffic_class: u8,
+    /// Whether this is the last entry of the stack
+    pub bottom_of_stack: bool,
+    pub ttl: u8,
+}
+
+impl MplsLabel {
+    /// Create a label stack entry with the given label value, and all the
+    /// other fields cleared
+    pub fn new(label: u32) -> Self {
+        MplsLabel {
+            label,
+            ..Default::default()
+        }
+    }
+
+    /// Create a label stack out of label values. The kernel requires the
+    /// bottom of stack flag to be set on the last entry only, and the
+    /// traffic class and TTL to be cleared.
+    pub fn stack(labels: &[u32]) -> Vec<Self> {
+        let mut stack: Vec<Self> = labels.iter().map(|label| Self::new(*label)).collect();
+        if let Some(last) = stack.last_mut() {
+            last.bottom_of_stack = true;
+        }
+        stack
+    }
+
+    /// Parse a label stack
+    pub fn parse_stack(payload: &[u8]) -> Result<Vec<Self>, DecodeError> {
+        let chunks = payload.chunks_exact(MPLS_LABEL_LEN);
+        if !chunks.remainder().is_empty() {
+            return Err(format!("invalid MPLS label stack: {:?}", payload).into());
+        }
+        Ok(chunks
+            .map(|chunk| {
+                let entry = BigEndian::read_u32(chunk);
+                MplsLabel {
+                    label: entry >> 12,
+                    traffic_class: ((entry >> 9) & 0x7) as u8,
+                    bottom_of_stack: entry & 0x100 != 0,
+                    ttl: (entry & 0xff) as u8,
+                }
+            })
+            .collect())
+    }
+
+    pub(crate) fn emit_stack(stack: &[Self], buffer: &mut [u8]) {
+        for (entry, chunk) in stack.iter().zip(buffer.chunks_mut(MPLS_LABEL_LEN)) {
+            entry.emit(chunk);
+        }
+    }
+}
+
+impl Emitable for MplsLabel {
+    fn buffer_len(&self) -> usize {
+        MPLS_LABEL_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let entry = (self.label & 0xf_ffff) << 12
+            | u32::from(self.traffic_class & 0x7) << 9
+            | u32::from(self.bottom_of_stack) << 8
+            | u32::from(self.ttl);
+        BigEndian::write_u32(buffer, entry);
+    }
+}
@@ -1,25 +1,27 @@
 use anyhow::Context;
 use std::net::IpAddr;
 
+use super::Nla;
 use crate::{
-    constants::{self, RTA_GATEWAY},
+    constants::{self, LWTUNNEL_ENCAP_NONE, RTA_ENCAP_TYPE, RTA_GATEWAY},
     emit_ip,
     ip_len,
-    nlas::{Nla, NlaBuffer},
-    parsers::parse_ip,
-    traits::{Emitable, Parseable},
+    nlas::{self, NlaBuffer, NlasIterator},
+    parsers::{parse_ip, parse_u16},
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
 bitflags! {
+    #[derive(Default)]
     pub struct NextHopFlags: u8 {
         const RTNH_F_EMPTY = 0;
-        const RTNH_F_DEAD = constants::RTNH_F_DEAD as u8;
-        const RTNH_F_PERVASIVE = constants::RTNH_F_PERVASIVE as u8;
-        const RTNH_F_ONLINK = constants::RTNH_F_ONLINK as u8;
-        const RTNH_F_OFFLOAD = constants::RTNH_F_OFFLOAD as u8;
-        const RTNH_F_LINKDOWN = constants::RTNH_F_LINKDOWN as u8;
-        const RTNH_F_UNRESOLVED = constants::RTNH_F_UNRESOLVED as u8;
+        const RTNH_F_DEAD = constants::RTNH_F_DEAD;
+        const RTNH_F_PERVASIVE = constants::RTNH_F_PERVASIVE;
+        const RTNH_F_ONLINK = constants::RTNH_F_ONLINK;
+        const RTNH_F_OFFLOAD = constants::RTNH_F_OFFLOAD;
+        const RTNH_F_LINKDOWN = constants::RTNH_F_LINKDOWN;
+        const RTNH_F_UNRESOLVED = constants::RTNH_F_UNRESOLVED;
     }
 }
 
@@ -28,7 +30,7 @@ buffer!(NextHopBuffer {
     flags: (u8, 2),
     hops: (u8, 3),
     interface_id: (u32, 4..8),
-    gateway_nla: (slice, GATEWAY_OFFSET..),
+    payload: (slice, PAYLOAD_OFFSET..),
 });
 
 impl<T: AsRef<[u8]>> NextHopBuffer<T> {
@@ -40,62 +42,99 @@ impl<T: AsRef<[u8]>> NextHopBuffer<T> {
 
     fn check_buffer_length(&self) -> Result<(), DecodeError> {
         let len = self.buffer.as_ref().len();
-        if len < 8 {
-            return Err(format!("invalid NextHopBuffer: length {} < {}", len, 8).into());
+        if len < PAYLOAD_OFFSET {
+            return Err(
+                format!("invalid NextHopBuffer: length {} < {}", len, PAYLOAD_OFFSET).into(),
+            );
         }
-        if len < self.length() as usize {
+        if (self.length() as usize) < PAYLOAD_OFFSET {
             return Err(format!(
-                "invalid NextHopBuffer: length {} < {}",
-                len,
-                8 + self.length()
+                "invalid NextHopBuffer: length field {} < {}",
+                self.length(),
+                PAYLOAD_OFFSET
             )
             .into());
         }
+        if len < self.length() as usize {
+            return Err(
+                format!("invalid NextHopBuffer: length {} < {}", len, self.length()).into(),
+            );
+        }
         Ok(())
     }
 }
 
-const GATEWAY_OFFSET: usize = 8;
+impl<'a, T: AsRef<[u8]> + ?Sized> NextHopBuffer<&'a T> {
+    /// Return an iterator over the attributes of the next-hop
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        let len = self.length() as usize - PAYLOAD_OFFSET;
+        NlasIterator::new(&self.payload()[..len])
+    }
+}
+
+const PAYLOAD_OFFSET: usize = 8;
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// A next-hop of a multipath route (`struct rtnexthop`)
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
 pub struct NextHop {
     /// Next-hop flags (see [`NextHopFlags`])
     pub flags: NextHopFlags,
-    /// Next-hop priority
+    /// Weight of the next-hop, minus one
     pub hops: u8,
     /// Interface index for the next-hop
     pub interface_id: u32,
     /// Gateway address (it is actually encoded as an `RTA_GATEWAY` nla)
     pub gateway: Option<IpAddr>,
+    /// Other attributes of the next-hop, like `Nla::Via` and
+    /// `Nla::NewDestination` for MPLS routes, or `Nla::Encap` and
+    /// `Nla::EncapType`
+    pub nlas: Vec<Nla>,
 }
 
-impl<'a, T: AsRef<[u8]>> Parseable<NextHopBuffer<&'a T>> for NextHop {
-    fn parse(buf: &NextHopBuffer<&T>) -> Result<NextHop, DecodeError> {
-        let gateway = if buf.length() as usize > GATEWAY_OFFSET {
-            let gateway_nla_buf = NlaBuffer::new_checked(buf.gateway_nla())
-                .context("cannot parse RTA_GATEWAY attribute in next-hop")?;
-            if gateway_nla_buf.kind() != RTA_GATEWAY {
-                return Err(format!("invalid RTA_GATEWAY attribute in next-hop: expected NLA type to be RTA_GATEWAY ({}), but got {} instead", RTA_GATEWAY, gateway_nla_buf.kind()).into());
+impl NextHop {
+    /// Return the weight of the next-hop, between 1 and 256
+    pub fn weight(&self) -> u16 {
+        u16::from(self.hops) + 1
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NextHopBuffer<&'a T>> for NextHop {
+    fn parse(buf: &NextHopBuffer<&'a T>) -> Result<NextHop, DecodeError> {
+        // Like for routes, the encapsulation of a next-hop comes before its
+        // type.
+        let mut encap_type = LWTUNNEL_ENCAP_NONE;
+        for nla_buf in buf.nlas() {
+            let nla_buf = nla_buf.context("invalid next-hop attribute")?;
+            if nla_buf.kind() == RTA_ENCAP_TYPE {
+                encap_type = parse_u16(nla_buf.value()).context("invalid RTA_ENCAP_TYPE value")?;
+            }
+        }
+        let mut gateway = None;
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            let nla_buf = nla_buf.context("invalid next-hop attribute")?;
+            if nla_buf.kind() == RTA_GATEWAY {
+                gateway = Some(
+                    parse_ip(nla_buf.value())
+                        .context("invalid RTA_GATEWAY attribute in next-hop")?,
+                );
+            } else {
+                nlas.push(Nla::parse_with_param(&nla_buf, encap_type)?);
             }
-            let gateway = parse_ip(gateway_nla_buf.value()).context(
-                "invalid RTA_GATEWAY attribute in next-hop: failed to parse NLA value as an IP address",
-            )?;
-            Some(gateway)
-        } else {
-            None
-        };
+        }
         Ok(NextHop {
             flags: NextHopFlags::from_bits_truncate(buf.flags()),
             hops: buf.hops(),
             interface_id: buf.interface_id(),
             gateway,
+            nlas,
         })
     }
 }
 
 struct GatewayNla<'a>(&'a IpAddr);
 
-impl<'a> Nla for GatewayNla<'a> {
+impl<'a> nlas::Nla for GatewayNla<'a> {
     fn value_len(&self) -> usize {
         ip_len(self.0)
     }
@@ -107,11 +146,9 @@ impl<'a> Nla for GatewayNla<'a> {
     }
 }
 
-impl Emitable for NextHop {
-    fn buffer_len(&self) -> usize {
-        // len, flags, hops and interface id fields
-        8 + self
-            .gateway
+impl NextHop {
+    fn gateway_len(&self) -> usize {
+        self.gateway
             .as_ref()
             .map(|ip| {
                 // RTA_GATEWAY attribute header (length and type) + value length
@@ -119,6 +156,13 @@ impl Emitable for NextHop {
             })
             .unwrap_or(0)
     }
+}
+
+impl Emitable for NextHop {
+    fn buffer_len(&self) -> usize {
+        // len, flags, hops and interface id fields
+        PAYLOAD_OFFSET + self.gateway_len() + self.nlas.as_slice().buffer_len()
+    }
 
     fn emit(&self, buffer: &mut [u8]) {
         let mut nh_buffer = NextHopBuffer::new(buffer);
@@ -128,7 +172,10 @@ impl Emitable for NextHop {
         nh_buffer.set_interface_id(self.interface_id);
         if let Some(ref gateway) = self.gateway {
             let gateway_nla = GatewayNla(gateway);
-            gateway_nla.emit(nh_buffer.gateway_nla_mut());
+            gateway_nla.emit(nh_buffer.payload_mut());
         }
+        self.nlas
+            .as_slice()
+            .emit(&mut nh_buffer.payload_mut()[self.gateway_len()..]);
     }
 }
@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_ipv6, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const SRH_FIXED_LEN: usize = 8;
+const SEGMENT_LEN: usize = 16;
+
+/// An IPv6 Segment Routing Header (`struct ipv6_sr_hdr`)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Ipv6SrHeader {
+    /// Type of the header following this one. The kernel overrides it when
+    /// it inserts the header.
+    pub next_header: u8,
+    /// Index of the next segment to visit in `segments`
+    pub segments_left: u8,
+    pub flags: u8,
+    pub tag: u16,
+    /// Segments of the path, in the order of the header: the last segment
+    /// to visit comes first
+    pub segments: Vec<Ipv6Addr>,
+    /// Raw type-length-value objects following the segments
+    pub tlvs: Vec<u8>,
+}
+
+impl Ipv6SrHeader {
+    /// Create a header visiting the given segments, in that order
+    pub fn new(segments: &[Ipv6Addr]) -> Self {
+        Ipv6SrHeader {
+            segments_left: segments.len().saturating_sub(1) as u8,
+            segments: segments.iter().rev().cloned().collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Emitable for Ipv6SrHeader {
+    fn buffer_len(&self) -> usize {
+        SRH_FIXED_LEN + self.segments.len() * SEGMENT_LEN + self.tlvs.len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[0] = self.next_header;
+        // Length of the header in 8 octets units, not including the first 8
+        // octets
+        buffer[1] = ((self.buffer_len() - SRH_FIXED_LEN) / 8) as u8;
+        buffer[2] = IPV6_SRCRT_TYPE_4;
+        buffer[3] = self.segments_left;
+        buffer[4] = self.segments.len().saturating_sub(1) as u8;
+        buffer[5] = self.flags;
+        BigEndian::write_u16(&mut buffer[6..8], self.tag);
+        let mut offset = SRH_FIXED_LEN;
+        for segment in &self.segments {
+            buffer[offset..offset + SEGMENT_LEN].copy_from_slice(&segment.octets());
+            offset += SEGMENT_LEN;
+        }
+        buffer[offset..offset + self.tlvs.len()].copy_from_slice(self.tlvs.as_slice());
+    }
+}
+
+impl Parseable<[u8]> for Ipv6SrHeader {
+    fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        if buf.len() < SRH_FIXED_LEN {
+            return Err(format!("invalid segment routing header: {:?}", buf).into());
+        }
+        let len = (buf[1] as usize + 1) * 8;
+        let segments_len = (buf[4] as usize + 1) * SEGMENT_LEN;
+        if buf.len() < len || len < SRH_FIXED_LEN + segments_len {
+            return Err(format!("invalid segment routing header: {:?}", buf).into());
+        }
+        let segments = buf[SRH_FIXED_LEN..SRH_FIXED_LEN + segments_len]
+            .chunks(SEGMENT_LEN)
+            .map(|chunk| parse_ipv6(chunk).map(Ipv6Addr::from))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Ipv6SrHeader {
+            next_header: buf[0],
+            segments_left: buf[3],
+            flags: buf[5],
+            tag: BigEndian::read_u16(&buf[6..8]),
+            segments,
+            tlvs: buf[SRH_FIXED_LEN + segments_len..len].to_vec(),
+        })
+    }
+}
+
+/// Segment routing encapsulation (`struct seg6_iptunnel_encap`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Seg6Encap {
+    /// One of the `SEG6_IPTUN_MODE_*` constants
+    pub mode: u32,
+    pub header: Ipv6SrHeader,
+}
+
+impl Emitable for Seg6Encap {
+    fn buffer_len(&self) -> usize {
+        4 + self.header.buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(buffer, self.mode);
+        self.header.emit(&mut buffer[4..]);
+    }
+}
+
+impl Parseable<[u8]> for Seg6Encap {
+    fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        if buf.len() < 4 {
+            return Err(format!("invalid segment routing encapsulation: {:?}", buf).into());
+        }
+        Ok(Seg6Encap {
+            mode: NativeEndian::read_u32(buf),
+            header: Ipv6SrHeader::parse(&buf[4..])?,
+        })
+    }
+}
+
+/// Attributes of a `LWTUNNEL_ENCAP_SEG6` encapsulation
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Seg6IpTunnel {
+    Srh(Seg6Encap),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Seg6IpTunnel {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Srh(encap) => encap.buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Srh(encap) => encap.emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Srh(_) => SEG6_IPTUNNEL_SRH,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Seg6IpTunnel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_IPTUNNEL_SRH => {
+                Self::Srh(Seg6Encap::parse(payload).context("invalid SEG6_IPTUNNEL_SRH value")?)
+            }
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
+
+/// Attributes of a `LWTUNNEL_ENCAP_SEG6_LOCAL` encapsulation
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Seg6Local {
+    /// One of the `SEG6_LOCAL_ACTION_*` constants
+    Action(u32),
+    Srh(Ipv6SrHeader),
+    Table(u32),
+    Nh4(Ipv4Addr),
+    Nh6(Ipv6Addr),
+    Iif(u32),
+    Oif(u32),
+    VrfTable(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Seg6Local {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Seg6Local::*;
+        match self {
+            Action(_)
+                | Table(_)
+                | Iif(_)
+                | Oif(_)
+                | VrfTable(_)
+                => 4,
+            Srh(header) => header.buffer_len(),
+            Nh4(_) => 4,
+            Nh6(_) => 16,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Seg6Local::*;
+        match self {
+            Action(value)
+                | Table(value)
+                | Iif(value)
+                | Oif(value)
+                | VrfTable(value)
+                => NativeEndian::write_u32(buffer, *value),
+            Srh(header) => header.emit(buffer),
+            Nh4(addr) => buffer.copy_from_slice(&addr.octets()),
+            Nh6(addr) => buffer.copy_from_slice(&addr.octets()),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Seg6Local::*;
+        match self {
+            Action(_) => SEG6_LOCAL_ACTION,
+            Srh(_) => SEG6_LOCAL_SRH,
+            Table(_) => SEG6_LOCAL_TABLE,
+            Nh4(_) => SEG6_LOCAL_NH4,
+            Nh6(_) => SEG6_LOCAL_NH6,
+            Iif(_) => SEG6_LOCAL_IIF,
+            Oif(_) => SEG6_LOCAL_OIF,
+            VrfTable(_) => SEG6_LOCAL_VRFTABLE,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Seg6Local {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Seg6Local::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_ACTION => {
+                Action(parse_u32(payload).context("invalid SEG6_LOCAL_ACTION value")?)
+            }
+            SEG6_LOCAL_SRH => {
+                Srh(Ipv6SrHeader::parse(payload).context("invalid SEG6_LOCAL_SRH value")?)
+            }
+            SEG6_LOCAL_TABLE => {
+                Table(parse_u32(payload).context("invalid SEG6_LOCAL_TABLE value")?)
+            }
+            SEG6_LOCAL_NH4 => {
+                if payload.len() != 4 {
+                    return Err(format!("invalid SEG6_LOCAL_NH4 value: {:?}", payload).into());
+                }
+                Nh4(Ipv4Addr::new(
+                    payload[0], payload[1], payload[2], payload[3],
+                ))
+            }
+            SEG6_LOCAL_NH6 => Nh6(parse_ipv6(payload)
+                .context("invalid SEG6_LOCAL_NH6 value")?
+                .into()),
+            SEG6_LOCAL_IIF => Iif(parse_u32(payload).context("invalid SEG6_LOCAL_IIF value")?),
+            SEG6_LOCAL_OIF => Oif(parse_u32(payload).context("invalid SEG6_LOCAL_OIF value")?),
+            SEG6_LOCAL_VRFTABLE => {
+                VrfTable(parse_u32(payload).context("invalid SEG6_LOCAL_VRFTABLE value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::{AF_INET, AF_INET6},
+    parsers::parse_ipv6,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Gateway of a route whose family may differ from the family of the route
+/// (`RTA_VIA`), like the IP next hop of a MPLS route
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RouteVia {
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr),
+    /// Address of another family, like `AF_PACKET` for link layer addresses,
+    /// or IP address whose length does not match its family
+    Other {
+        family: u16,
+        address: Vec<u8>,
+    },
+}
+
+impl Emitable for RouteVia {
+    fn buffer_len(&self) -> usize {
+        2 + match self {
+            Self::Ipv4(_) => 4,
+            Self::Ipv6(_) => 16,
+            Self::Other { address, .. } => address.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ipv4(addr) => {
+                NativeEndian::write_u16(buffer, AF_INET);
+                buffer[2..].copy_from_slice(&addr.octets());
+            }
+            Self::Ipv6(addr) => {
+                NativeEndian::write_u16(buffer, AF_INET6);
+                buffer[2..].copy_from_slice(&addr.octets());
+            }
+            Self::Other { family, address } => {
+                NativeEndian::write_u16(buffer, *family);
+                buffer[2..].copy_from_slice(address.as_slice());
+            }
+        }
+    }
+}
+
+impl Parseable<[u8]> for RouteVia {
+    fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        if buf.len() < 2 {
+            return Err(format!("invalid route gateway: {:?}", buf).into());
+        }
+        let family = NativeEndian::read_u16(buf);
+        let address = &buf[2..];
+        Ok(match family {
+            AF_INET if address.len() == 4 => Self::Ipv4(Ipv4Addr::new(
+                address[0], address[1], address[2], address[3],
+            )),
+            AF_INET6 if address.len() == 16 => Self::Ipv6(parse_ipv6(address)?.into()),
+            _ => Self::Other {
+                family,
+                address: address.to_vec(),
+            },
+        })
+    }
+}
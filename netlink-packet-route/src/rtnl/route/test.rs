@@ -1,10 +1,10 @@
 // SPDX-License-Identifier: MIT
 
-#[cfg(feature = "rich_nlas")]
-mod test_rich_nlas {
+mod test_multipath {
     use crate::{
+        constants::LWTUNNEL_ENCAP_MPLS,
         rtnl::route::{
-            nlas::{NextHop, NextHopFlags, Nla},
+            nlas::{Encap, MplsIpTunnel, MplsLabel, NextHop, NextHopFlags, Nla},
             RouteFlags,
             RouteMessage,
             RouteMessageBuffer,
@@ -83,18 +83,21 @@ mod test_rich_nlas {
                     hops: 0,
                     interface_id: 0,
                     gateway: Some("fc00::1".parse().unwrap()),
+                    nlas: vec![],
                 },
                 NextHop {
                     flags: NextHopFlags::empty(),
                     hops: 0,
                     interface_id: 0,
                     gateway: Some("fc01::1".parse().unwrap()),
+                    nlas: vec![],
                 },
                 NextHop {
                     flags: NextHopFlags::empty(),
                     hops: 0,
                     interface_id: 2,
                     gateway: None,
+                    nlas: vec![],
                 },
             ]),
         ];
@@ -118,4 +121,601 @@ mod test_rich_nlas {
         msg.emit(&mut buf[..]);
         assert_eq!(buf, ROUTE_MSG);
     }
+
+    #[rustfmt::skip]
+    static WEIGHTED_ROUTE_MSG: [u8; 76] = [
+        0x02, // address family
+        0x18, // length of destination
+        0x00, // length of source
+        0x00, // TOS
+        0xfe, // routing table id
+        0x04, // routing protocol (static)
+        0x00, // route origin (global)
+        0x01, // gateway or direct route
+        0x00, 0x00, 0x00, 0x00,
+
+            // Route destination address NLA
+            0x08, 0x00, // Length (8)
+            0x01, 0x00, // Type
+            0x0a, 0x02, 0x00, 0x00, // 10.2.0.0
+
+            // RTA_MULTIPATH attribute
+            0x38, 0x00, // Length (56)
+            0x09, 0x00, // Type
+
+                // next-hop 1
+                0x10, 0x00, // length (16)
+                0x04, // flags (onlink)
+                0x01, // hops (weight 2)
+                0x02, 0x00, 0x00, 0x00, // interface ID
+                    // nested RTA_GATEWAY
+                    0x08, 0x00, // Length (8)
+                    0x05, 0x00, // Type
+                    0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+
+                // next-hop 2
+                0x24, 0x00, // length (36)
+                0x00, // flags
+                0x00, // hops (weight 1)
+                0x03, 0x00, 0x00, 0x00, // interface ID
+                    // nested RTA_GATEWAY
+                    0x08, 0x00, // Length (8)
+                    0x05, 0x00, // Type
+                    0xc0, 0x00, 0x02, 0x02, // 192.0.2.2
+                    // nested RTA_ENCAP
+                    0x0c, 0x00, // Length (12)
+                    0x16, 0x00, // Type
+                        0x08, 0x00, 0x01, 0x00, // MPLS_IPTUNNEL_DST
+                        0x00, 0x06, 0x41, 0x00, // label 100, bottom of stack
+                    // nested RTA_ENCAP_TYPE
+                    0x06, 0x00, // Length (6)
+                    0x15, 0x00, // Type
+                    0x01, 0x00, 0x00, 0x00, // LWTUNNEL_ENCAP_MPLS + padding
+    ];
+
+    fn weighted_route_message() -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = 0x02;
+        msg.header.destination_prefix_length = 0x18;
+        msg.header.table = 0xfe;
+        msg.header.protocol = 0x04;
+        msg.header.kind = 0x01;
+        msg.nlas = vec![
+            Nla::Destination(vec![10, 2, 0, 0]),
+            Nla::MultiPath(vec![
+                NextHop {
+                    flags: NextHopFlags::RTNH_F_ONLINK,
+                    hops: 1,
+                    interface_id: 2,
+                    gateway: Some("192.0.2.1".parse().unwrap()),
+                    nlas: vec![],
+                },
+                NextHop {
+                    flags: NextHopFlags::empty(),
+                    hops: 0,
+                    interface_id: 3,
+                    gateway: Some("192.0.2.2".parse().unwrap()),
+                    nlas: vec![
+                        Nla::Encap(Encap::Mpls(vec![MplsIpTunnel::Destination(
+                            MplsLabel::stack(&[100]),
+                        )])),
+                        Nla::EncapType(LWTUNNEL_ENCAP_MPLS),
+                    ],
+                },
+            ]),
+        ];
+        msg
+    }
+
+    #[test]
+    fn parse_message_with_weighted_multipath_nla() {
+        let expected = weighted_route_message();
+        let actual = RouteMessage::parse(
+            &RouteMessageBuffer::new_checked(&&WEIGHTED_ROUTE_MSG[..]).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn emit_message_with_weighted_multipath_nla() {
+        let msg = weighted_route_message();
+        let mut buf = vec![0; 76];
+        assert_eq!(msg.buffer_len(), 76);
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, WEIGHTED_ROUTE_MSG);
+    }
+}
+
+mod test_encap {
+    use crate::{
+        constants::*,
+        rtnl::route::{
+            nlas::{
+                Encap,
+                Ipv6SrHeader,
+                MplsIpTunnel,
+                MplsLabel,
+                Nla,
+                Seg6Encap,
+                Seg6IpTunnel,
+                Seg6Local,
+            },
+            RouteMessage,
+            RouteMessageBuffer,
+        },
+        utils::{Emitable, Parseable},
+    };
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    fn route_message(address_family: u8, destination_prefix_length: u8) -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = address_family;
+        msg.header.destination_prefix_length = destination_prefix_length;
+        msg.header.table = RT_TABLE_MAIN;
+        msg.header.protocol = RTPROT_STATIC;
+        msg.header.scope = RT_SCOPE_UNIVERSE;
+        msg.header.kind = RTN_UNICAST;
+        msg
+    }
+
+    fn check(data: &[u8], expected: RouteMessage) {
+        let actual = RouteMessage::parse(&RouteMessageBuffer::new_checked(&data).unwrap()).unwrap();
+        assert_eq!(actual, expected);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf[..]);
+        assert_eq!(&buf[..], data);
+    }
+
+    #[rustfmt::skip]
+    static MPLS_ENCAP_ROUTE_MSG: [u8; 52] = [
+        0x02, // address family = AF_INET
+        0x10, // length of destination = 16
+        0x00, // length of source
+        0x00, // TOS
+        0xfe, // routing table id = RT_TABLE_MAIN
+        0x04, // routing protocol = RTPROT_STATIC
+        0x00, // scope = RT_SCOPE_UNIVERSE
+        0x01, // type = RTN_UNICAST
+        0x00, 0x00, 0x00, 0x00, // flags
+
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = RTA_DST
+        0x0a, 0x01, 0x00, 0x00, // 10.1.0.0
+
+        // encapsulation comes before its type, like in kernel messages
+        0x10, 0x00, 0x16, 0x00, // length = 16, type = RTA_ENCAP
+            0x0c, 0x00, 0x01, 0x00, // length = 12, type = MPLS_IPTUNNEL_DST
+            0x00, 0x06, 0x40, 0x00, // label 100
+            0x00, 0x0c, 0x81, 0x00, // label 200, bottom of stack
+
+        0x06, 0x00, 0x15, 0x00, // length = 6, type = RTA_ENCAP_TYPE
+        0x01, 0x00, 0x00, 0x00, // LWTUNNEL_ENCAP_MPLS + padding
+
+        0x08, 0x00, 0x04, 0x00, // length = 8, type = RTA_OIF
+        0x02, 0x00, 0x00, 0x00, // 2
+    ];
+
+    #[test]
+    fn parse_emit_mpls_encap() {
+        let mut expected = route_message(AF_INET as u8, 16);
+        expected.nlas = vec![
+            Nla::Destination(Ipv4Addr::new(10, 1, 0, 0).octets().to_vec()),
+            Nla::Encap(Encap::Mpls(vec![MplsIpTunnel::Destination(
+                MplsLabel::stack(&[100, 200]),
+            )])),
+            Nla::EncapType(LWTUNNEL_ENCAP_MPLS),
+            Nla::Oif(2),
+        ];
+        check(&MPLS_ENCAP_ROUTE_MSG, expected);
+    }
+
+    #[rustfmt::skip]
+    static SEG6_ENCAP_ROUTE_MSG: [u8; 100] = [
+        0x0a, // address family = AF_INET6
+        0x40, // length of destination = 64
+        0x00, // length of source
+        0x00, // TOS
+        0xfe, // routing table id = RT_TABLE_MAIN
+        0x04, // routing protocol = RTPROT_STATIC
+        0x00, // scope = RT_SCOPE_UNIVERSE
+        0x01, // type = RTN_UNICAST
+        0x00, 0x00, 0x00, 0x00, // flags
+
+        0x14, 0x00, 0x01, 0x00, // length = 20, type = RTA_DST
+        0xfc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // fc00::
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+
+        0x34, 0x00, 0x16, 0x00, // length = 52, type = RTA_ENCAP
+            0x30, 0x00, 0x01, 0x00, // length = 48, type = SEG6_IPTUNNEL_SRH
+            0x01, 0x00, 0x00, 0x00, // mode = SEG6_IPTUN_MODE_ENCAP
+            // ipv6_sr_hdr
+            0x00, // next header
+            0x04, // header length = 4 * 8 octets
+            0x04, // routing type = IPV6_SRCRT_TYPE_4
+            0x01, // segments left = 1
+            0x01, // last entry = 1
+            0x00, // flags
+            0x00, 0x00, // tag
+            0xfc, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, // fc00:2::1
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+            0xfc, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, // fc00:1::1
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+
+        0x06, 0x00, 0x15, 0x00, // length = 6, type = RTA_ENCAP_TYPE
+        0x05, 0x00, 0x00, 0x00, // LWTUNNEL_ENCAP_SEG6 + padding
+
+        0x08, 0x00, 0x04, 0x00, // length = 8, type = RTA_OIF
+        0x03, 0x00, 0x00, 0x00, // 3
+    ];
+
+    #[test]
+    fn parse_emit_seg6_encap() {
+        let mut expected = route_message(AF_INET6 as u8, 64);
+        expected.nlas = vec![
+            Nla::Destination("fc00::".parse::<Ipv6Addr>().unwrap().octets().to_vec()),
+            Nla::Encap(Encap::Seg6(vec![Seg6IpTunnel::Srh(Seg6Encap {
+                mode: SEG6_IPTUN_MODE_ENCAP,
+                header: Ipv6SrHeader::new(&[
+                    "fc00:1::1".parse().unwrap(),
+                    "fc00:2::1".parse().unwrap(),
+                ]),
+            })])),
+            Nla::EncapType(LWTUNNEL_ENCAP_SEG6),
+            Nla::Oif(3),
+        ];
+        check(&SEG6_ENCAP_ROUTE_MSG, expected);
+    }
+
+    #[rustfmt::skip]
+    static SEG6LOCAL_ENCAP_ROUTE_MSG: [u8; 68] = [
+        0x0a, // address family = AF_INET6
+        0x40, // length of destination = 64
+        0x00, // length of source
+        0x00, // TOS
+        0xfe, // routing table id = RT_TABLE_MAIN
+        0x04, // routing protocol = RTPROT_STATIC
+        0x00, // scope = RT_SCOPE_UNIVERSE
+        0x01, // type = RTN_UNICAST
+        0x00, 0x00, 0x00, 0x00, // flags
+
+        0x14, 0x00, 0x01, 0x00, // length = 20, type = RTA_DST
+        0xfc, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, // fc00:3::
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+
+        0x14, 0x00, 0x16, 0x00, // length = 20, type = RTA_ENCAP
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = SEG6_LOCAL_ACTION
+            0x07, 0x00, 0x00, 0x00, // SEG6_LOCAL_ACTION_END_DT6
+            0x08, 0x00, 0x03, 0x00, // length = 8, type = SEG6_LOCAL_TABLE
+            0x64, 0x00, 0x00, 0x00, // 100
+
+        0x06, 0x00, 0x15, 0x00, // length = 6, type = RTA_ENCAP_TYPE
+        0x07, 0x00, 0x00, 0x00, // LWTUNNEL_ENCAP_SEG6_LOCAL + padding
+
+        0x08, 0x00, 0x04, 0x00, // length = 8, type = RTA_OIF
+        0x03, 0x00, 0x00, 0x00, // 3
+    ];
+
+    #[test]
+    fn parse_emit_seg6local_encap() {
+        let mut expected = route_message(AF_INET6 as u8, 64);
+        expected.nlas = vec![
+            Nla::Destination("fc00:3::".parse::<Ipv6Addr>().unwrap().octets().to_vec()),
+            Nla::Encap(Encap::Seg6Local(vec![
+                Seg6Local::Action(SEG6_LOCAL_ACTION_END_DT6),
+                Seg6Local::Table(100),
+            ])),
+            Nla::EncapType(LWTUNNEL_ENCAP_SEG6_LOCAL),
+            Nla::Oif(3),
+        ];
+        check(&SEG6LOCAL_ENCAP_ROUTE_MSG, expected);
+    }
+}
+
+mod test_mpls {
+    use crate::{
+        constants::*,
+        rtnl::route::{
+            nlas::{MplsLabel, Nla, RouteVia},
+            RouteMessage,
+            RouteMessageBuffer,
+        },
+        utils::{Emitable, Parseable},
+    };
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    #[rustfmt::skip]
+    static MPLS_ROUTE_MSG: [u8; 60] = [
+        0x1c, // address family = AF_MPLS
+        0x14, // length of destination = 20
+        0x00, // length of source
+        0x00, // TOS
+        0xfe, // routing table id = RT_TABLE_MAIN
+        0x04, // routing protocol = RTPROT_STATIC
+        0x00, // scope = RT_SCOPE_UNIVERSE
+        0x01, // type = RTN_UNICAST
+        0x00, 0x00, 0x00, 0x00, // flags
+
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = RTA_DST
+        0x00, 0x06, 0x41, 0x00, // label 100, bottom of stack
+
+        0x0a, 0x00, 0x12, 0x00, // length = 10, type = RTA_VIA
+        0x02, 0x00, // family = AF_INET
+        0x0a, 0x00, 0x00, 0x01, // 10.0.0.1
+        0x00, 0x00, // padding
+
+        0x0c, 0x00, 0x13, 0x00, // length = 12, type = RTA_NEWDST
+        0x00, 0x0c, 0x80, 0x00, // label 200
+        0x00, 0x12, 0xc1, 0x00, // label 300, bottom of stack
+
+        0x05, 0x00, 0x1a, 0x00, // length = 5, type = RTA_TTL_PROPAGATE
+        0x01, 0x00, 0x00, 0x00, // enabled + padding
+
+        0x08, 0x00, 0x04, 0x00, // length = 8, type = RTA_OIF
+        0x02, 0x00, 0x00, 0x00, // 2
+    ];
+
+    fn route_message() -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = AF_MPLS as u8;
+        msg.header.destination_prefix_length = 20;
+        msg.header.table = RT_TABLE_MAIN;
+        msg.header.protocol = RTPROT_STATIC;
+        msg.header.scope = RT_SCOPE_UNIVERSE;
+        msg.header.kind = RTN_UNICAST;
+        let mut dst = vec![0; 4];
+        MplsLabel::stack(&[100])[0].emit(&mut dst);
+        msg.nlas = vec![
+            Nla::Destination(dst),
+            Nla::Via(RouteVia::Ipv4(Ipv4Addr::new(10, 0, 0, 1))),
+            Nla::NewDestination(MplsLabel::stack(&[200, 300])),
+            Nla::TtlPropagate(1),
+            Nla::Oif(2),
+        ];
+        msg
+    }
+
+    #[test]
+    fn parse_mpls_route() {
+        let expected = route_message();
+        let actual =
+            RouteMessage::parse(&RouteMessageBuffer::new_checked(&&MPLS_ROUTE_MSG[..]).unwrap())
+                .unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn emit_mpls_route() {
+        let msg = route_message();
+        let mut buf = vec![0; 60];
+        assert_eq!(msg.buffer_len(), 60);
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, MPLS_ROUTE_MSG);
+    }
+
+    #[test]
+    fn parse_via() {
+        let ipv6 = [
+            0x0a, 0x00, // family = AF_INET6
+            0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01, // fe80::1
+        ];
+        assert_eq!(
+            RouteVia::parse(&ipv6[..]).unwrap(),
+            RouteVia::Ipv6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1))
+        );
+
+        // Addresses whose length does not match their family are kept as is
+        let ipv4 = [0x02, 0x00, 0x0a, 0x00, 0x00];
+        assert_eq!(
+            RouteVia::parse(&ipv4[..]).unwrap(),
+            RouteVia::Other {
+                family: AF_INET,
+                address: vec![0x0a, 0x00, 0x00],
+            }
+        );
+        assert_eq!(
+            RouteVia::parse(&ipv6[..10]).unwrap(),
+            RouteVia::Other {
+                family: AF_INET6,
+                address: ipv6[2..10].to_vec(),
+            }
+        );
+    }
+}
+
+mod test_lookup {
+    use crate::{
+        constants::*,
+        rtnl::route::{nlas::Nla, RouteFlags, RouteMessage, RouteMessageBuffer},
+        utils::{Emitable, Parseable},
+    };
+
+    // Equivalent to
+    // `ip route get 8.8.8.8 mark 7 uid 1000 ipproto tcp sport 1234 dport 443`
+    #[rustfmt::skip]
+    static LOOKUP_ROUTE_MSG: [u8; 60] = [
+        0x02, // address family = AF_INET
+        0x20, // length of destination = 32
+        0x00, // length of source
+        0x00, // TOS
+        0x00, // routing table id
+        0x00, // routing protocol
+        0x00, // scope
+        0x00, // type
+        0x00, 0x10, 0x00, 0x00, // flags = RTM_F_LOOKUP_TABLE
+
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = RTA_DST
+        0x08, 0x08, 0x08, 0x08, // 8.8.8.8
+
+        0x08, 0x00, 0x10, 0x00, // length = 8, type = RTA_MARK
+        0x07, 0x00, 0x00, 0x00, // 7
+
+        0x08, 0x00, 0x19, 0x00, // length = 8, type = RTA_UID
+        0xe8, 0x03, 0x00, 0x00, // 1000
+
+        0x05, 0x00, 0x1b, 0x00, // length = 5, type = RTA_IP_PROTO
+        0x06, 0x00, 0x00, 0x00, // IPPROTO_TCP + padding
+
+        0x06, 0x00, 0x1c, 0x00, // length = 6, type = RTA_SPORT
+        0x04, 0xd2, 0x00, 0x00, // 1234 (big endian) + padding
+
+        0x06, 0x00, 0x1d, 0x00, // length = 6, type = RTA_DPORT
+        0x01, 0xbb, 0x00, 0x00, // 443 (big endian) + padding
+    ];
+
+    fn route_message() -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = AF_INET as u8;
+        msg.header.destination_prefix_length = 32;
+        msg.header.flags = RouteFlags::RTM_F_LOOKUP_TABLE;
+        msg.nlas = vec![
+            Nla::Destination(vec![8, 8, 8, 8]),
+            Nla::Mark(7),
+            Nla::Uid(1000),
+            Nla::IpProto(6),
+            Nla::SourcePort(1234),
+            Nla::DestinationPort(443),
+        ];
+        msg
+    }
+
+    #[test]
+    fn parse_lookup_route() {
+        let expected = route_message();
+        let actual =
+            RouteMessage::parse(&RouteMessageBuffer::new_checked(&&LOOKUP_ROUTE_MSG[..]).unwrap())
+                .unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn emit_lookup_route() {
+        let msg = route_message();
+        let mut buf = vec![0; 60];
+        assert_eq!(msg.buffer_len(), 60);
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, LOOKUP_ROUTE_MSG);
+    }
+}
+
+mod test_metrics {
+    use crate::{
+        constants::*,
+        rtnl::route::{
+            nlas::{CacheInfo, Metrics, Nla},
+            RouteMessage,
+            RouteMessageBuffer,
+        },
+        utils::{Emitable, Parseable},
+    };
+
+    // Route added with `ip route add 10.99.0.0/24 mtu lock 1400 window 65535
+    // advmss 1360 initcwnd 10 features ecn quickack 1 congctl cubic`
+    #[rustfmt::skip]
+    static METRICS_ROUTE_MSG: [u8; 128] = [
+        0x02, // address family = AF_INET
+        0x18, // length of destination = 24
+        0x00, // length of source
+        0x00, // TOS
+        0xfe, // routing table id = RT_TABLE_MAIN
+        0x04, // routing protocol = RTPROT_STATIC
+        0x00, // scope = RT_SCOPE_UNIVERSE
+        0x01, // type = RTN_UNICAST
+        0x00, 0x00, 0x00, 0x00, // flags
+
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = RTA_DST
+        0x0a, 0x63, 0x00, 0x00, // 10.99.0.0
+
+        0x48, 0x00, 0x08, 0x00, // length = 72, type = RTA_METRICS
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = RTAX_LOCK
+            0x04, 0x00, 0x00, 0x00, // 1 << RTAX_MTU
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = RTAX_MTU
+            0x78, 0x05, 0x00, 0x00, // 1400
+            0x08, 0x00, 0x03, 0x00, // length = 8, type = RTAX_WINDOW
+            0xff, 0xff, 0x00, 0x00, // 65535
+            0x08, 0x00, 0x08, 0x00, // length = 8, type = RTAX_ADVMSS
+            0x50, 0x05, 0x00, 0x00, // 1360
+            0x08, 0x00, 0x0b, 0x00, // length = 8, type = RTAX_INITCWND
+            0x0a, 0x00, 0x00, 0x00, // 10
+            0x08, 0x00, 0x0c, 0x00, // length = 8, type = RTAX_FEATURES
+            0x01, 0x00, 0x00, 0x00, // RTAX_FEATURE_ECN
+            0x08, 0x00, 0x0f, 0x00, // length = 8, type = RTAX_QUICKACK
+            0x01, 0x00, 0x00, 0x00, // 1
+            0x0a, 0x00, 0x10, 0x00, // length = 10, type = RTAX_CC_ALGO
+            0x63, 0x75, 0x62, 0x69, 0x63, 0x00, // "cubic\0"
+            0x00, 0x00, // padding
+
+        0x24, 0x00, 0x0c, 0x00, // length = 36, type = RTA_CACHEINFO
+        0x02, 0x00, 0x00, 0x00, // clntref = 2
+        0x00, 0x00, 0x00, 0x00, // last_use
+        0x00, 0x00, 0x00, 0x00, // expires
+        0x00, 0x00, 0x00, 0x00, // error
+        0x00, 0x00, 0x00, 0x00, // used
+        0x00, 0x00, 0x00, 0x00, // id
+        0x00, 0x00, 0x00, 0x00, // ts
+        0x00, 0x00, 0x00, 0x00, // ts_age
+    ];
+
+    fn route_message() -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = AF_INET as u8;
+        msg.header.destination_prefix_length = 24;
+        msg.header.table = RT_TABLE_MAIN;
+        msg.header.protocol = RTPROT_STATIC;
+        msg.header.scope = RT_SCOPE_UNIVERSE;
+        msg.header.kind = RTN_UNICAST;
+        msg.nlas = vec![
+            Nla::Destination(vec![10, 99, 0, 0]),
+            Nla::Metrics(vec![
+                Metrics::Lock(1 << RTAX_MTU),
+                Metrics::Mtu(1400),
+                Metrics::Window(65535),
+                Metrics::Advmss(1360),
+                Metrics::InitCwnd(10),
+                Metrics::Features(RTAX_FEATURE_ECN),
+                Metrics::QuickAck(1),
+                Metrics::CcAlgo("cubic".to_string()),
+            ]),
+            Nla::CacheInfo(CacheInfo {
+                clntref: 2,
+                last_use: 0,
+                expires: 0,
+                error: 0,
+                used: 0,
+                id: 0,
+                ts: 0,
+                ts_age: 0,
+            }),
+        ];
+        msg
+    }
+
+    #[test]
+    fn parse_metrics_route() {
+        let expected = route_message();
+        let actual =
+            RouteMessage::parse(&RouteMessageBuffer::new_checked(&&METRICS_ROUTE_MSG[..]).unwrap())
+                .unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn emit_metrics_route() {
+        let msg = route_message();
+        let mut buf = vec![0; 128];
+        assert_eq!(msg.buffer_len(), 128);
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, METRICS_ROUTE_MSG);
+    }
+
+    #[test]
+    fn raw_metrics_value() {
+        let data = &METRICS_ROUTE_MSG[..];
+        let buf = RouteMessageBuffer::new_checked(&data).unwrap();
+        assert_eq!(
+            buf.nla_value(RTA_METRICS).unwrap(),
+            &METRICS_ROUTE_MSG[24..92]
+        );
+        assert_eq!(buf.nla_value(RTA_MFC_STATS), None);
+    }
 }
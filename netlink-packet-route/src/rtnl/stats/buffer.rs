@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nlas::{NlaBuffer, NlasIterator},
+    DecodeError,
+};
+
+pub const STATS_HEADER_LEN: usize = 12;
+
+buffer!(StatsMessageBuffer(STATS_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..4),
+    ifindex: (u32, 4..8),
+    filter_mask: (u32, 8..STATS_HEADER_LEN),
+    payload: (slice, STATS_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> StatsMessageBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+
+use super::{StatsMessageBuffer, STATS_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Link statistics message header (`struct if_stats_msg`).
+///
+/// ```no_rust
+/// 0                8                16              24               32
+/// +----------------+----------------+----------------+----------------+
+/// |     family     |      pad       |               pad               |
+/// +----------------+----------------+----------------+----------------+
+/// |                          interface index                          |
+/// +----------------+----------------+----------------+----------------+
+/// |                            filter mask                            |
+/// +----------------+----------------+----------------+----------------+
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct StatsHeader {
+    pub family: u8,
+    /// Index of the link. It is ignored in dump requests.
+    pub ifindex: u32,
+    /// Statistics to retrieve. Each `IFLA_STATS_*` attribute is selected by
+    /// the bit `1 << (IFLA_STATS_* - 1)`.
+    pub filter_mask: u32,
+}
+
+impl Emitable for StatsHeader {
+    fn buffer_len(&self) -> usize {
+        STATS_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = StatsMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+        packet.set_ifindex(self.ifindex);
+        packet.set_filter_mask(self.filter_mask);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<StatsMessageBuffer<T>> for StatsHeader {
+    fn parse(buf: &StatsMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(StatsHeader {
+            family: buf.family(),
+            ifindex: buf.ifindex(),
+            filter_mask: buf.filter_mask(),
+        })
+    }
+}
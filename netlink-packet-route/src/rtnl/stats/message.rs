@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::stats::Nla,
+    traits::{Emitable, Parseable},
+    DecodeError,
+    StatsHeader,
+    StatsMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct StatsMessage {
+    pub header: StatsHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<StatsMessageBuffer<&'a T>> for StatsMessage {
+    fn parse(buf: &StatsMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: StatsHeader::parse(buf).context("failed to parse stats message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse stats message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<StatsMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &StatsMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for StatsMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        nlas::{
+            link::Stats64,
+            stats::{LinkXStats, Nla},
+        },
+        traits::{Emitable, ParseableParametrized},
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        StatsHeader,
+        StatsMessage,
+        RTM_NEWSTATS,
+    };
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_stats() {
+        let mut data = vec![
+            0xf0, 0x00, 0x00, 0x00, // length = 240
+            0x5c, 0x00, // message type = 92 = RTM_NEWSTATS
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // if_stats_msg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x02, 0x00, 0x00, 0x00, // ifindex = 2
+            0x03, 0x00, 0x00, 0x00, // filter_mask = LINK_64 | LINK_XSTATS
+
+            // NLAs
+            0x04, 0x00, 0x00, 0x00, // length = 4, type = IFLA_STATS_UNSPEC (padding)
+            0xc4, 0x00, 0x01, 0x00, // length = 196, type = IFLA_STATS_LINK_64
+            0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_packets = 10
+            0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_packets = 20
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // rx_bytes = 1 << 32
+            0xd0, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_bytes = 2000
+        ];
+        // all the other counters are 0
+        data.extend_from_slice(&[0; 160]);
+        data.extend_from_slice(&[
+            0x0c, 0x00, 0x02, 0x00, // length = 12, type = IFLA_STATS_LINK_XSTATS
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = LINK_XSTATS_TYPE_BRIDGE
+            0x04, 0x00, 0x00, 0x00, // opaque
+        ]);
+
+        let expected = StatsMessage {
+            header: StatsHeader {
+                family: 0,
+                ifindex: 2,
+                filter_mask: 3,
+            },
+            nlas: vec![
+                Nla::Unspec(vec![]),
+                Nla::Link64(Stats64 {
+                    rx_packets: 10,
+                    tx_packets: 20,
+                    rx_bytes: 0x1_0000_0000,
+                    tx_bytes: 2000,
+                    ..Default::default()
+                }),
+                Nla::LinkXStats(vec![LinkXStats::Bridge(vec![4, 0, 0, 0])]),
+            ],
+        };
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWSTATS).unwrap();
+        assert_eq!(RtnlMessage::NewStats(expected.clone()), actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+}
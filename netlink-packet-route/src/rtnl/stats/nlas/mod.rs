@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+
+mod xstats;
+pub use self::xstats::*;
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{
+        self,
+        link::{Stats64, Stats64Buffer},
+        DefaultNla,
+        NlaBuffer,
+        NlasIterator,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    /// Unspecified, also used as padding by the kernel
+    Unspec(Vec<u8>),
+    /// 64 bits counters of the link
+    Link64(Stats64),
+    /// Statistics specific to the kind of link
+    LinkXStats(Vec<LinkXStats>),
+    /// Statistics specific to the kind of master device the link is
+    /// enslaved to
+    LinkXStatsSlave(Vec<LinkXStats>),
+    /// Statistics of the hardware offloaded traffic
+    LinkOffloadXStats(Vec<OffloadXStats>),
+    /// Statistics specific to each address family
+    AfSpec(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | AfSpec(bytes) => bytes.len(),
+            Link64(stats) => stats.buffer_len(),
+            LinkXStats(nlas) | LinkXStatsSlave(nlas) => nlas.as_slice().buffer_len(),
+            LinkOffloadXStats(nlas) => nlas.as_slice().buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | AfSpec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Link64(stats) => stats.emit(buffer),
+            LinkXStats(nlas) | LinkXStatsSlave(nlas) => nlas.as_slice().emit(buffer),
+            LinkOffloadXStats(nlas) => nlas.as_slice().emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => IFLA_STATS_UNSPEC,
+            Link64(_) => IFLA_STATS_LINK_64,
+            LinkXStats(_) => IFLA_STATS_LINK_XSTATS,
+            LinkXStatsSlave(_) => IFLA_STATS_LINK_XSTATS_SLAVE,
+            LinkOffloadXStats(_) => IFLA_STATS_LINK_OFFLOAD_XSTATS,
+            AfSpec(_) => IFLA_STATS_AF_SPEC,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_STATS_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_STATS_LINK_64 => Link64(
+                Stats64::parse(&Stats64Buffer::new_checked(payload)?)
+                    .context("invalid IFLA_STATS_LINK_64 value")?,
+            ),
+            IFLA_STATS_LINK_XSTATS => {
+                let err = "invalid IFLA_STATS_LINK_XSTATS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(self::LinkXStats::parse(&nla.context(err)?).context(err)?);
+                }
+                LinkXStats(nlas)
+            }
+            IFLA_STATS_LINK_XSTATS_SLAVE => {
+                let err = "invalid IFLA_STATS_LINK_XSTATS_SLAVE value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(self::LinkXStats::parse(&nla.context(err)?).context(err)?);
+                }
+                LinkXStatsSlave(nlas)
+            }
+            IFLA_STATS_LINK_OFFLOAD_XSTATS => {
+                let err = "invalid IFLA_STATS_LINK_OFFLOAD_XSTATS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(self::OffloadXStats::parse(&nla.context(err)?).context(err)?);
+                }
+                LinkOffloadXStats(nlas)
+            }
+            IFLA_STATS_AF_SPEC => AfSpec(payload.to_vec()),
+            _ => Other(DefaultNla::parse(buf).context("invalid link stats attribute")?),
+        })
+    }
+}
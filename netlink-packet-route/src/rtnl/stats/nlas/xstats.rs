@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{
+        self,
+        link::{Stats64, Stats64Buffer},
+        DefaultNla,
+        NlaBuffer,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Statistics specific to a kind of link (`IFLA_STATS_LINK_XSTATS` and
+/// `IFLA_STATS_LINK_XSTATS_SLAVE`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum LinkXStats {
+    Unspec(Vec<u8>),
+    /// Bridge statistics (`BRIDGE_XSTATS_*` attributes)
+    Bridge(Vec<u8>),
+    /// Bond statistics (`BOND_XSTATS_*` attributes)
+    Bond(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for LinkXStats {
+    fn value_len(&self) -> usize {
+        use self::LinkXStats::*;
+        match self {
+            Unspec(bytes) | Bridge(bytes) | Bond(bytes) => bytes.len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkXStats::*;
+        match self {
+            Unspec(bytes) | Bridge(bytes) | Bond(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::LinkXStats::*;
+        match self {
+            Unspec(_) => LINK_XSTATS_TYPE_UNSPEC,
+            Bridge(_) => LINK_XSTATS_TYPE_BRIDGE,
+            Bond(_) => LINK_XSTATS_TYPE_BOND,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for LinkXStats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::LinkXStats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LINK_XSTATS_TYPE_UNSPEC => Unspec(payload.to_vec()),
+            LINK_XSTATS_TYPE_BRIDGE => Bridge(payload.to_vec()),
+            LINK_XSTATS_TYPE_BOND => Bond(payload.to_vec()),
+            _ => Other(DefaultNla::parse(buf).context("invalid link xstats attribute")?),
+        })
+    }
+}
+
+/// Statistics of the hardware offloaded traffic
+/// (`IFLA_STATS_LINK_OFFLOAD_XSTATS`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum OffloadXStats {
+    Unspec(Vec<u8>),
+    /// Counters of the traffic handled by the CPU
+    CpuHit(Stats64),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for OffloadXStats {
+    fn value_len(&self) -> usize {
+        use self::OffloadXStats::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            CpuHit(stats) => stats.buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::OffloadXStats::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            CpuHit(stats) => stats.emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::OffloadXStats::*;
+        match self {
+            Unspec(_) => IFLA_OFFLOAD_XSTATS_UNSPEC,
+            CpuHit(_) => IFLA_OFFLOAD_XSTATS_CPU_HIT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for OffloadXStats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::OffloadXStats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_OFFLOAD_XSTATS_CPU_HIT => CpuHit(
+                Stats64::parse(&Stats64Buffer::new_checked(payload)?)
+                    .context("invalid IFLA_OFFLOAD_XSTATS_CPU_HIT value")?,
+            ),
+            _ => Other(DefaultNla::parse(buf).context("invalid offload xstats attribute")?),
+        })
+    }
+}
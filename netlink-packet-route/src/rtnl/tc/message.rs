@@ -4,7 +4,7 @@ use anyhow::Context;
 
 use crate::{
     nlas::tc::Nla,
-    traits::{Emitable, Parseable},
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
     TcMessageBuffer,
     TC_HEADER_LEN,
@@ -60,7 +60,9 @@ impl Emitable for TcMessage {
 
     fn emit(&self, buffer: &mut [u8]) {
         self.header.emit(buffer);
-        self.nlas.as_slice().emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
     }
 }
 
@@ -88,9 +90,844 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcMessageBuffer<&'a T>> for TcMessage {
 impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcMessageBuffer<&'a T>> for Vec<Nla> {
     fn parse(buf: &TcMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
         let mut nlas = vec![];
+        // The kernel sends TCA_KIND before TCA_OPTIONS and TCA_XSTATS,
+        // whose format depends on the kind.
+        let mut kind = String::new();
         for nla_buf in buf.nlas() {
-            nlas.push(Nla::parse(&nla_buf?)?);
+            let nla = Nla::parse_with_param(&nla_buf?, &kind)?;
+            if let Nla::Kind(ref nla_kind) = nla {
+                kind = nla_kind.clone();
+            }
+            nlas.push(nla);
         }
         Ok(nlas)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv4Addr;
+
+    use crate::{
+        nlas::tc::{
+            actions::{
+                ActNla,
+                ActOptions,
+                Gact,
+                GactProb,
+                Mirred,
+                MirredParms,
+                TcAction,
+                TcGen,
+                Tcft,
+                Vlan,
+                VlanParms,
+            },
+            filters::{Bpf, Flower, Matchall, U32Key, U32Mark, U32Sel, U32},
+            qdiscs::{
+                FqCodel,
+                FqCodelQdiscStats,
+                FqCodelXStats,
+                Htb,
+                HtbOpt,
+                HtbXStats,
+                Netem,
+                NetemCorr,
+                NetemQopt,
+            },
+            Nla,
+            RateSpec,
+            TcOptions,
+            TcXStats,
+        },
+        traits::{Emitable, ParseableParametrized},
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        TcHeader,
+        TcMessage,
+        ETH_P_8021Q,
+        ETH_P_IP,
+        PGACT_NETRAND,
+        RTM_NEWQDISC,
+        RTM_NEWTCLASS,
+        RTM_NEWTFILTER,
+        TCA_BPF_FLAG_ACT_DIRECT,
+        TCA_CLS_FLAGS_NOT_IN_HW,
+        TCA_CLS_FLAGS_SKIP_HW,
+        TCA_EGRESS_MIRROR,
+        TCA_VLAN_ACT_PUSH,
+        TC_ACT_OK,
+        TC_ACT_PIPE,
+        TC_ACT_SHOT,
+        TC_U32_TERMINAL,
+    };
+
+    fn round_trip(data: &[u8], message_type: u16, expected: RtnlMessage) {
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, message_type).unwrap();
+        assert_eq!(expected, actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_qdisc_fq_codel() {
+        let data = vec![
+            0xa4, 0x00, 0x00, 0x00, // length = 164
+            0x24, 0x00, // message type = 36 = RTM_NEWQDISC
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcmsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x02, 0x00, 0x00, 0x00, // ifindex = 2
+            0x00, 0x00, 0x00, 0x00, // handle = 0:0
+            0xff, 0xff, 0xff, 0xff, // parent = root
+            0x02, 0x00, 0x00, 0x00, // info = 2
+
+            // NLAs
+            0x0d, 0x00, 0x01, 0x00, // length = 13, type = TCA_KIND
+            0x66, 0x71, 0x5f, 0x63, 0x6f, 0x64, 0x65, 0x6c, 0x00, // "fq_codel\0"
+            0x00, 0x00, 0x00, // padding
+            0x44, 0x00, 0x02, 0x00, // length = 68, type = TCA_OPTIONS
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = TCA_FQ_CODEL_TARGET
+            0x87, 0x13, 0x00, 0x00, // 4999
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = TCA_FQ_CODEL_LIMIT
+            0x00, 0x28, 0x00, 0x00, // 10240
+            0x08, 0x00, 0x03, 0x00, // length = 8, type = TCA_FQ_CODEL_INTERVAL
+            0x9f, 0x86, 0x01, 0x00, // 99999
+            0x08, 0x00, 0x04, 0x00, // length = 8, type = TCA_FQ_CODEL_ECN
+            0x01, 0x00, 0x00, 0x00, // 1
+            0x08, 0x00, 0x06, 0x00, // length = 8, type = TCA_FQ_CODEL_QUANTUM
+            0xea, 0x05, 0x00, 0x00, // 1514
+            0x08, 0x00, 0x08, 0x00, // length = 8, type = TCA_FQ_CODEL_DROP_BATCH_SIZE
+            0x40, 0x00, 0x00, 0x00, // 64
+            0x08, 0x00, 0x09, 0x00, // length = 8, type = TCA_FQ_CODEL_MEMORY_LIMIT
+            0x00, 0x00, 0x00, 0x02, // 33554432
+            0x08, 0x00, 0x05, 0x00, // length = 8, type = TCA_FQ_CODEL_FLOWS
+            0x00, 0x04, 0x00, 0x00, // 1024
+            0x2c, 0x00, 0x04, 0x00, // length = 44, type = TCA_XSTATS
+            0x00, 0x00, 0x00, 0x00, // type = TCA_FQ_CODEL_XSTATS_QDISC
+            0xea, 0x05, 0x00, 0x00, // maxpacket = 1514
+            0x00, 0x00, 0x00, 0x00, // drop_overlimit
+            0x00, 0x00, 0x00, 0x00, // ecn_mark
+            0x03, 0x00, 0x00, 0x00, // new_flow_count = 3
+            0x00, 0x00, 0x00, 0x00, // new_flows_len
+            0x01, 0x00, 0x00, 0x00, // old_flows_len = 1
+            0x00, 0x00, 0x00, 0x00, // ce_mark
+            0x00, 0x06, 0x00, 0x00, // memory_usage = 1536
+            0x00, 0x00, 0x00, 0x00, // drop_overmemory
+        ];
+        let expected = RtnlMessage::NewQueueDiscipline(TcMessage {
+            header: TcHeader {
+                family: 0,
+                index: 2,
+                handle: 0,
+                parent: 0xffff_ffff,
+                info: 2,
+            },
+            nlas: vec![
+                Nla::Kind("fq_codel".to_string()),
+                Nla::Options(TcOptions::FqCodel(vec![
+                    FqCodel::Target(4999),
+                    FqCodel::Limit(10240),
+                    FqCodel::Interval(99999),
+                    FqCodel::Ecn(1),
+                    FqCodel::Quantum(1514),
+                    FqCodel::DropBatchSize(64),
+                    FqCodel::MemoryLimit(33554432),
+                    FqCodel::Flows(1024),
+                ])),
+                Nla::XStats(TcXStats::FqCodel(FqCodelXStats::Qdisc(FqCodelQdiscStats {
+                    maxpacket: 1514,
+                    new_flow_count: 3,
+                    old_flows_len: 1,
+                    memory_usage: 1536,
+                    ..Default::default()
+                }))),
+            ],
+        });
+        round_trip(&data, RTM_NEWQDISC, expected);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_qdisc_netem() {
+        let data = vec![
+            0x74, 0x00, 0x00, 0x00, // length = 116
+            0x24, 0x00, // message type = 36 = RTM_NEWQDISC
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcmsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x02, 0x00, 0x00, 0x00, // ifindex = 2
+            0x00, 0x00, 0x01, 0x00, // handle = 1:0
+            0xff, 0xff, 0xff, 0xff, // parent = root
+            0x01, 0x00, 0x00, 0x00, // info = 1
+
+            // NLAs
+            0x0a, 0x00, 0x01, 0x00, // length = 10, type = TCA_KIND
+            0x6e, 0x65, 0x74, 0x65, 0x6d, 0x00, // "netem\0"
+            0x00, 0x00, // padding
+            0x44, 0x00, 0x02, 0x00, // length = 68, type = TCA_OPTIONS
+            0xa0, 0x86, 0x01, 0x00, // latency = 100000
+            0xe8, 0x03, 0x00, 0x00, // limit = 1000
+            0x29, 0x5c, 0x8f, 0x02, // loss = 1%
+            0x00, 0x00, 0x00, 0x00, // gap
+            0x00, 0x00, 0x00, 0x00, // duplicate
+            0x00, 0x00, 0x00, 0x00, // jitter
+            0x10, 0x00, 0x01, 0x00, // length = 16, type = TCA_NETEM_CORR
+            0x00, 0x00, 0x00, 0x00, // delay_corr
+            0x00, 0x00, 0x00, 0x00, // loss_corr
+            0x00, 0x00, 0x00, 0x00, // dup_corr
+            0x0c, 0x00, 0x0a, 0x00, // length = 12, type = TCA_NETEM_LATENCY64
+            0x00, 0xe1, 0xf5, 0x05, 0x00, 0x00, 0x00, 0x00, // 100ms
+            0x0c, 0x00, 0x0b, 0x00, // length = 12, type = TCA_NETEM_JITTER64
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 0
+        ];
+        let expected = RtnlMessage::NewQueueDiscipline(TcMessage {
+            header: TcHeader {
+                family: 0,
+                index: 2,
+                handle: 0x0001_0000,
+                parent: 0xffff_ffff,
+                info: 1,
+            },
+            nlas: vec![
+                Nla::Kind("netem".to_string()),
+                Nla::Options(TcOptions::Netem(
+                    NetemQopt {
+                        latency: 100000,
+                        limit: 1000,
+                        loss: 42949673,
+                        ..Default::default()
+                    },
+                    vec![
+                        Netem::Corr(NetemCorr::default()),
+                        Netem::Latency64(100_000_000),
+                        Netem::Jitter64(0),
+                    ],
+                )),
+            ],
+        });
+        round_trip(&data, RTM_NEWQDISC, expected);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_class_htb() {
+        let data = vec![
+            0x78, 0x00, 0x00, 0x00, // length = 120
+            0x28, 0x00, // message type = 40 = RTM_NEWTCLASS
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcmsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x02, 0x00, 0x00, 0x00, // ifindex = 2
+            0x01, 0x00, 0x01, 0x00, // handle = 1:1
+            0x00, 0x00, 0x01, 0x00, // parent = 1:0
+            0x00, 0x00, 0x00, 0x00, // info
+
+            // NLAs
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = TCA_KIND
+            0x68, 0x74, 0x62, 0x00, // "htb\0"
+            0x34, 0x00, 0x02, 0x00, // length = 52, type = TCA_OPTIONS
+            0x30, 0x00, 0x01, 0x00, // length = 48, type = TCA_HTB_PARMS
+            0x00, 0x01, 0x00, 0x00, // rate: cell_log, linklayer = 1, overhead
+            0xff, 0xff, 0x00, 0x00, // rate: cell_align = -1, mpu
+            0xd0, 0x12, 0x13, 0x00, // rate: rate = 1250000
+            0x00, 0x01, 0x00, 0x00, // ceil: cell_log, linklayer = 1, overhead
+            0xff, 0xff, 0x00, 0x00, // ceil: cell_align = -1, mpu
+            0xd0, 0x12, 0x13, 0x00, // ceil: rate = 1250000
+            0x40, 0x0d, 0x03, 0x00, // buffer = 200000
+            0x40, 0x0d, 0x03, 0x00, // cbuffer = 200000
+            0xd4, 0x30, 0x00, 0x00, // quantum = 12500
+            0x00, 0x00, 0x00, 0x00, // level
+            0x00, 0x00, 0x00, 0x00, // prio
+            0x18, 0x00, 0x04, 0x00, // length = 24, type = TCA_XSTATS
+            0x05, 0x00, 0x00, 0x00, // lends = 5
+            0x00, 0x00, 0x00, 0x00, // borrows
+            0x00, 0x00, 0x00, 0x00, // giants
+            0x40, 0x0d, 0x03, 0x00, // tokens = 200000
+            0x40, 0x0d, 0x03, 0x00, // ctokens = 200000
+        ];
+        let rate = RateSpec {
+            linklayer: 1,
+            cell_align: -1,
+            rate: 1250000,
+            ..Default::default()
+        };
+        let expected = RtnlMessage::NewTrafficClass(TcMessage {
+            header: TcHeader {
+                family: 0,
+                index: 2,
+                handle: 0x0001_0001,
+                parent: 0x0001_0000,
+                info: 0,
+            },
+            nlas: vec![
+                Nla::Kind("htb".to_string()),
+                Nla::Options(TcOptions::Htb(vec![Htb::Parms(HtbOpt {
+                    rate,
+                    ceil: rate,
+                    buffer: 200000,
+                    cbuffer: 200000,
+                    quantum: 12500,
+                    level: 0,
+                    prio: 0,
+                })])),
+                Nla::XStats(TcXStats::Htb(HtbXStats {
+                    lends: 5,
+                    borrows: 0,
+                    giants: 0,
+                    tokens: 200000,
+                    ctokens: 200000,
+                })),
+            ],
+        });
+        round_trip(&data, RTM_NEWTCLASS, expected);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_qdisc_unknown_kind() {
+        let data = vec![
+            0x38, 0x00, 0x00, 0x00, // length = 56
+            0x24, 0x00, // message type = 36 = RTM_NEWQDISC
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcmsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x02, 0x00, 0x00, 0x00, // ifindex = 2
+            0x00, 0x00, 0x01, 0x00, // handle = 1:0
+            0xff, 0xff, 0xff, 0xff, // parent = root
+            0x01, 0x00, 0x00, 0x00, // info = 1
+
+            // NLAs
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = TCA_KIND
+            0x73, 0x66, 0x71, 0x00, // "sfq\0"
+            0x0c, 0x00, 0x02, 0x00, // length = 12, type = TCA_OPTIONS
+            0x00, 0x00, 0x00, 0x00, // opaque
+            0x0a, 0x00, 0x00, 0x00, // opaque
+        ];
+        let expected = RtnlMessage::NewQueueDiscipline(TcMessage {
+            header: TcHeader {
+                family: 0,
+                index: 2,
+                handle: 0x0001_0000,
+                parent: 0xffff_ffff,
+                info: 1,
+            },
+            nlas: vec![
+                Nla::Kind("sfq".to_string()),
+                Nla::Options(TcOptions::Other(vec![0, 0, 0, 0, 0x0a, 0, 0, 0])),
+            ],
+        });
+        round_trip(&data, RTM_NEWQDISC, expected);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_qdisc_mq() {
+        let data = vec![
+            0x30, 0x00, 0x00, 0x00, // length = 48
+            0x24, 0x00, // message type = 36 = RTM_NEWQDISC
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcmsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x02, 0x00, 0x00, 0x00, // ifindex = 2
+            0x00, 0x00, 0x00, 0x00, // handle = 0:
+            0xff, 0xff, 0xff, 0xff, // parent = root
+            0x01, 0x00, 0x00, 0x00, // info = 1
+
+            // NLAs
+            0x07, 0x00, 0x01, 0x00, // length = 7, type = TCA_KIND
+            0x6d, 0x71, 0x00, // "mq\0"
+            0x00, // padding
+            0x04, 0x00, 0x02, 0x00, // length = 4, type = TCA_OPTIONS
+        ];
+        let expected = RtnlMessage::NewQueueDiscipline(TcMessage {
+            header: TcHeader {
+                family: 0,
+                index: 2,
+                handle: 0,
+                parent: 0xffff_ffff,
+                info: 1,
+            },
+            nlas: vec![Nla::Kind("mq".to_string()), Nla::Options(TcOptions::Mq)],
+        });
+        round_trip(&data, RTM_NEWQDISC, expected);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_qdisc_malformed_options() {
+        let data = vec![
+            0x38, 0x00, 0x00, 0x00, // length = 56
+            0x24, 0x00, // message type = 36 = RTM_NEWQDISC
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcmsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x02, 0x00, 0x00, 0x00, // ifindex = 2
+            0x00, 0x00, 0x01, 0x00, // handle = 1:0
+            0xff, 0xff, 0xff, 0xff, // parent = root
+            0x01, 0x00, 0x00, 0x00, // info = 1
+
+            // NLAs
+            0x09, 0x00, 0x01, 0x00, // length = 9, type = TCA_KIND
+            0x70, 0x72, 0x69, 0x6f, 0x00, // "prio\0"
+            0x00, 0x00, 0x00, // padding
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = TCA_OPTIONS
+            0x03, 0x00, 0x00, 0x00, // too short for a tc_prio_qopt
+        ];
+        let expected = RtnlMessage::NewQueueDiscipline(TcMessage {
+            header: TcHeader {
+                family: 0,
+                index: 2,
+                handle: 0x0001_0000,
+                parent: 0xffff_ffff,
+                info: 1,
+            },
+            nlas: vec![
+                Nla::Kind("prio".to_string()),
+                Nla::Options(TcOptions::Other(vec![0x03, 0x00, 0x00, 0x00])),
+            ],
+        });
+        round_trip(&data, RTM_NEWQDISC, expected);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_filter_matchall_mirred() {
+        let data = vec![
+            0x78, 0x00, 0x00, 0x00, // length = 120
+            0x2c, 0x00, // message type = 44 = RTM_NEWTFILTER
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcmsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x03, 0x00, 0x00, 0x00, // ifindex = 3
+            0x01, 0x00, 0x00, 0x00, // handle = 1
+            0xf2, 0xff, 0xff, 0xff, // parent = ffff:fff2 (clsact ingress)
+            0x00, 0x03, 0x00, 0xc0, // info = priority 49152, protocol ETH_P_ALL
+
+            // NLAs
+            0x0d, 0x00, 0x01, 0x00, // length = 13, type = TCA_KIND
+            0x6d, 0x61, 0x74, 0x63, 0x68, 0x61, 0x6c, 0x6c, 0x00, // "matchall\0"
+            0x00, 0x00, 0x00, // padding
+            0x44, 0x00, 0x02, 0x00, // length = 68, type = TCA_OPTIONS
+            0x38, 0x00, 0x02, 0x00, // length = 56, type = TCA_MATCHALL_ACT
+            0x34, 0x00, 0x01, 0x00, // length = 52, tab = 1
+            0x0b, 0x00, 0x01, 0x00, // length = 11, type = TCA_ACT_KIND
+            0x6d, 0x69, 0x72, 0x72, 0x65, 0x64, 0x00, // "mirred\0"
+            0x00, // padding
+            0x24, 0x00, 0x02, 0x00, // length = 36, type = TCA_ACT_OPTIONS
+            0x20, 0x00, 0x02, 0x00, // length = 32, type = TCA_MIRRED_PARMS
+            0x00, 0x00, 0x00, 0x00, // index = 0
+            0x00, 0x00, 0x00, 0x00, // capab = 0
+            0x03, 0x00, 0x00, 0x00, // action = TC_ACT_PIPE
+            0x00, 0x00, 0x00, 0x00, // refcnt = 0
+            0x00, 0x00, 0x00, 0x00, // bindcnt = 0
+            0x02, 0x00, 0x00, 0x00, // eaction = TCA_EGRESS_MIRROR
+            0x04, 0x00, 0x00, 0x00, // ifindex = 4
+            0x08, 0x00, 0x03, 0x00, // length = 8, type = TCA_MATCHALL_FLAGS
+            0x01, 0x00, 0x00, 0x00, // TCA_CLS_FLAGS_SKIP_HW
+        ];
+        let expected = RtnlMessage::NewTrafficFilter(TcMessage {
+            header: TcHeader {
+                family: 0,
+                index: 3,
+                handle: 1,
+                parent: 0xffff_fff2,
+                info: 0xc000_0300,
+            },
+            nlas: vec![
+                Nla::Kind("matchall".to_string()),
+                Nla::Options(TcOptions::Matchall(vec![
+                    Matchall::Act(vec![TcAction {
+                        tab: 1,
+                        nlas: vec![
+                            ActNla::Kind("mirred".to_string()),
+                            ActNla::Options(ActOptions::Mirred(vec![Mirred::Parms(
+                                MirredParms {
+                                    gen: TcGen {
+                                        action: TC_ACT_PIPE,
+                                        ..Default::default()
+                                    },
+                                    eaction: TCA_EGRESS_MIRROR,
+                                    ifindex: 4,
+                                },
+                            )])),
+                        ],
+                    }]),
+                    Matchall::Flags(TCA_CLS_FLAGS_SKIP_HW),
+                ])),
+            ],
+        });
+        round_trip(&data, RTM_NEWTFILTER, expected);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_filter_u32_gact() {
+        let data = vec![
+            0xb8, 0x00, 0x00, 0x00, // length = 184
+            0x2c, 0x00, // message type = 44 = RTM_NEWTFILTER
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcmsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x02, 0x00, 0x00, 0x00, // ifindex = 2
+            0x00, 0x08, 0x00, 0x80, // handle = 800::800
+            0x00, 0x00, 0xff, 0xff, // parent = ffff:
+            0x08, 0x00, 0x01, 0x00, // info = priority 1, protocol ETH_P_IP
+
+            // NLAs
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = TCA_KIND
+            0x75, 0x33, 0x32, 0x00, // "u32\0"
+            0x8c, 0x00, 0x02, 0x00, // length = 140, type = TCA_OPTIONS
+            0x34, 0x00, 0x05, 0x00, // length = 52, type = TCA_U32_SEL
+            0x01, // flags = TC_U32_TERMINAL
+            0x00, // offshift = 0
+            0x02, // nkeys = 2
+            0x00, // padding
+            0x00, 0x00, // offmask = 0
+            0x00, 0x00, // off = 0
+            0x00, 0x00, // offoff = 0
+            0x00, 0x00, // hoff = 0
+            0x00, 0x00, 0x00, 0x00, // hmask = 0
+            // match ip protocol 6 0xff
+            0x00, 0xff, 0x00, 0x00, // mask = 0x00ff0000 (network order)
+            0x00, 0x06, 0x00, 0x00, // val = 0x00060000 (network order)
+            0x08, 0x00, 0x00, 0x00, // off = 8
+            0x00, 0x00, 0x00, 0x00, // offmask = 0
+            // match ip dst 10.0.0.0/8
+            0xff, 0x00, 0x00, 0x00, // mask = 0xff000000 (network order)
+            0x0a, 0x00, 0x00, 0x00, // val = 0x0a000000 (network order)
+            0x10, 0x00, 0x00, 0x00, // off = 16
+            0x00, 0x00, 0x00, 0x00, // offmask = 0
+            0x10, 0x00, 0x0a, 0x00, // length = 16, type = TCA_U32_MARK
+            0x2a, 0x00, 0x00, 0x00, // val = 42
+            0xff, 0xff, 0xff, 0xff, // mask = 0xffffffff
+            0x00, 0x00, 0x00, 0x00, // success = 0
+            0x08, 0x00, 0x0b, 0x00, // length = 8, type = TCA_U32_FLAGS
+            0x01, 0x00, 0x00, 0x00, // TCA_CLS_FLAGS_SKIP_HW
+            0x3c, 0x00, 0x07, 0x00, // length = 60, type = TCA_U32_ACT
+            0x38, 0x00, 0x01, 0x00, // length = 56, tab = 1
+            0x09, 0x00, 0x01, 0x00, // length = 9, type = TCA_ACT_KIND
+            0x67, 0x61, 0x63, 0x74, 0x00, // "gact\0"
+            0x00, 0x00, 0x00, // padding
+            0x28, 0x00, 0x02, 0x00, // length = 40, type = TCA_ACT_OPTIONS
+            0x18, 0x00, 0x02, 0x00, // length = 24, type = TCA_GACT_PARMS
+            0x00, 0x00, 0x00, 0x00, // index = 0
+            0x00, 0x00, 0x00, 0x00, // capab = 0
+            0x02, 0x00, 0x00, 0x00, // action = TC_ACT_SHOT
+            0x00, 0x00, 0x00, 0x00, // refcnt = 0
+            0x00, 0x00, 0x00, 0x00, // bindcnt = 0
+            0x0c, 0x00, 0x03, 0x00, // length = 12, type = TCA_GACT_PROB
+            0x01, 0x00, // ptype = PGACT_NETRAND
+            0xc4, 0x09, // pval = 2500
+            0x00, 0x00, 0x00, 0x00, // paction = TC_ACT_OK
+        ];
+        let expected = RtnlMessage::NewTrafficFilter(TcMessage {
+            header: TcHeader {
+                family: 0,
+                index: 2,
+                handle: 0x8000_0800,
+                parent: 0xffff_0000,
+                info: 0x0001_0008,
+            },
+            nlas: vec![
+                Nla::Kind("u32".to_string()),
+                Nla::Options(TcOptions::U32(vec![
+                    U32::Sel(U32Sel {
+                        flags: TC_U32_TERMINAL,
+                        keys: vec![
+                            U32Key {
+                                mask: 0x00ff_0000,
+                                val: 0x0006_0000,
+                                off: 8,
+                                offmask: 0,
+                            },
+                            U32Key {
+                                mask: 0xff00_0000,
+                                val: 0x0a00_0000,
+                                off: 16,
+                                offmask: 0,
+                            },
+                        ],
+                        ..Default::default()
+                    }),
+                    U32::Mark(U32Mark {
+                        val: 42,
+                        mask: 0xffff_ffff,
+                        success: 0,
+                    }),
+                    U32::Flags(TCA_CLS_FLAGS_SKIP_HW),
+                    U32::Act(vec![TcAction {
+                        tab: 1,
+                        nlas: vec![
+                            ActNla::Kind("gact".to_string()),
+                            ActNla::Options(ActOptions::Gact(vec![
+                                Gact::Parms(TcGen {
+                                    action: TC_ACT_SHOT,
+                                    ..Default::default()
+                                }),
+                                Gact::Prob(GactProb {
+                                    ptype: PGACT_NETRAND,
+                                    pval: 2500,
+                                    paction: TC_ACT_OK,
+                                }),
+                            ])),
+                        ],
+                    }]),
+                ])),
+            ],
+        });
+        round_trip(&data, RTM_NEWTFILTER, expected);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_filter_flower_vlan() {
+        let data = vec![
+            0xdc, 0x00, 0x00, 0x00, // length = 220
+            0x2c, 0x00, // message type = 44 = RTM_NEWTFILTER
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcmsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x03, 0x00, 0x00, 0x00, // ifindex = 3
+            0x01, 0x00, 0x00, 0x00, // handle = 1
+            0xf2, 0xff, 0xff, 0xff, // parent = ffff:fff2 (clsact ingress)
+            0x08, 0x00, 0x02, 0x00, // info = priority 2, protocol ETH_P_IP
+
+            // NLAs
+            0x0b, 0x00, 0x01, 0x00, // length = 11, type = TCA_KIND
+            0x66, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x00, // "flower\0"
+            0x00, // padding
+            0xac, 0x00, 0x02, 0x00, // length = 172, type = TCA_OPTIONS
+            0x06, 0x00, 0x08, 0x00, // length = 6, type = TCA_FLOWER_KEY_ETH_TYPE
+            0x08, 0x00, // ETH_P_IP (network order)
+            0x00, 0x00, // padding
+            0x05, 0x00, 0x09, 0x00, // length = 5, type = TCA_FLOWER_KEY_IP_PROTO
+            0x06, // IPPROTO_TCP
+            0x00, 0x00, 0x00, // padding
+            0x08, 0x00, 0x0c, 0x00, // length = 8, type = TCA_FLOWER_KEY_IPV4_DST
+            0xc0, 0xa8, 0x01, 0x00, // 192.168.1.0
+            0x08, 0x00, 0x0d, 0x00, // length = 8, type = TCA_FLOWER_KEY_IPV4_DST_MASK
+            0xff, 0xff, 0xff, 0x00, // 255.255.255.0
+            0x06, 0x00, 0x13, 0x00, // length = 6, type = TCA_FLOWER_KEY_TCP_DST
+            0x00, 0x50, // 80 (network order)
+            0x00, 0x00, // padding
+            0x06, 0x00, 0x24, 0x00, // length = 6, type = TCA_FLOWER_KEY_TCP_DST_MASK
+            0xff, 0xff, // 0xffff
+            0x00, 0x00, // padding
+            0x08, 0x00, 0x16, 0x00, // length = 8, type = TCA_FLOWER_FLAGS
+            0x01, 0x00, 0x00, 0x00, // TCA_CLS_FLAGS_SKIP_HW
+            0x70, 0x00, 0x03, 0x00, // length = 112, type = TCA_FLOWER_ACT
+            0x6c, 0x00, 0x01, 0x00, // length = 108, tab = 1
+            0x09, 0x00, 0x01, 0x00, // length = 9, type = TCA_ACT_KIND
+            0x76, 0x6c, 0x61, 0x6e, 0x00, // "vlan\0"
+            0x00, 0x00, 0x00, // padding
+            0x5c, 0x00, 0x02, 0x00, // length = 92, type = TCA_ACT_OPTIONS
+            0x1c, 0x00, 0x02, 0x00, // length = 28, type = TCA_VLAN_PARMS
+            0x00, 0x00, 0x00, 0x00, // index = 0
+            0x00, 0x00, 0x00, 0x00, // capab = 0
+            0x03, 0x00, 0x00, 0x00, // action = TC_ACT_PIPE
+            0x00, 0x00, 0x00, 0x00, // refcnt = 0
+            0x00, 0x00, 0x00, 0x00, // bindcnt = 0
+            0x02, 0x00, 0x00, 0x00, // v_action = TCA_VLAN_ACT_PUSH
+            0x06, 0x00, 0x03, 0x00, // length = 6, type = TCA_VLAN_PUSH_VLAN_ID
+            0x64, 0x00, // 100
+            0x00, 0x00, // padding
+            0x06, 0x00, 0x04, 0x00, // length = 6, type = TCA_VLAN_PUSH_VLAN_PROTOCOL
+            0x81, 0x00, // ETH_P_8021Q (network order)
+            0x00, 0x00, // padding
+            0x05, 0x00, 0x06, 0x00, // length = 5, type = TCA_VLAN_PUSH_VLAN_PRIORITY
+            0x03, // 3
+            0x00, 0x00, 0x00, // padding
+            0x24, 0x00, 0x01, 0x00, // length = 36, type = TCA_VLAN_TM
+            0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // install = 100
+            0x32, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // lastuse = 50
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // expires = 0
+            0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // firstuse = 30
+        ];
+        let expected = RtnlMessage::NewTrafficFilter(TcMessage {
+            header: TcHeader {
+                family: 0,
+                index: 3,
+                handle: 1,
+                parent: 0xffff_fff2,
+                info: 0x0002_0008,
+            },
+            nlas: vec![
+                Nla::Kind("flower".to_string()),
+                Nla::Options(TcOptions::Flower(vec![
+                    Flower::KeyEthType(ETH_P_IP),
+                    Flower::KeyIpProto(6),
+                    Flower::KeyIpv4Dst(Ipv4Addr::new(192, 168, 1, 0)),
+                    Flower::KeyIpv4DstMask(Ipv4Addr::new(255, 255, 255, 0)),
+                    Flower::KeyTcpDst(80),
+                    Flower::KeyTcpDstMask(0xffff),
+                    Flower::Flags(TCA_CLS_FLAGS_SKIP_HW),
+                    Flower::Act(vec![TcAction {
+                        tab: 1,
+                        nlas: vec![
+                            ActNla::Kind("vlan".to_string()),
+                            ActNla::Options(ActOptions::Vlan(vec![
+                                Vlan::Parms(VlanParms {
+                                    gen: TcGen {
+                                        action: TC_ACT_PIPE,
+                                        ..Default::default()
+                                    },
+                                    v_action: TCA_VLAN_ACT_PUSH,
+                                }),
+                                Vlan::PushVlanId(100),
+                                Vlan::PushVlanProtocol(ETH_P_8021Q),
+                                Vlan::PushVlanPriority(3),
+                                Vlan::Tm(Tcft {
+                                    install: 100,
+                                    lastuse: 50,
+                                    expires: 0,
+                                    firstuse: 30,
+                                }),
+                            ])),
+                        ],
+                    }]),
+                ])),
+            ],
+        });
+        round_trip(&data, RTM_NEWTFILTER, expected);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_filter_bpf_classic() {
+        let data = vec![
+            0x4c, 0x00, 0x00, 0x00, // length = 76
+            0x2c, 0x00, // message type = 44 = RTM_NEWTFILTER
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcmsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x02, 0x00, 0x00, 0x00, // ifindex = 2
+            0x01, 0x00, 0x00, 0x00, // handle = 1
+            0x00, 0x00, 0x01, 0x00, // parent = 1:
+            0x00, 0x03, 0x00, 0xc0, // info = priority 49152, protocol ETH_P_ALL
+
+            // NLAs
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = TCA_KIND
+            0x62, 0x70, 0x66, 0x00, // "bpf\0"
+            0x20, 0x00, 0x02, 0x00, // length = 32, type = TCA_OPTIONS
+            0x08, 0x00, 0x03, 0x00, // length = 8, type = TCA_BPF_CLASSID
+            0x01, 0x00, 0x01, 0x00, // 1:1
+            0x06, 0x00, 0x04, 0x00, // length = 6, type = TCA_BPF_OPS_LEN
+            0x01, 0x00, // 1 instruction
+            0x00, 0x00, // padding
+            0x0c, 0x00, 0x05, 0x00, // length = 12, type = TCA_BPF_OPS
+            0x06, 0x00, // code = BPF_RET | BPF_K
+            0x00, // jt = 0
+            0x00, // jf = 0
+            0x00, 0x00, 0x04, 0x00, // k = 262144
+        ];
+        let expected = RtnlMessage::NewTrafficFilter(TcMessage {
+            header: TcHeader {
+                family: 0,
+                index: 2,
+                handle: 1,
+                parent: 0x0001_0000,
+                info: 0xc000_0300,
+            },
+            nlas: vec![
+                Nla::Kind("bpf".to_string()),
+                Nla::Options(TcOptions::Bpf(vec![
+                    Bpf::ClassId(0x0001_0001),
+                    Bpf::OpsLen(1),
+                    Bpf::Ops(vec![0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00]),
+                ])),
+            ],
+        });
+        round_trip(&data, RTM_NEWTFILTER, expected);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_filter_bpf_direct_action() {
+        let data = vec![
+            0x60, 0x00, 0x00, 0x00, // length = 96
+            0x2c, 0x00, // message type = 44 = RTM_NEWTFILTER
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcmsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+            0x03, 0x00, 0x00, 0x00, // ifindex = 3
+            0x01, 0x00, 0x00, 0x00, // handle = 1
+            0xf2, 0xff, 0xff, 0xff, // parent = ffff:fff2 (clsact ingress)
+            0x00, 0x03, 0x00, 0xc0, // info = priority 49152, protocol ETH_P_ALL
+
+            // NLAs
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = TCA_KIND
+            0x62, 0x70, 0x66, 0x00, // "bpf\0"
+            0x34, 0x00, 0x02, 0x00, // length = 52, type = TCA_OPTIONS
+            0x08, 0x00, 0x0b, 0x00, // length = 8, type = TCA_BPF_ID
+            0x2a, 0x00, 0x00, 0x00, // 42
+            0x0c, 0x00, 0x07, 0x00, // length = 12, type = TCA_BPF_NAME
+            0x74, 0x63, 0x5f, 0x70, 0x72, 0x6f, 0x67, 0x00, // "tc_prog\0"
+            0x0c, 0x00, 0x0a, 0x00, // length = 12, type = TCA_BPF_TAG
+            0xde, 0xad, 0xbe, 0xef, 0x01, 0x23, 0x45, 0x67,
+            0x08, 0x00, 0x08, 0x00, // length = 8, type = TCA_BPF_FLAGS
+            0x01, 0x00, 0x00, 0x00, // TCA_BPF_FLAG_ACT_DIRECT
+            0x08, 0x00, 0x09, 0x00, // length = 8, type = TCA_BPF_FLAGS_GEN
+            0x08, 0x00, 0x00, 0x00, // TCA_CLS_FLAGS_NOT_IN_HW
+        ];
+        let expected = RtnlMessage::NewTrafficFilter(TcMessage {
+            header: TcHeader {
+                family: 0,
+                index: 3,
+                handle: 1,
+                parent: 0xffff_fff2,
+                info: 0xc000_0300,
+            },
+            nlas: vec![
+                Nla::Kind("bpf".to_string()),
+                Nla::Options(TcOptions::Bpf(vec![
+                    Bpf::Id(42),
+                    Bpf::Name("tc_prog".to_string()),
+                    Bpf::Tag(vec![0xde, 0xad, 0xbe, 0xef, 0x01, 0x23, 0x45, 0x67]),
+                    Bpf::Flags(TCA_BPF_FLAG_ACT_DIRECT),
+                    Bpf::FlagsGen(TCA_CLS_FLAGS_NOT_IN_HW),
+                ])),
+            ],
+        });
+        round_trip(&data, RTM_NEWTFILTER, expected);
+    }
+}
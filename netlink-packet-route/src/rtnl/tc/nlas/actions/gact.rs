@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    tc::actions::{TcGen, TcGenBuffer, Tcft, TcftBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const GACT: &str = "gact";
+
+/// Options of the `gact` (generic) action, which returns a fixed verdict,
+/// for instance to drop packets
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Gact {
+    Unspec(Vec<u8>),
+    Tm(Tcft),
+    Parms(TcGen),
+    /// Alternative verdict, returned with a given probability
+    Prob(GactProb),
+    Pad(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Gact {
+    fn value_len(&self) -> usize {
+        use self::Gact::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => bytes.len(),
+            Tm(tm) => tm.buffer_len(),
+            Parms(parms) => parms.buffer_len(),
+            Prob(prob) => prob.buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Gact::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Tm(tm) => tm.emit(buffer),
+            Parms(parms) => parms.emit(buffer),
+            Prob(prob) => prob.emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Gact::*;
+        match self {
+            Unspec(_) => TCA_GACT_UNSPEC,
+            Tm(_) => TCA_GACT_TM,
+            Parms(_) => TCA_GACT_PARMS,
+            Prob(_) => TCA_GACT_PROB,
+            Pad(_) => TCA_GACT_PAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Gact {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Gact::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_GACT_UNSPEC => Unspec(payload.to_vec()),
+            TCA_GACT_TM => Tm(Tcft::parse(&TcftBuffer::new_checked(payload)?)
+                .context("invalid TCA_GACT_TM value")?),
+            TCA_GACT_PARMS => Parms(
+                TcGen::parse(&TcGenBuffer::new_checked(payload)?)
+                    .context("invalid TCA_GACT_PARMS value")?,
+            ),
+            TCA_GACT_PROB => Prob(
+                GactProb::parse(&GactProbBuffer::new_checked(payload)?)
+                    .context("invalid TCA_GACT_PROB value")?,
+            ),
+            TCA_GACT_PAD => Pad(payload.to_vec()),
+            _ => Other(DefaultNla::parse(buf).context("invalid gact option")?),
+        })
+    }
+}
+
+/// Probabilistic verdict of the `gact` action (`struct tc_gact_p`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct GactProb {
+    /// How the alternative verdict is chosen, as one of the `PGACT_*`
+    /// constants
+    pub ptype: u16,
+    /// Probability of the alternative verdict, out of 10000 for
+    /// `PGACT_NETRAND`, or its period for `PGACT_DETERM`
+    pub pval: u16,
+    /// Alternative verdict, as one of the `TC_ACT_*` constants
+    pub paction: i32,
+}
+
+pub const GACT_PROB_LEN: usize = 8;
+
+buffer!(GactProbBuffer(GACT_PROB_LEN) {
+    ptype: (u16, 0..2),
+    pval: (u16, 2..4),
+    paction: (i32, 4..8),
+});
+
+impl<T: AsRef<[u8]>> Parseable<GactProbBuffer<T>> for GactProb {
+    fn parse(buf: &GactProbBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            ptype: buf.ptype(),
+            pval: buf.pval(),
+            paction: buf.paction(),
+        })
+    }
+}
+
+impl Emitable for GactProb {
+    fn buffer_len(&self) -> usize {
+        GACT_PROB_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = GactProbBuffer::new(buffer);
+        buffer.set_ptype(self.ptype);
+        buffer.set_pval(self.pval);
+        buffer.set_paction(self.paction);
+    }
+}
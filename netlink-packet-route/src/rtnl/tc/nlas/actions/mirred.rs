@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::parse_u32,
+    tc::actions::{TcGen, TcGenBuffer, Tcft, TcftBuffer, TC_GEN_LEN},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const MIRRED: &str = "mirred";
+
+/// Options of the `mirred` action, which mirrors or redirects packets to
+/// another interface
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Mirred {
+    Unspec(Vec<u8>),
+    Tm(Tcft),
+    Parms(MirredParms),
+    Pad(Vec<u8>),
+    /// Shared block to mirror or redirect packets to
+    BlockId(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Mirred {
+    fn value_len(&self) -> usize {
+        use self::Mirred::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => bytes.len(),
+            Tm(tm) => tm.buffer_len(),
+            Parms(parms) => parms.buffer_len(),
+            BlockId(_) => 4,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Mirred::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Tm(tm) => tm.emit(buffer),
+            Parms(parms) => parms.emit(buffer),
+            BlockId(value) => NativeEndian::write_u32(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Mirred::*;
+        match self {
+            Unspec(_) => TCA_MIRRED_UNSPEC,
+            Tm(_) => TCA_MIRRED_TM,
+            Parms(_) => TCA_MIRRED_PARMS,
+            Pad(_) => TCA_MIRRED_PAD,
+            BlockId(_) => TCA_MIRRED_BLOCKID,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Mirred {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Mirred::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_MIRRED_UNSPEC => Unspec(payload.to_vec()),
+            TCA_MIRRED_TM => Tm(Tcft::parse(&TcftBuffer::new_checked(payload)?)
+                .context("invalid TCA_MIRRED_TM value")?),
+            TCA_MIRRED_PARMS => Parms(
+                MirredParms::parse(&MirredParmsBuffer::new_checked(payload)?)
+                    .context("invalid TCA_MIRRED_PARMS value")?,
+            ),
+            TCA_MIRRED_PAD => Pad(payload.to_vec()),
+            TCA_MIRRED_BLOCKID => {
+                BlockId(parse_u32(payload).context("invalid TCA_MIRRED_BLOCKID value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid mirred option")?),
+        })
+    }
+}
+
+/// Parameters of the `mirred` action (`struct tc_mirred`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct MirredParms {
+    pub gen: TcGen,
+    /// What to do with the packets, as one of the `TCA_EGRESS_*` and
+    /// `TCA_INGRESS_*` constants
+    pub eaction: i32,
+    /// Index of the interface to send the packets to
+    pub ifindex: u32,
+}
+
+pub const MIRRED_PARMS_LEN: usize = TC_GEN_LEN + 8;
+
+buffer!(MirredParmsBuffer(MIRRED_PARMS_LEN) {
+    gen: (slice, 0..TC_GEN_LEN),
+    eaction: (i32, TC_GEN_LEN..TC_GEN_LEN + 4),
+    ifindex: (u32, TC_GEN_LEN + 4..TC_GEN_LEN + 8),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<MirredParmsBuffer<&'a T>> for MirredParms {
+    fn parse(buf: &MirredParmsBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            gen: TcGen::parse(&TcGenBuffer::new(buf.gen()))?,
+            eaction: buf.eaction(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
+
+impl Emitable for MirredParms {
+    fn buffer_len(&self) -> usize {
+        MIRRED_PARMS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = MirredParmsBuffer::new(buffer);
+        self.gen.emit(buffer.gen_mut());
+        buffer.set_eaction(self.eaction);
+        buffer.set_ifindex(self.ifindex);
+    }
+}
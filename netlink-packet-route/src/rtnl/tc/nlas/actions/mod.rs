@@ -0,0 +1,309 @@
+// SPDX-License-Identifier: MIT
+
+mod gact;
+pub use self::gact::*;
+
+mod mirred;
+pub use self::mirred::*;
+
+mod pedit;
+pub use self::pedit::*;
+
+mod police;
+pub use self::police::*;
+
+mod vlan;
+pub use self::vlan::*;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u32},
+    tc::{parse_nlas, Stats2},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+/// An action, either attached to a filter or managed on its own with the
+/// `RTM_*ACTION` messages. Actions are stored in lists, where `tab` is the
+/// position of the action in the list, starting at 1. Actions are executed
+/// in that order.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TcAction {
+    pub tab: u16,
+    pub nlas: Vec<ActNla>,
+}
+
+impl nlas::Nla for TcAction {
+    fn value_len(&self) -> usize {
+        self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.nlas.as_slice().emit(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        self.tab
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for TcAction {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        // The format of TCA_ACT_OPTIONS depends on TCA_ACT_KIND, which the
+        // kernel always sends first.
+        let mut kind = String::new();
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = ActNla::parse_with_param(&nla.context("invalid tc action")?, &kind)?;
+            if let ActNla::Kind(ref nla_kind) = nla {
+                kind = nla_kind.clone();
+            }
+            nlas.push(nla);
+        }
+        Ok(Self {
+            tab: buf.kind(),
+            nlas,
+        })
+    }
+}
+
+/// Attributes of an action
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ActNla {
+    Unspec(Vec<u8>),
+    /// Kind of action, for instance `"mirred"`
+    Kind(String),
+    Options(ActOptions),
+    /// Index of the action, shared by all the filters using it
+    Index(u32),
+    Stats(Vec<Stats2>),
+    /// Opaque data attached to the action by the user
+    Cookie(Vec<u8>),
+    /// Number of hardware devices the action is offloaded to
+    InHwCount(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for ActNla {
+    fn value_len(&self) -> usize {
+        use self::ActNla::*;
+        match self {
+            Unspec(bytes) | Cookie(bytes) => bytes.len(),
+            Kind(string) => string.len() + 1,
+            Options(options) => options.buffer_len(),
+            Index(_) | InHwCount(_) => 4,
+            Stats(nlas) => nlas.as_slice().buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::ActNla::*;
+        match self {
+            Unspec(bytes) | Cookie(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Kind(string) => {
+                buffer[..string.len()].copy_from_slice(string.as_bytes());
+                buffer[string.len()] = 0;
+            }
+            Options(options) => options.emit(buffer),
+            Index(value) | InHwCount(value) => NativeEndian::write_u32(buffer, *value),
+            Stats(nlas) => nlas.as_slice().emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::ActNla::*;
+        match self {
+            Unspec(_) => TCA_ACT_UNSPEC,
+            Kind(_) => TCA_ACT_KIND,
+            Options(_) => TCA_ACT_OPTIONS,
+            Index(_) => TCA_ACT_INDEX,
+            Stats(_) => TCA_ACT_STATS,
+            Cookie(_) => TCA_ACT_COOKIE,
+            InHwCount(_) => TCA_ACT_IN_HW_COUNT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, &str> for ActNla {
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, kind: &str) -> Result<Self, DecodeError> {
+        use self::ActNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_ACT_UNSPEC => Unspec(payload.to_vec()),
+            TCA_ACT_KIND => Kind(parse_string(payload).context("invalid TCA_ACT_KIND value")?),
+            TCA_ACT_OPTIONS => Options(ActOptions::parse_with_param(buf, kind)?),
+            TCA_ACT_INDEX => Index(parse_u32(payload).context("invalid TCA_ACT_INDEX value")?),
+            TCA_ACT_STATS => Stats(parse_nlas(payload).context("invalid TCA_ACT_STATS value")?),
+            TCA_ACT_COOKIE => Cookie(payload.to_vec()),
+            TCA_ACT_IN_HW_COUNT => {
+                InHwCount(parse_u32(payload).context("invalid TCA_ACT_IN_HW_COUNT value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid tc action attribute")?),
+        })
+    }
+}
+
+/// Options of an action (`TCA_ACT_OPTIONS`). Their format depends on the
+/// kind of the action, so options of unknown kinds are kept as raw bytes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ActOptions {
+    Gact(Vec<Gact>),
+    Mirred(Vec<Mirred>),
+    Pedit(Vec<Pedit>),
+    Police(Vec<Police>),
+    Vlan(Vec<Vlan>),
+    Other(Vec<u8>),
+}
+
+impl ActOptions {
+    /// Return the kind of action these options apply to, if known
+    pub fn kind(&self) -> Option<&'static str> {
+        use self::ActOptions::*;
+        match self {
+            Gact(_) => Some(GACT),
+            Mirred(_) => Some(MIRRED),
+            Pedit(_) => Some(PEDIT),
+            Police(_) => Some(POLICE),
+            Vlan(_) => Some(VLAN),
+            Other(_) => None,
+        }
+    }
+}
+
+impl Emitable for ActOptions {
+    fn buffer_len(&self) -> usize {
+        use self::ActOptions::*;
+        match self {
+            Gact(nlas) => nlas.as_slice().buffer_len(),
+            Mirred(nlas) => nlas.as_slice().buffer_len(),
+            Pedit(nlas) => nlas.as_slice().buffer_len(),
+            Police(nlas) => nlas.as_slice().buffer_len(),
+            Vlan(nlas) => nlas.as_slice().buffer_len(),
+            Other(bytes) => bytes.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        use self::ActOptions::*;
+        match self {
+            Gact(nlas) => nlas.as_slice().emit(buffer),
+            Mirred(nlas) => nlas.as_slice().emit(buffer),
+            Pedit(nlas) => nlas.as_slice().emit(buffer),
+            Police(nlas) => nlas.as_slice().emit(buffer),
+            Vlan(nlas) => nlas.as_slice().emit(buffer),
+            Other(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, &str> for ActOptions {
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, kind: &str) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match kind {
+            GACT => Self::Gact(parse_nlas(payload).context("invalid gact options")?),
+            MIRRED => Self::Mirred(parse_nlas(payload).context("invalid mirred options")?),
+            PEDIT => Self::Pedit(parse_nlas(payload).context("invalid pedit options")?),
+            POLICE => Self::Police(parse_nlas(payload).context("invalid police options")?),
+            VLAN => Self::Vlan(parse_nlas(payload).context("invalid vlan options")?),
+            _ => Self::Other(payload.to_vec()),
+        })
+    }
+}
+
+/// Parameters common to all the actions (`struct tc_gen`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct TcGen {
+    pub index: u32,
+    pub capab: u32,
+    /// Verdict of the action, as one of the `TC_ACT_*` constants
+    pub action: i32,
+    pub refcnt: i32,
+    pub bindcnt: i32,
+}
+
+pub const TC_GEN_LEN: usize = 20;
+
+buffer!(TcGenBuffer(TC_GEN_LEN) {
+    index: (u32, 0..4),
+    capab: (u32, 4..8),
+    action: (i32, 8..12),
+    refcnt: (i32, 12..16),
+    bindcnt: (i32, 16..20),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcGenBuffer<T>> for TcGen {
+    fn parse(buf: &TcGenBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            index: buf.index(),
+            capab: buf.capab(),
+            action: buf.action(),
+            refcnt: buf.refcnt(),
+            bindcnt: buf.bindcnt(),
+        })
+    }
+}
+
+impl Emitable for TcGen {
+    fn buffer_len(&self) -> usize {
+        TC_GEN_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcGenBuffer::new(buffer);
+        buffer.set_index(self.index);
+        buffer.set_capab(self.capab);
+        buffer.set_action(self.action);
+        buffer.set_refcnt(self.refcnt);
+        buffer.set_bindcnt(self.bindcnt);
+    }
+}
+
+/// Timestamps of an action (`struct tcf_t`), in jiffies
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Tcft {
+    pub install: u64,
+    pub lastuse: u64,
+    pub expires: u64,
+    pub firstuse: u64,
+}
+
+pub const TCFT_LEN: usize = 32;
+
+buffer!(TcftBuffer(TCFT_LEN) {
+    install: (u64, 0..8),
+    lastuse: (u64, 8..16),
+    expires: (u64, 16..24),
+    firstuse: (u64, 24..32),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcftBuffer<T>> for Tcft {
+    fn parse(buf: &TcftBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            install: buf.install(),
+            lastuse: buf.lastuse(),
+            expires: buf.expires(),
+            firstuse: buf.firstuse(),
+        })
+    }
+}
+
+impl Emitable for Tcft {
+    fn buffer_len(&self) -> usize {
+        TCFT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcftBuffer::new(buffer);
+        buffer.set_install(self.install);
+        buffer.set_lastuse(self.lastuse);
+        buffer.set_expires(self.expires);
+        buffer.set_firstuse(self.firstuse);
+    }
+}
@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    tc::actions::{TcGen, TcGenBuffer, Tcft, TcftBuffer, TC_GEN_LEN},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const PEDIT: &str = "pedit";
+
+/// Options of the `pedit` action, which edits arbitrary bytes of the packets
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Pedit {
+    Unspec(Vec<u8>),
+    Tm(Tcft),
+    Parms(PeditSel),
+    Pad(Vec<u8>),
+    /// Same as `Parms`, used along with `KeysEx`
+    ParmsEx(PeditSel),
+    /// Extended keys, which give the header and command of each key
+    KeysEx(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Pedit {
+    fn value_len(&self) -> usize {
+        use self::Pedit::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) | KeysEx(bytes) => bytes.len(),
+            Tm(tm) => tm.buffer_len(),
+            Parms(sel) | ParmsEx(sel) => sel.buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Pedit::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) | KeysEx(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Tm(tm) => tm.emit(buffer),
+            Parms(sel) | ParmsEx(sel) => sel.emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Pedit::*;
+        match self {
+            Unspec(_) => TCA_PEDIT_UNSPEC,
+            Tm(_) => TCA_PEDIT_TM,
+            Parms(_) => TCA_PEDIT_PARMS,
+            Pad(_) => TCA_PEDIT_PAD,
+            ParmsEx(_) => TCA_PEDIT_PARMS_EX,
+            KeysEx(_) => TCA_PEDIT_KEYS_EX,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Pedit {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Pedit::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_PEDIT_UNSPEC => Unspec(payload.to_vec()),
+            TCA_PEDIT_TM => Tm(Tcft::parse(&TcftBuffer::new_checked(payload)?)
+                .context("invalid TCA_PEDIT_TM value")?),
+            TCA_PEDIT_PARMS => Parms(
+                PeditSel::parse(&PeditSelBuffer::new_checked(payload)?)
+                    .context("invalid TCA_PEDIT_PARMS value")?,
+            ),
+            TCA_PEDIT_PAD => Pad(payload.to_vec()),
+            TCA_PEDIT_PARMS_EX => ParmsEx(
+                PeditSel::parse(&PeditSelBuffer::new_checked(payload)?)
+                    .context("invalid TCA_PEDIT_PARMS_EX value")?,
+            ),
+            TCA_PEDIT_KEYS_EX => KeysEx(payload.to_vec()),
+            _ => Other(DefaultNla::parse(buf).context("invalid pedit option")?),
+        })
+    }
+}
+
+/// Parameters of the `pedit` action (`struct tc_pedit_sel`)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct PeditSel {
+    pub gen: TcGen,
+    pub flags: u8,
+    pub keys: Vec<PeditKey>,
+}
+
+pub const PEDIT_SEL_LEN: usize = TC_GEN_LEN + 4;
+
+buffer!(PeditSelBuffer(PEDIT_SEL_LEN) {
+    gen: (slice, 0..TC_GEN_LEN),
+    nkeys: (u8, TC_GEN_LEN),
+    flags: (u8, TC_GEN_LEN + 1),
+    keys: (slice, PEDIT_SEL_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<PeditSelBuffer<&'a T>> for PeditSel {
+    fn parse(buf: &PeditSelBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let nkeys = buf.nkeys() as usize;
+        let keys = buf.keys();
+        if keys.len() < nkeys * PEDIT_KEY_LEN {
+            return Err(format!("expected {} pedit keys, got {} bytes", nkeys, keys.len()).into());
+        }
+        Ok(Self {
+            gen: TcGen::parse(&TcGenBuffer::new(buf.gen()))?,
+            flags: buf.flags(),
+            keys: keys
+                .chunks_exact(PEDIT_KEY_LEN)
+                .take(nkeys)
+                .map(|key| PeditKey::parse(&PeditKeyBuffer::new(key)))
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl Emitable for PeditSel {
+    fn buffer_len(&self) -> usize {
+        PEDIT_SEL_LEN + self.keys.len() * PEDIT_KEY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = PeditSelBuffer::new(buffer);
+        self.gen.emit(buffer.gen_mut());
+        buffer.set_nkeys(self.keys.len() as u8);
+        buffer.set_flags(self.flags);
+        for (key, chunk) in self
+            .keys
+            .iter()
+            .zip(buffer.keys_mut().chunks_exact_mut(PEDIT_KEY_LEN))
+        {
+            key.emit(chunk);
+        }
+    }
+}
+
+/// A 32 bits word to edit in the packets (`struct tc_pedit_key`). The new
+/// value of the word is `(old & mask) ^ val`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct PeditKey {
+    pub mask: u32,
+    pub val: u32,
+    /// Offset of the word, relative to the start of the network header
+    pub off: u32,
+    pub at: u32,
+    pub offmask: u32,
+    pub shift: u32,
+}
+
+pub const PEDIT_KEY_LEN: usize = 24;
+
+buffer!(PeditKeyBuffer(PEDIT_KEY_LEN) {
+    mask: (u32, 0..4),
+    val: (u32, 4..8),
+    off: (u32, 8..12),
+    at: (u32, 12..16),
+    offmask: (u32, 16..20),
+    shift: (u32, 20..24),
+});
+
+impl<T: AsRef<[u8]>> Parseable<PeditKeyBuffer<T>> for PeditKey {
+    fn parse(buf: &PeditKeyBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            mask: buf.mask(),
+            val: buf.val(),
+            off: buf.off(),
+            at: buf.at(),
+            offmask: buf.offmask(),
+            shift: buf.shift(),
+        })
+    }
+}
+
+impl Emitable for PeditKey {
+    fn buffer_len(&self) -> usize {
+        PEDIT_KEY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = PeditKeyBuffer::new(buffer);
+        buffer.set_mask(self.mask);
+        buffer.set_val(self.val);
+        buffer.set_off(self.off);
+        buffer.set_at(self.at);
+        buffer.set_offmask(self.offmask);
+        buffer.set_shift(self.shift);
+    }
+}
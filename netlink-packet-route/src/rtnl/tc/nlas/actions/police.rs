@@ -0,0 +1,202 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_i32, parse_u32, parse_u64},
+    tc::{
+        actions::{Tcft, TcftBuffer},
+        RateSpec,
+        RateSpecBuffer,
+        RATE_SPEC_LEN,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const POLICE: &str = "police";
+
+/// Options of the `police` action, which limits the rate of the packets
+/// going through it
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Police {
+    Unspec(Vec<u8>),
+    Tbf(PoliceTbf),
+    /// Rate table, computed from the rate specification by the user
+    Rate(Vec<u8>),
+    /// Peak rate table, computed from the peak rate specification by the user
+    PeakRate(Vec<u8>),
+    /// Average rate, in bytes per second
+    AvRate(u32),
+    /// Verdict used when the average rate is exceeded, as one of the
+    /// `TC_ACT_*` constants
+    Result(i32),
+    Tm(Tcft),
+    Pad(Vec<u8>),
+    Rate64(u64),
+    PeakRate64(u64),
+    /// Rate in packets per second
+    PktRate64(u64),
+    /// Burst size in packets
+    PktBurst64(u64),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Police {
+    fn value_len(&self) -> usize {
+        use self::Police::*;
+        match self {
+            Unspec(bytes) | Rate(bytes) | PeakRate(bytes) | Pad(bytes) => bytes.len(),
+            Tbf(tbf) => tbf.buffer_len(),
+            AvRate(_) | Result(_) => 4,
+            Tm(tm) => tm.buffer_len(),
+            Rate64(_) | PeakRate64(_) | PktRate64(_) | PktBurst64(_) => 8,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Police::*;
+        match self {
+            Unspec(bytes) | Rate(bytes) | PeakRate(bytes) | Pad(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Tbf(tbf) => tbf.emit(buffer),
+            AvRate(value) => NativeEndian::write_u32(buffer, *value),
+            Result(value) => NativeEndian::write_i32(buffer, *value),
+            Tm(tm) => tm.emit(buffer),
+            Rate64(value) | PeakRate64(value) | PktRate64(value) | PktBurst64(value) => {
+                NativeEndian::write_u64(buffer, *value)
+            }
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Police::*;
+        match self {
+            Unspec(_) => TCA_POLICE_UNSPEC,
+            Tbf(_) => TCA_POLICE_TBF,
+            Rate(_) => TCA_POLICE_RATE,
+            PeakRate(_) => TCA_POLICE_PEAKRATE,
+            AvRate(_) => TCA_POLICE_AVRATE,
+            Result(_) => TCA_POLICE_RESULT,
+            Tm(_) => TCA_POLICE_TM,
+            Pad(_) => TCA_POLICE_PAD,
+            Rate64(_) => TCA_POLICE_RATE64,
+            PeakRate64(_) => TCA_POLICE_PEAKRATE64,
+            PktRate64(_) => TCA_POLICE_PKTRATE64,
+            PktBurst64(_) => TCA_POLICE_PKTBURST64,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Police {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Police::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_POLICE_UNSPEC => Unspec(payload.to_vec()),
+            TCA_POLICE_TBF => Tbf(PoliceTbf::parse(&PoliceTbfBuffer::new_checked(payload)?)
+                .context("invalid TCA_POLICE_TBF value")?),
+            TCA_POLICE_RATE => Rate(payload.to_vec()),
+            TCA_POLICE_PEAKRATE => PeakRate(payload.to_vec()),
+            TCA_POLICE_AVRATE => {
+                AvRate(parse_u32(payload).context("invalid TCA_POLICE_AVRATE value")?)
+            }
+            TCA_POLICE_RESULT => {
+                Result(parse_i32(payload).context("invalid TCA_POLICE_RESULT value")?)
+            }
+            TCA_POLICE_TM => Tm(Tcft::parse(&TcftBuffer::new_checked(payload)?)
+                .context("invalid TCA_POLICE_TM value")?),
+            TCA_POLICE_PAD => Pad(payload.to_vec()),
+            TCA_POLICE_RATE64 => {
+                Rate64(parse_u64(payload).context("invalid TCA_POLICE_RATE64 value")?)
+            }
+            TCA_POLICE_PEAKRATE64 => {
+                PeakRate64(parse_u64(payload).context("invalid TCA_POLICE_PEAKRATE64 value")?)
+            }
+            TCA_POLICE_PKTRATE64 => {
+                PktRate64(parse_u64(payload).context("invalid TCA_POLICE_PKTRATE64 value")?)
+            }
+            TCA_POLICE_PKTBURST64 => {
+                PktBurst64(parse_u64(payload).context("invalid TCA_POLICE_PKTBURST64 value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid police option")?),
+        })
+    }
+}
+
+/// Parameters of the `police` action (`struct tc_police`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct PoliceTbf {
+    pub index: u32,
+    /// Verdict used when the rate is exceeded, as one of the `TC_ACT_*`
+    /// constants
+    pub action: i32,
+    pub limit: u32,
+    /// Burst size, in scheduler ticks
+    pub burst: u32,
+    pub mtu: u32,
+    pub rate: RateSpec,
+    pub peakrate: RateSpec,
+    pub refcnt: i32,
+    pub bindcnt: i32,
+    pub capab: u32,
+}
+
+pub const POLICE_TBF_LEN: usize = 32 + RATE_SPEC_LEN * 2;
+
+buffer!(PoliceTbfBuffer(POLICE_TBF_LEN) {
+    index: (u32, 0..4),
+    action: (i32, 4..8),
+    limit: (u32, 8..12),
+    burst: (u32, 12..16),
+    mtu: (u32, 16..20),
+    rate: (slice, 20..32),
+    peakrate: (slice, 32..44),
+    refcnt: (i32, 44..48),
+    bindcnt: (i32, 48..52),
+    capab: (u32, 52..56),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<PoliceTbfBuffer<&'a T>> for PoliceTbf {
+    fn parse(buf: &PoliceTbfBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            index: buf.index(),
+            action: buf.action(),
+            limit: buf.limit(),
+            burst: buf.burst(),
+            mtu: buf.mtu(),
+            rate: RateSpec::parse(&RateSpecBuffer::new(buf.rate()))?,
+            peakrate: RateSpec::parse(&RateSpecBuffer::new(buf.peakrate()))?,
+            refcnt: buf.refcnt(),
+            bindcnt: buf.bindcnt(),
+            capab: buf.capab(),
+        })
+    }
+}
+
+impl Emitable for PoliceTbf {
+    fn buffer_len(&self) -> usize {
+        POLICE_TBF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = PoliceTbfBuffer::new(buffer);
+        buffer.set_index(self.index);
+        buffer.set_action(self.action);
+        buffer.set_limit(self.limit);
+        buffer.set_burst(self.burst);
+        buffer.set_mtu(self.mtu);
+        self.rate.emit(buffer.rate_mut());
+        self.peakrate.emit(buffer.peakrate_mut());
+        buffer.set_refcnt(self.refcnt);
+        buffer.set_bindcnt(self.bindcnt);
+        buffer.set_capab(self.capab);
+    }
+}
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_mac, parse_u16, parse_u16_be, parse_u8},
+    tc::actions::{TcGen, TcGenBuffer, Tcft, TcftBuffer, TC_GEN_LEN},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const VLAN: &str = "vlan";
+
+/// Options of the `vlan` action, which pushes, pops or modifies VLAN tags
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Vlan {
+    Unspec(Vec<u8>),
+    Tm(Tcft),
+    Parms(VlanParms),
+    PushVlanId(u16),
+    /// Ethernet protocol of the pushed tag, for instance `ETH_P_8021Q`
+    PushVlanProtocol(u16),
+    Pad(Vec<u8>),
+    PushVlanPriority(u8),
+    PushEthDst([u8; 6]),
+    PushEthSrc([u8; 6]),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Vlan {
+    fn value_len(&self) -> usize {
+        use self::Vlan::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => bytes.len(),
+            Tm(tm) => tm.buffer_len(),
+            Parms(parms) => parms.buffer_len(),
+            PushVlanId(_) | PushVlanProtocol(_) => 2,
+            PushVlanPriority(_) => 1,
+            PushEthDst(_) | PushEthSrc(_) => 6,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Vlan::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Tm(tm) => tm.emit(buffer),
+            Parms(parms) => parms.emit(buffer),
+            PushVlanId(value) => NativeEndian::write_u16(buffer, *value),
+            PushVlanProtocol(value) => BigEndian::write_u16(buffer, *value),
+            PushVlanPriority(value) => buffer[0] = *value,
+            PushEthDst(mac) | PushEthSrc(mac) => buffer.copy_from_slice(&mac[..]),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Vlan::*;
+        match self {
+            Unspec(_) => TCA_VLAN_UNSPEC,
+            Tm(_) => TCA_VLAN_TM,
+            Parms(_) => TCA_VLAN_PARMS,
+            PushVlanId(_) => TCA_VLAN_PUSH_VLAN_ID,
+            PushVlanProtocol(_) => TCA_VLAN_PUSH_VLAN_PROTOCOL,
+            Pad(_) => TCA_VLAN_PAD,
+            PushVlanPriority(_) => TCA_VLAN_PUSH_VLAN_PRIORITY,
+            PushEthDst(_) => TCA_VLAN_PUSH_ETH_DST,
+            PushEthSrc(_) => TCA_VLAN_PUSH_ETH_SRC,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Vlan {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Vlan::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_VLAN_UNSPEC => Unspec(payload.to_vec()),
+            TCA_VLAN_TM => Tm(Tcft::parse(&TcftBuffer::new_checked(payload)?)
+                .context("invalid TCA_VLAN_TM value")?),
+            TCA_VLAN_PARMS => Parms(
+                VlanParms::parse(&VlanParmsBuffer::new_checked(payload)?)
+                    .context("invalid TCA_VLAN_PARMS value")?,
+            ),
+            TCA_VLAN_PUSH_VLAN_ID => {
+                PushVlanId(parse_u16(payload).context("invalid TCA_VLAN_PUSH_VLAN_ID value")?)
+            }
+            TCA_VLAN_PUSH_VLAN_PROTOCOL => PushVlanProtocol(
+                parse_u16_be(payload).context("invalid TCA_VLAN_PUSH_VLAN_PROTOCOL value")?,
+            ),
+            TCA_VLAN_PAD => Pad(payload.to_vec()),
+            TCA_VLAN_PUSH_VLAN_PRIORITY => PushVlanPriority(
+                parse_u8(payload).context("invalid TCA_VLAN_PUSH_VLAN_PRIORITY value")?,
+            ),
+            TCA_VLAN_PUSH_ETH_DST => {
+                PushEthDst(parse_mac(payload).context("invalid TCA_VLAN_PUSH_ETH_DST value")?)
+            }
+            TCA_VLAN_PUSH_ETH_SRC => {
+                PushEthSrc(parse_mac(payload).context("invalid TCA_VLAN_PUSH_ETH_SRC value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid vlan option")?),
+        })
+    }
+}
+
+/// Parameters of the `vlan` action (`struct tc_vlan`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct VlanParms {
+    pub gen: TcGen,
+    /// What to do with the VLAN tag, as one of the `TCA_VLAN_ACT_*` constants
+    pub v_action: i32,
+}
+
+pub const VLAN_PARMS_LEN: usize = TC_GEN_LEN + 4;
+
+buffer!(VlanParmsBuffer(VLAN_PARMS_LEN) {
+    gen: (slice, 0..TC_GEN_LEN),
+    v_action: (i32, TC_GEN_LEN..VLAN_PARMS_LEN),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<VlanParmsBuffer<&'a T>> for VlanParms {
+    fn parse(buf: &VlanParmsBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            gen: TcGen::parse(&TcGenBuffer::new(buf.gen()))?,
+            v_action: buf.v_action(),
+        })
+    }
+}
+
+impl Emitable for VlanParms {
+    fn buffer_len(&self) -> usize {
+        VLAN_PARMS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = VlanParmsBuffer::new(buffer);
+        self.gen.emit(buffer.gen_mut());
+        buffer.set_v_action(self.v_action);
+    }
+}
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_string, parse_u16, parse_u32},
+    tc::{
+        actions::{Police, TcAction},
+        parse_nlas,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const BPF: &str = "bpf";
+
+/// Options of the `bpf` classifier, which classifies packets with a BPF
+/// program
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Bpf {
+    Unspec(Vec<u8>),
+    /// Actions to run on the matching packets
+    Act(Vec<TcAction>),
+    Police(Vec<Police>),
+    ClassId(u32),
+    /// Number of classic BPF instructions in `Ops`
+    OpsLen(u16),
+    /// Classic BPF program
+    Ops(Vec<u8>),
+    /// File descriptor of an eBPF program
+    Fd(u32),
+    /// Name of the eBPF program
+    Name(String),
+    /// Combination of the `TCA_BPF_FLAG_*` constants
+    Flags(u32),
+    /// Offloading flags, as a combination of the `TCA_CLS_FLAGS_*` constants
+    FlagsGen(u32),
+    Tag(Vec<u8>),
+    Id(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Bpf {
+    fn value_len(&self) -> usize {
+        use self::Bpf::*;
+        match self {
+            Unspec(bytes) | Ops(bytes) | Tag(bytes) => bytes.len(),
+            Act(actions) => actions.as_slice().buffer_len(),
+            Police(nlas) => nlas.as_slice().buffer_len(),
+            OpsLen(_) => 2,
+            ClassId(_) | Fd(_) | Flags(_) | FlagsGen(_) | Id(_) => 4,
+            Name(string) => string.len() + 1,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Bpf::*;
+        match self {
+            Unspec(bytes) | Ops(bytes) | Tag(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Act(actions) => actions.as_slice().emit(buffer),
+            Police(nlas) => nlas.as_slice().emit(buffer),
+            OpsLen(value) => NativeEndian::write_u16(buffer, *value),
+            ClassId(value) | Fd(value) | Flags(value) | FlagsGen(value) | Id(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Name(string) => {
+                buffer[..string.len()].copy_from_slice(string.as_bytes());
+                buffer[string.len()] = 0;
+            }
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Bpf::*;
+        match self {
+            Unspec(_) => TCA_BPF_UNSPEC,
+            Act(_) => TCA_BPF_ACT,
+            Police(_) => TCA_BPF_POLICE,
+            ClassId(_) => TCA_BPF_CLASSID,
+            OpsLen(_) => TCA_BPF_OPS_LEN,
+            Ops(_) => TCA_BPF_OPS,
+            Fd(_) => TCA_BPF_FD,
+            Name(_) => TCA_BPF_NAME,
+            Flags(_) => TCA_BPF_FLAGS,
+            FlagsGen(_) => TCA_BPF_FLAGS_GEN,
+            Tag(_) => TCA_BPF_TAG,
+            Id(_) => TCA_BPF_ID,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Bpf {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Bpf::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_BPF_UNSPEC => Unspec(payload.to_vec()),
+            TCA_BPF_ACT => Act(parse_nlas(payload).context("invalid TCA_BPF_ACT value")?),
+            TCA_BPF_POLICE => Police(parse_nlas(payload).context("invalid TCA_BPF_POLICE value")?),
+            TCA_BPF_CLASSID => {
+                ClassId(parse_u32(payload).context("invalid TCA_BPF_CLASSID value")?)
+            }
+            TCA_BPF_OPS_LEN => OpsLen(parse_u16(payload).context("invalid TCA_BPF_OPS_LEN value")?),
+            TCA_BPF_OPS => Ops(payload.to_vec()),
+            TCA_BPF_FD => Fd(parse_u32(payload).context("invalid TCA_BPF_FD value")?),
+            TCA_BPF_NAME => Name(parse_string(payload).context("invalid TCA_BPF_NAME value")?),
+            TCA_BPF_FLAGS => Flags(parse_u32(payload).context("invalid TCA_BPF_FLAGS value")?),
+            TCA_BPF_FLAGS_GEN => {
+                FlagsGen(parse_u32(payload).context("invalid TCA_BPF_FLAGS_GEN value")?)
+            }
+            TCA_BPF_TAG => Tag(payload.to_vec()),
+            TCA_BPF_ID => Id(parse_u32(payload).context("invalid TCA_BPF_ID value")?),
+            _ => Other(DefaultNla::parse(buf).context("invalid bpf option")?),
+        })
+    }
+}
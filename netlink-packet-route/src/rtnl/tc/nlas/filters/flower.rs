@@ -0,0 +1,409 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_ipv6, parse_mac, parse_string, parse_u16, parse_u16_be, parse_u32, parse_u8},
+    tc::{actions::TcAction, parse_nlas},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const FLOWER: &str = "flower";
+
+/// Options of the `flower` classifier, which matches packets on the fields
+/// of their headers. Ethernet types and ports are given in host byte order.
+/// Keys that are not supported yet are kept as `Other`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Flower {
+    Unspec(Vec<u8>),
+    ClassId(u32),
+    /// Only match packets received on this interface
+    Indev(String),
+    /// Actions to run on the matching packets
+    Act(Vec<TcAction>),
+    KeyEthDst([u8; 6]),
+    KeyEthDstMask([u8; 6]),
+    KeyEthSrc([u8; 6]),
+    KeyEthSrcMask([u8; 6]),
+    KeyEthType(u16),
+    KeyIpProto(u8),
+    KeyIpv4Src(Ipv4Addr),
+    KeyIpv4SrcMask(Ipv4Addr),
+    KeyIpv4Dst(Ipv4Addr),
+    KeyIpv4DstMask(Ipv4Addr),
+    KeyIpv6Src(Ipv6Addr),
+    KeyIpv6SrcMask(Ipv6Addr),
+    KeyIpv6Dst(Ipv6Addr),
+    KeyIpv6DstMask(Ipv6Addr),
+    KeyTcpSrc(u16),
+    KeyTcpDst(u16),
+    KeyUdpSrc(u16),
+    KeyUdpDst(u16),
+    /// Offloading flags, as a combination of the `TCA_CLS_FLAGS_*` constants
+    Flags(u32),
+    KeyVlanId(u16),
+    KeyVlanPrio(u8),
+    KeyVlanEthType(u16),
+    KeyTcpSrcMask(u16),
+    KeyTcpDstMask(u16),
+    KeyUdpSrcMask(u16),
+    KeyUdpDstMask(u16),
+    KeySctpSrcMask(u16),
+    KeySctpDstMask(u16),
+    KeySctpSrc(u16),
+    KeySctpDst(u16),
+    KeyIcmpv4Code(u8),
+    KeyIcmpv4CodeMask(u8),
+    KeyIcmpv4Type(u8),
+    KeyIcmpv4TypeMask(u8),
+    KeyIcmpv6Code(u8),
+    KeyIcmpv6CodeMask(u8),
+    KeyIcmpv6Type(u8),
+    KeyIcmpv6TypeMask(u8),
+    KeyIpTos(u8),
+    KeyIpTosMask(u8),
+    KeyIpTtl(u8),
+    KeyIpTtlMask(u8),
+    /// Number of hardware devices the filter is offloaded to
+    InHwCount(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Flower {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Flower::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Indev(string) => string.len() + 1,
+            Act(actions) => actions.as_slice().buffer_len(),
+            KeyEthDst(_)
+                | KeyEthDstMask(_)
+                | KeyEthSrc(_)
+                | KeyEthSrcMask(_) => 6,
+            KeyIpv6Src(_)
+                | KeyIpv6SrcMask(_)
+                | KeyIpv6Dst(_)
+                | KeyIpv6DstMask(_) => 16,
+            ClassId(_)
+                | KeyIpv4Src(_)
+                | KeyIpv4SrcMask(_)
+                | KeyIpv4Dst(_)
+                | KeyIpv4DstMask(_)
+                | Flags(_)
+                | InHwCount(_) => 4,
+            KeyEthType(_)
+                | KeyTcpSrc(_)
+                | KeyTcpDst(_)
+                | KeyUdpSrc(_)
+                | KeyUdpDst(_)
+                | KeyVlanId(_)
+                | KeyVlanEthType(_)
+                | KeyTcpSrcMask(_)
+                | KeyTcpDstMask(_)
+                | KeyUdpSrcMask(_)
+                | KeyUdpDstMask(_)
+                | KeySctpSrcMask(_)
+                | KeySctpDstMask(_)
+                | KeySctpSrc(_)
+                | KeySctpDst(_) => 2,
+            KeyIpProto(_)
+                | KeyVlanPrio(_)
+                | KeyIcmpv4Code(_)
+                | KeyIcmpv4CodeMask(_)
+                | KeyIcmpv4Type(_)
+                | KeyIcmpv4TypeMask(_)
+                | KeyIcmpv6Code(_)
+                | KeyIcmpv6CodeMask(_)
+                | KeyIcmpv6Type(_)
+                | KeyIcmpv6TypeMask(_)
+                | KeyIpTos(_)
+                | KeyIpTosMask(_)
+                | KeyIpTtl(_)
+                | KeyIpTtlMask(_) => 1,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Flower::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Indev(string) => {
+                buffer[..string.len()].copy_from_slice(string.as_bytes());
+                buffer[string.len()] = 0;
+            }
+            Act(actions) => actions.as_slice().emit(buffer),
+            KeyEthDst(mac)
+                | KeyEthDstMask(mac)
+                | KeyEthSrc(mac)
+                | KeyEthSrcMask(mac) => buffer.copy_from_slice(&mac[..]),
+            KeyIpv4Src(addr)
+                | KeyIpv4SrcMask(addr)
+                | KeyIpv4Dst(addr)
+                | KeyIpv4DstMask(addr) => buffer.copy_from_slice(&addr.octets()),
+            KeyIpv6Src(addr)
+                | KeyIpv6SrcMask(addr)
+                | KeyIpv6Dst(addr)
+                | KeyIpv6DstMask(addr) => buffer.copy_from_slice(&addr.octets()),
+            ClassId(value)
+                | Flags(value)
+                | InHwCount(value) => NativeEndian::write_u32(buffer, *value),
+            KeyVlanId(value) => NativeEndian::write_u16(buffer, *value),
+            KeyEthType(value)
+                | KeyTcpSrc(value)
+                | KeyTcpDst(value)
+                | KeyUdpSrc(value)
+                | KeyUdpDst(value)
+                | KeyVlanEthType(value)
+                | KeyTcpSrcMask(value)
+                | KeyTcpDstMask(value)
+                | KeyUdpSrcMask(value)
+                | KeyUdpDstMask(value)
+                | KeySctpSrcMask(value)
+                | KeySctpDstMask(value)
+                | KeySctpSrc(value)
+                | KeySctpDst(value) => BigEndian::write_u16(buffer, *value),
+            KeyIpProto(value)
+                | KeyVlanPrio(value)
+                | KeyIcmpv4Code(value)
+                | KeyIcmpv4CodeMask(value)
+                | KeyIcmpv4Type(value)
+                | KeyIcmpv4TypeMask(value)
+                | KeyIcmpv6Code(value)
+                | KeyIcmpv6CodeMask(value)
+                | KeyIcmpv6Type(value)
+                | KeyIcmpv6TypeMask(value)
+                | KeyIpTos(value)
+                | KeyIpTosMask(value)
+                | KeyIpTtl(value)
+                | KeyIpTtlMask(value) => buffer[0] = *value,
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Flower::*;
+        match self {
+            Unspec(_) => TCA_FLOWER_UNSPEC,
+            ClassId(_) => TCA_FLOWER_CLASSID,
+            Indev(_) => TCA_FLOWER_INDEV,
+            Act(_) => TCA_FLOWER_ACT,
+            KeyEthDst(_) => TCA_FLOWER_KEY_ETH_DST,
+            KeyEthDstMask(_) => TCA_FLOWER_KEY_ETH_DST_MASK,
+            KeyEthSrc(_) => TCA_FLOWER_KEY_ETH_SRC,
+            KeyEthSrcMask(_) => TCA_FLOWER_KEY_ETH_SRC_MASK,
+            KeyEthType(_) => TCA_FLOWER_KEY_ETH_TYPE,
+            KeyIpProto(_) => TCA_FLOWER_KEY_IP_PROTO,
+            KeyIpv4Src(_) => TCA_FLOWER_KEY_IPV4_SRC,
+            KeyIpv4SrcMask(_) => TCA_FLOWER_KEY_IPV4_SRC_MASK,
+            KeyIpv4Dst(_) => TCA_FLOWER_KEY_IPV4_DST,
+            KeyIpv4DstMask(_) => TCA_FLOWER_KEY_IPV4_DST_MASK,
+            KeyIpv6Src(_) => TCA_FLOWER_KEY_IPV6_SRC,
+            KeyIpv6SrcMask(_) => TCA_FLOWER_KEY_IPV6_SRC_MASK,
+            KeyIpv6Dst(_) => TCA_FLOWER_KEY_IPV6_DST,
+            KeyIpv6DstMask(_) => TCA_FLOWER_KEY_IPV6_DST_MASK,
+            KeyTcpSrc(_) => TCA_FLOWER_KEY_TCP_SRC,
+            KeyTcpDst(_) => TCA_FLOWER_KEY_TCP_DST,
+            KeyUdpSrc(_) => TCA_FLOWER_KEY_UDP_SRC,
+            KeyUdpDst(_) => TCA_FLOWER_KEY_UDP_DST,
+            Flags(_) => TCA_FLOWER_FLAGS,
+            KeyVlanId(_) => TCA_FLOWER_KEY_VLAN_ID,
+            KeyVlanPrio(_) => TCA_FLOWER_KEY_VLAN_PRIO,
+            KeyVlanEthType(_) => TCA_FLOWER_KEY_VLAN_ETH_TYPE,
+            KeyTcpSrcMask(_) => TCA_FLOWER_KEY_TCP_SRC_MASK,
+            KeyTcpDstMask(_) => TCA_FLOWER_KEY_TCP_DST_MASK,
+            KeyUdpSrcMask(_) => TCA_FLOWER_KEY_UDP_SRC_MASK,
+            KeyUdpDstMask(_) => TCA_FLOWER_KEY_UDP_DST_MASK,
+            KeySctpSrcMask(_) => TCA_FLOWER_KEY_SCTP_SRC_MASK,
+            KeySctpDstMask(_) => TCA_FLOWER_KEY_SCTP_DST_MASK,
+            KeySctpSrc(_) => TCA_FLOWER_KEY_SCTP_SRC,
+            KeySctpDst(_) => TCA_FLOWER_KEY_SCTP_DST,
+            KeyIcmpv4Code(_) => TCA_FLOWER_KEY_ICMPV4_CODE,
+            KeyIcmpv4CodeMask(_) => TCA_FLOWER_KEY_ICMPV4_CODE_MASK,
+            KeyIcmpv4Type(_) => TCA_FLOWER_KEY_ICMPV4_TYPE,
+            KeyIcmpv4TypeMask(_) => TCA_FLOWER_KEY_ICMPV4_TYPE_MASK,
+            KeyIcmpv6Code(_) => TCA_FLOWER_KEY_ICMPV6_CODE,
+            KeyIcmpv6CodeMask(_) => TCA_FLOWER_KEY_ICMPV6_CODE_MASK,
+            KeyIcmpv6Type(_) => TCA_FLOWER_KEY_ICMPV6_TYPE,
+            KeyIcmpv6TypeMask(_) => TCA_FLOWER_KEY_ICMPV6_TYPE_MASK,
+            KeyIpTos(_) => TCA_FLOWER_KEY_IP_TOS,
+            KeyIpTosMask(_) => TCA_FLOWER_KEY_IP_TOS_MASK,
+            KeyIpTtl(_) => TCA_FLOWER_KEY_IP_TTL,
+            KeyIpTtlMask(_) => TCA_FLOWER_KEY_IP_TTL_MASK,
+            InHwCount(_) => TCA_FLOWER_IN_HW_COUNT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+fn parse_ipv4(payload: &[u8]) -> Result<Ipv4Addr, DecodeError> {
+    if payload.len() != 4 {
+        return Err(format!("invalid IPv4 address: {:?}", payload).into());
+    }
+    Ok(Ipv4Addr::new(
+        payload[0], payload[1], payload[2], payload[3],
+    ))
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Flower {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Flower::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FLOWER_UNSPEC => Unspec(payload.to_vec()),
+            TCA_FLOWER_CLASSID => {
+                ClassId(parse_u32(payload).context("invalid TCA_FLOWER_CLASSID value")?)
+            }
+            TCA_FLOWER_INDEV => {
+                Indev(parse_string(payload).context("invalid TCA_FLOWER_INDEV value")?)
+            }
+            TCA_FLOWER_ACT => Act(parse_nlas(payload).context("invalid TCA_FLOWER_ACT value")?),
+            TCA_FLOWER_KEY_ETH_DST => {
+                KeyEthDst(parse_mac(payload).context("invalid TCA_FLOWER_KEY_ETH_DST value")?)
+            }
+            TCA_FLOWER_KEY_ETH_DST_MASK => KeyEthDstMask(
+                parse_mac(payload).context("invalid TCA_FLOWER_KEY_ETH_DST_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_ETH_SRC => {
+                KeyEthSrc(parse_mac(payload).context("invalid TCA_FLOWER_KEY_ETH_SRC value")?)
+            }
+            TCA_FLOWER_KEY_ETH_SRC_MASK => KeyEthSrcMask(
+                parse_mac(payload).context("invalid TCA_FLOWER_KEY_ETH_SRC_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_ETH_TYPE => {
+                KeyEthType(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_ETH_TYPE value")?)
+            }
+            TCA_FLOWER_KEY_IP_PROTO => {
+                KeyIpProto(parse_u8(payload).context("invalid TCA_FLOWER_KEY_IP_PROTO value")?)
+            }
+            TCA_FLOWER_KEY_IPV4_SRC => {
+                KeyIpv4Src(parse_ipv4(payload).context("invalid TCA_FLOWER_KEY_IPV4_SRC value")?)
+            }
+            TCA_FLOWER_KEY_IPV4_SRC_MASK => KeyIpv4SrcMask(
+                parse_ipv4(payload).context("invalid TCA_FLOWER_KEY_IPV4_SRC_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_IPV4_DST => {
+                KeyIpv4Dst(parse_ipv4(payload).context("invalid TCA_FLOWER_KEY_IPV4_DST value")?)
+            }
+            TCA_FLOWER_KEY_IPV4_DST_MASK => KeyIpv4DstMask(
+                parse_ipv4(payload).context("invalid TCA_FLOWER_KEY_IPV4_DST_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_IPV6_SRC => KeyIpv6Src(
+                parse_ipv6(payload)
+                    .context("invalid TCA_FLOWER_KEY_IPV6_SRC value")?
+                    .into(),
+            ),
+            TCA_FLOWER_KEY_IPV6_SRC_MASK => KeyIpv6SrcMask(
+                parse_ipv6(payload)
+                    .context("invalid TCA_FLOWER_KEY_IPV6_SRC_MASK value")?
+                    .into(),
+            ),
+            TCA_FLOWER_KEY_IPV6_DST => KeyIpv6Dst(
+                parse_ipv6(payload)
+                    .context("invalid TCA_FLOWER_KEY_IPV6_DST value")?
+                    .into(),
+            ),
+            TCA_FLOWER_KEY_IPV6_DST_MASK => KeyIpv6DstMask(
+                parse_ipv6(payload)
+                    .context("invalid TCA_FLOWER_KEY_IPV6_DST_MASK value")?
+                    .into(),
+            ),
+            TCA_FLOWER_KEY_TCP_SRC => {
+                KeyTcpSrc(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_TCP_SRC value")?)
+            }
+            TCA_FLOWER_KEY_TCP_DST => {
+                KeyTcpDst(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_TCP_DST value")?)
+            }
+            TCA_FLOWER_KEY_UDP_SRC => {
+                KeyUdpSrc(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_UDP_SRC value")?)
+            }
+            TCA_FLOWER_KEY_UDP_DST => {
+                KeyUdpDst(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_UDP_DST value")?)
+            }
+            TCA_FLOWER_FLAGS => {
+                Flags(parse_u32(payload).context("invalid TCA_FLOWER_FLAGS value")?)
+            }
+            TCA_FLOWER_KEY_VLAN_ID => {
+                KeyVlanId(parse_u16(payload).context("invalid TCA_FLOWER_KEY_VLAN_ID value")?)
+            }
+            TCA_FLOWER_KEY_VLAN_PRIO => {
+                KeyVlanPrio(parse_u8(payload).context("invalid TCA_FLOWER_KEY_VLAN_PRIO value")?)
+            }
+            TCA_FLOWER_KEY_VLAN_ETH_TYPE => KeyVlanEthType(
+                parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_VLAN_ETH_TYPE value")?,
+            ),
+            TCA_FLOWER_KEY_TCP_SRC_MASK => KeyTcpSrcMask(
+                parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_TCP_SRC_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_TCP_DST_MASK => KeyTcpDstMask(
+                parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_TCP_DST_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_UDP_SRC_MASK => KeyUdpSrcMask(
+                parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_UDP_SRC_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_UDP_DST_MASK => KeyUdpDstMask(
+                parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_UDP_DST_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_SCTP_SRC_MASK => KeySctpSrcMask(
+                parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_SCTP_SRC_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_SCTP_DST_MASK => KeySctpDstMask(
+                parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_SCTP_DST_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_SCTP_SRC => {
+                KeySctpSrc(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_SCTP_SRC value")?)
+            }
+            TCA_FLOWER_KEY_SCTP_DST => {
+                KeySctpDst(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_SCTP_DST value")?)
+            }
+            TCA_FLOWER_KEY_ICMPV4_CODE => KeyIcmpv4Code(
+                parse_u8(payload).context("invalid TCA_FLOWER_KEY_ICMPV4_CODE value")?,
+            ),
+            TCA_FLOWER_KEY_ICMPV4_CODE_MASK => KeyIcmpv4CodeMask(
+                parse_u8(payload).context("invalid TCA_FLOWER_KEY_ICMPV4_CODE_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_ICMPV4_TYPE => KeyIcmpv4Type(
+                parse_u8(payload).context("invalid TCA_FLOWER_KEY_ICMPV4_TYPE value")?,
+            ),
+            TCA_FLOWER_KEY_ICMPV4_TYPE_MASK => KeyIcmpv4TypeMask(
+                parse_u8(payload).context("invalid TCA_FLOWER_KEY_ICMPV4_TYPE_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_ICMPV6_CODE => KeyIcmpv6Code(
+                parse_u8(payload).context("invalid TCA_FLOWER_KEY_ICMPV6_CODE value")?,
+            ),
+            TCA_FLOWER_KEY_ICMPV6_CODE_MASK => KeyIcmpv6CodeMask(
+                parse_u8(payload).context("invalid TCA_FLOWER_KEY_ICMPV6_CODE_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_ICMPV6_TYPE => KeyIcmpv6Type(
+                parse_u8(payload).context("invalid TCA_FLOWER_KEY_ICMPV6_TYPE value")?,
+            ),
+            TCA_FLOWER_KEY_ICMPV6_TYPE_MASK => KeyIcmpv6TypeMask(
+                parse_u8(payload).context("invalid TCA_FLOWER_KEY_ICMPV6_TYPE_MASK value")?,
+            ),
+            TCA_FLOWER_KEY_IP_TOS => {
+                KeyIpTos(parse_u8(payload).context("invalid TCA_FLOWER_KEY_IP_TOS value")?)
+            }
+            TCA_FLOWER_KEY_IP_TOS_MASK => {
+                KeyIpTosMask(parse_u8(payload).context("invalid TCA_FLOWER_KEY_IP_TOS_MASK value")?)
+            }
+            TCA_FLOWER_KEY_IP_TTL => {
+                KeyIpTtl(parse_u8(payload).context("invalid TCA_FLOWER_KEY_IP_TTL value")?)
+            }
+            TCA_FLOWER_KEY_IP_TTL_MASK => {
+                KeyIpTtlMask(parse_u8(payload).context("invalid TCA_FLOWER_KEY_IP_TTL_MASK value")?)
+            }
+            TCA_FLOWER_IN_HW_COUNT => {
+                InHwCount(parse_u32(payload).context("invalid TCA_FLOWER_IN_HW_COUNT value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid flower option")?),
+        })
+    }
+}
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::parse_u32,
+    tc::{actions::TcAction, parse_nlas},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const MATCHALL: &str = "matchall";
+
+/// Options of the `matchall` classifier, which matches every packet
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Matchall {
+    Unspec(Vec<u8>),
+    ClassId(u32),
+    /// Actions to run on the packets
+    Act(Vec<TcAction>),
+    /// Offloading flags, as a combination of the `TCA_CLS_FLAGS_*` constants
+    Flags(u32),
+    Pcnt(Vec<u8>),
+    Pad(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Matchall {
+    fn value_len(&self) -> usize {
+        use self::Matchall::*;
+        match self {
+            Unspec(bytes) | Pcnt(bytes) | Pad(bytes) => bytes.len(),
+            ClassId(_) | Flags(_) => 4,
+            Act(actions) => actions.as_slice().buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Matchall::*;
+        match self {
+            Unspec(bytes) | Pcnt(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            ClassId(value) | Flags(value) => NativeEndian::write_u32(buffer, *value),
+            Act(actions) => actions.as_slice().emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Matchall::*;
+        match self {
+            Unspec(_) => TCA_MATCHALL_UNSPEC,
+            ClassId(_) => TCA_MATCHALL_CLASSID,
+            Act(_) => TCA_MATCHALL_ACT,
+            Flags(_) => TCA_MATCHALL_FLAGS,
+            Pcnt(_) => TCA_MATCHALL_PCNT,
+            Pad(_) => TCA_MATCHALL_PAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Matchall {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Matchall::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_MATCHALL_UNSPEC => Unspec(payload.to_vec()),
+            TCA_MATCHALL_CLASSID => {
+                ClassId(parse_u32(payload).context("invalid TCA_MATCHALL_CLASSID value")?)
+            }
+            TCA_MATCHALL_ACT => Act(parse_nlas(payload).context("invalid TCA_MATCHALL_ACT value")?),
+            TCA_MATCHALL_FLAGS => {
+                Flags(parse_u32(payload).context("invalid TCA_MATCHALL_FLAGS value")?)
+            }
+            TCA_MATCHALL_PCNT => Pcnt(payload.to_vec()),
+            TCA_MATCHALL_PAD => Pad(payload.to_vec()),
+            _ => Other(DefaultNla::parse(buf).context("invalid matchall option")?),
+        })
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod bpf;
+pub use self::bpf::*;
+
+mod flower;
+pub use self::flower::*;
+
+mod matchall;
+pub use self::matchall::*;
+
+mod u32;
+pub use self::u32::*;
@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_string, parse_u32},
+    tc::{
+        actions::{Police, TcAction},
+        parse_nlas,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const U32: &str = "u32";
+
+/// Options of the `u32` classifier, which matches packets on arbitrary
+/// 32 bits words of their headers
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum U32 {
+    Unspec(Vec<u8>),
+    ClassId(u32),
+    /// Handle of the hash table the filter is inserted in
+    Hash(u32),
+    /// Handle of the hash table to continue the classification with
+    Link(u32),
+    /// Number of buckets of a new hash table
+    Divisor(u32),
+    Sel(U32Sel),
+    Police(Vec<Police>),
+    /// Actions to run on the matching packets
+    Act(Vec<TcAction>),
+    /// Only match packets received on this interface
+    Indev(String),
+    Pcnt(Vec<u8>),
+    Mark(U32Mark),
+    /// Offloading flags, as a combination of the `TCA_CLS_FLAGS_*` constants
+    Flags(u32),
+    Pad(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for U32 {
+    fn value_len(&self) -> usize {
+        use self::U32::*;
+        match self {
+            Unspec(bytes) | Pcnt(bytes) | Pad(bytes) => bytes.len(),
+            ClassId(_) | Hash(_) | Link(_) | Divisor(_) | Flags(_) => 4,
+            Sel(sel) => sel.buffer_len(),
+            Police(nlas) => nlas.as_slice().buffer_len(),
+            Act(actions) => actions.as_slice().buffer_len(),
+            Indev(string) => string.len() + 1,
+            Mark(mark) => mark.buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::U32::*;
+        match self {
+            Unspec(bytes) | Pcnt(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            ClassId(value) | Hash(value) | Link(value) | Divisor(value) | Flags(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Sel(sel) => sel.emit(buffer),
+            Police(nlas) => nlas.as_slice().emit(buffer),
+            Act(actions) => actions.as_slice().emit(buffer),
+            Indev(string) => {
+                buffer[..string.len()].copy_from_slice(string.as_bytes());
+                buffer[string.len()] = 0;
+            }
+            Mark(mark) => mark.emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::U32::*;
+        match self {
+            Unspec(_) => TCA_U32_UNSPEC,
+            ClassId(_) => TCA_U32_CLASSID,
+            Hash(_) => TCA_U32_HASH,
+            Link(_) => TCA_U32_LINK,
+            Divisor(_) => TCA_U32_DIVISOR,
+            Sel(_) => TCA_U32_SEL,
+            Police(_) => TCA_U32_POLICE,
+            Act(_) => TCA_U32_ACT,
+            Indev(_) => TCA_U32_INDEV,
+            Pcnt(_) => TCA_U32_PCNT,
+            Mark(_) => TCA_U32_MARK,
+            Flags(_) => TCA_U32_FLAGS,
+            Pad(_) => TCA_U32_PAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for U32 {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::U32::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_U32_UNSPEC => Unspec(payload.to_vec()),
+            TCA_U32_CLASSID => {
+                ClassId(parse_u32(payload).context("invalid TCA_U32_CLASSID value")?)
+            }
+            TCA_U32_HASH => Hash(parse_u32(payload).context("invalid TCA_U32_HASH value")?),
+            TCA_U32_LINK => Link(parse_u32(payload).context("invalid TCA_U32_LINK value")?),
+            TCA_U32_DIVISOR => {
+                Divisor(parse_u32(payload).context("invalid TCA_U32_DIVISOR value")?)
+            }
+            TCA_U32_SEL => Sel(U32Sel::parse(&U32SelBuffer::new_checked(payload)?)
+                .context("invalid TCA_U32_SEL value")?),
+            TCA_U32_POLICE => Police(parse_nlas(payload).context("invalid TCA_U32_POLICE value")?),
+            TCA_U32_ACT => Act(parse_nlas(payload).context("invalid TCA_U32_ACT value")?),
+            TCA_U32_INDEV => Indev(parse_string(payload).context("invalid TCA_U32_INDEV value")?),
+            TCA_U32_PCNT => Pcnt(payload.to_vec()),
+            TCA_U32_MARK => Mark(
+                U32Mark::parse(&U32MarkBuffer::new_checked(payload)?)
+                    .context("invalid TCA_U32_MARK value")?,
+            ),
+            TCA_U32_FLAGS => Flags(parse_u32(payload).context("invalid TCA_U32_FLAGS value")?),
+            TCA_U32_PAD => Pad(payload.to_vec()),
+            _ => Other(DefaultNla::parse(buf).context("invalid u32 option")?),
+        })
+    }
+}
+
+/// Selector of the `u32` classifier (`struct tc_u32_sel`). A packet matches
+/// the selector if it matches all its keys. Fields the kernel stores in
+/// network byte order are given in host byte order.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct U32Sel {
+    /// Combination of the `TC_U32_*` constants
+    pub flags: u8,
+    pub offshift: u8,
+    pub offmask: u16,
+    pub off: u16,
+    pub offoff: i16,
+    pub hoff: i16,
+    pub hmask: u32,
+    pub keys: Vec<U32Key>,
+}
+
+pub const U32_SEL_LEN: usize = 16;
+
+buffer!(U32SelBuffer(U32_SEL_LEN) {
+    flags: (u8, 0),
+    offshift: (u8, 1),
+    nkeys: (u8, 2),
+    offmask: (u16, 4..6),
+    off: (u16, 6..8),
+    offoff: (i16, 8..10),
+    hoff: (i16, 10..12),
+    hmask: (u32, 12..16),
+    keys: (slice, U32_SEL_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<U32SelBuffer<&'a T>> for U32Sel {
+    fn parse(buf: &U32SelBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let nkeys = buf.nkeys() as usize;
+        let keys = buf.keys();
+        if keys.len() < nkeys * U32_KEY_LEN {
+            return Err(format!("expected {} u32 keys, got {} bytes", nkeys, keys.len()).into());
+        }
+        Ok(Self {
+            flags: buf.flags(),
+            offshift: buf.offshift(),
+            offmask: u16::from_be(buf.offmask()),
+            off: buf.off(),
+            offoff: buf.offoff(),
+            hoff: buf.hoff(),
+            hmask: u32::from_be(buf.hmask()),
+            keys: keys
+                .chunks_exact(U32_KEY_LEN)
+                .take(nkeys)
+                .map(|key| U32Key::parse(&U32KeyBuffer::new(key)))
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl Emitable for U32Sel {
+    fn buffer_len(&self) -> usize {
+        U32_SEL_LEN + self.keys.len() * U32_KEY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = U32SelBuffer::new(buffer);
+        buffer.set_flags(self.flags);
+        buffer.set_offshift(self.offshift);
+        buffer.set_nkeys(self.keys.len() as u8);
+        buffer.set_offmask(self.offmask.to_be());
+        buffer.set_off(self.off);
+        buffer.set_offoff(self.offoff);
+        buffer.set_hoff(self.hoff);
+        buffer.set_hmask(self.hmask.to_be());
+        for (key, chunk) in self
+            .keys
+            .iter()
+            .zip(buffer.keys_mut().chunks_exact_mut(U32_KEY_LEN))
+        {
+            key.emit(chunk);
+        }
+    }
+}
+
+/// A 32 bits word to match (`struct tc_u32_key`). The key matches if
+/// `word & mask == val`, `mask` and `val` being in host byte order.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct U32Key {
+    pub mask: u32,
+    pub val: u32,
+    /// Offset of the word, relative to the start of the network header
+    pub off: i32,
+    pub offmask: i32,
+}
+
+pub const U32_KEY_LEN: usize = 16;
+
+buffer!(U32KeyBuffer(U32_KEY_LEN) {
+    mask: (u32, 0..4),
+    val: (u32, 4..8),
+    off: (i32, 8..12),
+    offmask: (i32, 12..16),
+});
+
+impl<T: AsRef<[u8]>> Parseable<U32KeyBuffer<T>> for U32Key {
+    fn parse(buf: &U32KeyBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            mask: u32::from_be(buf.mask()),
+            val: u32::from_be(buf.val()),
+            off: buf.off(),
+            offmask: buf.offmask(),
+        })
+    }
+}
+
+impl Emitable for U32Key {
+    fn buffer_len(&self) -> usize {
+        U32_KEY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = U32KeyBuffer::new(buffer);
+        buffer.set_mask(self.mask.to_be());
+        buffer.set_val(self.val.to_be());
+        buffer.set_off(self.off);
+        buffer.set_offmask(self.offmask);
+    }
+}
+
+/// Match on the firewall mark of the packets (`struct tc_u32_mark`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct U32Mark {
+    pub val: u32,
+    pub mask: u32,
+    /// Number of packets that matched, only set by the kernel
+    pub success: u32,
+}
+
+pub const U32_MARK_LEN: usize = 12;
+
+buffer!(U32MarkBuffer(U32_MARK_LEN) {
+    val: (u32, 0..4),
+    mask: (u32, 4..8),
+    success: (u32, 8..12),
+});
+
+impl<T: AsRef<[u8]>> Parseable<U32MarkBuffer<T>> for U32Mark {
+    fn parse(buf: &U32MarkBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            val: buf.val(),
+            mask: buf.mask(),
+            success: buf.success(),
+        })
+    }
+}
+
+impl Emitable for U32Mark {
+    fn buffer_len(&self) -> usize {
+        U32_MARK_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = U32MarkBuffer::new(buffer);
+        buffer.set_val(self.val);
+        buffer.set_mask(self.mask);
+        buffer.set_success(self.success);
+    }
+}
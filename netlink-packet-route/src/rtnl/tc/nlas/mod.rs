@@ -9,11 +9,21 @@ pub use self::stats_queue::*;
 mod stats_basic;
 pub use self::stats_basic::*;
 
+mod ratespec;
+pub use self::ratespec::*;
+
+mod options;
+pub use self::options::*;
+
+pub mod actions;
+pub mod filters;
+pub mod qdiscs;
+
 use crate::{
     constants::*,
     nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
     parsers::{parse_string, parse_u8},
-    traits::{Emitable, Parseable},
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
@@ -24,11 +34,11 @@ pub enum Nla {
     /// Name of queueing discipline
     Kind(String),
     /// Qdisc-specific options follow
-    Options(Vec<u8>),
+    Options(TcOptions),
     /// Qdisc statistics
     Stats(Stats),
     /// Module-specific statistics
-    XStats(Vec<u8>),
+    XStats(TcXStats),
     /// Rate limit
     Rate(Vec<u8>),
     Fcnt(Vec<u8>),
@@ -46,16 +56,16 @@ impl nlas::Nla for Nla {
         match *self {
             // Vec<u8>
             Unspec(ref bytes)
-                | Options(ref bytes)
-                | XStats(ref bytes)
                 | Rate(ref bytes)
                 | Fcnt(ref bytes)
                 | Stab(ref bytes)
                 | Chain(ref bytes) => bytes.len(),
+            Options(ref options) => options.buffer_len(),
+            XStats(ref xstats) => xstats.buffer_len(),
             HwOffload(_) => 1,
             Stats2(ref thing) => thing.as_slice().buffer_len(),
             Stats(_) => STATS_LEN,
-            Kind(ref string) => string.as_bytes().len() + 1,
+            Kind(ref string) => string.len() + 1,
 
             // Defaults
             Other(ref attr)  => attr.value_len(),
@@ -68,20 +78,21 @@ impl nlas::Nla for Nla {
         match *self {
             // Vec<u8>
             Unspec(ref bytes)
-                | Options(ref bytes)
-                | XStats(ref bytes)
                 | Rate(ref bytes)
                 | Fcnt(ref bytes)
                 | Stab(ref bytes)
                 | Chain(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
 
+            Options(ref options) => options.emit(buffer),
+            XStats(ref xstats) => xstats.emit(buffer),
+
             HwOffload(ref val) => buffer[0] = *val,
             Stats2(ref stats) => stats.as_slice().emit(buffer),
             Stats(ref stats) => stats.emit(buffer),
 
             Kind(ref string) => {
-                buffer.copy_from_slice(string.as_bytes());
-                buffer[string.as_bytes().len()] = 0;
+                buffer[..string.len()].copy_from_slice(string.as_bytes());
+                buffer[string.len()] = 0;
             }
 
             // Default
@@ -108,15 +119,18 @@ impl nlas::Nla for Nla {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
-    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, &str> for Nla {
+    /// Parse a tc attribute. The format of the `TCA_OPTIONS` and
+    /// `TCA_XSTATS` attributes depends on `kind`, the value of the
+    /// `TCA_KIND` attribute of the message.
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, kind: &str) -> Result<Self, DecodeError> {
         let payload = buf.value();
         Ok(match buf.kind() {
             TCA_UNSPEC => Self::Unspec(payload.to_vec()),
             TCA_KIND => Self::Kind(parse_string(payload)?),
-            TCA_OPTIONS => Self::Options(payload.to_vec()),
+            TCA_OPTIONS => Self::Options(TcOptions::parse_with_param(buf, kind)?),
             TCA_STATS => Self::Stats(Stats::parse(&StatsBuffer::new_checked(payload)?)?),
-            TCA_XSTATS => Self::XStats(payload.to_vec()),
+            TCA_XSTATS => Self::XStats(TcXStats::parse_with_param(buf, kind)?),
             TCA_RATE => Self::Rate(payload.to_vec()),
             TCA_FCNT => Self::Fcnt(payload.to_vec()),
             TCA_STATS2 => {
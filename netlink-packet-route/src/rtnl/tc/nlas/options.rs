@@ -0,0 +1,176 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::{NlaBuffer, NlasIterator},
+    tc::{filters::*, qdiscs::*},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+/// Options of a qdisc, class or filter (`TCA_OPTIONS`). Their format depends
+/// on the kind of the qdisc or classifier, so options of unknown kinds, and
+/// options that cannot be decoded, are kept as raw bytes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TcOptions {
+    FqCodel(Vec<FqCodel>),
+    Htb(Vec<Htb>),
+    Tbf(Vec<Tbf>),
+    Netem(NetemQopt, Vec<Netem>),
+    Prio(PrioQopt),
+    Ingress,
+    Clsact,
+    Mq,
+    U32(Vec<U32>),
+    Flower(Vec<Flower>),
+    Matchall(Vec<Matchall>),
+    Bpf(Vec<Bpf>),
+    Other(Vec<u8>),
+}
+
+impl TcOptions {
+    /// Return the kind of qdisc or classifier these options apply to, if
+    /// known
+    pub fn kind(&self) -> Option<&'static str> {
+        use self::TcOptions::*;
+        match self {
+            FqCodel(_) => Some(FQ_CODEL),
+            Htb(_) => Some(HTB),
+            Tbf(_) => Some(TBF),
+            Netem(_, _) => Some(NETEM),
+            Prio(_) => Some(PRIO),
+            Ingress => Some(INGRESS),
+            Clsact => Some(CLSACT),
+            Mq => Some(MQ),
+            U32(_) => Some(self::U32),
+            Flower(_) => Some(FLOWER),
+            Matchall(_) => Some(MATCHALL),
+            Bpf(_) => Some(BPF),
+            Other(_) => None,
+        }
+    }
+}
+
+impl Emitable for TcOptions {
+    fn buffer_len(&self) -> usize {
+        use self::TcOptions::*;
+        match self {
+            FqCodel(nlas) => nlas.as_slice().buffer_len(),
+            Htb(nlas) => nlas.as_slice().buffer_len(),
+            Tbf(nlas) => nlas.as_slice().buffer_len(),
+            Netem(qopt, nlas) => qopt.buffer_len() + nlas.as_slice().buffer_len(),
+            Prio(qopt) => qopt.buffer_len(),
+            Ingress | Clsact | Mq => 0,
+            U32(nlas) => nlas.as_slice().buffer_len(),
+            Flower(nlas) => nlas.as_slice().buffer_len(),
+            Matchall(nlas) => nlas.as_slice().buffer_len(),
+            Bpf(nlas) => nlas.as_slice().buffer_len(),
+            Other(bytes) => bytes.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        use self::TcOptions::*;
+        match self {
+            FqCodel(nlas) => nlas.as_slice().emit(buffer),
+            Htb(nlas) => nlas.as_slice().emit(buffer),
+            Tbf(nlas) => nlas.as_slice().emit(buffer),
+            Netem(qopt, nlas) => {
+                qopt.emit(buffer);
+                nlas.as_slice().emit(&mut buffer[qopt.buffer_len()..]);
+            }
+            Prio(qopt) => qopt.emit(buffer),
+            Ingress | Clsact | Mq => {}
+            U32(nlas) => nlas.as_slice().emit(buffer),
+            Flower(nlas) => nlas.as_slice().emit(buffer),
+            Matchall(nlas) => nlas.as_slice().emit(buffer),
+            Bpf(nlas) => nlas.as_slice().emit(buffer),
+            Other(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+        }
+    }
+}
+
+pub(crate) fn parse_nlas<'a, N>(payload: &'a [u8]) -> Result<Vec<N>, DecodeError>
+where
+    N: Parseable<NlaBuffer<&'a [u8]>>,
+{
+    let mut nlas = vec![];
+    for nla in NlasIterator::new(payload) {
+        nlas.push(N::parse(&nla?)?);
+    }
+    Ok(nlas)
+}
+
+impl TcOptions {
+    fn parse_kind(payload: &[u8], kind: &str) -> Result<Self, DecodeError> {
+        Ok(match kind {
+            FQ_CODEL => Self::FqCodel(parse_nlas(payload)?),
+            HTB => Self::Htb(parse_nlas(payload)?),
+            TBF => Self::Tbf(parse_nlas(payload)?),
+            NETEM => {
+                let qopt = NetemQopt::parse(&NetemQoptBuffer::new_checked(payload)?)?;
+                Self::Netem(qopt, parse_nlas(&payload[NETEM_QOPT_LEN..])?)
+            }
+            PRIO => Self::Prio(PrioQopt::parse(&PrioQoptBuffer::new_checked(payload)?)?),
+            INGRESS if payload.is_empty() => Self::Ingress,
+            CLSACT if payload.is_empty() => Self::Clsact,
+            MQ if payload.is_empty() => Self::Mq,
+            U32 => Self::U32(parse_nlas(payload)?),
+            FLOWER => Self::Flower(parse_nlas(payload)?),
+            MATCHALL => Self::Matchall(parse_nlas(payload)?),
+            BPF => Self::Bpf(parse_nlas(payload)?),
+            _ => Self::Other(payload.to_vec()),
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, &str> for TcOptions {
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, kind: &str) -> Result<Self, DecodeError> {
+        // Newer kernels may send options that this crate does not know how to
+        // decode. Keep them as raw bytes instead of failing the whole message.
+        let payload = buf.value();
+        Ok(Self::parse_kind(payload, kind).unwrap_or_else(|_| Self::Other(payload.to_vec())))
+    }
+}
+
+/// Qdisc or class specific statistics (`TCA_XSTATS`). Statistics of unknown
+/// kinds are kept as raw bytes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TcXStats {
+    FqCodel(FqCodelXStats),
+    Htb(HtbXStats),
+    Other(Vec<u8>),
+}
+
+impl Emitable for TcXStats {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::FqCodel(stats) => stats.buffer_len(),
+            Self::Htb(stats) => stats.buffer_len(),
+            Self::Other(bytes) => bytes.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::FqCodel(stats) => stats.emit(buffer),
+            Self::Htb(stats) => stats.emit(buffer),
+            Self::Other(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, &str> for TcXStats {
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, kind: &str) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match kind {
+            FQ_CODEL => Self::FqCodel(FqCodelXStats::parse(payload)?),
+            HTB => Self::Htb(
+                HtbXStats::parse(&HtbXStatsBuffer::new_checked(payload)?)
+                    .context("invalid htb xstats")?,
+            ),
+            _ => Self::Other(payload.to_vec()),
+        })
+    }
+}
@@ -0,0 +1,313 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const FQ_CODEL: &str = "fq_codel";
+
+/// Options of the `fq_codel` qdisc
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FqCodel {
+    Unspec(Vec<u8>),
+    /// Target queue delay, in microseconds
+    Target(u32),
+    /// Maximum number of packets in the queue
+    Limit(u32),
+    /// Width of the moving time window, in microseconds
+    Interval(u32),
+    /// Mark packets with ECN instead of dropping them
+    Ecn(u32),
+    /// Number of flows
+    Flows(u32),
+    /// Number of bytes dequeued from a flow at once
+    Quantum(u32),
+    /// Queue delay above which packets are CE marked, in microseconds
+    CeThreshold(u32),
+    /// Maximum number of packets dropped at once when the limit is reached
+    DropBatchSize(u32),
+    /// Maximum number of bytes used by the queued packets
+    MemoryLimit(u32),
+    CeThresholdSelector(u8),
+    CeThresholdMask(u8),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for FqCodel {
+    fn value_len(&self) -> usize {
+        use self::FqCodel::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Target(_) | Limit(_) | Interval(_) | Ecn(_) | Flows(_) | Quantum(_)
+            | CeThreshold(_) | DropBatchSize(_) | MemoryLimit(_) => 4,
+            CeThresholdSelector(_) | CeThresholdMask(_) => 1,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::FqCodel::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Target(value) | Limit(value) | Interval(value) | Ecn(value) | Flows(value)
+            | Quantum(value) | CeThreshold(value) | DropBatchSize(value) | MemoryLimit(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            CeThresholdSelector(value) | CeThresholdMask(value) => buffer[0] = *value,
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::FqCodel::*;
+        match self {
+            Unspec(_) => TCA_FQ_CODEL_UNSPEC,
+            Target(_) => TCA_FQ_CODEL_TARGET,
+            Limit(_) => TCA_FQ_CODEL_LIMIT,
+            Interval(_) => TCA_FQ_CODEL_INTERVAL,
+            Ecn(_) => TCA_FQ_CODEL_ECN,
+            Flows(_) => TCA_FQ_CODEL_FLOWS,
+            Quantum(_) => TCA_FQ_CODEL_QUANTUM,
+            CeThreshold(_) => TCA_FQ_CODEL_CE_THRESHOLD,
+            DropBatchSize(_) => TCA_FQ_CODEL_DROP_BATCH_SIZE,
+            MemoryLimit(_) => TCA_FQ_CODEL_MEMORY_LIMIT,
+            CeThresholdSelector(_) => TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR,
+            CeThresholdMask(_) => TCA_FQ_CODEL_CE_THRESHOLD_MASK,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for FqCodel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::FqCodel::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FQ_CODEL_UNSPEC => Unspec(payload.to_vec()),
+            TCA_FQ_CODEL_TARGET => {
+                Target(parse_u32(payload).context("invalid TCA_FQ_CODEL_TARGET value")?)
+            }
+            TCA_FQ_CODEL_LIMIT => {
+                Limit(parse_u32(payload).context("invalid TCA_FQ_CODEL_LIMIT value")?)
+            }
+            TCA_FQ_CODEL_INTERVAL => {
+                Interval(parse_u32(payload).context("invalid TCA_FQ_CODEL_INTERVAL value")?)
+            }
+            TCA_FQ_CODEL_ECN => Ecn(parse_u32(payload).context("invalid TCA_FQ_CODEL_ECN value")?),
+            TCA_FQ_CODEL_FLOWS => {
+                Flows(parse_u32(payload).context("invalid TCA_FQ_CODEL_FLOWS value")?)
+            }
+            TCA_FQ_CODEL_QUANTUM => {
+                Quantum(parse_u32(payload).context("invalid TCA_FQ_CODEL_QUANTUM value")?)
+            }
+            TCA_FQ_CODEL_CE_THRESHOLD => {
+                CeThreshold(parse_u32(payload).context("invalid TCA_FQ_CODEL_CE_THRESHOLD value")?)
+            }
+            TCA_FQ_CODEL_DROP_BATCH_SIZE => DropBatchSize(
+                parse_u32(payload).context("invalid TCA_FQ_CODEL_DROP_BATCH_SIZE value")?,
+            ),
+            TCA_FQ_CODEL_MEMORY_LIMIT => {
+                MemoryLimit(parse_u32(payload).context("invalid TCA_FQ_CODEL_MEMORY_LIMIT value")?)
+            }
+            TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR => CeThresholdSelector(
+                parse_u8(payload).context("invalid TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR value")?,
+            ),
+            TCA_FQ_CODEL_CE_THRESHOLD_MASK => CeThresholdMask(
+                parse_u8(payload).context("invalid TCA_FQ_CODEL_CE_THRESHOLD_MASK value")?,
+            ),
+            _ => Other(DefaultNla::parse(buf).context("invalid fq_codel option")?),
+        })
+    }
+}
+
+/// Statistics of a `fq_codel` qdisc or of one of its flows
+/// (`struct tc_fq_codel_xstats`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FqCodelXStats {
+    Qdisc(FqCodelQdiscStats),
+    Class(FqCodelClassStats),
+    Other(Vec<u8>),
+}
+
+/// Length of `struct tc_fq_codel_xstats`, which is a type followed by the
+/// largest of the qdisc and class statistics
+pub const FQ_CODEL_XSTATS_LEN: usize = 4 + FQ_CODEL_QDISC_STATS_LEN;
+
+impl Emitable for FqCodelXStats {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Other(bytes) => bytes.len(),
+            _ => FQ_CODEL_XSTATS_LEN,
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Qdisc(stats) => {
+                NativeEndian::write_u32(&mut buffer[..4], TCA_FQ_CODEL_XSTATS_QDISC);
+                stats.emit(&mut buffer[4..]);
+            }
+            Self::Class(stats) => {
+                // the class statistics are shorter than the qdisc ones, so
+                // zero out the end of the union
+                for byte in buffer[4 + FQ_CODEL_CLASS_STATS_LEN..].iter_mut() {
+                    *byte = 0;
+                }
+                NativeEndian::write_u32(&mut buffer[..4], TCA_FQ_CODEL_XSTATS_CLASS);
+                stats.emit(&mut buffer[4..]);
+            }
+            Self::Other(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+        }
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for FqCodelXStats {
+    fn parse(buf: &T) -> Result<Self, DecodeError> {
+        let payload = buf.as_ref();
+        if payload.len() < 4 {
+            return Err(format!("invalid fq_codel xstats: {:?}", payload).into());
+        }
+        Ok(match NativeEndian::read_u32(&payload[..4]) {
+            TCA_FQ_CODEL_XSTATS_QDISC => Self::Qdisc(
+                FqCodelQdiscStats::parse(&FqCodelQdiscStatsBuffer::new_checked(&payload[4..])?)
+                    .context("invalid fq_codel qdisc xstats")?,
+            ),
+            TCA_FQ_CODEL_XSTATS_CLASS => Self::Class(
+                FqCodelClassStats::parse(&FqCodelClassStatsBuffer::new_checked(&payload[4..])?)
+                    .context("invalid fq_codel class xstats")?,
+            ),
+            _ => Self::Other(payload.to_vec()),
+        })
+    }
+}
+
+/// Statistics of a `fq_codel` qdisc (`struct tc_fq_codel_qd_stats`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct FqCodelQdiscStats {
+    /// Largest packet seen so far
+    pub maxpacket: u32,
+    /// Number of packets dropped because the limit was reached
+    pub drop_overlimit: u32,
+    /// Number of packets ECN marked instead of being dropped
+    pub ecn_mark: u32,
+    /// Number of times packets created a new flow
+    pub new_flow_count: u32,
+    /// Number of flows in the new list
+    pub new_flows_len: u32,
+    /// Number of flows in the old list
+    pub old_flows_len: u32,
+    /// Number of packets CE marked because of the CE threshold
+    pub ce_mark: u32,
+    /// Memory used by the queued packets
+    pub memory_usage: u32,
+    /// Number of packets dropped because the memory limit was reached
+    pub drop_overmemory: u32,
+}
+
+pub const FQ_CODEL_QDISC_STATS_LEN: usize = 36;
+
+buffer!(FqCodelQdiscStatsBuffer(FQ_CODEL_QDISC_STATS_LEN) {
+    maxpacket: (u32, 0..4),
+    drop_overlimit: (u32, 4..8),
+    ecn_mark: (u32, 8..12),
+    new_flow_count: (u32, 12..16),
+    new_flows_len: (u32, 16..20),
+    old_flows_len: (u32, 20..24),
+    ce_mark: (u32, 24..28),
+    memory_usage: (u32, 28..32),
+    drop_overmemory: (u32, 32..36),
+});
+
+impl<T: AsRef<[u8]>> Parseable<FqCodelQdiscStatsBuffer<T>> for FqCodelQdiscStats {
+    fn parse(buf: &FqCodelQdiscStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            maxpacket: buf.maxpacket(),
+            drop_overlimit: buf.drop_overlimit(),
+            ecn_mark: buf.ecn_mark(),
+            new_flow_count: buf.new_flow_count(),
+            new_flows_len: buf.new_flows_len(),
+            old_flows_len: buf.old_flows_len(),
+            ce_mark: buf.ce_mark(),
+            memory_usage: buf.memory_usage(),
+            drop_overmemory: buf.drop_overmemory(),
+        })
+    }
+}
+
+impl Emitable for FqCodelQdiscStats {
+    fn buffer_len(&self) -> usize {
+        FQ_CODEL_QDISC_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = FqCodelQdiscStatsBuffer::new(buffer);
+        buffer.set_maxpacket(self.maxpacket);
+        buffer.set_drop_overlimit(self.drop_overlimit);
+        buffer.set_ecn_mark(self.ecn_mark);
+        buffer.set_new_flow_count(self.new_flow_count);
+        buffer.set_new_flows_len(self.new_flows_len);
+        buffer.set_old_flows_len(self.old_flows_len);
+        buffer.set_ce_mark(self.ce_mark);
+        buffer.set_memory_usage(self.memory_usage);
+        buffer.set_drop_overmemory(self.drop_overmemory);
+    }
+}
+
+/// Statistics of a `fq_codel` flow (`struct tc_fq_codel_cl_stats`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct FqCodelClassStats {
+    pub deficit: i32,
+    /// Time spent in the queue by the last dequeued packet, in microseconds
+    pub ldelay: u32,
+    pub count: u32,
+    pub lastcount: u32,
+    pub dropping: u32,
+    pub drop_next: i32,
+}
+
+pub const FQ_CODEL_CLASS_STATS_LEN: usize = 24;
+
+buffer!(FqCodelClassStatsBuffer(FQ_CODEL_CLASS_STATS_LEN) {
+    deficit: (i32, 0..4),
+    ldelay: (u32, 4..8),
+    count: (u32, 8..12),
+    lastcount: (u32, 12..16),
+    dropping: (u32, 16..20),
+    drop_next: (i32, 20..24),
+});
+
+impl<T: AsRef<[u8]>> Parseable<FqCodelClassStatsBuffer<T>> for FqCodelClassStats {
+    fn parse(buf: &FqCodelClassStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            deficit: buf.deficit(),
+            ldelay: buf.ldelay(),
+            count: buf.count(),
+            lastcount: buf.lastcount(),
+            dropping: buf.dropping(),
+            drop_next: buf.drop_next(),
+        })
+    }
+}
+
+impl Emitable for FqCodelClassStats {
+    fn buffer_len(&self) -> usize {
+        FQ_CODEL_CLASS_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = FqCodelClassStatsBuffer::new(buffer);
+        buffer.set_deficit(self.deficit);
+        buffer.set_ldelay(self.ldelay);
+        buffer.set_count(self.count);
+        buffer.set_lastcount(self.lastcount);
+        buffer.set_dropping(self.dropping);
+        buffer.set_drop_next(self.drop_next);
+    }
+}
@@ -0,0 +1,268 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u32, parse_u64},
+    tc::{RateSpec, RateSpecBuffer, RATE_SPEC_LEN},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const HTB: &str = "htb";
+
+/// Options of the `htb` qdisc and of its classes
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Htb {
+    Unspec(Vec<u8>),
+    /// Parameters of a class
+    Parms(HtbOpt),
+    /// Parameters of the qdisc
+    Init(HtbGlob),
+    /// Ceil rate table
+    Ctab(Vec<u8>),
+    /// Rate table
+    Rtab(Vec<u8>),
+    /// Length of the queue of packets which are not classified
+    DirectQlen(u32),
+    /// Rate of a class, in bytes per second, when it does not fit in 32 bits
+    Rate64(u64),
+    /// Ceil rate of a class, in bytes per second, when it does not fit in
+    /// 32 bits
+    Ceil64(u64),
+    Pad(Vec<u8>),
+    /// Offload the qdisc to the hardware
+    Offload,
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Htb {
+    fn value_len(&self) -> usize {
+        use self::Htb::*;
+        match self {
+            Unspec(bytes) | Ctab(bytes) | Rtab(bytes) | Pad(bytes) => bytes.len(),
+            Parms(opt) => opt.buffer_len(),
+            Init(glob) => glob.buffer_len(),
+            DirectQlen(_) => 4,
+            Rate64(_) | Ceil64(_) => 8,
+            Offload => 0,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Htb::*;
+        match self {
+            Unspec(bytes) | Ctab(bytes) | Rtab(bytes) | Pad(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Parms(opt) => opt.emit(buffer),
+            Init(glob) => glob.emit(buffer),
+            DirectQlen(value) => NativeEndian::write_u32(buffer, *value),
+            Rate64(value) | Ceil64(value) => NativeEndian::write_u64(buffer, *value),
+            Offload => {}
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Htb::*;
+        match self {
+            Unspec(_) => TCA_HTB_UNSPEC,
+            Parms(_) => TCA_HTB_PARMS,
+            Init(_) => TCA_HTB_INIT,
+            Ctab(_) => TCA_HTB_CTAB,
+            Rtab(_) => TCA_HTB_RTAB,
+            DirectQlen(_) => TCA_HTB_DIRECT_QLEN,
+            Rate64(_) => TCA_HTB_RATE64,
+            Ceil64(_) => TCA_HTB_CEIL64,
+            Pad(_) => TCA_HTB_PAD,
+            Offload => TCA_HTB_OFFLOAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Htb {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Htb::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_HTB_UNSPEC => Unspec(payload.to_vec()),
+            TCA_HTB_PARMS => Parms(
+                HtbOpt::parse(&HtbOptBuffer::new_checked(payload)?)
+                    .context("invalid TCA_HTB_PARMS value")?,
+            ),
+            TCA_HTB_INIT => Init(
+                HtbGlob::parse(&HtbGlobBuffer::new_checked(payload)?)
+                    .context("invalid TCA_HTB_INIT value")?,
+            ),
+            TCA_HTB_CTAB => Ctab(payload.to_vec()),
+            TCA_HTB_RTAB => Rtab(payload.to_vec()),
+            TCA_HTB_DIRECT_QLEN => {
+                DirectQlen(parse_u32(payload).context("invalid TCA_HTB_DIRECT_QLEN value")?)
+            }
+            TCA_HTB_RATE64 => Rate64(parse_u64(payload).context("invalid TCA_HTB_RATE64 value")?),
+            TCA_HTB_CEIL64 => Ceil64(parse_u64(payload).context("invalid TCA_HTB_CEIL64 value")?),
+            TCA_HTB_PAD => Pad(payload.to_vec()),
+            TCA_HTB_OFFLOAD => Offload,
+            _ => Other(DefaultNla::parse(buf).context("invalid htb option")?),
+        })
+    }
+}
+
+/// Parameters of a `htb` class (`struct tc_htb_opt`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct HtbOpt {
+    pub rate: RateSpec,
+    pub ceil: RateSpec,
+    pub buffer: u32,
+    pub cbuffer: u32,
+    pub quantum: u32,
+    pub level: u32,
+    pub prio: u32,
+}
+
+pub const HTB_OPT_LEN: usize = 44;
+
+buffer!(HtbOptBuffer(HTB_OPT_LEN) {
+    rate: (slice, 0..RATE_SPEC_LEN),
+    ceil: (slice, RATE_SPEC_LEN..2 * RATE_SPEC_LEN),
+    buffer: (u32, 24..28),
+    cbuffer: (u32, 28..32),
+    quantum: (u32, 32..36),
+    level: (u32, 36..40),
+    prio: (u32, 40..44),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<HtbOptBuffer<&'a T>> for HtbOpt {
+    fn parse(buf: &HtbOptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rate: RateSpec::parse(&RateSpecBuffer::new(buf.rate()))?,
+            ceil: RateSpec::parse(&RateSpecBuffer::new(buf.ceil()))?,
+            buffer: buf.buffer(),
+            cbuffer: buf.cbuffer(),
+            quantum: buf.quantum(),
+            level: buf.level(),
+            prio: buf.prio(),
+        })
+    }
+}
+
+impl Emitable for HtbOpt {
+    fn buffer_len(&self) -> usize {
+        HTB_OPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = HtbOptBuffer::new(buffer);
+        self.rate.emit(buffer.rate_mut());
+        self.ceil.emit(buffer.ceil_mut());
+        buffer.set_buffer(self.buffer);
+        buffer.set_cbuffer(self.cbuffer);
+        buffer.set_quantum(self.quantum);
+        buffer.set_level(self.level);
+        buffer.set_prio(self.prio);
+    }
+}
+
+/// Parameters of a `htb` qdisc (`struct tc_htb_glob`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct HtbGlob {
+    /// Version of the htb implementation. Should be 3.
+    pub version: u32,
+    /// Factor used to compute the quantum of the classes from their rate
+    pub rate2quantum: u32,
+    /// Minor number of the class unclassified traffic is sent to
+    pub defcls: u32,
+    pub debug: u32,
+    /// Number of packets sent directly, without going through a class
+    pub direct_pkts: u32,
+}
+
+pub const HTB_GLOB_LEN: usize = 20;
+
+buffer!(HtbGlobBuffer(HTB_GLOB_LEN) {
+    version: (u32, 0..4),
+    rate2quantum: (u32, 4..8),
+    defcls: (u32, 8..12),
+    debug: (u32, 12..16),
+    direct_pkts: (u32, 16..20),
+});
+
+impl<T: AsRef<[u8]>> Parseable<HtbGlobBuffer<T>> for HtbGlob {
+    fn parse(buf: &HtbGlobBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            version: buf.version(),
+            rate2quantum: buf.rate2quantum(),
+            defcls: buf.defcls(),
+            debug: buf.debug(),
+            direct_pkts: buf.direct_pkts(),
+        })
+    }
+}
+
+impl Emitable for HtbGlob {
+    fn buffer_len(&self) -> usize {
+        HTB_GLOB_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = HtbGlobBuffer::new(buffer);
+        buffer.set_version(self.version);
+        buffer.set_rate2quantum(self.rate2quantum);
+        buffer.set_defcls(self.defcls);
+        buffer.set_debug(self.debug);
+        buffer.set_direct_pkts(self.direct_pkts);
+    }
+}
+
+/// Statistics of a `htb` class (`struct tc_htb_xstats`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct HtbXStats {
+    pub lends: u32,
+    pub borrows: u32,
+    pub giants: u32,
+    pub tokens: i32,
+    pub ctokens: i32,
+}
+
+pub const HTB_XSTATS_LEN: usize = 20;
+
+buffer!(HtbXStatsBuffer(HTB_XSTATS_LEN) {
+    lends: (u32, 0..4),
+    borrows: (u32, 4..8),
+    giants: (u32, 8..12),
+    tokens: (i32, 12..16),
+    ctokens: (i32, 16..20),
+});
+
+impl<T: AsRef<[u8]>> Parseable<HtbXStatsBuffer<T>> for HtbXStats {
+    fn parse(buf: &HtbXStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            lends: buf.lends(),
+            borrows: buf.borrows(),
+            giants: buf.giants(),
+            tokens: buf.tokens(),
+            ctokens: buf.ctokens(),
+        })
+    }
+}
+
+impl Emitable for HtbXStats {
+    fn buffer_len(&self) -> usize {
+        HTB_XSTATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = HtbXStatsBuffer::new(buffer);
+        buffer.set_lends(self.lends);
+        buffer.set_borrows(self.borrows);
+        buffer.set_giants(self.giants);
+        buffer.set_tokens(self.tokens);
+        buffer.set_ctokens(self.ctokens);
+    }
+}
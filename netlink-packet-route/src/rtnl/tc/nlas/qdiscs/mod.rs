@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+mod fq_codel;
+pub use self::fq_codel::*;
+
+mod htb;
+pub use self::htb::*;
+
+mod netem;
+pub use self::netem::*;
+
+mod prio;
+pub use self::prio::*;
+
+mod tbf;
+pub use self::tbf::*;
+
+/// Kind of the `ingress` qdisc, which has no options
+pub const INGRESS: &str = "ingress";
+/// Kind of the `clsact` qdisc, which has no options
+pub const CLSACT: &str = "clsact";
+/// Kind of the `mq` qdisc, which has no options
+pub const MQ: &str = "mq";
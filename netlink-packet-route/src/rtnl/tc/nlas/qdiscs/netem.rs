@@ -0,0 +1,548 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_i64, parse_u32, parse_u64},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const NETEM: &str = "netem";
+
+/// Parameters of the `netem` qdisc (`struct tc_netem_qopt`). Unlike most
+/// qdiscs, `netem` options start with this structure, which is then followed
+/// by the [`Netem`] attributes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct NetemQopt {
+    /// Added delay, in scheduler ticks
+    pub latency: u32,
+    /// Maximum number of packets in the queue
+    pub limit: u32,
+    /// Random packet loss, as a fraction of `u32::MAX`
+    pub loss: u32,
+    /// Re-ordering gap, in packets
+    pub gap: u32,
+    /// Random packet duplication, as a fraction of `u32::MAX`
+    pub duplicate: u32,
+    /// Random jitter, in scheduler ticks
+    pub jitter: u32,
+}
+
+pub const NETEM_QOPT_LEN: usize = 24;
+
+buffer!(NetemQoptBuffer(NETEM_QOPT_LEN) {
+    latency: (u32, 0..4),
+    limit: (u32, 4..8),
+    loss: (u32, 8..12),
+    gap: (u32, 12..16),
+    duplicate: (u32, 16..20),
+    jitter: (u32, 20..24),
+});
+
+impl<T: AsRef<[u8]>> Parseable<NetemQoptBuffer<T>> for NetemQopt {
+    fn parse(buf: &NetemQoptBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            latency: buf.latency(),
+            limit: buf.limit(),
+            loss: buf.loss(),
+            gap: buf.gap(),
+            duplicate: buf.duplicate(),
+            jitter: buf.jitter(),
+        })
+    }
+}
+
+impl Emitable for NetemQopt {
+    fn buffer_len(&self) -> usize {
+        NETEM_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NetemQoptBuffer::new(buffer);
+        buffer.set_latency(self.latency);
+        buffer.set_limit(self.limit);
+        buffer.set_loss(self.loss);
+        buffer.set_gap(self.gap);
+        buffer.set_duplicate(self.duplicate);
+        buffer.set_jitter(self.jitter);
+    }
+}
+
+/// Attributes of the `netem` qdisc, following the [`NetemQopt`] structure
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Netem {
+    Unspec(Vec<u8>),
+    /// Correlations of the delay, loss and duplication
+    Corr(NetemCorr),
+    /// Delay distribution table
+    DelayDist(Vec<u8>),
+    /// Re-ordering probability
+    Reorder(NetemProbability),
+    /// Corruption probability
+    Corrupt(NetemProbability),
+    /// Loss models
+    Loss(Vec<NetemLoss>),
+    /// Rate limit
+    Rate(NetemRate),
+    /// Mark packets with ECN instead of dropping them
+    Ecn(u32),
+    /// Rate limit, in bytes per second, when it does not fit in 32 bits
+    Rate64(u64),
+    Pad(Vec<u8>),
+    /// Added delay, in nanoseconds
+    Latency64(i64),
+    /// Random jitter, in nanoseconds
+    Jitter64(i64),
+    /// Slotting parameters
+    Slot(NetemSlot),
+    /// Slot delay distribution table
+    SlotDist(Vec<u8>),
+    /// Seed of the pseudo random number generator
+    PrngSeed(u64),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Netem {
+    fn value_len(&self) -> usize {
+        use self::Netem::*;
+        match self {
+            Unspec(bytes) | DelayDist(bytes) | Pad(bytes) | SlotDist(bytes) => bytes.len(),
+            Corr(corr) => corr.buffer_len(),
+            Reorder(probability) | Corrupt(probability) => probability.buffer_len(),
+            Loss(nlas) => nlas.as_slice().buffer_len(),
+            Rate(rate) => rate.buffer_len(),
+            Slot(slot) => slot.buffer_len(),
+            Ecn(_) => 4,
+            Rate64(_) | Latency64(_) | Jitter64(_) | PrngSeed(_) => 8,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Netem::*;
+        match self {
+            Unspec(bytes) | DelayDist(bytes) | Pad(bytes) | SlotDist(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Corr(corr) => corr.emit(buffer),
+            Reorder(probability) | Corrupt(probability) => probability.emit(buffer),
+            Loss(nlas) => nlas.as_slice().emit(buffer),
+            Rate(rate) => rate.emit(buffer),
+            Slot(slot) => slot.emit(buffer),
+            Ecn(value) => NativeEndian::write_u32(buffer, *value),
+            Rate64(value) | PrngSeed(value) => NativeEndian::write_u64(buffer, *value),
+            Latency64(value) | Jitter64(value) => NativeEndian::write_i64(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Netem::*;
+        match self {
+            Unspec(_) => TCA_NETEM_UNSPEC,
+            Corr(_) => TCA_NETEM_CORR,
+            DelayDist(_) => TCA_NETEM_DELAY_DIST,
+            Reorder(_) => TCA_NETEM_REORDER,
+            Corrupt(_) => TCA_NETEM_CORRUPT,
+            Loss(_) => TCA_NETEM_LOSS,
+            Rate(_) => TCA_NETEM_RATE,
+            Ecn(_) => TCA_NETEM_ECN,
+            Rate64(_) => TCA_NETEM_RATE64,
+            Pad(_) => TCA_NETEM_PAD,
+            Latency64(_) => TCA_NETEM_LATENCY64,
+            Jitter64(_) => TCA_NETEM_JITTER64,
+            Slot(_) => TCA_NETEM_SLOT,
+            SlotDist(_) => TCA_NETEM_SLOT_DIST,
+            PrngSeed(_) => TCA_NETEM_PRNG_SEED,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Netem {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Netem::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_NETEM_UNSPEC => Unspec(payload.to_vec()),
+            TCA_NETEM_CORR => Corr(
+                NetemCorr::parse(&NetemCorrBuffer::new_checked(payload)?)
+                    .context("invalid TCA_NETEM_CORR value")?,
+            ),
+            TCA_NETEM_DELAY_DIST => DelayDist(payload.to_vec()),
+            TCA_NETEM_REORDER => Reorder(
+                NetemProbability::parse(&NetemProbabilityBuffer::new_checked(payload)?)
+                    .context("invalid TCA_NETEM_REORDER value")?,
+            ),
+            TCA_NETEM_CORRUPT => Corrupt(
+                NetemProbability::parse(&NetemProbabilityBuffer::new_checked(payload)?)
+                    .context("invalid TCA_NETEM_CORRUPT value")?,
+            ),
+            TCA_NETEM_LOSS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context("invalid TCA_NETEM_LOSS value")?;
+                    nlas.push(NetemLoss::parse(nla)?);
+                }
+                Loss(nlas)
+            }
+            TCA_NETEM_RATE => Rate(
+                NetemRate::parse(&NetemRateBuffer::new_checked(payload)?)
+                    .context("invalid TCA_NETEM_RATE value")?,
+            ),
+            TCA_NETEM_ECN => Ecn(parse_u32(payload).context("invalid TCA_NETEM_ECN value")?),
+            TCA_NETEM_RATE64 => {
+                Rate64(parse_u64(payload).context("invalid TCA_NETEM_RATE64 value")?)
+            }
+            TCA_NETEM_PAD => Pad(payload.to_vec()),
+            TCA_NETEM_LATENCY64 => {
+                Latency64(parse_i64(payload).context("invalid TCA_NETEM_LATENCY64 value")?)
+            }
+            TCA_NETEM_JITTER64 => {
+                Jitter64(parse_i64(payload).context("invalid TCA_NETEM_JITTER64 value")?)
+            }
+            TCA_NETEM_SLOT => Slot(
+                NetemSlot::parse(&NetemSlotBuffer::new_checked(payload)?)
+                    .context("invalid TCA_NETEM_SLOT value")?,
+            ),
+            TCA_NETEM_SLOT_DIST => SlotDist(payload.to_vec()),
+            TCA_NETEM_PRNG_SEED => {
+                PrngSeed(parse_u64(payload).context("invalid TCA_NETEM_PRNG_SEED value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid netem option")?),
+        })
+    }
+}
+
+/// Loss models of the `netem` qdisc
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum NetemLoss {
+    Unspec(Vec<u8>),
+    /// General Intuitive, 4 state model
+    Gi(NetemGiModel),
+    /// Gilbert Elliot model
+    Ge(NetemGeModel),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for NetemLoss {
+    fn value_len(&self) -> usize {
+        use self::NetemLoss::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Gi(model) => model.buffer_len(),
+            Ge(model) => model.buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::NetemLoss::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Gi(model) => model.emit(buffer),
+            Ge(model) => model.emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::NetemLoss::*;
+        match self {
+            Unspec(_) => NETEM_LOSS_UNSPEC,
+            Gi(_) => NETEM_LOSS_GI,
+            Ge(_) => NETEM_LOSS_GE,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for NetemLoss {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::NetemLoss::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NETEM_LOSS_UNSPEC => Unspec(payload.to_vec()),
+            NETEM_LOSS_GI => Gi(
+                NetemGiModel::parse(&NetemGiModelBuffer::new_checked(payload)?)
+                    .context("invalid NETEM_LOSS_GI value")?,
+            ),
+            NETEM_LOSS_GE => Ge(
+                NetemGeModel::parse(&NetemGeModelBuffer::new_checked(payload)?)
+                    .context("invalid NETEM_LOSS_GE value")?,
+            ),
+            _ => Other(DefaultNla::parse(buf).context("invalid netem loss model")?),
+        })
+    }
+}
+
+/// Correlations of the `netem` random parameters (`struct tc_netem_corr`),
+/// as fractions of `u32::MAX`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct NetemCorr {
+    pub delay_corr: u32,
+    pub loss_corr: u32,
+    pub dup_corr: u32,
+}
+
+pub const NETEM_CORR_LEN: usize = 12;
+
+buffer!(NetemCorrBuffer(NETEM_CORR_LEN) {
+    delay_corr: (u32, 0..4),
+    loss_corr: (u32, 4..8),
+    dup_corr: (u32, 8..12),
+});
+
+impl<T: AsRef<[u8]>> Parseable<NetemCorrBuffer<T>> for NetemCorr {
+    fn parse(buf: &NetemCorrBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            delay_corr: buf.delay_corr(),
+            loss_corr: buf.loss_corr(),
+            dup_corr: buf.dup_corr(),
+        })
+    }
+}
+
+impl Emitable for NetemCorr {
+    fn buffer_len(&self) -> usize {
+        NETEM_CORR_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NetemCorrBuffer::new(buffer);
+        buffer.set_delay_corr(self.delay_corr);
+        buffer.set_loss_corr(self.loss_corr);
+        buffer.set_dup_corr(self.dup_corr);
+    }
+}
+
+/// Probability of an event and its correlation, as fractions of `u32::MAX`
+/// (`struct tc_netem_reorder` and `struct tc_netem_corrupt`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct NetemProbability {
+    pub probability: u32,
+    pub correlation: u32,
+}
+
+pub const NETEM_PROBABILITY_LEN: usize = 8;
+
+buffer!(NetemProbabilityBuffer(NETEM_PROBABILITY_LEN) {
+    probability: (u32, 0..4),
+    correlation: (u32, 4..8),
+});
+
+impl<T: AsRef<[u8]>> Parseable<NetemProbabilityBuffer<T>> for NetemProbability {
+    fn parse(buf: &NetemProbabilityBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            probability: buf.probability(),
+            correlation: buf.correlation(),
+        })
+    }
+}
+
+impl Emitable for NetemProbability {
+    fn buffer_len(&self) -> usize {
+        NETEM_PROBABILITY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NetemProbabilityBuffer::new(buffer);
+        buffer.set_probability(self.probability);
+        buffer.set_correlation(self.correlation);
+    }
+}
+
+/// Rate limit of the `netem` qdisc (`struct tc_netem_rate`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct NetemRate {
+    /// Rate, in bytes per second
+    pub rate: u32,
+    pub packet_overhead: i32,
+    pub cell_size: u32,
+    pub cell_overhead: i32,
+}
+
+pub const NETEM_RATE_LEN: usize = 16;
+
+buffer!(NetemRateBuffer(NETEM_RATE_LEN) {
+    rate: (u32, 0..4),
+    packet_overhead: (i32, 4..8),
+    cell_size: (u32, 8..12),
+    cell_overhead: (i32, 12..16),
+});
+
+impl<T: AsRef<[u8]>> Parseable<NetemRateBuffer<T>> for NetemRate {
+    fn parse(buf: &NetemRateBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rate: buf.rate(),
+            packet_overhead: buf.packet_overhead(),
+            cell_size: buf.cell_size(),
+            cell_overhead: buf.cell_overhead(),
+        })
+    }
+}
+
+impl Emitable for NetemRate {
+    fn buffer_len(&self) -> usize {
+        NETEM_RATE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NetemRateBuffer::new(buffer);
+        buffer.set_rate(self.rate);
+        buffer.set_packet_overhead(self.packet_overhead);
+        buffer.set_cell_size(self.cell_size);
+        buffer.set_cell_overhead(self.cell_overhead);
+    }
+}
+
+/// Slotting parameters of the `netem` qdisc (`struct tc_netem_slot`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct NetemSlot {
+    /// Minimum delay between slots, in nanoseconds
+    pub min_delay: i64,
+    /// Maximum delay between slots, in nanoseconds
+    pub max_delay: i64,
+    /// Maximum number of packets sent per slot
+    pub max_packets: i32,
+    /// Maximum number of bytes sent per slot
+    pub max_bytes: i32,
+    pub dist_delay: i64,
+    pub dist_jitter: i64,
+}
+
+pub const NETEM_SLOT_LEN: usize = 40;
+
+buffer!(NetemSlotBuffer(NETEM_SLOT_LEN) {
+    min_delay: (i64, 0..8),
+    max_delay: (i64, 8..16),
+    max_packets: (i32, 16..20),
+    max_bytes: (i32, 20..24),
+    dist_delay: (i64, 24..32),
+    dist_jitter: (i64, 32..40),
+});
+
+impl<T: AsRef<[u8]>> Parseable<NetemSlotBuffer<T>> for NetemSlot {
+    fn parse(buf: &NetemSlotBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            min_delay: buf.min_delay(),
+            max_delay: buf.max_delay(),
+            max_packets: buf.max_packets(),
+            max_bytes: buf.max_bytes(),
+            dist_delay: buf.dist_delay(),
+            dist_jitter: buf.dist_jitter(),
+        })
+    }
+}
+
+impl Emitable for NetemSlot {
+    fn buffer_len(&self) -> usize {
+        NETEM_SLOT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NetemSlotBuffer::new(buffer);
+        buffer.set_min_delay(self.min_delay);
+        buffer.set_max_delay(self.max_delay);
+        buffer.set_max_packets(self.max_packets);
+        buffer.set_max_bytes(self.max_bytes);
+        buffer.set_dist_delay(self.dist_delay);
+        buffer.set_dist_jitter(self.dist_jitter);
+    }
+}
+
+/// Parameters of the General Intuitive loss model
+/// (`struct tc_netem_gimodel`), as fractions of `u32::MAX`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct NetemGiModel {
+    pub p13: u32,
+    pub p31: u32,
+    pub p32: u32,
+    pub p14: u32,
+    pub p23: u32,
+}
+
+pub const NETEM_GI_MODEL_LEN: usize = 20;
+
+buffer!(NetemGiModelBuffer(NETEM_GI_MODEL_LEN) {
+    p13: (u32, 0..4),
+    p31: (u32, 4..8),
+    p32: (u32, 8..12),
+    p14: (u32, 12..16),
+    p23: (u32, 16..20),
+});
+
+impl<T: AsRef<[u8]>> Parseable<NetemGiModelBuffer<T>> for NetemGiModel {
+    fn parse(buf: &NetemGiModelBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            p13: buf.p13(),
+            p31: buf.p31(),
+            p32: buf.p32(),
+            p14: buf.p14(),
+            p23: buf.p23(),
+        })
+    }
+}
+
+impl Emitable for NetemGiModel {
+    fn buffer_len(&self) -> usize {
+        NETEM_GI_MODEL_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NetemGiModelBuffer::new(buffer);
+        buffer.set_p13(self.p13);
+        buffer.set_p31(self.p31);
+        buffer.set_p32(self.p32);
+        buffer.set_p14(self.p14);
+        buffer.set_p23(self.p23);
+    }
+}
+
+/// Parameters of the Gilbert Elliot loss model (`struct tc_netem_gemodel`),
+/// as fractions of `u32::MAX`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct NetemGeModel {
+    pub p: u32,
+    pub r: u32,
+    pub h: u32,
+    pub k1: u32,
+}
+
+pub const NETEM_GE_MODEL_LEN: usize = 16;
+
+buffer!(NetemGeModelBuffer(NETEM_GE_MODEL_LEN) {
+    p: (u32, 0..4),
+    r: (u32, 4..8),
+    h: (u32, 8..12),
+    k1: (u32, 12..16),
+});
+
+impl<T: AsRef<[u8]>> Parseable<NetemGeModelBuffer<T>> for NetemGeModel {
+    fn parse(buf: &NetemGeModelBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            p: buf.p(),
+            r: buf.r(),
+            h: buf.h(),
+            k1: buf.k1(),
+        })
+    }
+}
+
+impl Emitable for NetemGeModel {
+    fn buffer_len(&self) -> usize {
+        NETEM_GE_MODEL_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NetemGeModelBuffer::new(buffer);
+        buffer.set_p(self.p);
+        buffer.set_r(self.r);
+        buffer.set_h(self.h);
+        buffer.set_k1(self.k1);
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    constants::TC_PRIO_MAX,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const PRIO: &str = "prio";
+
+/// Options of the `prio` qdisc (`struct tc_prio_qopt`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct PrioQopt {
+    /// Number of bands
+    pub bands: i32,
+    /// Band each packet priority is mapped to
+    pub priomap: [u8; TC_PRIO_MAX + 1],
+}
+
+pub const PRIO_QOPT_LEN: usize = 20;
+
+buffer!(PrioQoptBuffer(PRIO_QOPT_LEN) {
+    bands: (i32, 0..4),
+    priomap: (slice, 4..20),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<PrioQoptBuffer<&'a T>> for PrioQopt {
+    fn parse(buf: &PrioQoptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut priomap = [0; TC_PRIO_MAX + 1];
+        priomap.copy_from_slice(buf.priomap());
+        Ok(Self {
+            bands: buf.bands(),
+            priomap,
+        })
+    }
+}
+
+impl Emitable for PrioQopt {
+    fn buffer_len(&self) -> usize {
+        PRIO_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = PrioQoptBuffer::new(buffer);
+        buffer.set_bands(self.bands);
+        buffer.priomap_mut().copy_from_slice(&self.priomap);
+    }
+}
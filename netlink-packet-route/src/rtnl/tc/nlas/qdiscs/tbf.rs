@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u32, parse_u64},
+    tc::{RateSpec, RateSpecBuffer, RATE_SPEC_LEN},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const TBF: &str = "tbf";
+
+/// Options of the `tbf` qdisc
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Tbf {
+    Unspec(Vec<u8>),
+    Parms(TbfQopt),
+    /// Rate table
+    Rtab(Vec<u8>),
+    /// Peak rate table
+    Ptab(Vec<u8>),
+    /// Rate, in bytes per second, when it does not fit in 32 bits
+    Rate64(u64),
+    /// Peak rate, in bytes per second, when it does not fit in 32 bits
+    Prate64(u64),
+    /// Size of the bucket, in bytes
+    Burst(u32),
+    /// Size of the peak rate bucket, in bytes
+    Pburst(u32),
+    Pad(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Tbf {
+    fn value_len(&self) -> usize {
+        use self::Tbf::*;
+        match self {
+            Unspec(bytes) | Rtab(bytes) | Ptab(bytes) | Pad(bytes) => bytes.len(),
+            Parms(qopt) => qopt.buffer_len(),
+            Rate64(_) | Prate64(_) => 8,
+            Burst(_) | Pburst(_) => 4,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Tbf::*;
+        match self {
+            Unspec(bytes) | Rtab(bytes) | Ptab(bytes) | Pad(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Parms(qopt) => qopt.emit(buffer),
+            Rate64(value) | Prate64(value) => NativeEndian::write_u64(buffer, *value),
+            Burst(value) | Pburst(value) => NativeEndian::write_u32(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Tbf::*;
+        match self {
+            Unspec(_) => TCA_TBF_UNSPEC,
+            Parms(_) => TCA_TBF_PARMS,
+            Rtab(_) => TCA_TBF_RTAB,
+            Ptab(_) => TCA_TBF_PTAB,
+            Rate64(_) => TCA_TBF_RATE64,
+            Prate64(_) => TCA_TBF_PRATE64,
+            Burst(_) => TCA_TBF_BURST,
+            Pburst(_) => TCA_TBF_PBURST,
+            Pad(_) => TCA_TBF_PAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Tbf {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Tbf::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_TBF_UNSPEC => Unspec(payload.to_vec()),
+            TCA_TBF_PARMS => Parms(
+                TbfQopt::parse(&TbfQoptBuffer::new_checked(payload)?)
+                    .context("invalid TCA_TBF_PARMS value")?,
+            ),
+            TCA_TBF_RTAB => Rtab(payload.to_vec()),
+            TCA_TBF_PTAB => Ptab(payload.to_vec()),
+            TCA_TBF_RATE64 => Rate64(parse_u64(payload).context("invalid TCA_TBF_RATE64 value")?),
+            TCA_TBF_PRATE64 => {
+                Prate64(parse_u64(payload).context("invalid TCA_TBF_PRATE64 value")?)
+            }
+            TCA_TBF_BURST => Burst(parse_u32(payload).context("invalid TCA_TBF_BURST value")?),
+            TCA_TBF_PBURST => Pburst(parse_u32(payload).context("invalid TCA_TBF_PBURST value")?),
+            TCA_TBF_PAD => Pad(payload.to_vec()),
+            _ => Other(DefaultNla::parse(buf).context("invalid tbf option")?),
+        })
+    }
+}
+
+/// Parameters of a `tbf` qdisc (`struct tc_tbf_qopt`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct TbfQopt {
+    pub rate: RateSpec,
+    pub peakrate: RateSpec,
+    /// Maximum number of bytes waiting for tokens
+    pub limit: u32,
+    /// Size of the bucket, in scheduler ticks
+    pub buffer: u32,
+    /// Size of the peak rate bucket, in scheduler ticks
+    pub mtu: u32,
+}
+
+pub const TBF_QOPT_LEN: usize = 36;
+
+buffer!(TbfQoptBuffer(TBF_QOPT_LEN) {
+    rate: (slice, 0..RATE_SPEC_LEN),
+    peakrate: (slice, RATE_SPEC_LEN..2 * RATE_SPEC_LEN),
+    limit: (u32, 24..28),
+    buffer: (u32, 28..32),
+    mtu: (u32, 32..36),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TbfQoptBuffer<&'a T>> for TbfQopt {
+    fn parse(buf: &TbfQoptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rate: RateSpec::parse(&RateSpecBuffer::new(buf.rate()))?,
+            peakrate: RateSpec::parse(&RateSpecBuffer::new(buf.peakrate()))?,
+            limit: buf.limit(),
+            buffer: buf.buffer(),
+            mtu: buf.mtu(),
+        })
+    }
+}
+
+impl Emitable for TbfQopt {
+    fn buffer_len(&self) -> usize {
+        TBF_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TbfQoptBuffer::new(buffer);
+        self.rate.emit(buffer.rate_mut());
+        self.peakrate.emit(buffer.peakrate_mut());
+        buffer.set_limit(self.limit);
+        buffer.set_buffer(self.buffer);
+        buffer.set_mtu(self.mtu);
+    }
+}
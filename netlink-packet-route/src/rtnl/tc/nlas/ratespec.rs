@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Rate specification, used by several qdiscs and actions to configure a
+/// rate limit (`struct tc_ratespec`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct RateSpec {
+    pub cell_log: u8,
+    pub linklayer: u8,
+    pub overhead: u16,
+    pub cell_align: i16,
+    pub mpu: u16,
+    /// Rate in bytes per second. Rates that do not fit in 32 bits are
+    /// carried by a separate 64 bits attribute, and this field is then set
+    /// to `u32::MAX`.
+    pub rate: u32,
+}
+
+pub const RATE_SPEC_LEN: usize = 12;
+
+buffer!(RateSpecBuffer(RATE_SPEC_LEN) {
+    cell_log: (u8, 0),
+    linklayer: (u8, 1),
+    overhead: (u16, 2..4),
+    cell_align: (i16, 4..6),
+    mpu: (u16, 6..8),
+    rate: (u32, 8..12),
+});
+
+impl<T: AsRef<[u8]>> Parseable<RateSpecBuffer<T>> for RateSpec {
+    fn parse(buf: &RateSpecBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            cell_log: buf.cell_log(),
+            linklayer: buf.linklayer(),
+            overhead: buf.overhead(),
+            cell_align: buf.cell_align(),
+            mpu: buf.mpu(),
+            rate: buf.rate(),
+        })
+    }
+}
+
+impl Emitable for RateSpec {
+    fn buffer_len(&self) -> usize {
+        RATE_SPEC_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = RateSpecBuffer::new(buffer);
+        buffer.set_cell_log(self.cell_log);
+        buffer.set_linklayer(self.linklayer);
+        buffer.set_overhead(self.overhead);
+        buffer.set_cell_align(self.cell_align);
+        buffer.set_mpu(self.mpu);
+        buffer.set_rate(self.rate);
+    }
+}
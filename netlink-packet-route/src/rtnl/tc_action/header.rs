@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+use super::{TcActionMessageBuffer, TC_ACTION_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Action message header (`struct tcamsg`).
+///
+/// ```no_rust
+/// 0                8                16              24               32
+/// +----------------+----------------+----------------+----------------+
+/// |     family     |      pad       |               pad               |
+/// +----------------+----------------+----------------+----------------+
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcActionHeader {
+    pub family: u8,
+}
+
+impl Emitable for TcActionHeader {
+    fn buffer_len(&self) -> usize {
+        TC_ACTION_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcActionMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcActionMessageBuffer<T>> for TcActionHeader {
+    fn parse(buf: &TcActionMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(TcActionHeader {
+            family: buf.family(),
+        })
+    }
+}
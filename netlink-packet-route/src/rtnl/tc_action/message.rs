@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::tc_action::Nla,
+    traits::{Emitable, Parseable},
+    DecodeError,
+    TcActionHeader,
+    TcActionMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcActionMessage {
+    pub header: TcActionHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcActionMessageBuffer<&'a T>> for TcActionMessage {
+    fn parse(buf: &TcActionMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: TcActionHeader::parse(buf).context("failed to parse action message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse action message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcActionMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &TcActionMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for TcActionMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        nlas::{
+            tc::{
+                actions::{
+                    ActNla,
+                    ActOptions,
+                    Pedit,
+                    PeditKey,
+                    PeditSel,
+                    Police,
+                    PoliceTbf,
+                    TcAction,
+                    TcGen,
+                    Tcft,
+                },
+                RateSpec,
+            },
+            tc_action::Nla,
+        },
+        traits::{Emitable, ParseableParametrized},
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        TcActionHeader,
+        TcActionMessage,
+        RTM_NEWACTION,
+        TC_ACT_OK,
+        TC_ACT_SHOT,
+    };
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_action_police() {
+        let data = vec![
+            0x70, 0x00, 0x00, 0x00, // length = 112
+            0x30, 0x00, // message type = 48 = RTM_NEWACTION
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcamsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+
+            // NLAs
+            0x5c, 0x00, 0x01, 0x00, // length = 92, type = TCA_ROOT_TAB
+            0x58, 0x00, 0x01, 0x00, // length = 88, tab = 1
+            0x0b, 0x00, 0x01, 0x00, // length = 11, type = TCA_ACT_KIND
+            0x70, 0x6f, 0x6c, 0x69, 0x63, 0x65, 0x00, // "police\0"
+            0x00, // padding
+            0x40, 0x00, 0x02, 0x00, // length = 64, type = TCA_ACT_OPTIONS
+            0x3c, 0x00, 0x01, 0x00, // length = 60, type = TCA_POLICE_TBF
+            0x00, 0x00, 0x00, 0x00, // index = 0
+            0x02, 0x00, 0x00, 0x00, // action = TC_ACT_SHOT
+            0x00, 0x00, 0x00, 0x00, // limit = 0
+            0x00, 0x32, 0x00, 0x00, // burst = 12800
+            0xf8, 0x07, 0x00, 0x00, // mtu = 2040
+            // rate
+            0x03, // cell_log = 3
+            0x01, // linklayer = 1
+            0x00, 0x00, // overhead = 0
+            0xff, 0xff, // cell_align = -1
+            0x00, 0x00, // mpu = 0
+            0x48, 0xe8, 0x01, 0x00, // rate = 125000
+            // peakrate
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, // refcnt = 0
+            0x00, 0x00, 0x00, 0x00, // bindcnt = 0
+            0x00, 0x00, 0x00, 0x00, // capab = 0
+            0x08, 0x00, 0x03, 0x00, // length = 8, type = TCA_ACT_INDEX
+            0x01, 0x00, 0x00, 0x00, // 1
+        ];
+        let expected = TcActionMessage {
+            header: TcActionHeader::default(),
+            nlas: vec![Nla::Tab(vec![TcAction {
+                tab: 1,
+                nlas: vec![
+                    ActNla::Kind("police".to_string()),
+                    ActNla::Options(ActOptions::Police(vec![Police::Tbf(PoliceTbf {
+                        action: TC_ACT_SHOT,
+                        burst: 12800,
+                        mtu: 2040,
+                        rate: RateSpec {
+                            cell_log: 3,
+                            linklayer: 1,
+                            cell_align: -1,
+                            rate: 125000,
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })])),
+                    ActNla::Index(1),
+                ],
+            }])],
+        };
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWACTION).unwrap();
+        assert_eq!(RtnlMessage::NewTrafficAction(expected.clone()), actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_action_pedit() {
+        let data = vec![
+            0x9c, 0x00, 0x00, 0x00, // length = 156
+            0x30, 0x00, // message type = 48 = RTM_NEWACTION
+            0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // tcamsg
+            0x00, 0x00, 0x00, 0x00, // family and padding
+
+            // NLAs
+            0x88, 0x00, 0x01, 0x00, // length = 136, type = TCA_ROOT_TAB
+            0x84, 0x00, 0x01, 0x00, // length = 132, tab = 1
+            0x0a, 0x00, 0x01, 0x00, // length = 10, type = TCA_ACT_KIND
+            0x70, 0x65, 0x64, 0x69, 0x74, 0x00, // "pedit\0"
+            0x00, 0x00, // padding
+            0x74, 0x00, 0x02, 0x00, // length = 116, type = TCA_ACT_OPTIONS
+            0x34, 0x00, 0x04, 0x00, // length = 52, type = TCA_PEDIT_PARMS_EX
+            0x01, 0x00, 0x00, 0x00, // index = 1
+            0x00, 0x00, 0x00, 0x00, // capab = 0
+            0x00, 0x00, 0x00, 0x00, // action = TC_ACT_OK
+            0x01, 0x00, 0x00, 0x00, // refcnt = 1
+            0x00, 0x00, 0x00, 0x00, // bindcnt = 0
+            0x01, // nkeys = 1
+            0x00, // flags = 0
+            0x00, 0x00, // padding
+            // munge ip ttl set 10
+            0x00, 0xff, 0xff, 0xff, // mask
+            0x0a, 0x00, 0x00, 0x00, // val
+            0x08, 0x00, 0x00, 0x00, // off = 8
+            0x00, 0x00, 0x00, 0x00, // at = 0
+            0x00, 0x00, 0x00, 0x00, // offmask = 0
+            0x00, 0x00, 0x00, 0x00, // shift = 0
+            0x18, 0x00, 0x05, 0x00, // length = 24, type = TCA_PEDIT_KEYS_EX
+            0x14, 0x00, 0x06, 0x00, // length = 20, type = TCA_PEDIT_KEY_EX
+            0x06, 0x00, 0x01, 0x00, // length = 6, type = TCA_PEDIT_KEY_EX_HTYPE
+            0x01, 0x00, // TCA_PEDIT_KEY_EX_HDR_TYPE_IP4
+            0x00, 0x00, // padding
+            0x06, 0x00, 0x02, 0x00, // length = 6, type = TCA_PEDIT_KEY_EX_CMD
+            0x00, 0x00, // TCA_PEDIT_KEY_EX_CMD_SET
+            0x00, 0x00, // padding
+            0x24, 0x00, 0x01, 0x00, // length = 36, type = TCA_PEDIT_TM
+            0xe8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // install = 1000
+            0xe8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // lastuse = 1000
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // expires = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // firstuse = 0
+        ];
+        let expected = TcActionMessage {
+            header: TcActionHeader::default(),
+            nlas: vec![Nla::Tab(vec![TcAction {
+                tab: 1,
+                nlas: vec![
+                    ActNla::Kind("pedit".to_string()),
+                    ActNla::Options(ActOptions::Pedit(vec![
+                        Pedit::ParmsEx(PeditSel {
+                            gen: TcGen {
+                                index: 1,
+                                action: TC_ACT_OK,
+                                refcnt: 1,
+                                ..Default::default()
+                            },
+                            flags: 0,
+                            keys: vec![PeditKey {
+                                mask: u32::from_ne_bytes([0x00, 0xff, 0xff, 0xff]),
+                                val: u32::from_ne_bytes([0x0a, 0x00, 0x00, 0x00]),
+                                off: 8,
+                                ..Default::default()
+                            }],
+                        }),
+                        Pedit::KeysEx(vec![
+                            0x14, 0x00, 0x06, 0x00,
+                            0x06, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+                            0x06, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+                        ]),
+                        Pedit::Tm(Tcft {
+                            install: 1000,
+                            lastuse: 1000,
+                            ..Default::default()
+                        }),
+                    ])),
+                ],
+            }])],
+        };
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWACTION).unwrap();
+        assert_eq!(RtnlMessage::NewTrafficAction(expected.clone()), actual);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &data[16..]);
+    }
+}
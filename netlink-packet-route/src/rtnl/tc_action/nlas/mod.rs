@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::parse_u32,
+    tc::{actions::TcAction, parse_nlas},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// List of actions
+    Tab(Vec<TcAction>),
+    /// Flags of the request (`struct nla_bitfield32`): the value of the
+    /// flags, followed by the mask of the flags to consider. The only flag
+    /// is `TCA_FLAG_LARGE_DUMP_ON`.
+    Flags(u32, u32),
+    /// Number of actions in a dump
+    Count(u32),
+    /// Only dump the actions used in the last given milliseconds
+    TimeDelta(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Tab(actions) => actions.as_slice().buffer_len(),
+            Flags(_, _) => 8,
+            Count(_) | TimeDelta(_) => 4,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Tab(actions) => actions.as_slice().emit(buffer),
+            Flags(value, selector) => {
+                NativeEndian::write_u32(&mut buffer[..4], *value);
+                NativeEndian::write_u32(&mut buffer[4..8], *selector);
+            }
+            Count(value) | TimeDelta(value) => NativeEndian::write_u32(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_ROOT_UNSPEC,
+            Tab(_) => TCA_ROOT_TAB,
+            Flags(_, _) => TCA_ROOT_FLAGS,
+            Count(_) => TCA_ROOT_COUNT,
+            TimeDelta(_) => TCA_ROOT_TIME_DELTA,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_ROOT_UNSPEC => Unspec(payload.to_vec()),
+            TCA_ROOT_TAB => Tab(parse_nlas(payload).context("invalid TCA_ROOT_TAB value")?),
+            TCA_ROOT_FLAGS => {
+                if payload.len() != 8 {
+                    return Err(format!("invalid TCA_ROOT_FLAGS value: {:?}", payload).into());
+                }
+                Flags(
+                    NativeEndian::read_u32(&payload[..4]),
+                    NativeEndian::read_u32(&payload[4..8]),
+                )
+            }
+            TCA_ROOT_COUNT => Count(parse_u32(payload).context("invalid TCA_ROOT_COUNT value")?),
+            TCA_ROOT_TIME_DELTA => {
+                TimeDelta(parse_u32(payload).context("invalid TCA_ROOT_TIME_DELTA value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid action message attribute")?),
+        })
+    }
+}
@@ -114,9 +114,30 @@ pub fn parse_i32(payload: &[u8]) -> Result<i32, DecodeError> {
     Ok(NativeEndian::read_i32(payload))
 }
 
+pub fn parse_i64(payload: &[u8]) -> Result<i64, DecodeError> {
+    if payload.len() != 8 {
+        return Err(format!("invalid i64: {:?}", payload).into());
+    }
+    Ok(NativeEndian::read_i64(payload))
+}
+
 pub fn parse_u16_be(payload: &[u8]) -> Result<u16, DecodeError> {
     if payload.len() != size_of::<u16>() {
         return Err(format!("invalid u16: {:?}", payload).into());
     }
     Ok(BigEndian::read_u16(payload))
 }
+
+pub fn parse_u32_be(payload: &[u8]) -> Result<u32, DecodeError> {
+    if payload.len() != size_of::<u32>() {
+        return Err(format!("invalid u32: {:?}", payload).into());
+    }
+    Ok(BigEndian::read_u32(payload))
+}
+
+pub fn parse_u64_be(payload: &[u8]) -> Result<u64, DecodeError> {
+    if payload.len() != size_of::<u64>() {
+        return Err(format!("invalid u64: {:?}", payload).into());
+    }
+    Ok(BigEndian::read_u64(payload))
+}
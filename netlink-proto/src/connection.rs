@@ -234,13 +234,13 @@ where
                         trace!("not forwarding Noop/Ack/Done message to the handle");
                         continue;
                     }
-                    // I'm not sure how we should handle overrun messages
-                    Overrun(_) => unimplemented!("overrun is not handled yet"),
                     // We need to forward error messages and messages
                     // that are part of the netlink subprotocol,
                     // because only the user knows how they want to
-                    // handle them.
-                    Error(_) | InnerMessage(_) => {}
+                    // handle them. Overrun messages are never
+                    // responses: `self.protocol` turns them into
+                    // errors for all the pending requests.
+                    Error(_) | Overrun(_) | InnerMessage(_) => {}
                 }
             }
 
@@ -254,6 +254,14 @@ where
         trace!("forward_responses done");
     }
 
+    /// Drop the requests whose response stream was dropped by the user. Without this, a request
+    /// that never gets a response would stay in memory for the lifetime of the connection.
+    pub fn drop_cancelled_requests(&mut self) {
+        trace!("drop_cancelled_requests called");
+        self.protocol.drop_cancelled_requests(|tx| tx.is_closed());
+        trace!("drop_cancelled_requests done");
+    }
+
     pub fn should_shut_down(&self) -> bool {
         self.socket_closed || (self.unsolicited_messages_tx.is_none() && self.requests_rx.is_none())
     }
@@ -280,6 +288,9 @@ where
         debug!("forwaring responses to previous requests to the connection handle");
         pinned.forward_responses();
 
+        debug!("dropping cancelled requests");
+        pinned.drop_cancelled_requests();
+
         debug!("handling requests");
         pinned.poll_requests(cx);
 
@@ -42,6 +42,9 @@ where
 
     /// Error while reading from or writing to the netlink socket
     SocketIo(io::Error),
+
+    /// The request was not fully processed in time
+    Timeout,
 }
 
 impl<T> From<ErrorKind<T>> for Error<T>
@@ -60,9 +63,16 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use crate::ErrorKind::*;
         match self.kind() {
-            SocketIo(ref e) => write!(f, "{}: {}", self, e),
-            ConnectionClosed => write!(f, "{}", self),
-            NetlinkError(ref message) => write!(f, "{}: {:?}", self, message),
+            SocketIo(ref e) => write!(
+                f,
+                "Error while reading from or writing to the netlink socket: {}",
+                e
+            ),
+            ConnectionClosed => write!(f, "The netlink connection is closed"),
+            NetlinkError(ref message) => {
+                write!(f, "Received an error message as a response: {:?}", message)
+            }
+            Timeout => write!(f, "The request was not fully processed in time"),
         }
     }
 }
@@ -77,6 +87,7 @@ where
             SocketIo(_) => "Error while reading from or writing to the netlink socket",
             ConnectionClosed => "The netlink connection is closed",
             NetlinkError(_) => "Received an error message as a response",
+            Timeout => "The request was not fully processed in time",
         }
     }
 
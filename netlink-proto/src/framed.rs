@@ -10,13 +10,20 @@ use std::{
 };
 
 use futures::{Sink, Stream};
-use log::error;
+use log::{error, warn};
 
 use crate::{
     codecs::NetlinkMessageCodec,
     sys::{AsyncSocket, SocketAddr},
 };
-use netlink_packet_core::{NetlinkDeserializable, NetlinkMessage, NetlinkSerializable};
+use netlink_packet_core::{
+    NetlinkDeserializable,
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    NetlinkSerializable,
+    NLMSG_OVERRUN,
+};
 
 pub struct NetlinkFramed<T, S, C> {
     socket: S,
@@ -63,6 +70,19 @@ where
 
             *in_addr = match ready!(socket.poll_recv_from(cx, reader)) {
                 Ok(addr) => addr,
+                // The socket receive buffer overflowed and the kernel
+                // dropped some messages. The socket is still usable,
+                // so instead of shutting down we report the loss as
+                // an overrun message.
+                Err(e) if e.raw_os_error() == Some(libc::ENOBUFS) => {
+                    warn!("netlink socket receive buffer overflowed, messages were lost");
+                    let header = NetlinkHeader {
+                        message_type: NLMSG_OVERRUN,
+                        ..Default::default()
+                    };
+                    let message = NetlinkMessage::new(header, NetlinkPayload::Overrun(vec![]));
+                    return Poll::Ready(Some((message, SocketAddr::new(0, 0))));
+                }
                 Err(e) => {
                     error!("failed to read from netlink socket: {:?}", e);
                     return Poll::Ready(None);
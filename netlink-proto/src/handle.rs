@@ -2,10 +2,17 @@
 
 use futures::{
     channel::mpsc::{unbounded, UnboundedSender},
+    Future,
     Stream,
 };
+use futures_timer::Delay;
 use netlink_packet_core::NetlinkMessage;
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use crate::{
     errors::{Error, ErrorKind},
@@ -36,6 +43,11 @@ where
     /// - **acknowledgements**: when an acknowledgement is received, the stream is closed
     /// - **end of dump messages**: similarly, upon receiving an "end of dump" message, the stream is
     /// closed
+    ///
+    /// If the connection loses messages because the socket receive buffer overflowed, the stream
+    /// yields an error message with the `ENOBUFS` error code and is closed.
+    ///
+    /// Dropping the stream cancels the request: the connection stops waiting for a response to it.
     pub fn request(
         &mut self,
         message: NetlinkMessage<T>,
@@ -58,6 +70,22 @@ where
         Ok(rx)
     }
 
+    /// Same as [`request`](#method.request), but if the request is not fully processed after
+    /// `timeout`, the stream yields an [`ErrorKind::Timeout`](enum.ErrorKind.html) error, the
+    /// request is cancelled and the stream is closed. The responses are wrapped in `Ok`.
+    pub fn request_with_timeout(
+        &mut self,
+        message: NetlinkMessage<T>,
+        destination: SocketAddr,
+        timeout: Duration,
+    ) -> Result<impl Stream<Item = Result<NetlinkMessage<T>, Error<T>>>, Error<T>> {
+        let response = self.request(message, destination)?;
+        Ok(TimeoutResponse {
+            response: Some(response),
+            delay: Delay::new(timeout),
+        })
+    }
+
     pub fn notify(
         &mut self,
         message: NetlinkMessage<T>,
@@ -70,3 +98,103 @@ where
             .map_err(|_| ErrorKind::ConnectionClosed.into())
     }
 }
+
+/// Stream of responses to a request sent with
+/// [`ConnectionHandle::request_with_timeout`](struct.ConnectionHandle.html#method.request_with_timeout)
+struct TimeoutResponse<S> {
+    response: Option<S>,
+    delay: Delay,
+}
+
+impl<T, S> Stream for TimeoutResponse<S>
+where
+    T: Debug,
+    S: Stream<Item = NetlinkMessage<T>> + Unpin,
+{
+    type Item = Result<NetlinkMessage<T>, Error<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let response = match self.response.as_mut() {
+            Some(response) => response,
+            None => return Poll::Ready(None),
+        };
+        if let Poll::Ready(message) = Pin::new(response).poll_next(cx) {
+            if message.is_none() {
+                self.response = None;
+            }
+            return Poll::Ready(message.map(Ok));
+        }
+        ready!(Pin::new(&mut self.delay).poll(cx));
+
+        debug!("handle: request timed out");
+        // Dropping the response stream signals the connection that the request is cancelled.
+        self.response = None;
+        Poll::Ready(Some(Err(ErrorKind::Timeout.into())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::{channel::mpsc::unbounded, executor::block_on, StreamExt};
+    use netlink_packet_core::NetlinkMessage;
+    use netlink_packet_route::{LinkMessage, RtnlMessage};
+    use std::time::Duration;
+
+    use super::ConnectionHandle;
+    use crate::{sys::SocketAddr, ErrorKind};
+
+    fn get_link() -> NetlinkMessage<RtnlMessage> {
+        NetlinkMessage::from(RtnlMessage::GetLink(LinkMessage::default()))
+    }
+
+    #[test]
+    fn request_times_out() {
+        let (requests_tx, mut requests_rx) = unbounded();
+        let mut handle = ConnectionHandle::new(requests_tx);
+        let mut response = handle
+            .request_with_timeout(get_link(), SocketAddr::new(0, 0), Duration::from_millis(10))
+            .unwrap();
+        let request = block_on(requests_rx.next()).unwrap();
+
+        match block_on(response.next()) {
+            Some(Err(e)) => assert!(matches!(e.kind(), ErrorKind::Timeout)),
+            other => panic!("expected a timeout error, got {:?}", other),
+        }
+        assert!(block_on(response.next()).is_none());
+        // The connection sees the request as cancelled
+        assert!(request.metadata.is_closed());
+    }
+
+    #[test]
+    fn request_answered_in_time() {
+        let (requests_tx, mut requests_rx) = unbounded();
+        let mut handle = ConnectionHandle::new(requests_tx);
+        let mut response = handle
+            .request_with_timeout(get_link(), SocketAddr::new(0, 0), Duration::from_secs(60))
+            .unwrap();
+        let request = block_on(requests_rx.next()).unwrap();
+
+        let answer = NetlinkMessage::from(RtnlMessage::NewLink(LinkMessage::default()));
+        request.metadata.unbounded_send(answer.clone()).unwrap();
+        drop(request);
+
+        match block_on(response.next()) {
+            Some(Ok(message)) => assert_eq!(message, answer),
+            other => panic!("expected a response, got {:?}", other),
+        }
+        assert!(block_on(response.next()).is_none());
+    }
+
+    #[test]
+    fn request_with_timeout_on_closed_connection() {
+        let (requests_tx, requests_rx) = unbounded();
+        let mut handle = ConnectionHandle::<RtnlMessage>::new(requests_tx);
+        drop(requests_rx);
+        let result =
+            handle.request_with_timeout(get_link(), SocketAddr::new(0, 0), Duration::from_secs(1));
+        match result {
+            Err(e) => assert!(matches!(e.kind(), ErrorKind::ConnectionClosed)),
+            Ok(_) => panic!("expected the request to fail"),
+        }
+    }
+}
@@ -219,6 +219,12 @@ pub mod sys {
 /// Most of the time, users will want to spawn the `Connection` on an async runtime, and use the
 /// handle to send messages.
 ///
+/// When messages are lost because the socket receive buffer overflowed, a message with a
+/// [`NetlinkPayload::Overrun`][overrun] payload is sent on the stream of unsolicited messages.
+/// Subscribers should treat it as a request to resynchronize their state, for instance by dumping
+/// it again.
+///
+/// [overrun]: crate::packet::NetlinkPayload::Overrun
 /// [protos]: crate::sys::protocols
 #[cfg(feature = "tokio_socket")]
 #[allow(clippy::type_complexity)]
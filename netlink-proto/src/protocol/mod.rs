@@ -6,3 +6,6 @@ mod request;
 
 pub(crate) use protocol::{Protocol, Response};
 pub(crate) use request::Request;
+
+#[cfg(test)]
+mod test;
@@ -3,20 +3,24 @@
 use std::{
     collections::{hash_map, HashMap, VecDeque},
     fmt::Debug,
+    time::{Duration, Instant},
 };
 
 use netlink_packet_core::{
     constants::*,
+    ErrorMessage,
     NetlinkDeserializable,
+    NetlinkHeader,
     NetlinkMessage,
     NetlinkPayload,
     NetlinkSerializable,
+    NLMSG_ERROR,
 };
 
 use super::Request;
 use crate::sys::SocketAddr;
 
-#[derive(Debug, Eq, PartialEq, Hash)]
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
 struct RequestId {
     sequence_number: u32,
     port: u32,
@@ -44,6 +48,28 @@ struct PendingRequest<M> {
     metadata: M,
 }
 
+/// How long to wait for the remaining responses to a cancelled request. The kernel may never
+/// answer a request, or its last response may be lost, so cancelled requests cannot be kept until
+/// their last response arrives.
+pub(super) const CANCELLED_REQUEST_LIFETIME: Duration = Duration::from_secs(60);
+
+/// A request that was given up on before it was fully processed, because it was cancelled or
+/// because messages were lost. The remaining responses to such a request are discarded.
+#[derive(Debug)]
+struct CancelledRequest {
+    expecting_ack: bool,
+    cancelled_at: Instant,
+}
+
+impl CancelledRequest {
+    fn new(expecting_ack: bool) -> Self {
+        Self {
+            expecting_ack,
+            cancelled_at: Instant::now(),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct Protocol<T, M> {
     /// Counter that is incremented for each message sent
@@ -53,6 +79,11 @@ pub(crate) struct Protocol<T, M> {
     /// associated with each request.
     pending_requests: HashMap<RequestId, PendingRequest<M>>,
 
+    /// Requests that are not pending anymore, but for which more responses may still come. They
+    /// are kept until their last response is received or until they expire, so that responses
+    /// are not mistaken for unsolicited messages.
+    cancelled_requests: HashMap<RequestId, CancelledRequest>,
+
     /// Responses to pending requests
     pub incoming_responses: VecDeque<Response<T, M>>,
 
@@ -72,6 +103,7 @@ where
         Self {
             sequence_id: 0,
             pending_requests: HashMap::new(),
+            cancelled_requests: HashMap::new(),
             incoming_responses: VecDeque::new(),
             incoming_requests: VecDeque::new(),
             outgoing_messages: VecDeque::new(),
@@ -81,8 +113,15 @@ where
     pub fn handle_message(&mut self, message: NetlinkMessage<T>, source: SocketAddr) {
         let request_id = RequestId::new(message.header.sequence_number, source.port_number());
         debug!("handling messages (request id = {:?})", request_id);
-        if let hash_map::Entry::Occupied(entry) = self.pending_requests.entry(request_id) {
+        if let NetlinkPayload::Overrun(_) = message.payload {
+            self.handle_overrun(message, source);
+        } else if let hash_map::Entry::Occupied(entry) = self.pending_requests.entry(request_id) {
             Self::handle_response(&mut self.incoming_responses, entry, message);
+        } else if let hash_map::Entry::Occupied(entry) = self.cancelled_requests.entry(request_id) {
+            trace!("discarding response to cancelled request {:?}", entry.key());
+            if is_last_response(&message, entry.get().expecting_ack) {
+                entry.remove();
+            }
         } else {
             self.incoming_requests.push_back((message, source));
         }
@@ -97,18 +136,7 @@ where
         let mut request_id = entry.key();
         debug!("handling response to request {:?}", request_id);
 
-        // A request is processed if we receive an Ack, Error,
-        // Done, Overrun, or InnerMessage without the
-        // multipart flag and we were not expecting an Ack
-        let done = match message.payload {
-            NetlinkPayload::InnerMessage(_)
-                if message.header.flags & NLM_F_MULTIPART == NLM_F_MULTIPART =>
-            {
-                false
-            }
-            NetlinkPayload::InnerMessage(_) => !entry.get().expecting_ack,
-            _ => true,
-        };
+        let done = is_last_response(&message, entry.get().expecting_ack);
 
         let metadata = if done {
             trace!("request {:?} fully processed", request_id);
@@ -130,6 +158,77 @@ where
         debug!("done handling response to request {:?}", request_id);
     }
 
+    /// Handle a `NLMSG_OVERRUN` message, or a synthetic one created when the socket receive
+    /// buffer overflowed (`ENOBUFS`). Messages have been lost, so none of the pending requests can
+    /// be expected to complete: they all fail with an `ENOBUFS` error, and their remaining
+    /// responses are discarded. The overrun message itself is passed on as an unsolicited message,
+    /// so that subscribers know they must resynchronize their state.
+    fn handle_overrun(&mut self, message: NetlinkMessage<T>, source: SocketAddr) {
+        warn!(
+            "netlink messages were lost, failing {} pending request(s)",
+            self.pending_requests.len()
+        );
+        for (request_id, pending_request) in self.pending_requests.drain() {
+            let header = NetlinkHeader {
+                message_type: NLMSG_ERROR,
+                sequence_number: request_id.sequence_number,
+                port_number: request_id.port,
+                ..Default::default()
+            };
+            let payload = NetlinkPayload::Error(ErrorMessage {
+                code: -libc::ENOBUFS,
+                header: vec![],
+                nlas: vec![],
+            });
+            self.incoming_responses.push_back(Response {
+                done: true,
+                message: NetlinkMessage::new(header, payload),
+                metadata: pending_request.metadata,
+            });
+            self.cancelled_requests.insert(
+                request_id,
+                CancelledRequest::new(pending_request.expecting_ack),
+            );
+        }
+        self.incoming_requests.push_back((message, source));
+    }
+
+    /// Forget about the pending requests for which `is_cancelled` returns `true`. Responses that
+    /// may still arrive for these requests are discarded, until the requests expire.
+    pub fn drop_cancelled_requests<F>(&mut self, mut is_cancelled: F)
+    where
+        F: FnMut(&M) -> bool,
+    {
+        self.expire_cancelled_requests(Instant::now());
+        let cancelled_requests = &mut self.cancelled_requests;
+        self.pending_requests.retain(|request_id, pending_request| {
+            if is_cancelled(&pending_request.metadata) {
+                trace!("request {:?} was cancelled", request_id);
+                cancelled_requests.insert(
+                    *request_id,
+                    CancelledRequest::new(pending_request.expecting_ack),
+                );
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Forget about the requests that were cancelled more than [`CANCELLED_REQUEST_LIFETIME`]
+    /// before `now`. Responses that still arrive for them are treated as unsolicited messages.
+    pub fn expire_cancelled_requests(&mut self, now: Instant) {
+        self.cancelled_requests
+            .retain(|request_id, cancelled_request| {
+                let expired = now.saturating_duration_since(cancelled_request.cancelled_at)
+                    >= CANCELLED_REQUEST_LIFETIME;
+                if expired {
+                    trace!("cancelled request {:?} expired", request_id);
+                }
+                !expired
+            });
+    }
+
     pub fn request(&mut self, request: Request<T, M>) {
         let Request {
             mut message,
@@ -169,3 +268,18 @@ where
         message.header.sequence_number = self.sequence_id;
     }
 }
+
+/// Whether `message` is the last response to a request. A request is processed if we receive an
+/// Ack, Error, Done, Overrun, or InnerMessage without the multipart flag and we were not expecting
+/// an Ack.
+fn is_last_response<T>(message: &NetlinkMessage<T>, expecting_ack: bool) -> bool {
+    match message.payload {
+        NetlinkPayload::InnerMessage(_)
+            if message.header.flags & NLM_F_MULTIPART == NLM_F_MULTIPART =>
+        {
+            false
+        }
+        NetlinkPayload::InnerMessage(_) => !expecting_ack,
+        _ => true,
+    }
+}
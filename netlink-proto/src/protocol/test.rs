@@ -0,0 +1,285 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    constants::*,
+    ErrorMessage,
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    NLMSG_OVERRUN,
+};
+use netlink_packet_route::{LinkMessage, RtnlMessage};
+
+use std::time::Instant;
+
+use super::{protocol::CANCELLED_REQUEST_LIFETIME, Protocol, Request};
+use crate::sys::SocketAddr;
+
+/// The metadata of the requests identify them in the tests
+type TestProtocol = Protocol<RtnlMessage, u32>;
+
+fn kernel() -> SocketAddr {
+    SocketAddr::new(0, 0)
+}
+
+fn send_request(protocol: &mut TestProtocol, flags: u16, id: u32) -> u32 {
+    let mut message = NetlinkMessage::from(RtnlMessage::GetLink(LinkMessage::default()));
+    message.header.flags = flags;
+    protocol.request(Request::from((message, kernel(), id)));
+    let (message, _) = protocol.outgoing_messages.pop_back().unwrap();
+    message.header.sequence_number
+}
+
+fn response(
+    sequence_number: u32,
+    flags: u16,
+    payload: NetlinkPayload<RtnlMessage>,
+) -> NetlinkMessage<RtnlMessage> {
+    let header = NetlinkHeader {
+        sequence_number,
+        flags,
+        ..Default::default()
+    };
+    let mut message = NetlinkMessage::new(header, payload);
+    message.finalize();
+    message
+}
+
+fn dump_part(sequence_number: u32) -> NetlinkMessage<RtnlMessage> {
+    response(
+        sequence_number,
+        NLM_F_MULTIPART,
+        NetlinkPayload::InnerMessage(RtnlMessage::NewLink(LinkMessage::default())),
+    )
+}
+
+fn done(sequence_number: u32) -> NetlinkMessage<RtnlMessage> {
+    response(sequence_number, NLM_F_MULTIPART, NetlinkPayload::Done)
+}
+
+fn ack(sequence_number: u32) -> NetlinkMessage<RtnlMessage> {
+    response(
+        sequence_number,
+        0,
+        NetlinkPayload::Ack(ErrorMessage {
+            code: 0,
+            header: vec![],
+            nlas: vec![],
+        }),
+    )
+}
+
+fn error(sequence_number: u32, code: i32) -> NetlinkMessage<RtnlMessage> {
+    response(
+        sequence_number,
+        0,
+        NetlinkPayload::Error(ErrorMessage {
+            code,
+            header: vec![],
+            nlas: vec![],
+        }),
+    )
+}
+
+fn overrun() -> NetlinkMessage<RtnlMessage> {
+    let header = NetlinkHeader {
+        message_type: NLMSG_OVERRUN,
+        ..Default::default()
+    };
+    NetlinkMessage::new(header, NetlinkPayload::Overrun(vec![]))
+}
+
+fn unsolicited() -> NetlinkMessage<RtnlMessage> {
+    response(
+        0,
+        0,
+        NetlinkPayload::InnerMessage(RtnlMessage::NewLink(LinkMessage::default())),
+    )
+}
+
+#[test]
+fn dump_responses() {
+    let mut protocol = TestProtocol::new();
+    let seq = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_DUMP, 1);
+
+    protocol.handle_message(dump_part(seq), kernel());
+    protocol.handle_message(dump_part(seq), kernel());
+    protocol.handle_message(done(seq), kernel());
+
+    let responses: Vec<_> = protocol.incoming_responses.drain(..).collect();
+    assert_eq!(responses.len(), 3);
+    assert!(responses.iter().all(|response| response.metadata == 1));
+    assert_eq!(
+        responses.iter().map(|r| r.done).collect::<Vec<_>>(),
+        vec![false, false, true]
+    );
+    assert!(protocol.incoming_requests.is_empty());
+}
+
+#[test]
+fn cancel_mid_dump() {
+    let mut protocol = TestProtocol::new();
+    let seq = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_DUMP, 1);
+
+    protocol.handle_message(dump_part(seq), kernel());
+    assert_eq!(protocol.incoming_responses.len(), 1);
+    protocol.incoming_responses.clear();
+
+    protocol.drop_cancelled_requests(|id| *id == 1);
+
+    // The rest of the dump is neither a response nor an unsolicited message
+    protocol.handle_message(dump_part(seq), kernel());
+    protocol.handle_message(dump_part(seq), kernel());
+    protocol.handle_message(done(seq), kernel());
+    assert!(protocol.incoming_responses.is_empty());
+    assert!(protocol.incoming_requests.is_empty());
+
+    // Once the dump is over, the request is forgotten for good
+    protocol.handle_message(dump_part(seq), kernel());
+    assert_eq!(protocol.incoming_requests.len(), 1);
+}
+
+#[test]
+fn cancel_only_matching_requests() {
+    let mut protocol = TestProtocol::new();
+    let seq1 = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_DUMP, 1);
+    let seq2 = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_DUMP, 2);
+
+    protocol.drop_cancelled_requests(|id| *id == 1);
+
+    protocol.handle_message(dump_part(seq1), kernel());
+    protocol.handle_message(dump_part(seq2), kernel());
+    assert_eq!(protocol.incoming_responses.len(), 1);
+    assert_eq!(protocol.incoming_responses[0].metadata, 2);
+    assert!(protocol.incoming_requests.is_empty());
+}
+
+#[test]
+fn timed_out_request() {
+    // A request that timed out is cancelled before receiving any response. When the kernel
+    // eventually answers, the answer is discarded.
+    let mut protocol = TestProtocol::new();
+    let seq = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_ACK, 1);
+
+    protocol.drop_cancelled_requests(|id| *id == 1);
+
+    protocol.handle_message(error(seq, -libc::EINVAL), kernel());
+    assert!(protocol.incoming_responses.is_empty());
+    assert!(protocol.incoming_requests.is_empty());
+}
+
+#[test]
+fn cancelled_requests_expire() {
+    // The kernel may never answer a request, so cancelled requests are eventually forgotten
+    let mut protocol = TestProtocol::new();
+    let seq1 = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_ACK, 1);
+    protocol.drop_cancelled_requests(|id| *id == 1);
+
+    protocol.expire_cancelled_requests(Instant::now());
+    let seq2 = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_ACK, 2);
+    protocol.drop_cancelled_requests(|id| *id == 2);
+
+    // Both requests are still recent enough for their responses to be discarded
+    protocol.handle_message(ack(seq1), kernel());
+    assert!(protocol.incoming_requests.is_empty());
+
+    // Once expired, the late responses are not recognized anymore
+    protocol.expire_cancelled_requests(Instant::now() + CANCELLED_REQUEST_LIFETIME);
+    protocol.handle_message(ack(seq2), kernel());
+    assert!(protocol.incoming_responses.is_empty());
+    assert_eq!(protocol.incoming_requests.len(), 1);
+}
+
+#[test]
+fn overrun_failed_requests_expire() {
+    // The last response to a request failed by an overrun may have been lost
+    let mut protocol = TestProtocol::new();
+    let seq = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_DUMP, 1);
+    protocol.handle_message(overrun(), kernel());
+    protocol.incoming_responses.clear();
+    protocol.incoming_requests.clear();
+
+    protocol.expire_cancelled_requests(Instant::now() + CANCELLED_REQUEST_LIFETIME);
+    protocol.handle_message(done(seq), kernel());
+    assert!(protocol.incoming_responses.is_empty());
+    assert_eq!(protocol.incoming_requests.len(), 1);
+}
+
+#[test]
+fn notify_ack_is_not_leaked() {
+    // `ConnectionHandle::notify` drops the response stream right away, so the connection cancels
+    // the request before the kernel acknowledges it.
+    let mut protocol = TestProtocol::new();
+    let seq1 = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_ACK, 1);
+    let seq2 = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_ACK, 2);
+    protocol.drop_cancelled_requests(|_| true);
+
+    protocol.handle_message(ack(seq1), kernel());
+    protocol.handle_message(error(seq2, -libc::EEXIST), kernel());
+    assert!(protocol.incoming_responses.is_empty());
+    assert!(protocol.incoming_requests.is_empty());
+
+    // Genuine unsolicited messages are still passed on
+    protocol.handle_message(unsolicited(), kernel());
+    assert_eq!(protocol.incoming_requests.len(), 1);
+}
+
+#[test]
+fn overrun_fails_pending_requests() {
+    let mut protocol = TestProtocol::new();
+    let seq1 = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_DUMP, 1);
+    let seq2 = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_ACK, 2);
+    protocol.handle_message(dump_part(seq1), kernel());
+    protocol.incoming_responses.clear();
+
+    protocol.handle_message(overrun(), kernel());
+
+    let mut responses: Vec<_> = protocol.incoming_responses.drain(..).collect();
+    responses.sort_by_key(|response| response.metadata);
+    assert_eq!(responses.len(), 2);
+    for (response, seq) in responses.iter().zip([seq1, seq2]) {
+        assert!(response.done);
+        assert_eq!(response.message.header.sequence_number, seq);
+        match response.message.payload {
+            NetlinkPayload::Error(ref e) => assert_eq!(e.code, -libc::ENOBUFS),
+            ref payload => panic!("expected an ENOBUFS error, got {:?}", payload),
+        }
+    }
+}
+
+#[test]
+fn overrun_marker_is_unsolicited() {
+    let mut protocol = TestProtocol::new();
+    send_request(&mut protocol, NLM_F_REQUEST | NLM_F_DUMP, 1);
+
+    protocol.handle_message(overrun(), kernel());
+
+    assert_eq!(protocol.incoming_requests.len(), 1);
+    let (message, _) = protocol.incoming_requests.pop_front().unwrap();
+    assert!(matches!(message.payload, NetlinkPayload::Overrun(_)));
+}
+
+#[test]
+fn requests_after_overrun() {
+    let mut protocol = TestProtocol::new();
+    let seq1 = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_DUMP, 1);
+    protocol.handle_message(overrun(), kernel());
+    protocol.incoming_responses.clear();
+    protocol.incoming_requests.clear();
+
+    // The remaining responses to the failed request are discarded
+    protocol.handle_message(dump_part(seq1), kernel());
+    protocol.handle_message(done(seq1), kernel());
+    assert!(protocol.incoming_responses.is_empty());
+    assert!(protocol.incoming_requests.is_empty());
+
+    // New requests work as usual
+    let seq2 = send_request(&mut protocol, NLM_F_REQUEST | NLM_F_ACK, 2);
+    assert_ne!(seq1, seq2);
+    protocol.handle_message(ack(seq2), kernel());
+    assert_eq!(protocol.incoming_responses.len(), 1);
+    let response = protocol.incoming_responses.pop_front().unwrap();
+    assert!(response.done);
+    assert_eq!(response.metadata, 2);
+    assert!(protocol.incoming_requests.is_empty());
+}
@@ -8,6 +8,9 @@ use std::{
 
 use crate::SocketAddr;
 
+// Not available in all the versions of `libc` we support
+const NETLINK_GET_STRICT_CHK: libc::c_int = 12;
+
 /// A netlink socket.
 ///
 /// # Example
@@ -446,6 +449,32 @@ impl Socket {
         let res = getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, libc::NETLINK_CAP_ACK)?;
         Ok(res == 1)
     }
+
+    /// `NETLINK_EXT_ACK` (since Linux 4.12). When set, the kernel may add extended ACK attributes
+    /// to the error and acknowledgment messages it sends back, such as a human readable error
+    /// message or the offset of the attribute that caused the error.
+    pub fn set_ext_ack(&mut self, value: bool) -> Result<()> {
+        let value: libc::c_int = if value { 1 } else { 0 };
+        setsockopt(self.0, libc::SOL_NETLINK, libc::NETLINK_EXT_ACK, value)
+    }
+
+    pub fn get_ext_ack(&self) -> Result<bool> {
+        let res = getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, libc::NETLINK_EXT_ACK)?;
+        Ok(res == 1)
+    }
+
+    /// `NETLINK_GET_STRICT_CHK` (since Linux 4.20). When set, the kernel strictly validates the
+    /// headers and attributes of dump requests, and honours the attributes that some dump
+    /// requests use to filter their results, such as the bridge and bridge port of FDB dumps.
+    pub fn set_strict_checking(&mut self, value: bool) -> Result<()> {
+        let value: libc::c_int = if value { 1 } else { 0 };
+        setsockopt(self.0, libc::SOL_NETLINK, NETLINK_GET_STRICT_CHK, value)
+    }
+
+    pub fn get_strict_checking(&self) -> Result<bool> {
+        let res = getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, NETLINK_GET_STRICT_CHK)?;
+        Ok(res == 1)
+    }
 }
 
 /// Wrapper around `getsockopt`:
@@ -548,6 +577,16 @@ mod test {
         sock.set_broadcast_error(false).unwrap();
         assert!(!sock.get_broadcast_error().unwrap());
 
+        sock.set_ext_ack(true).unwrap();
+        assert!(sock.get_ext_ack().unwrap());
+        sock.set_ext_ack(false).unwrap();
+        assert!(!sock.get_ext_ack().unwrap());
+
+        sock.set_strict_checking(true).unwrap();
+        assert!(sock.get_strict_checking().unwrap());
+        sock.set_strict_checking(false).unwrap();
+        assert!(!sock.get_strict_checking().unwrap());
+
         // FIXME: these require root permissions
         // sock.set_listen_all_namespaces(true).unwrap();
         // assert!(sock.get_listen_all_namespaces().unwrap());
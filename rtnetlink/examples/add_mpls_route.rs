@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use std::{env, net::IpAddr};
+
+use rtnetlink::{new_connection, Error, Handle};
+
+#[tokio::main]
+async fn main() -> Result<(), ()> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 3 {
+        usage();
+        return Ok(());
+    }
+
+    let label: u32 = args[1].parse().unwrap_or_else(|_| {
+        eprintln!("invalid label");
+        std::process::exit(1);
+    });
+    let gateway: IpAddr = args[2].parse().unwrap_or_else(|_| {
+        eprintln!("invalid gateway");
+        std::process::exit(1);
+    });
+    let new_labels: Vec<u32> = args[3..]
+        .iter()
+        .map(|label| {
+            label.parse().unwrap_or_else(|_| {
+                eprintln!("invalid outgoing label");
+                std::process::exit(1);
+            })
+        })
+        .collect();
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    if let Err(e) = add_mpls_route(label, gateway, &new_labels, handle.clone()).await {
+        eprintln!("{}", e);
+    }
+    Ok(())
+}
+
+async fn add_mpls_route(
+    label: u32,
+    gateway: IpAddr,
+    new_labels: &[u32],
+    handle: Handle,
+) -> Result<(), Error> {
+    let mut request = handle.route().add().mpls().label(label).via(gateway);
+    if !new_labels.is_empty() {
+        request = request.new_destination(new_labels);
+    }
+    request.execute().await?;
+    Ok(())
+}
+
+fn usage() {
+    eprintln!(
+        "usage:
+    cargo run --example add_mpls_route -- <label> <gateway> [<outgoing label>...]
+
+This swaps the incoming label for the outgoing labels, or pops it if there are
+none. MPLS routing must be enabled first, by loading the mpls_router module and
+setting the net.mpls.platform_labels sysctl.
+
+Note that you need to run this program as root. Instead of running cargo as root,
+build the example normally:
+
+    cd rtnetlink ; cargo build --example add_mpls_route
+
+Then find the binary in the target directory:
+
+    cd ../target/debug/example ; sudo ./add_mpls_route <label> <gateway> [<outgoing label>...]"
+    );
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+
+use rtnetlink::{
+    new_connection,
+    packet::{AD_LACP_FAST, BOND_MODE_8023AD, BOND_XMIT_POLICY_LAYER34},
+};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+    handle
+        .link()
+        .add()
+        .bond("my-bond".into())
+        .mode(BOND_MODE_8023AD)
+        .miimon(100)
+        .updelay(100)
+        .downdelay(100)
+        .min_links(2)
+        .ad_lacp_rate(AD_LACP_FAST)
+        .xmit_hash_policy(BOND_XMIT_POLICY_LAYER34)
+        .up()
+        .execute()
+        .await
+        .map_err(|e| format!("{}", e))
+}
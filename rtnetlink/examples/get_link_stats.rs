@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::TryStreamExt;
+use netlink_packet_route::nlas::stats::Nla;
+use rtnetlink::{new_connection, Error, Handle};
+
+#[tokio::main]
+async fn main() -> Result<(), ()> {
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    println!("dumping link statistics");
+    if let Err(e) = dump_link_stats(handle).await {
+        eprintln!("{}", e);
+    }
+
+    Ok(())
+}
+
+async fn dump_link_stats(handle: Handle) -> Result<(), Error> {
+    let mut stats = handle.stats().get().execute();
+    while let Some(msg) = stats.try_next().await? {
+        for nla in msg.nlas.into_iter() {
+            if let Nla::Link64(stats) = nla {
+                println!(
+                    "link {}: rx {} bytes, {} packets, tx {} bytes, {} packets",
+                    msg.header.ifindex,
+                    stats.rx_bytes,
+                    stats.rx_packets,
+                    stats.tx_bytes,
+                    stats.tx_packets
+                );
+            }
+        }
+    }
+    Ok(())
+}
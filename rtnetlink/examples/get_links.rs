@@ -3,10 +3,7 @@
 use futures::stream::TryStreamExt;
 use rtnetlink::{
     new_connection,
-    packet::rtnl::{
-        constants::{AF_BRIDGE, RTEXT_FILTER_BRVLAN},
-        link::nlas::Nla,
-    },
+    packet::rtnl::link::nlas::{AfSpecBridge, Nla},
     Error,
     Handle,
 };
@@ -96,19 +93,18 @@ async fn dump_links(handle: Handle) -> Result<(), Error> {
 }
 
 async fn dump_bridge_filter_info(handle: Handle) -> Result<(), Error> {
-    let mut links = handle
-        .link()
-        .get()
-        .set_filter_mask(AF_BRIDGE as u8, RTEXT_FILTER_BRVLAN)
-        .execute();
-    'outer: while let Some(msg) = links.try_next().await? {
+    let mut links = handle.link().get().bridge_vlans().execute();
+    while let Some(msg) = links.try_next().await? {
         for nla in msg.nlas.into_iter() {
-            if let Nla::AfSpecBridge(data) = nla {
-                println!(
-                    "found interface {} with AfSpecBridge data {:?})",
-                    msg.header.index, data
-                );
-                continue 'outer;
+            if let Nla::AfSpecBridge(nlas) = nla {
+                for nla in nlas {
+                    if let AfSpecBridge::VlanInfo(info) = nla {
+                        println!(
+                            "found interface {} with vlan {} (flags = {:#x})",
+                            msg.header.index, info.vid, info.flags
+                        );
+                    }
+                }
             }
         }
     }
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::TryStreamExt;
+use rtnetlink::{new_connection, Error, Handle};
+
+#[tokio::main]
+async fn main() -> Result<(), ()> {
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    println!("dumping multicast groups");
+    if let Err(e) = dump_mdb(handle).await {
+        eprintln!("{}", e);
+    }
+
+    Ok(())
+}
+
+async fn dump_mdb(handle: Handle) -> Result<(), Error> {
+    let mut bridges = handle.mdb().get().execute();
+    while let Some(bridge) = bridges.try_next().await? {
+        println!("{:?}", bridge);
+    }
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::TryStreamExt;
+use rtnetlink::{new_connection, Error, Handle};
+
+#[tokio::main]
+async fn main() -> Result<(), ()> {
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    println!("dumping neighbour tables");
+    if let Err(e) = dump_neighbour_tables(handle).await {
+        eprintln!("{}", e);
+    }
+
+    Ok(())
+}
+
+async fn dump_neighbour_tables(handle: Handle) -> Result<(), Error> {
+    let mut tables = handle.neighbour_table().get().execute();
+    while let Some(table) = tables.try_next().await? {
+        println!("{:?}", table);
+    }
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::TryStreamExt;
+use rtnetlink::{new_connection, Error, Handle, IpVersion};
+
+#[tokio::main]
+async fn main() -> Result<(), ()> {
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    println!("dumping IPv4 configuration");
+    if let Err(e) = dump_netconf(handle.clone(), IpVersion::V4).await {
+        eprintln!("{}", e);
+    }
+    println!();
+
+    println!("dumping IPv6 configuration");
+    if let Err(e) = dump_netconf(handle, IpVersion::V6).await {
+        eprintln!("{}", e);
+    }
+
+    Ok(())
+}
+
+async fn dump_netconf(handle: Handle, ip_version: IpVersion) -> Result<(), Error> {
+    let mut netconfs = handle.netconf().get(ip_version).execute();
+    while let Some(netconf) = netconfs.try_next().await? {
+        println!("{:?}", netconf);
+    }
+    Ok(())
+}
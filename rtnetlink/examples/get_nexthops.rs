@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::TryStreamExt;
+use rtnetlink::{new_connection, Error, Handle};
+
+#[tokio::main]
+async fn main() -> Result<(), ()> {
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    println!("dumping nexthops");
+    if let Err(e) = dump_nexthops(handle).await {
+        eprintln!("{}", e);
+    }
+
+    Ok(())
+}
+
+async fn dump_nexthops(handle: Handle) -> Result<(), Error> {
+    let mut nexthops = handle.nexthop().get().execute();
+    while let Some(nexthop) = nexthops.try_next().await? {
+        println!("{:?}", nexthop);
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::Ipv6Addr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::address_label::Nla,
+    AddressLabelMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to add an entry to the IPv6 address label table, used for source address
+/// selection (RFC 6724). This is equivalent to the `ip addrlabel add` commands.
+pub struct AddressLabelAddRequest {
+    handle: Handle,
+    message: AddressLabelMessage,
+    replace: bool,
+}
+
+impl AddressLabelAddRequest {
+    pub(crate) fn new(handle: Handle, prefix: Ipv6Addr, prefix_len: u8, label: u32) -> Self {
+        let mut message = AddressLabelMessage::default();
+        message.header.family = AF_INET6 as u8;
+        message.header.prefix_len = prefix_len;
+        message.nlas.push(Nla::Address(prefix));
+        message.nlas.push(Nla::Label(label));
+        AddressLabelAddRequest {
+            handle,
+            message,
+            replace: false,
+        }
+    }
+
+    /// Only apply the label to the addresses of the given interface (equivalent to
+    /// `dev DEV`)
+    pub fn index(mut self, index: u32) -> Self {
+        self.message.header.index = index;
+        self
+    }
+
+    /// Replace the existing entry for the same prefix and interface.
+    pub fn replace(self) -> Self {
+        Self {
+            replace: true,
+            ..self
+        }
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let AddressLabelAddRequest {
+            mut handle,
+            message,
+            replace,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::NewAddressLabel(message));
+        let replace = if replace { NLM_F_REPLACE } else { NLM_F_EXCL };
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | replace | NLM_F_CREATE;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut AddressLabelMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::Ipv6Addr;
+
+use crate::{
+    packet::{
+        constants::*,
+        nlas::address_label::Nla,
+        AddressLabelMessage,
+        NetlinkMessage,
+        RtnlMessage,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct AddressLabelDelRequest {
+    handle: Handle,
+    message: AddressLabelMessage,
+}
+
+impl AddressLabelDelRequest {
+    pub(crate) fn new(handle: Handle, prefix: Ipv6Addr, prefix_len: u8, label: u32) -> Self {
+        let mut message = AddressLabelMessage::default();
+        message.header.family = AF_INET6 as u8;
+        message.header.prefix_len = prefix_len;
+        message.nlas.push(Nla::Address(prefix));
+        message.nlas.push(Nla::Label(label));
+        AddressLabelDelRequest { handle, message }
+    }
+
+    /// Delete the entry of the given interface (equivalent to `dev DEV`)
+    pub fn index(mut self, index: u32) -> Self {
+        self.message.header.index = index;
+        self
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let AddressLabelDelRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelAddressLabel(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    pub fn message_mut(&mut self) -> &mut AddressLabelMessage {
+        &mut self.message
+    }
+}
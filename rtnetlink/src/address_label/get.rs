@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{constants::*, AddressLabelMessage, NetlinkMessage, RtnlMessage};
+
+use crate::{try_rtnl, Error, Handle};
+
+pub struct AddressLabelGetRequest {
+    handle: Handle,
+    message: AddressLabelMessage,
+}
+
+impl AddressLabelGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let mut message = AddressLabelMessage::default();
+        message.header.family = AF_INET6 as u8;
+        AddressLabelGetRequest { handle, message }
+    }
+
+    pub fn message_mut(&mut self) -> &mut AddressLabelMessage {
+        &mut self.message
+    }
+
+    pub fn execute(self) -> impl TryStream<Ok = AddressLabelMessage, Error = Error> {
+        let AddressLabelGetRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetAddressLabel(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewAddressLabel))),
+            ),
+            Err(e) => Either::Right(future::err::<AddressLabelMessage, Error>(e).into_stream()),
+        }
+    }
+}
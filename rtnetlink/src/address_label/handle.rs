@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use crate::{AddressLabelAddRequest, AddressLabelDelRequest, AddressLabelGetRequest, Handle};
+
+pub struct AddressLabelHandle(Handle);
+
+impl AddressLabelHandle {
+    pub fn new(handle: Handle) -> Self {
+        AddressLabelHandle(handle)
+    }
+
+    /// Retrieve the IPv6 address label table (equivalent to `ip addrlabel list`)
+    pub fn get(&self) -> AddressLabelGetRequest {
+        AddressLabelGetRequest::new(self.0.clone())
+    }
+
+    /// Add a label to the addresses of the given prefix (equivalent to
+    /// `ip addrlabel add prefix PREFIX label LABEL`)
+    pub fn add(&self, prefix: Ipv6Addr, prefix_len: u8, label: u32) -> AddressLabelAddRequest {
+        AddressLabelAddRequest::new(self.0.clone(), prefix, prefix_len, label)
+    }
+
+    /// Delete the label of the given prefix (equivalent to
+    /// `ip addrlabel del prefix PREFIX label LABEL`)
+    pub fn del(&self, prefix: Ipv6Addr, prefix_len: u8, label: u32) -> AddressLabelDelRequest {
+        AddressLabelDelRequest::new(self.0.clone(), prefix, prefix_len, label)
+    }
+}
@@ -5,12 +5,18 @@ use futures::Stream;
 use crate::{
     packet::{NetlinkMessage, RtnlMessage},
     AddressHandle,
+    AddressLabelHandle,
     Error,
     LinkHandle,
+    MdbHandle,
     NeighbourHandle,
+    NeighbourTableHandle,
+    NetconfHandle,
+    NexthopHandle,
     QDiscHandle,
     RouteHandle,
     RuleHandle,
+    StatsHandle,
     TrafficChainHandle,
     TrafficClassHandle,
     TrafficFilterHandle,
@@ -51,21 +57,57 @@ impl Handle {
         AddressHandle::new(self.clone())
     }
 
+    /// Create a new handle, specifically for IPv6 address label requests (equivalent to
+    /// `ip addrlabel` commands)
+    pub fn address_label(&self) -> AddressLabelHandle {
+        AddressLabelHandle::new(self.clone())
+    }
+
     /// Create a new handle, specifically for routing table requests (equivalent to `ip route` commands)
     pub fn route(&self) -> RouteHandle {
         RouteHandle::new(self.clone())
     }
 
+    /// Create a new handle, specifically for nexthop object requests (equivalent to `ip nexthop`
+    /// commands)
+    pub fn nexthop(&self) -> NexthopHandle {
+        NexthopHandle::new(self.clone())
+    }
+
     /// Create a new handle, specifically for routing rule requests (equivalent to `ip rule` commands)
     pub fn rule(&self) -> RuleHandle {
         RuleHandle::new(self.clone())
     }
 
+    /// Create a new handle, specifically for link statistics requests (equivalent to `ip stats`
+    /// commands)
+    pub fn stats(&self) -> StatsHandle {
+        StatsHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for bridge multicast database requests (equivalent to
+    /// `bridge mdb` commands)
+    pub fn mdb(&self) -> MdbHandle {
+        MdbHandle::new(self.clone())
+    }
+
     /// Create a new handle, specifically for routing neighbours requests (equivalent to `ip neighbour` commands)
     pub fn neighbours(&self) -> NeighbourHandle {
         NeighbourHandle::new(self.clone())
     }
 
+    /// Create a new handle, specifically for neighbour table requests (equivalent to `ip ntable`
+    /// commands)
+    pub fn neighbour_table(&self) -> NeighbourTableHandle {
+        NeighbourTableHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for interface IP configuration requests (equivalent to
+    /// `ip netconf` commands)
+    pub fn netconf(&self) -> NetconfHandle {
+        NetconfHandle::new(self.clone())
+    }
+
     /// Create a new handle, specifically for traffic control qdisc requests
     /// (equivalent to `tc qdisc show` commands)
     pub fn qdisc(&self) -> QDiscHandle {
@@ -20,12 +20,27 @@ pub use crate::link::*;
 mod addr;
 pub use crate::addr::*;
 
+mod address_label;
+pub use crate::address_label::*;
+
 mod route;
 pub use crate::route::*;
 
+mod mdb;
+pub use crate::mdb::*;
+
+mod netconf;
+pub use crate::netconf::*;
+
+mod nexthop;
+pub use crate::nexthop::*;
+
 mod rule;
 pub use crate::rule::*;
 
+mod stats;
+pub use crate::stats::*;
+
 mod connection;
 pub use crate::connection::*;
 
@@ -35,6 +50,9 @@ pub use crate::traffic_control::*;
 mod neighbour;
 pub use crate::neighbour::*;
 
+mod neighbour_table;
+pub use crate::neighbour_table::*;
+
 pub mod constants;
 
 pub use netlink_packet_route as packet;
@@ -50,3 +68,14 @@ pub mod proto {
 pub use netlink_proto::sys;
 
 mod macros;
+
+/// Return a handle whose connection is dropped right away. Requests built with it are never
+/// sent, so tests can check them without any privilege.
+#[cfg(test)]
+pub(crate) fn test_handle() -> Handle {
+    // Creating the socket registers it with the runtime
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let _guard = rt.enter();
+    let (_, handle, _) = new_connection().unwrap();
+    handle
+}
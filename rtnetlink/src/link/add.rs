@@ -1,19 +1,45 @@
 // SPDX-License-Identifier: MIT
 
 use futures::stream::StreamExt;
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    os::unix::io::RawFd,
+};
 
 use crate::{
     packet::{
-        nlas::link::{Info, InfoData, InfoKind, InfoMacVlan, InfoVlan, InfoVxlan, Nla, VethInfo},
+        nlas::link::{
+            Info,
+            InfoBond,
+            InfoData,
+            InfoGeneve,
+            InfoGreTun,
+            InfoIpTun,
+            InfoIpVlan,
+            InfoKind,
+            InfoMacVlan,
+            InfoMacVtap,
+            InfoMacsec,
+            InfoVlan,
+            InfoVrf,
+            InfoVti,
+            InfoVxlan,
+            Nla,
+            VethInfo,
+        },
         LinkMessage,
         NetlinkMessage,
         RtnlMessage,
+        GRE_CSUM,
+        GRE_KEY,
+        GRE_SEQ,
         IFF_UP,
         NLM_F_ACK,
         NLM_F_CREATE,
         NLM_F_EXCL,
         NLM_F_REPLACE,
         NLM_F_REQUEST,
+        SIT_ISATAP,
     },
     try_nl,
     Error,
@@ -239,148 +265,1314 @@ impl VxlanAddRequest {
     }
 }
 
-/// A request to create a new link. This is equivalent to the `ip link add` commands.
-///
-/// A few methods for common actions (creating a veth pair, creating a vlan interface, etc.) are
-/// provided, but custom requests can be made using the [`message_mut()`](#method.message_mut)
-/// accessor.
-pub struct LinkAddRequest {
-    handle: Handle,
-    message: LinkMessage,
-    replace: bool,
+/// A request to create a new bond link.
+/// This is equivalent to `ip link add NAME type bond ...` commands.
+/// It provides methods to customize the creation of the bond interface.
+/// It provides almost all parameters that are listed by `man ip link`.
+pub struct BondAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoBond>,
 }
 
-impl LinkAddRequest {
-    pub(crate) fn new(handle: Handle) -> Self {
-        LinkAddRequest {
-            handle,
-            message: LinkMessage::default(),
-            replace: false,
-        }
+impl BondAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let s = self
+            .request
+            .link_info(InfoKind::Bond, Some(InfoData::Bond(self.info_data)));
+        s.execute().await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Adds the `mode` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond mode MODE`.
+    /// MODE is one of the `BOND_MODE_*` constants.
+    pub fn mode(mut self, mode: u8) -> Self {
+        self.info_data.push(InfoBond::Mode(mode));
+        self
+    }
+
+    /// Adds the `active_slave` attribute to the bond, where `active_slave`
+    /// is the ifindex of an interface attached to the bond.
+    /// This is equivalent to `ip link add name NAME type bond active_slave ACTIVE_SLAVE_NAME`.
+    pub fn active_slave(mut self, active_slave: u32) -> Self {
+        self.info_data.push(InfoBond::ActiveSlave(active_slave));
+        self
+    }
+
+    /// Adds the `miimon` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond miimon MIIMON`.
+    pub fn miimon(mut self, miimon: u32) -> Self {
+        self.info_data.push(InfoBond::MiiMon(miimon));
+        self
+    }
+
+    /// Adds the `updelay` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond updelay UPDELAY`.
+    pub fn updelay(mut self, updelay: u32) -> Self {
+        self.info_data.push(InfoBond::UpDelay(updelay));
+        self
+    }
+
+    /// Adds the `downdelay` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond downdelay DOWNDELAY`.
+    pub fn downdelay(mut self, downdelay: u32) -> Self {
+        self.info_data.push(InfoBond::DownDelay(downdelay));
+        self
+    }
+
+    /// Adds the `use_carrier` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond use_carrier USE_CARRIER`.
+    pub fn use_carrier(mut self, use_carrier: u8) -> Self {
+        self.info_data.push(InfoBond::UseCarrier(use_carrier));
+        self
+    }
+
+    /// Adds the `arp_interval` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond arp_interval ARP_INTERVAL`.
+    pub fn arp_interval(mut self, arp_interval: u32) -> Self {
+        self.info_data.push(InfoBond::ArpInterval(arp_interval));
+        self
+    }
+
+    /// Adds the `arp_ip_target` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond arp_ip_target LIST`.
+    pub fn arp_ip_target(mut self, arp_ip_target: Vec<Ipv4Addr>) -> Self {
+        self.info_data.push(InfoBond::ArpIpTarget(arp_ip_target));
+        self
+    }
+
+    /// Adds the `ns_ip6_target` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond ns_ip6_target LIST`.
+    pub fn ns_ip6_target(mut self, ns_ip6_target: Vec<Ipv6Addr>) -> Self {
+        self.info_data.push(InfoBond::NsIp6Target(ns_ip6_target));
+        self
+    }
+
+    /// Adds the `arp_validate` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond arp_validate ARP_VALIDATE`.
+    pub fn arp_validate(mut self, arp_validate: u32) -> Self {
+        self.info_data.push(InfoBond::ArpValidate(arp_validate));
+        self
+    }
+
+    /// Adds the `arp_all_targets` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond arp_all_targets ARP_ALL_TARGETS`
+    pub fn arp_all_targets(mut self, arp_all_targets: u32) -> Self {
+        self.info_data
+            .push(InfoBond::ArpAllTargets(arp_all_targets));
+        self
+    }
+
+    /// Adds the `primary` attribute to the bond, where `primary` is the ifindex
+    /// of an interface.
+    /// This is equivalent to `ip link add name NAME type bond primary PRIMARY_NAME`
+    pub fn primary(mut self, primary: u32) -> Self {
+        self.info_data.push(InfoBond::Primary(primary));
+        self
+    }
+
+    /// Adds the `primary_reselect` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond primary_reselect PRIMARY_RESELECT`.
+    pub fn primary_reselect(mut self, primary_reselect: u8) -> Self {
+        self.info_data
+            .push(InfoBond::PrimaryReselect(primary_reselect));
+        self
+    }
+
+    /// Adds the `fail_over_mac` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond fail_over_mac FAIL_OVER_MAC`.
+    pub fn fail_over_mac(mut self, fail_over_mac: u8) -> Self {
+        self.info_data.push(InfoBond::FailOverMac(fail_over_mac));
+        self
+    }
+
+    /// Adds the `xmit_hash_policy` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond xmit_hash_policy XMIT_HASH_POLICY`.
+    pub fn xmit_hash_policy(mut self, xmit_hash_policy: u8) -> Self {
+        self.info_data
+            .push(InfoBond::XmitHashPolicy(xmit_hash_policy));
+        self
+    }
+
+    /// Adds the `resend_igmp` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond resend_igmp RESEND_IGMP`.
+    pub fn resend_igmp(mut self, resend_igmp: u32) -> Self {
+        self.info_data.push(InfoBond::ResendIgmp(resend_igmp));
+        self
+    }
+
+    /// Adds the `num_peer_notif` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond num_peer_notif NUM_PEER_NOTIF`.
+    pub fn num_peer_notif(mut self, num_peer_notif: u8) -> Self {
+        self.info_data.push(InfoBond::NumPeerNotif(num_peer_notif));
+        self
+    }
+
+    /// Adds the `all_slaves_active` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond all_slaves_active ALL_SLAVES_ACTIVE`.
+    pub fn all_slaves_active(mut self, all_slaves_active: u8) -> Self {
+        self.info_data
+            .push(InfoBond::AllSlavesActive(all_slaves_active));
+        self
+    }
+
+    /// Adds the `min_links` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond min_links MIN_LINKS`.
+    pub fn min_links(mut self, min_links: u32) -> Self {
+        self.info_data.push(InfoBond::MinLinks(min_links));
+        self
+    }
+
+    /// Adds the `lp_interval` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond lp_interval LP_INTERVAL`.
+    pub fn lp_interval(mut self, lp_interval: u32) -> Self {
+        self.info_data.push(InfoBond::LpInterval(lp_interval));
+        self
+    }
+
+    /// Adds the `packets_per_slave` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond packets_per_slave PACKETS_PER_SLAVE`.
+    pub fn packets_per_slave(mut self, packets_per_slave: u32) -> Self {
+        self.info_data
+            .push(InfoBond::PacketsPerSlave(packets_per_slave));
+        self
+    }
+
+    /// Adds the `ad_lacp_rate` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond ad_lacp_rate AD_LACP_RATE`.
+    pub fn ad_lacp_rate(mut self, ad_lacp_rate: u8) -> Self {
+        self.info_data.push(InfoBond::AdLacpRate(ad_lacp_rate));
+        self
+    }
+
+    /// Adds the `ad_select` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond ad_select AD_SELECT`.
+    pub fn ad_select(mut self, ad_select: u8) -> Self {
+        self.info_data.push(InfoBond::AdSelect(ad_select));
+        self
+    }
+
+    /// Adds the `ad_actor_sys_prio` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond ad_actor_sys_prio AD_ACTOR_SYS_PRIO`.
+    pub fn ad_actor_sys_prio(mut self, ad_actor_sys_prio: u16) -> Self {
+        self.info_data
+            .push(InfoBond::AdActorSysPrio(ad_actor_sys_prio));
+        self
+    }
+
+    /// Adds the `ad_user_port_key` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond ad_user_port_key AD_USER_PORT_KEY`.
+    pub fn ad_user_port_key(mut self, ad_user_port_key: u16) -> Self {
+        self.info_data
+            .push(InfoBond::AdUserPortKey(ad_user_port_key));
+        self
+    }
+
+    /// Adds the `ad_actor_system` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond ad_actor_system AD_ACTOR_SYSTEM`.
+    pub fn ad_actor_system(mut self, ad_actor_system: [u8; 6]) -> Self {
+        self.info_data
+            .push(InfoBond::AdActorSystem(ad_actor_system));
+        self
+    }
+
+    /// Adds the `tlb_dynamic_lb` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond tlb_dynamic_lb TLB_DYNAMIC_LB`.
+    pub fn tlb_dynamic_lb(mut self, tlb_dynamic_lb: u8) -> Self {
+        self.info_data.push(InfoBond::TlbDynamicLb(tlb_dynamic_lb));
+        self
+    }
+
+    /// Adds the `peer_notify_delay` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond peer_notify_delay PEER_NOTIFY_DELAY`.
+    pub fn peer_notify_delay(mut self, peer_notify_delay: u32) -> Self {
+        self.info_data
+            .push(InfoBond::PeerNotifDelay(peer_notify_delay));
+        self
+    }
+
+    /// Adds the `lacp_active` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond lacp_active LACP_ACTIVE`.
+    pub fn lacp_active(mut self, lacp_active: u8) -> Self {
+        self.info_data.push(InfoBond::AdLacpActive(lacp_active));
+        self
+    }
+
+    /// Adds the `arp_missed_max` attribute to the bond
+    /// This is equivalent to `ip link add name NAME type bond arp_missed_max ARP_MISSED_MAX`.
+    pub fn arp_missed_max(mut self, arp_missed_max: u8) -> Self {
+        self.info_data.push(InfoBond::MissedMax(arp_missed_max));
+        self
     }
+}
+
+/// A request to create a new GRE link (`gre`, `gretap`, `ip6gre`, `ip6gretap`
+/// or `erspan`).
+/// This is equivalent to `ip link add NAME type gre ...` commands.
+/// It provides methods to customize the creation of the tunnel interface.
+pub struct GreAddRequest {
+    request: LinkAddRequest,
+    kind: InfoKind,
+    iflags: u16,
+    oflags: u16,
+    info_data: Vec<InfoGreTun>,
+}
 
+impl GreAddRequest {
     /// Execute the request.
     pub async fn execute(self) -> Result<(), Error> {
-        let LinkAddRequest {
-            mut handle,
-            message,
-            replace,
-        } = self;
-        let mut req = NetlinkMessage::from(RtnlMessage::NewLink(message));
-        let replace = if replace { NLM_F_REPLACE } else { NLM_F_EXCL };
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | replace | NLM_F_CREATE;
+        self.into_link_add_request().execute().await
+    }
 
-        let mut response = handle.request(req)?;
-        while let Some(message) = response.next().await {
-            try_nl!(message);
+    pub(crate) fn into_link_add_request(self) -> LinkAddRequest {
+        let GreAddRequest {
+            request,
+            kind,
+            iflags,
+            oflags,
+            mut info_data,
+        } = self;
+        if iflags != 0 {
+            info_data.push(InfoGreTun::IFlags(iflags));
         }
-        Ok(())
+        if oflags != 0 {
+            info_data.push(InfoGreTun::OFlags(oflags));
+        }
+        let data = match kind {
+            InfoKind::GreTap => InfoData::GreTap(info_data),
+            InfoKind::GreTun6 => InfoData::GreTun6(info_data),
+            InfoKind::GreTap6 => InfoData::GreTap6(info_data),
+            InfoKind::Erspan => InfoData::Erspan(info_data),
+            _ => InfoData::GreTun(info_data),
+        };
+        request.link_info(kind, Some(data))
     }
 
-    /// Return a mutable reference to the request message.
-    ///
-    /// # Example
-    ///
-    /// Let's say we want to create a vlan interface on a link with id 6. By default, the
-    /// [`vlan()`](#method.vlan) method would create a request with the `IFF_UP` link set, so that the
-    /// interface is up after creation. If we want to create a interface tha tis down by default we
-    /// could do:
-    ///
-    /// ```rust,no_run
-    /// use futures::Future;
-    /// use rtnetlink::{Handle, new_connection, packet::IFF_UP};
-    ///
-    /// async fn run(handle: Handle) -> Result<(), String> {
-    ///     let vlan_id = 100;
-    ///     let link_id = 6;
-    ///     let mut request = handle.link().add().vlan("my-vlan-itf".into(), link_id, vlan_id);
-    ///     // unset the IFF_UP flag before sending the request
-    ///     request.message_mut().header.flags &= !IFF_UP;
-    ///     request.message_mut().header.change_mask &= !IFF_UP;
-    ///     // send the request
-    ///     request.execute().await.map_err(|e| format!("{}", e))
-    /// }
-    pub fn message_mut(&mut self) -> &mut LinkMessage {
-        &mut self.message
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
     }
 
-    /// Create a dummy link.
-    /// This is equivalent to `ip link add NAME type dummy`.
-    pub fn dummy(self, name: String) -> Self {
-        self.name(name).link_info(InfoKind::Dummy, None).up()
+    /// Adds the `dev` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre dev LINK`,
+    /// but instead of specifing a link name (`LINK`), we specify a link index.
+    pub fn link(mut self, index: u32) -> Self {
+        self.info_data.push(InfoGreTun::Link(index));
+        self
     }
 
-    /// Create a veth pair.
-    /// This is equivalent to `ip link add NAME1 type veth peer name NAME2`.
-    pub fn veth(self, name: String, peer_name: String) -> Self {
-        // NOTE: `name` is the name of the peer in the netlink message (ie the link created via the
-        // VethInfo::Peer attribute, and `peer_name` is the name in the main netlink message.
-        // This is a bit weird, but it's all hidden from the user.
+    /// Adds the `local` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre local ADDR`.
+    /// The address family must match the tunnel kind.
+    pub fn local(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoGreTun::Local(addr));
+        self
+    }
 
-        let mut peer = LinkMessage::default();
-        // FIXME: we get a -107 (ENOTCONN) (???) when trying to set `name` up.
-        // peer.header.flags = LinkFlags::from(IFF_UP);
-        // peer.header.change_mask = LinkFlags::from(IFF_UP);
-        peer.nlas.push(Nla::IfName(name));
-        let link_info_data = InfoData::Veth(VethInfo::Peer(peer));
-        self.name(peer_name)
-            .up() // iproute2 does not set this one up
-            .link_info(InfoKind::Veth, Some(link_info_data))
+    /// Adds the `remote` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre remote ADDR`.
+    /// The address family must match the tunnel kind.
+    pub fn remote(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoGreTun::Remote(addr));
+        self
     }
 
-    /// Create VLAN on a link.
-    /// This is equivalent to `ip link add link LINK name NAME type vlan id VLAN_ID`,
-    /// but instead of specifying a link name (`LINK`), we specify a link index.
-    pub fn vlan(self, name: String, index: u32, vlan_id: u16) -> Self {
-        self.name(name)
-            .link_info(
-                InfoKind::Vlan,
-                Some(InfoData::Vlan(vec![InfoVlan::Id(vlan_id)])),
-            )
-            .append_nla(Nla::Link(index))
-            .up()
+    /// Sets the key of both received and sent packets
+    /// This is equivalent to `ip link add name NAME type gre key KEY`.
+    pub fn key(self, key: u32) -> Self {
+        self.ikey(key).okey(key)
     }
 
-    /// Create macvlan on a link.
-    /// This is equivalent to `ip link add name NAME link LINK type macvlan mode MACVLAN_MODE`,
-    ///   but instead of specifying a link name (`LINK`), we specify a link index.
-    /// The MACVLAN_MODE is an integer consisting of flags from MACVLAN_MODE (netlink-packet-route/src/rtnl/constants.rs)
-    ///   being: _PRIVATE, _VEPA, _BRIDGE, _PASSTHRU, _SOURCE, which can be *combined*.
-    pub fn macvlan(self, name: String, index: u32, mode: u32) -> Self {
-        self.name(name)
-            .link_info(
-                InfoKind::MacVlan,
-                Some(InfoData::MacVlan(vec![InfoMacVlan::Mode(mode)])),
-            )
-            .append_nla(Nla::Link(index))
-            .up()
+    /// Sets the key expected in received packets
+    /// This is equivalent to `ip link add name NAME type gre ikey KEY`.
+    pub fn ikey(mut self, key: u32) -> Self {
+        self.iflags |= GRE_KEY;
+        self.info_data.push(InfoGreTun::IKey(key));
+        self
     }
 
-    /// Create a VxLAN
-    /// This is equivalent to `ip link add name NAME type vxlan id VNI`,
-    /// it returns a VxlanAddRequest to further customize the vxlan
-    /// interface creation.
-    pub fn vxlan(self, name: String, vni: u32) -> VxlanAddRequest {
-        let s = self.name(name);
-        VxlanAddRequest {
-            request: s,
-            info_data: vec![InfoVxlan::Id(vni)],
-        }
+    /// Sets the key of sent packets
+    /// This is equivalent to `ip link add name NAME type gre okey KEY`.
+    pub fn okey(mut self, key: u32) -> Self {
+        self.oflags |= GRE_KEY;
+        self.info_data.push(InfoGreTun::OKey(key));
+        self
     }
 
-    /// Create a new bridge.
-    /// This is equivalent to `ip link add link NAME type bridge`.
-    pub fn bridge(self, name: String) -> Self {
-        self.name(name.clone())
-            .link_info(InfoKind::Bridge, None)
-            .append_nla(Nla::IfName(name))
+    /// Checksums received and sent packets
+    /// This is equivalent to `ip link add name NAME type gre csum`.
+    pub fn csum(mut self) -> Self {
+        self.iflags |= GRE_CSUM;
+        self.oflags |= GRE_CSUM;
+        self
     }
 
-    /// Replace existing matching link.
-    pub fn replace(self) -> Self {
-        Self {
-            replace: true,
-            ..self
+    /// Serializes received and sent packets
+    /// This is equivalent to `ip link add name NAME type gre seq`.
+    pub fn seq(mut self) -> Self {
+        self.iflags |= GRE_SEQ;
+        self.oflags |= GRE_SEQ;
+        self
+    }
+
+    /// Adds the `ttl` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre ttl TTL`.
+    /// 0 means that the TTL is inherited from the inner packet.
+    pub fn ttl(mut self, ttl: u8) -> Self {
+        self.info_data.push(InfoGreTun::Ttl(ttl));
+        self
+    }
+
+    /// Adds the `tos` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre tos TOS`.
+    pub fn tos(mut self, tos: u8) -> Self {
+        self.info_data.push(InfoGreTun::Tos(tos));
+        self
+    }
+
+    /// Adds the `pmtudisc` attribute to the tunnel, for IPv4 tunnels only
+    /// This is equivalent to `ip link add name NAME type gre [no]pmtudisc`.
+    pub fn pmtudisc(mut self, pmtudisc: u8) -> Self {
+        self.info_data.push(InfoGreTun::PMtuDisc(pmtudisc));
+        self
+    }
+
+    /// Adds the `encaplimit` attribute to the tunnel, for IPv6 tunnels only
+    /// This is equivalent to `ip link add name NAME type ip6gre encaplimit LIMIT`.
+    pub fn encap_limit(mut self, limit: u8) -> Self {
+        self.info_data.push(InfoGreTun::EncapLimit(limit));
+        self
+    }
+
+    /// Adds the `flowlabel` attribute to the tunnel, for IPv6 tunnels only
+    /// This is equivalent to `ip link add name NAME type ip6gre flowlabel LABEL`.
+    pub fn flowinfo(mut self, flowinfo: u32) -> Self {
+        self.info_data.push(InfoGreTun::FlowInfo(flowinfo));
+        self
+    }
+
+    /// Sets the flags of an IPv6 tunnel: a combination of the `IP6_TNL_F_*` constants.
+    pub fn flags(mut self, flags: u32) -> Self {
+        self.info_data.push(InfoGreTun::Flags(flags));
+        self
+    }
+
+    /// Adds the `encap` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre encap TYPE`.
+    /// TYPE is one of the `TUNNEL_ENCAP_*` constants.
+    pub fn encap(mut self, encap_type: u16) -> Self {
+        self.info_data.push(InfoGreTun::EncapType(encap_type));
+        self
+    }
+
+    /// Sets the flags of the encapsulation: a combination of the
+    /// `TUNNEL_ENCAP_FLAG_*` constants.
+    /// This is equivalent to `ip link add name NAME type gre encap TYPE [no]encap-csum`.
+    pub fn encap_flags(mut self, flags: u16) -> Self {
+        self.info_data.push(InfoGreTun::EncapFlags(flags));
+        self
+    }
+
+    /// Adds the `encap-sport` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre encap TYPE encap-sport PORT`.
+    pub fn encap_sport(mut self, port: u16) -> Self {
+        self.info_data.push(InfoGreTun::EncapSport(port));
+        self
+    }
+
+    /// Adds the `encap-dport` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre encap TYPE encap-dport PORT`.
+    pub fn encap_dport(mut self, port: u16) -> Self {
+        self.info_data.push(InfoGreTun::EncapDport(port));
+        self
+    }
+
+    /// Adds the `ignore-df` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gretap [no]ignore-df`.
+    pub fn ignore_df(mut self, ignore_df: u8) -> Self {
+        self.info_data.push(InfoGreTun::IgnoreDf(ignore_df));
+        self
+    }
+
+    /// Adds the `fwmark` attribute to the tunnel
+    pub fn fwmark(mut self, fwmark: u32) -> Self {
+        self.info_data.push(InfoGreTun::FwMark(fwmark));
+        self
+    }
+
+    /// Creates a metadata based tunnel
+    /// This is equivalent to `ip link add name NAME type gre external`.
+    pub fn collect_metadata(mut self) -> Self {
+        self.info_data.push(InfoGreTun::CollectMetadata);
+        self
+    }
+
+    /// Adds the `erspan` attribute to an erspan tunnel
+    /// This is equivalent to `ip link add name NAME type erspan erspan IDX`.
+    pub fn erspan_index(mut self, index: u32) -> Self {
+        self.info_data.push(InfoGreTun::ErspanIndex(index));
+        self
+    }
+
+    /// Adds the `erspan_ver` attribute to an erspan tunnel
+    /// This is equivalent to `ip link add name NAME type erspan erspan_ver VERSION`.
+    pub fn erspan_ver(mut self, version: u8) -> Self {
+        self.info_data.push(InfoGreTun::ErspanVer(version));
+        self
+    }
+}
+
+/// A request to create a new IP in IP link (`ipip`, `sit` or `ip6tnl`).
+/// This is equivalent to `ip link add NAME type ipip ...` commands.
+/// It provides methods to customize the creation of the tunnel interface.
+pub struct IpTunAddRequest {
+    request: LinkAddRequest,
+    kind: InfoKind,
+    info_data: Vec<InfoIpTun>,
+}
+
+impl IpTunAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        self.into_link_add_request().execute().await
+    }
+
+    pub(crate) fn into_link_add_request(self) -> LinkAddRequest {
+        let data = match self.kind {
+            InfoKind::SitTun => InfoData::SitTun(self.info_data),
+            InfoKind::Ip6Tnl => InfoData::Ip6Tnl(self.info_data),
+            _ => InfoData::IpTun(self.info_data),
+        };
+        self.request.link_info(self.kind, Some(data))
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Adds the `dev` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip dev LINK`,
+    /// but instead of specifing a link name (`LINK`), we specify a link index.
+    pub fn link(mut self, index: u32) -> Self {
+        self.info_data.push(InfoIpTun::Link(index));
+        self
+    }
+
+    /// Adds the `local` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip local ADDR`.
+    /// The address must be an IPv6 address for ip6tnl tunnels, and an IPv4
+    /// address otherwise.
+    pub fn local(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoIpTun::Local(addr));
+        self
+    }
+
+    /// Adds the `remote` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip remote ADDR`.
+    /// The address must be an IPv6 address for ip6tnl tunnels, and an IPv4
+    /// address otherwise.
+    pub fn remote(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoIpTun::Remote(addr));
+        self
+    }
+
+    /// Adds the `ttl` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip ttl TTL`.
+    /// 0 means that the TTL is inherited from the inner packet.
+    pub fn ttl(mut self, ttl: u8) -> Self {
+        self.info_data.push(InfoIpTun::Ttl(ttl));
+        self
+    }
+
+    /// Adds the `tos` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip tos TOS`.
+    pub fn tos(mut self, tos: u8) -> Self {
+        self.info_data.push(InfoIpTun::Tos(tos));
+        self
+    }
+
+    /// Adds the `pmtudisc` attribute to the tunnel, for IPv4 tunnels only
+    /// This is equivalent to `ip link add name NAME type ipip [no]pmtudisc`.
+    pub fn pmtudisc(mut self, pmtudisc: u8) -> Self {
+        self.info_data.push(InfoIpTun::PMtuDisc(pmtudisc));
+        self
+    }
+
+    /// Adds the `mode` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ip6tnl mode MODE`,
+    /// where the protocol is `IPPROTO_IPIP` for `ipip6`, `IPPROTO_IPV6` for
+    /// `ip6ip6`, or 0 for `any`.
+    pub fn proto(mut self, proto: u8) -> Self {
+        self.info_data.push(InfoIpTun::Proto(proto));
+        self
+    }
+
+    /// Adds the `encaplimit` attribute to an ip6tnl tunnel
+    /// This is equivalent to `ip link add name NAME type ip6tnl encaplimit LIMIT`.
+    pub fn encap_limit(mut self, limit: u8) -> Self {
+        self.info_data.push(InfoIpTun::EncapLimit(limit));
+        self
+    }
+
+    /// Adds the `flowlabel` attribute to an ip6tnl tunnel
+    /// This is equivalent to `ip link add name NAME type ip6tnl flowlabel LABEL`.
+    pub fn flowinfo(mut self, flowinfo: u32) -> Self {
+        self.info_data.push(InfoIpTun::FlowInfo(flowinfo));
+        self
+    }
+
+    /// Sets the flags of an ip6tnl tunnel: a combination of the
+    /// `IP6_TNL_F_*` constants.
+    pub fn ip6tnl_flags(mut self, flags: u32) -> Self {
+        self.info_data.push(InfoIpTun::Ip6TnlFlags(flags));
+        self
+    }
+
+    /// Creates an ISATAP sit tunnel
+    /// This is equivalent to `ip link add name NAME type sit isatap`.
+    pub fn isatap(mut self) -> Self {
+        self.info_data.push(InfoIpTun::Flags(SIT_ISATAP));
+        self
+    }
+
+    /// Adds the `encap` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip encap TYPE`.
+    /// TYPE is one of the `TUNNEL_ENCAP_*` constants.
+    pub fn encap(mut self, encap_type: u16) -> Self {
+        self.info_data.push(InfoIpTun::EncapType(encap_type));
+        self
+    }
+
+    /// Sets the flags of the encapsulation: a combination of the
+    /// `TUNNEL_ENCAP_FLAG_*` constants.
+    /// This is equivalent to `ip link add name NAME type ipip encap TYPE [no]encap-csum`.
+    pub fn encap_flags(mut self, flags: u16) -> Self {
+        self.info_data.push(InfoIpTun::EncapFlags(flags));
+        self
+    }
+
+    /// Adds the `encap-sport` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip encap TYPE encap-sport PORT`.
+    pub fn encap_sport(mut self, port: u16) -> Self {
+        self.info_data.push(InfoIpTun::EncapSport(port));
+        self
+    }
+
+    /// Adds the `encap-dport` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip encap TYPE encap-dport PORT`.
+    pub fn encap_dport(mut self, port: u16) -> Self {
+        self.info_data.push(InfoIpTun::EncapDport(port));
+        self
+    }
+
+    /// Adds the `fwmark` attribute to the tunnel
+    pub fn fwmark(mut self, fwmark: u32) -> Self {
+        self.info_data.push(InfoIpTun::FwMark(fwmark));
+        self
+    }
+
+    /// Creates a metadata based tunnel
+    /// This is equivalent to `ip link add name NAME type ipip external`.
+    pub fn collect_metadata(mut self) -> Self {
+        self.info_data.push(InfoIpTun::CollectMetadata);
+        self
+    }
+}
+
+/// A request to create a new vti link.
+/// This is equivalent to `ip link add NAME type vti ...` commands.
+/// It provides methods to customize the creation of the tunnel interface.
+pub struct VtiAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoVti>,
+}
+
+impl VtiAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        self.into_link_add_request().execute().await
+    }
+
+    pub(crate) fn into_link_add_request(self) -> LinkAddRequest {
+        self.request
+            .link_info(InfoKind::Vti, Some(InfoData::Vti(self.info_data)))
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Adds the `dev` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type vti dev LINK`,
+    /// but instead of specifing a link name (`LINK`), we specify a link index.
+    pub fn link(mut self, index: u32) -> Self {
+        self.info_data.push(InfoVti::Link(index));
+        self
+    }
+
+    /// Adds the `local` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type vti local ADDR`.
+    pub fn local(mut self, addr: Ipv4Addr) -> Self {
+        self.info_data.push(InfoVti::Local(addr.into()));
+        self
+    }
+
+    /// Adds the `remote` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type vti remote ADDR`.
+    pub fn remote(mut self, addr: Ipv4Addr) -> Self {
+        self.info_data.push(InfoVti::Remote(addr.into()));
+        self
+    }
+
+    /// Sets the mark of both received and sent packets
+    /// This is equivalent to `ip link add name NAME type vti key KEY`.
+    pub fn key(self, key: u32) -> Self {
+        self.ikey(key).okey(key)
+    }
+
+    /// Sets the mark of received packets
+    /// This is equivalent to `ip link add name NAME type vti ikey KEY`.
+    pub fn ikey(mut self, key: u32) -> Self {
+        self.info_data.push(InfoVti::IKey(key));
+        self
+    }
+
+    /// Sets the mark of sent packets
+    /// This is equivalent to `ip link add name NAME type vti okey KEY`.
+    pub fn okey(mut self, key: u32) -> Self {
+        self.info_data.push(InfoVti::OKey(key));
+        self
+    }
+
+    /// Adds the `fwmark` attribute to the tunnel
+    pub fn fwmark(mut self, fwmark: u32) -> Self {
+        self.info_data.push(InfoVti::FwMark(fwmark));
+        self
+    }
+}
+
+/// A request to create a new geneve link.
+/// This is equivalent to `ip link add NAME type geneve id VNI ...` commands.
+/// It provides methods to customize the creation of the geneve interface.
+pub struct GeneveAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoGeneve>,
+}
+
+impl GeneveAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        self.into_link_add_request().execute().await
+    }
+
+    pub(crate) fn into_link_add_request(self) -> LinkAddRequest {
+        self.request
+            .link_info(InfoKind::Geneve, Some(InfoData::Geneve(self.info_data)))
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Adds the `remote` attribute to the geneve link
+    /// This is equivalent to `ip link add name NAME type geneve id VNI remote IPADDR`.
+    /// This function takes an IPv4 address.
+    pub fn remote(mut self, addr: Ipv4Addr) -> Self {
+        self.info_data.push(InfoGeneve::Remote(addr));
+        self
+    }
+
+    /// Adds the `remote` attribute to the geneve link
+    /// This is equivalent to `ip link add name NAME type geneve id VNI remote IPADDR`.
+    /// This function takes an IPv6 address.
+    pub fn remote6(mut self, addr: Ipv6Addr) -> Self {
+        self.info_data.push(InfoGeneve::Remote6(addr));
+        self
+    }
+
+    /// Adds the `ttl` attribute to the geneve link
+    /// This is equivalent to `ip link add name NAME type geneve id VNI ttl TTL`.
+    pub fn ttl(mut self, ttl: u8) -> Self {
+        self.info_data.push(InfoGeneve::Ttl(ttl));
+        self
+    }
+
+    /// Inherits the TTL of the inner packet
+    /// This is equivalent to `ip link add name NAME type geneve id VNI ttl inherit`.
+    pub fn ttl_inherit(mut self) -> Self {
+        self.info_data.push(InfoGeneve::TtlInherit(1));
+        self
+    }
+
+    /// Adds the `tos` attribute to the geneve link
+    /// This is equivalent to `ip link add name NAME type geneve id VNI tos TOS`.
+    pub fn tos(mut self, tos: u8) -> Self {
+        self.info_data.push(InfoGeneve::Tos(tos));
+        self
+    }
+
+    /// Adds the `df` attribute to the geneve link
+    /// This is equivalent to `ip link add name NAME type geneve id VNI df DF`.
+    /// DF is one of the `GENEVE_DF_*` constants.
+    pub fn df(mut self, df: u8) -> Self {
+        self.info_data.push(InfoGeneve::Df(df));
+        self
+    }
+
+    /// Adds the `flowlabel` attribute to the geneve link
+    /// This is equivalent to `ip link add name NAME type geneve id VNI flowlabel LABEL`.
+    pub fn label(mut self, label: u32) -> Self {
+        self.info_data.push(InfoGeneve::Label(label));
+        self
+    }
+
+    /// Adds the `dstport` attribute to the geneve link
+    /// This is equivalent to `ip link add name NAME type geneve id VNI dstport PORT`.
+    pub fn port(mut self, port: u16) -> Self {
+        self.info_data.push(InfoGeneve::Port(port));
+        self
+    }
+
+    /// Adds the `udpcsum` attribute to the geneve link
+    /// This is equivalent to `ip link add name NAME type geneve id VNI [no]udpcsum`.
+    pub fn udp_csum(mut self, udp_csum: u8) -> Self {
+        self.info_data.push(InfoGeneve::UDPCsum(udp_csum));
+        self
+    }
+
+    /// Adds the `udp6zerocsumtx` attribute to the geneve link
+    /// This is equivalent to `ip link add name NAME type geneve id VNI [no]udp6zerocsumtx`.
+    pub fn udp_zero_csum6_tx(mut self, value: u8) -> Self {
+        self.info_data.push(InfoGeneve::UDPZeroCsumTX(value));
+        self
+    }
+
+    /// Adds the `udp6zerocsumrx` attribute to the geneve link
+    /// This is equivalent to `ip link add name NAME type geneve id VNI [no]udp6zerocsumrx`.
+    pub fn udp_zero_csum6_rx(mut self, value: u8) -> Self {
+        self.info_data.push(InfoGeneve::UDPZeroCsumRX(value));
+        self
+    }
+
+    /// Creates a metadata based geneve link
+    /// This is equivalent to `ip link add name NAME type geneve external`.
+    pub fn collect_metadata(mut self) -> Self {
+        self.info_data.push(InfoGeneve::CollectMetadata);
+        self
+    }
+}
+
+/// A request to create a new MACsec link.
+/// This is equivalent to `ip link add link LINK NAME type macsec ...` commands.
+/// It provides methods to customize the creation of the MACsec interface.
+/// The secure channels and associations are managed with the generic netlink
+/// `macsec` family (see the `netlink-packet-macsec` crate).
+pub struct MacsecAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoMacsec>,
+}
+
+impl MacsecAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let s = self
+            .request
+            .link_info(InfoKind::Macsec, Some(InfoData::Macsec(self.info_data)));
+        s.execute().await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Adds the `sci` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec sci SCI`.
+    pub fn sci(mut self, sci: u64) -> Self {
+        self.info_data.push(InfoMacsec::Sci(sci));
+        self
+    }
+
+    /// Adds the `port` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec port PORT`.
+    pub fn port(mut self, port: u16) -> Self {
+        self.info_data.push(InfoMacsec::Port(port));
+        self
+    }
+
+    /// Adds the `cipher` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec cipher CIPHER`.
+    /// CIPHER is one of the `MACSEC_CIPHER_ID_*` constants.
+    pub fn cipher_suite(mut self, cipher_suite: u64) -> Self {
+        self.info_data.push(InfoMacsec::CipherSuite(cipher_suite));
+        self
+    }
+
+    /// Adds the `icvlen` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec icvlen LEN`.
+    pub fn icv_len(mut self, icv_len: u8) -> Self {
+        self.info_data.push(InfoMacsec::IcvLen(icv_len));
+        self
+    }
+
+    /// Adds the `encrypt` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec encrypt { on | off }`.
+    pub fn encrypt(mut self, encrypt: u8) -> Self {
+        self.info_data.push(InfoMacsec::Encrypt(encrypt));
+        self
+    }
+
+    /// Adds the `protect` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec protect { on | off }`.
+    pub fn protect(mut self, protect: u8) -> Self {
+        self.info_data.push(InfoMacsec::Protect(protect));
+        self
+    }
+
+    /// Adds the `send_sci` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec send_sci { on | off }`.
+    pub fn send_sci(mut self, send_sci: u8) -> Self {
+        self.info_data.push(InfoMacsec::IncSci(send_sci));
+        self
+    }
+
+    /// Adds the `end_station` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec end_station { on | off }`.
+    pub fn end_station(mut self, end_station: u8) -> Self {
+        self.info_data.push(InfoMacsec::Es(end_station));
+        self
+    }
+
+    /// Adds the `scb` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec scb { on | off }`.
+    pub fn scb(mut self, scb: u8) -> Self {
+        self.info_data.push(InfoMacsec::Scb(scb));
+        self
+    }
+
+    /// Enables replay protection with the given window
+    /// This is equivalent to `ip link add link LINK NAME type macsec replay on window WINDOW`.
+    pub fn replay_protect(mut self, window: u32) -> Self {
+        self.info_data.push(InfoMacsec::ReplayProtect(1));
+        self.info_data.push(InfoMacsec::Window(window));
+        self
+    }
+
+    /// Adds the `validate` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec validate VALIDATE`.
+    /// VALIDATE is one of the `MACSEC_VALIDATE_*` constants.
+    pub fn validation(mut self, validation: u8) -> Self {
+        self.info_data.push(InfoMacsec::Validation(validation));
+        self
+    }
+
+    /// Adds the `encodingsa` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec encodingsa SA`.
+    pub fn encoding_sa(mut self, encoding_sa: u8) -> Self {
+        self.info_data.push(InfoMacsec::EncodingSa(encoding_sa));
+        self
+    }
+
+    /// Adds the `offload` attribute to the MACsec link
+    /// This is equivalent to `ip link add link LINK NAME type macsec offload OFFLOAD`.
+    /// OFFLOAD is one of the `MACSEC_OFFLOAD_*` constants.
+    pub fn offload(mut self, offload: u8) -> Self {
+        self.info_data.push(InfoMacsec::Offload(offload));
+        self
+    }
+}
+
+/// A request to create a new link. This is equivalent to the `ip link add` commands.
+///
+/// A few methods for common actions (creating a veth pair, creating a vlan interface, etc.) are
+/// provided, but custom requests can be made using the [`message_mut()`](#method.message_mut)
+/// accessor.
+pub struct LinkAddRequest {
+    handle: Handle,
+    message: LinkMessage,
+    replace: bool,
+}
+
+impl LinkAddRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        LinkAddRequest {
+            handle,
+            message: LinkMessage::default(),
+            replace: false,
+        }
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let LinkAddRequest {
+            mut handle,
+            message,
+            replace,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::NewLink(message));
+        let replace = if replace { NLM_F_REPLACE } else { NLM_F_EXCL };
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | replace | NLM_F_CREATE;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    ///
+    /// # Example
+    ///
+    /// Let's say we want to create a vlan interface on a link with id 6. By default, the
+    /// [`vlan()`](#method.vlan) method would create a request with the `IFF_UP` link set, so that the
+    /// interface is up after creation. If we want to create a interface tha tis down by default we
+    /// could do:
+    ///
+    /// ```rust,no_run
+    /// use futures::Future;
+    /// use rtnetlink::{Handle, new_connection, packet::IFF_UP};
+    ///
+    /// async fn run(handle: Handle) -> Result<(), String> {
+    ///     let vlan_id = 100;
+    ///     let link_id = 6;
+    ///     let mut request = handle.link().add().vlan("my-vlan-itf".into(), link_id, vlan_id);
+    ///     // unset the IFF_UP flag before sending the request
+    ///     request.message_mut().header.flags &= !IFF_UP;
+    ///     request.message_mut().header.change_mask &= !IFF_UP;
+    ///     // send the request
+    ///     request.execute().await.map_err(|e| format!("{}", e))
+    /// }
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Create a dummy link.
+    /// This is equivalent to `ip link add NAME type dummy`.
+    pub fn dummy(self, name: String) -> Self {
+        self.name(name).link_info(InfoKind::Dummy, None).up()
+    }
+
+    /// Create a veth pair.
+    /// This is equivalent to `ip link add NAME1 type veth peer name NAME2`.
+    pub fn veth(self, name: String, peer_name: String) -> Self {
+        // NOTE: `name` is the name of the peer in the netlink message (ie the link created via the
+        // VethInfo::Peer attribute, and `peer_name` is the name in the main netlink message.
+        // This is a bit weird, but it's all hidden from the user.
+
+        let mut peer = LinkMessage::default();
+        // FIXME: we get a -107 (ENOTCONN) (???) when trying to set `name` up.
+        // peer.header.flags = LinkFlags::from(IFF_UP);
+        // peer.header.change_mask = LinkFlags::from(IFF_UP);
+        peer.nlas.push(Nla::IfName(name));
+        self.veth_with_peer(peer_name, peer).up() // iproute2 does not set this one up
+    }
+
+    /// Create a veth pair, the peer being described by a `LinkMessage`.
+    /// This is equivalent to `ip link add NAME type veth peer PEER_NAME PEER_OPTIONS`, and allows
+    /// setting the attributes of the peer at creation, for instance its MTU (`Nla::Mtu`), its
+    /// address (`Nla::Address`) or its network namespace (`Nla::NetNsFd` or `Nla::NetNsPid`).
+    pub fn veth_with_peer(self, name: String, peer: LinkMessage) -> Self {
+        let link_info_data = InfoData::Veth(VethInfo::Peer(peer));
+        self.name(name)
+            .link_info(InfoKind::Veth, Some(link_info_data))
+    }
+
+    /// Create VLAN on a link.
+    /// This is equivalent to `ip link add link LINK name NAME type vlan id VLAN_ID`,
+    /// but instead of specifying a link name (`LINK`), we specify a link index.
+    pub fn vlan(self, name: String, index: u32, vlan_id: u16) -> Self {
+        self.name(name)
+            .link_info(
+                InfoKind::Vlan,
+                Some(InfoData::Vlan(vec![InfoVlan::Id(vlan_id)])),
+            )
+            .append_nla(Nla::Link(index))
+            .up()
+    }
+
+    /// Create macvlan on a link.
+    /// This is equivalent to `ip link add name NAME link LINK type macvlan mode MACVLAN_MODE`,
+    ///   but instead of specifying a link name (`LINK`), we specify a link index.
+    /// The MACVLAN_MODE is an integer consisting of flags from MACVLAN_MODE (netlink-packet-route/src/rtnl/constants.rs)
+    ///   being: _PRIVATE, _VEPA, _BRIDGE, _PASSTHRU, _SOURCE, which can be *combined*.
+    pub fn macvlan(self, name: String, index: u32, mode: u32) -> Self {
+        self.name(name)
+            .link_info(
+                InfoKind::MacVlan,
+                Some(InfoData::MacVlan(vec![InfoMacVlan::Mode(mode)])),
+            )
+            .append_nla(Nla::Link(index))
+            .up()
+    }
+
+    /// Create macvtap on a link.
+    /// This is equivalent to `ip link add name NAME link LINK type macvtap mode MACVTAP_MODE`,
+    ///   but instead of specifying a link name (`LINK`), we specify a link index.
+    /// The MACVTAP_MODE is built from the same MACVLAN_MODE flags as for [`macvlan()`](Self::macvlan).
+    pub fn macvtap(self, name: String, index: u32, mode: u32) -> Self {
+        self.name(name)
+            .link_info(
+                InfoKind::MacVtap,
+                Some(InfoData::MacVtap(vec![InfoMacVtap::Mode(mode)])),
+            )
+            .append_nla(Nla::Link(index))
+            .up()
+    }
+
+    /// Create ipvlan on a link.
+    /// This is equivalent to `ip link add name NAME link LINK type ipvlan mode IPVLAN_MODE
+    /// IPVLAN_FLAGS`, but instead of specifying a link name (`LINK`), we specify a link index.
+    /// The IPVLAN_MODE is one of the IPVLAN_MODE_* constants (_L2, _L3, _L3S), and
+    /// IPVLAN_FLAGS a combination of the IPVLAN_F_* flags (_PRIVATE, _VEPA), or 0.
+    pub fn ipvlan(self, name: String, index: u32, mode: u16, flags: u16) -> Self {
+        self.name(name)
+            .link_info(
+                InfoKind::IpVlan,
+                Some(InfoData::IpVlan(vec![
+                    InfoIpVlan::Mode(mode),
+                    InfoIpVlan::Flags(flags),
+                ])),
+            )
+            .append_nla(Nla::Link(index))
+            .up()
+    }
+
+    /// Create a VRF device.
+    /// This is equivalent to `ip link add NAME type vrf table TABLE_ID`.
+    pub fn vrf(self, name: String, table_id: u32) -> Self {
+        self.name(name)
+            .link_info(
+                InfoKind::Vrf,
+                Some(InfoData::Vrf(vec![InfoVrf::TableId(table_id)])),
+            )
+            .up()
+    }
+
+    /// Create an intermediate functional block device.
+    /// This is equivalent to `ip link add NAME type ifb`.
+    pub fn ifb(self, name: String) -> Self {
+        self.name(name).link_info(InfoKind::Ifb, None).up()
+    }
+
+    /// Create a netlink monitor device.
+    /// This is equivalent to `ip link add NAME type nlmon`.
+    pub fn nlmon(self, name: String) -> Self {
+        self.name(name).link_info(InfoKind::Nlmon, None).up()
+    }
+
+    /// Create a wireguard device.
+    /// This is equivalent to `ip link add NAME type wireguard`. The keys and peers are configured
+    /// with the generic netlink `wireguard` family (see the `netlink-packet-wireguard` crate).
+    pub fn wireguard(self, name: String) -> Self {
+        self.name(name).link_info(InfoKind::Wireguard, None).up()
+    }
+
+    /// Create a VxLAN
+    /// This is equivalent to `ip link add name NAME type vxlan id VNI`,
+    /// it returns a VxlanAddRequest to further customize the vxlan
+    /// interface creation.
+    pub fn vxlan(self, name: String, vni: u32) -> VxlanAddRequest {
+        let s = self.name(name);
+        VxlanAddRequest {
+            request: s,
+            info_data: vec![InfoVxlan::Id(vni)],
+        }
+    }
+
+    /// Create a bond.
+    /// This is equivalent to `ip link add name NAME type bond`,
+    /// it returns a BondAddRequest to further customize the bond
+    /// interface creation.
+    pub fn bond(self, name: String) -> BondAddRequest {
+        let s = self.name(name);
+        BondAddRequest {
+            request: s,
+            info_data: vec![],
+        }
+    }
+
+    /// Create a GRE tunnel.
+    /// This is equivalent to `ip link add name NAME type gre`,
+    /// it returns a GreAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn gre(self, name: String) -> GreAddRequest {
+        self.gre_kind(name, InfoKind::GreTun)
+    }
+
+    /// Create a GRE tunnel carrying ethernet frames.
+    /// This is equivalent to `ip link add name NAME type gretap`,
+    /// it returns a GreAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn gretap(self, name: String) -> GreAddRequest {
+        self.gre_kind(name, InfoKind::GreTap)
+    }
+
+    /// Create a GRE over IPv6 tunnel.
+    /// This is equivalent to `ip link add name NAME type ip6gre`,
+    /// it returns a GreAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn ip6gre(self, name: String) -> GreAddRequest {
+        self.gre_kind(name, InfoKind::GreTun6)
+    }
+
+    /// Create a GRE over IPv6 tunnel carrying ethernet frames.
+    /// This is equivalent to `ip link add name NAME type ip6gretap`,
+    /// it returns a GreAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn ip6gretap(self, name: String) -> GreAddRequest {
+        self.gre_kind(name, InfoKind::GreTap6)
+    }
+
+    /// Create an ERSPAN tunnel.
+    /// This is equivalent to `ip link add name NAME type erspan`,
+    /// it returns a GreAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn erspan(self, name: String) -> GreAddRequest {
+        self.gre_kind(name, InfoKind::Erspan)
+    }
+
+    /// Create an IPv4 over IPv4 tunnel.
+    /// This is equivalent to `ip link add name NAME type ipip`,
+    /// it returns a IpTunAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn ipip(self, name: String) -> IpTunAddRequest {
+        self.ip_tun_kind(name, InfoKind::IpTun)
+    }
+
+    /// Create an IPv6 over IPv4 tunnel.
+    /// This is equivalent to `ip link add name NAME type sit`,
+    /// it returns a IpTunAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn sit(self, name: String) -> IpTunAddRequest {
+        self.ip_tun_kind(name, InfoKind::SitTun)
+    }
+
+    /// Create an IPv4 or IPv6 over IPv6 tunnel.
+    /// This is equivalent to `ip link add name NAME type ip6tnl`,
+    /// it returns a IpTunAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn ip6tnl(self, name: String) -> IpTunAddRequest {
+        self.ip_tun_kind(name, InfoKind::Ip6Tnl)
+    }
+
+    /// Create a VTI tunnel.
+    /// This is equivalent to `ip link add name NAME type vti`,
+    /// it returns a VtiAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn vti(self, name: String) -> VtiAddRequest {
+        let s = self.name(name);
+        VtiAddRequest {
+            request: s,
+            info_data: vec![],
+        }
+    }
+
+    /// Create a geneve link.
+    /// This is equivalent to `ip link add name NAME type geneve id VNI`,
+    /// it returns a GeneveAddRequest to further customize the geneve
+    /// interface creation.
+    pub fn geneve(self, name: String, vni: u32) -> GeneveAddRequest {
+        let s = self.name(name);
+        GeneveAddRequest {
+            request: s,
+            info_data: vec![InfoGeneve::Id(vni)],
+        }
+    }
+
+    /// Create a MACsec link on top of a link.
+    /// This is equivalent to `ip link add link LINK name NAME type macsec`,
+    /// but instead of specifying a link name (`LINK`), we specify a link index.
+    /// It returns a MacsecAddRequest to further customize the MACsec
+    /// interface creation.
+    pub fn macsec(self, name: String, index: u32) -> MacsecAddRequest {
+        let s = self.name(name).append_nla(Nla::Link(index));
+        MacsecAddRequest {
+            request: s,
+            info_data: vec![],
+        }
+    }
+
+    /// Create a new bridge.
+    /// This is equivalent to `ip link add link NAME type bridge`.
+    pub fn bridge(self, name: String) -> Self {
+        self.name(name.clone())
+            .link_info(InfoKind::Bridge, None)
+            .append_nla(Nla::IfName(name))
+    }
+
+    /// Replace existing matching link.
+    pub fn replace(self) -> Self {
+        Self {
+            replace: true,
+            ..self
+        }
+    }
+
+    /// Set the mtu of the new link (equivalent to `ip link add NAME mtu MTU ...`)
+    pub fn mtu(self, mtu: u32) -> Self {
+        self.append_nla(Nla::Mtu(mtu))
+    }
+
+    /// Set the hardware address of the new link (equivalent to `ip link add NAME address ADDRESS
+    /// ...`)
+    pub fn address(self, address: Vec<u8>) -> Self {
+        self.append_nla(Nla::Address(address))
+    }
+
+    /// Enslave the new link to the link with the given index, for instance a bridge, a bond or a
+    /// VRF device (equivalent to `ip link add NAME master MASTER ...`)
+    pub fn master(self, master_index: u32) -> Self {
+        self.append_nla(Nla::Master(master_index))
+    }
+
+    /// Create the link in the network namespace of the process with the given `pid` (equivalent
+    /// to `ip link add NAME netns PID ...`)
+    pub fn setns_by_pid(self, pid: u32) -> Self {
+        self.append_nla(Nla::NetNsPid(pid))
+    }
+
+    /// Create the link in the network namespace corresponding to the given file descriptor
+    /// (equivalent to `ip link add NAME netns NETNSNAME ...`)
+    pub fn setns_by_fd(self, fd: RawFd) -> Self {
+        self.append_nla(Nla::NetNsFd(fd))
+    }
+
+    fn gre_kind(self, name: String, kind: InfoKind) -> GreAddRequest {
+        GreAddRequest {
+            request: self.name(name),
+            kind,
+            iflags: 0,
+            oflags: 0,
+            info_data: vec![],
+        }
+    }
+
+    fn ip_tun_kind(self, name: String, kind: InfoKind) -> IpTunAddRequest {
+        IpTunAddRequest {
+            request: self.name(name),
+            kind,
+            info_data: vec![],
         }
     }
 
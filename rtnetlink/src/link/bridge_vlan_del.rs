@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use super::set::af_spec_bridge;
+use crate::{
+    packet::{
+        nlas::link::{AfSpecBridge, BridgeVlanInfo},
+        LinkMessage,
+        NetlinkMessage,
+        RtnlMessage,
+        BRIDGE_FLAGS_SELF,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+/// A request to remove VLANs from a bridge port (equivalent to `bridge vlan del dev DEV vid
+/// VID`). The kernel expects a `RTM_DELLINK` message with the `AF_BRIDGE` family for this, which
+/// is why it is not part of [`LinkSetRequest`](crate::LinkSetRequest).
+pub struct LinkDelBridgeVlanRequest {
+    handle: Handle,
+    message: LinkMessage,
+}
+
+impl LinkDelBridgeVlanRequest {
+    pub(crate) fn new(handle: Handle, index: u32) -> Self {
+        let mut message = LinkMessage::default();
+        message.header.index = index;
+        af_spec_bridge(&mut message);
+        LinkDelBridgeVlanRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let LinkDelBridgeVlanRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::DelLink(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Remove the given VLAN. This can be called several times to remove several VLANs at once.
+    pub fn vid(mut self, vid: u16) -> Self {
+        af_spec_bridge(&mut self.message)
+            .push(AfSpecBridge::VlanInfo(BridgeVlanInfo { flags: 0, vid }));
+        self
+    }
+
+    /// Remove the VLANs from the link itself instead of its bridge master, which is needed to
+    /// change the VLANs of a bridge device (equivalent to the `self` option of `bridge vlan del`)
+    pub fn bridge_self(mut self) -> Self {
+        af_spec_bridge(&mut self.message).push(AfSpecBridge::Flags(BRIDGE_FLAGS_SELF));
+        self
+    }
+}
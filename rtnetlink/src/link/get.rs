@@ -41,6 +41,21 @@ impl LinkGetRequest {
         self
     }
 
+    /// Retrieve the VLANs of the bridges and bridge ports, as `IFLA_BRIDGE_VLAN_INFO`
+    /// attributes (equivalent to `bridge vlan show`). The kernel only returns them when dumping
+    /// the links, so this should not be combined with `match_index()` or `match_name()`.
+    pub fn bridge_vlans(self) -> Self {
+        self.set_filter_mask(AF_BRIDGE as u8, RTEXT_FILTER_BRVLAN)
+    }
+
+    /// Retrieve the bridge ports along with their `IFLA_BRPORT_*` attributes (equivalent to
+    /// `bridge link show`). The kernel only returns them when dumping the links, so this should
+    /// not be combined with `match_index()` or `match_name()`.
+    pub fn bridge_ports(mut self) -> Self {
+        self.message.header.interface_family = AF_BRIDGE as u8;
+        self
+    }
+
     /// Execute the request
     pub fn execute(self) -> impl TryStream<Ok = LinkMessage, Error = Error> {
         let LinkGetRequest {
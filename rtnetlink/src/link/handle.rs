@@ -2,6 +2,7 @@
 
 use super::{
     LinkAddRequest,
+    LinkDelBridgeVlanRequest,
     LinkDelPropRequest,
     LinkDelRequest,
     LinkGetRequest,
@@ -37,6 +38,11 @@ impl LinkHandle {
         LinkDelRequest::new(self.0.clone(), index)
     }
 
+    /// Remove VLANs from a bridge port (equivalent to `bridge vlan del`)
+    pub fn bridge_vlan_del(&self, index: u32) -> LinkDelBridgeVlanRequest {
+        LinkDelBridgeVlanRequest::new(self.0.clone(), index)
+    }
+
     /// Retrieve the list of links (equivalent to `ip link show`)
     pub fn get(&mut self) -> LinkGetRequest {
         LinkGetRequest::new(self.0.clone())
@@ -21,5 +21,8 @@ pub use self::property_add::*;
 mod property_del;
 pub use self::property_del::*;
 
+mod bridge_vlan_del;
+pub use self::bridge_vlan_del::*;
+
 #[cfg(test)]
 mod test;
@@ -2,10 +2,12 @@
 
 use crate::{
     packet::{
-        nlas::link::Nla,
+        nlas::link::{AfSpecBridge, BridgeVlanInfo, InfoBridgePort, Nla},
         LinkMessage,
         NetlinkMessage,
         RtnlMessage,
+        AF_BRIDGE,
+        BRIDGE_FLAGS_SELF,
         IFF_NOARP,
         IFF_PROMISC,
         IFF_UP,
@@ -137,4 +139,102 @@ impl LinkSetRequest {
         self.message.nlas.push(Nla::NetNsFd(fd));
         self
     }
+
+    /// Add a VLAN to the bridge port (equivalent to `bridge vlan add dev DEV vid VID`). `flags`
+    /// is a combination of the `BRIDGE_VLAN_INFO_*` constants, for instance
+    /// `BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED` for `bridge vlan add dev DEV vid VID
+    /// pvid untagged`.
+    ///
+    /// Bridge requests are sent with the `AF_BRIDGE` family, so they cannot be combined with
+    /// changes of the generic link attributes. VLANs are removed with
+    /// [`LinkHandle::bridge_vlan_del()`](crate::LinkHandle::bridge_vlan_del).
+    pub fn bridge_vlan_add(mut self, vid: u16, flags: u16) -> Self {
+        af_spec_bridge(&mut self.message)
+            .push(AfSpecBridge::VlanInfo(BridgeVlanInfo { flags, vid }));
+        self
+    }
+
+    /// Apply the bridge request to the link itself instead of its bridge master, which is needed
+    /// to change the VLANs of a bridge device (equivalent to the `self` option of the `bridge`
+    /// commands)
+    pub fn bridge_self(mut self) -> Self {
+        af_spec_bridge(&mut self.message).push(AfSpecBridge::Flags(BRIDGE_FLAGS_SELF));
+        self
+    }
+
+    /// Set an attribute of the bridge port (equivalent to `bridge link set dev DEV ...`)
+    pub fn bridge_port(mut self, nla: InfoBridgePort) -> Self {
+        self.message.header.interface_family = AF_BRIDGE as u8;
+        let nlas = &mut self.message.nlas;
+        match nlas.iter_mut().find_map(|nla| match nla {
+            Nla::ProtoInfoBridge(nlas) => Some(nlas),
+            _ => None,
+        }) {
+            Some(port_nlas) => port_nlas.push(nla),
+            None => nlas.push(Nla::ProtoInfoBridge(vec![nla])),
+        }
+        self
+    }
+
+    /// Set the STP state of the bridge port, as one of the `BR_STATE_*` constants (equivalent to
+    /// `bridge link set dev DEV state STATE`)
+    pub fn bridge_port_state(self, state: u8) -> Self {
+        self.bridge_port(InfoBridgePort::State(state))
+    }
+
+    /// Set the STP cost of the bridge port (equivalent to `bridge link set dev DEV cost COST`)
+    pub fn bridge_port_cost(self, cost: u32) -> Self {
+        self.bridge_port(InfoBridgePort::Cost(cost))
+    }
+
+    /// Set the STP priority of the bridge port (equivalent to `bridge link set dev DEV priority
+    /// PRIO`)
+    pub fn bridge_port_priority(self, priority: u16) -> Self {
+        self.bridge_port(InfoBridgePort::Priority(priority))
+    }
+
+    /// Enable or disable MAC address learning on the bridge port (equivalent to `bridge link set
+    /// dev DEV learning on/off`)
+    pub fn bridge_port_learning(self, enable: bool) -> Self {
+        self.bridge_port(InfoBridgePort::Learning(enable as u8))
+    }
+
+    /// Enable or disable flooding of unknown unicast traffic to the bridge port (equivalent to
+    /// `bridge link set dev DEV flood on/off`)
+    pub fn bridge_port_flood(self, enable: bool) -> Self {
+        self.bridge_port(InfoBridgePort::UnicastFlood(enable as u8))
+    }
+
+    /// Enable or disable hairpin mode on the bridge port (equivalent to `bridge link set dev DEV
+    /// hairpin on/off`)
+    pub fn bridge_port_hairpin(self, enable: bool) -> Self {
+        self.bridge_port(InfoBridgePort::HairpinMode(enable as u8))
+    }
+
+    /// Isolate the bridge port or not (equivalent to `bridge link set dev DEV isolated on/off`)
+    pub fn bridge_port_isolated(self, enable: bool) -> Self {
+        self.bridge_port(InfoBridgePort::Isolated(enable as u8))
+    }
+
+    /// Enable or disable ARP and ND suppression on the bridge port (equivalent to `bridge link
+    /// set dev DEV neigh_suppress on/off`)
+    pub fn bridge_port_neigh_suppress(self, enable: bool) -> Self {
+        self.bridge_port(InfoBridgePort::NeighSuppress(enable as u8))
+    }
+}
+
+/// Return the `IFLA_AF_SPEC` attributes of a bridge request, adding them to
+/// the message if needed
+pub(crate) fn af_spec_bridge(message: &mut LinkMessage) -> &mut Vec<AfSpecBridge> {
+    message.header.interface_family = AF_BRIDGE as u8;
+    let nlas = &mut message.nlas;
+    if !nlas.iter().any(|nla| matches!(nla, Nla::AfSpecBridge(_))) {
+        nlas.push(Nla::AfSpecBridge(vec![]));
+    }
+    nlas.iter_mut()
+        .find_map(|nla| match nla {
+            Nla::AfSpecBridge(nlas) => Some(nlas),
+            _ => None,
+        })
+        .unwrap()
 }
@@ -1,15 +1,46 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::{Ipv4Addr, Ipv6Addr};
+
 use futures::stream::TryStreamExt;
 use tokio::runtime::Runtime;
 
 use crate::{
     new_connection,
-    packet::rtnl::link::{
-        nlas::{Info, InfoKind, Nla},
-        LinkMessage,
+    packet::{
+        rtnl::link::{
+            nlas::{
+                AfSpecBridge,
+                BridgeVlanInfo,
+                Info,
+                InfoData,
+                InfoGeneve,
+                InfoGreTun,
+                InfoIpTun,
+                InfoIpVlan,
+                InfoKind,
+                InfoMacVtap,
+                InfoVrf,
+                InfoVti,
+                Nla,
+            },
+            LinkMessage,
+        },
+        traits::Emitable,
+        AF_BRIDGE,
+        BRIDGE_FLAGS_SELF,
+        GRE_CSUM,
+        GRE_KEY,
+        IFF_UP,
+        IP6_TNL_F_IGN_ENCAP_LIMIT,
+        IP6_TNL_F_RCV_DSCP_COPY,
+        IPVLAN_F_PRIVATE,
+        IPVLAN_MODE_L3,
+        MACVLAN_MODE_BRIDGE,
     },
+    test_handle,
     Error,
+    LinkAddRequest,
     LinkHandle,
 };
 
@@ -57,3 +88,371 @@ async fn _get_wg(handle: &mut LinkHandle) -> Result<LinkMessage, Error> {
 async fn _del_wg(handle: &mut LinkHandle, index: u32) -> Result<(), Error> {
     handle.del(index).execute().await
 }
+
+/// Return the message of a link creation request, checking that the link is created UP
+fn add_message(mut request: LinkAddRequest) -> LinkMessage {
+    let message = request.message_mut().clone();
+    assert_eq!(message.header.flags, IFF_UP);
+    assert_eq!(message.header.change_mask, IFF_UP);
+    message
+}
+
+#[test]
+fn add_macvtap_message() {
+    let message = add_message(test_handle().link().add().macvtap(
+        "macvtap0".into(),
+        2,
+        MACVLAN_MODE_BRIDGE,
+    ));
+    assert_eq!(
+        message.nlas,
+        vec![
+            Nla::IfName("macvtap0".into()),
+            Nla::Info(vec![
+                Info::Kind(InfoKind::MacVtap),
+                Info::Data(InfoData::MacVtap(vec![InfoMacVtap::Mode(
+                    MACVLAN_MODE_BRIDGE
+                )])),
+            ]),
+            Nla::Link(2),
+        ]
+    );
+}
+
+#[test]
+fn add_ipvlan_message() {
+    let message = add_message(test_handle().link().add().ipvlan(
+        "ipvlan0".into(),
+        2,
+        IPVLAN_MODE_L3,
+        IPVLAN_F_PRIVATE,
+    ));
+    assert_eq!(
+        message.nlas,
+        vec![
+            Nla::IfName("ipvlan0".into()),
+            Nla::Info(vec![
+                Info::Kind(InfoKind::IpVlan),
+                Info::Data(InfoData::IpVlan(vec![
+                    InfoIpVlan::Mode(IPVLAN_MODE_L3),
+                    InfoIpVlan::Flags(IPVLAN_F_PRIVATE),
+                ])),
+            ]),
+            Nla::Link(2),
+        ]
+    );
+}
+
+#[test]
+fn add_vrf_message() {
+    let message = add_message(test_handle().link().add().vrf("vrf0".into(), 10));
+    assert_eq!(
+        message.nlas,
+        vec![
+            Nla::IfName("vrf0".into()),
+            Nla::Info(vec![
+                Info::Kind(InfoKind::Vrf),
+                Info::Data(InfoData::Vrf(vec![InfoVrf::TableId(10)])),
+            ]),
+        ]
+    );
+}
+
+#[test]
+fn add_links_without_info_data_messages() {
+    let handle = test_handle().link();
+    for (request, name, kind) in [
+        (handle.add().ifb("ifb0".into()), "ifb0", InfoKind::Ifb),
+        (
+            handle.add().nlmon("nlmon0".into()),
+            "nlmon0",
+            InfoKind::Nlmon,
+        ),
+        (
+            handle.add().wireguard("wg0".into()),
+            "wg0",
+            InfoKind::Wireguard,
+        ),
+    ] {
+        let message = add_message(request);
+        assert_eq!(
+            message.nlas,
+            vec![Nla::IfName(name.into()), Nla::Info(vec![Info::Kind(kind)])]
+        );
+    }
+}
+
+#[test]
+fn add_link_attributes_message() {
+    let address = vec![0x02, 0x00, 0x00, 0x00, 0x00, 0x01];
+    let message = add_message(
+        test_handle()
+            .link()
+            .add()
+            .ifb("ifb0".into())
+            .mtu(1400)
+            .address(address.clone())
+            .master(5)
+            .setns_by_pid(1234)
+            .setns_by_fd(7),
+    );
+    assert_eq!(
+        message.nlas,
+        vec![
+            Nla::IfName("ifb0".into()),
+            Nla::Info(vec![Info::Kind(InfoKind::Ifb)]),
+            Nla::Mtu(1400),
+            Nla::Address(address),
+            Nla::Master(5),
+            Nla::NetNsPid(1234),
+            Nla::NetNsFd(7),
+        ]
+    );
+}
+
+/// Whether the emitted `message` contains the given attribute bytes
+fn emits(message: &LinkMessage, attribute: &[u8]) -> bool {
+    let mut buffer = vec![0; message.buffer_len()];
+    message.emit(&mut buffer);
+    buffer
+        .windows(attribute.len())
+        .any(|window| window == attribute)
+}
+
+#[test]
+fn add_gre_message() {
+    let message = add_message(
+        test_handle()
+            .link()
+            .add()
+            .gre("gre0".into())
+            .local(Ipv4Addr::new(192, 0, 2, 1).into())
+            .remote(Ipv4Addr::new(192, 0, 2, 2).into())
+            .ikey(0x0102_0304)
+            .okey(0x0506_0708)
+            .csum()
+            .ttl(64)
+            .up()
+            .into_link_add_request(),
+    );
+    assert_eq!(
+        message.nlas,
+        vec![
+            Nla::IfName("gre0".into()),
+            Nla::Info(vec![
+                Info::Kind(InfoKind::GreTun),
+                Info::Data(InfoData::GreTun(vec![
+                    InfoGreTun::Local(Ipv4Addr::new(192, 0, 2, 1).into()),
+                    InfoGreTun::Remote(Ipv4Addr::new(192, 0, 2, 2).into()),
+                    InfoGreTun::IKey(0x0102_0304),
+                    InfoGreTun::OKey(0x0506_0708),
+                    InfoGreTun::Ttl(64),
+                    InfoGreTun::IFlags(GRE_KEY | GRE_CSUM),
+                    InfoGreTun::OFlags(GRE_KEY | GRE_CSUM),
+                ])),
+            ]),
+        ]
+    );
+    // The keys and the GRE flags are in network byte order
+    assert!(emits(&message, &[8, 0, 4, 0, 0x01, 0x02, 0x03, 0x04]));
+    assert!(emits(&message, &[8, 0, 5, 0, 0x05, 0x06, 0x07, 0x08]));
+    assert!(emits(&message, &[6, 0, 2, 0, 0xa0, 0x00]));
+    assert!(emits(&message, &[6, 0, 3, 0, 0xa0, 0x00]));
+}
+
+#[test]
+fn add_ip6gre_message() {
+    let local = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+    let remote = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 2);
+    let message = add_message(
+        test_handle()
+            .link()
+            .add()
+            .ip6gre("ip6gre0".into())
+            .local(local.into())
+            .remote(remote.into())
+            .key(42)
+            .encap_limit(4)
+            .flags(IP6_TNL_F_RCV_DSCP_COPY)
+            .up()
+            .into_link_add_request(),
+    );
+    assert_eq!(
+        message.nlas,
+        vec![
+            Nla::IfName("ip6gre0".into()),
+            Nla::Info(vec![
+                Info::Kind(InfoKind::GreTun6),
+                Info::Data(InfoData::GreTun6(vec![
+                    InfoGreTun::Local(local.into()),
+                    InfoGreTun::Remote(remote.into()),
+                    InfoGreTun::IKey(42),
+                    InfoGreTun::OKey(42),
+                    InfoGreTun::EncapLimit(4),
+                    InfoGreTun::Flags(IP6_TNL_F_RCV_DSCP_COPY),
+                    InfoGreTun::IFlags(GRE_KEY),
+                    InfoGreTun::OFlags(GRE_KEY),
+                ])),
+            ]),
+        ]
+    );
+    assert!(emits(&message, &[8, 0, 4, 0, 0, 0, 0, 42]));
+    assert!(emits(&message, &[6, 0, 2, 0, 0x20, 0x00]));
+    // Unlike the GRE flags, the ip6gre tunnel flags are in host byte order
+    let mut flags = vec![8, 0, 13, 0];
+    flags.extend_from_slice(&IP6_TNL_F_RCV_DSCP_COPY.to_ne_bytes());
+    assert!(emits(&message, &flags));
+}
+
+#[test]
+fn add_ipip_message() {
+    let message = add_message(
+        test_handle()
+            .link()
+            .add()
+            .ipip("ipip0".into())
+            .local(Ipv4Addr::new(192, 0, 2, 1).into())
+            .remote(Ipv4Addr::new(192, 0, 2, 2).into())
+            .ttl(64)
+            .pmtudisc(1)
+            .up()
+            .into_link_add_request(),
+    );
+    assert_eq!(
+        message.nlas,
+        vec![
+            Nla::IfName("ipip0".into()),
+            Nla::Info(vec![
+                Info::Kind(InfoKind::IpTun),
+                Info::Data(InfoData::IpTun(vec![
+                    InfoIpTun::Local(Ipv4Addr::new(192, 0, 2, 1).into()),
+                    InfoIpTun::Remote(Ipv4Addr::new(192, 0, 2, 2).into()),
+                    InfoIpTun::Ttl(64),
+                    InfoIpTun::PMtuDisc(1),
+                ])),
+            ]),
+        ]
+    );
+}
+
+#[test]
+fn add_ip6tnl_message() {
+    let local = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+    let remote = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 2);
+    let flags = IP6_TNL_F_IGN_ENCAP_LIMIT | IP6_TNL_F_RCV_DSCP_COPY;
+    let message = add_message(
+        test_handle()
+            .link()
+            .add()
+            .ip6tnl("ip6tnl0".into())
+            .local(local.into())
+            .remote(remote.into())
+            .ip6tnl_flags(flags)
+            .up()
+            .into_link_add_request(),
+    );
+    assert_eq!(
+        message.nlas,
+        vec![
+            Nla::IfName("ip6tnl0".into()),
+            Nla::Info(vec![
+                Info::Kind(InfoKind::Ip6Tnl),
+                Info::Data(InfoData::Ip6Tnl(vec![
+                    InfoIpTun::Local(local.into()),
+                    InfoIpTun::Remote(remote.into()),
+                    InfoIpTun::Ip6TnlFlags(flags),
+                ])),
+            ]),
+        ]
+    );
+    // ip6tnl tunnels have 32 bits flags, in host byte order
+    let mut attribute = vec![8, 0, 8, 0];
+    attribute.extend_from_slice(&flags.to_ne_bytes());
+    assert!(emits(&message, &attribute));
+}
+
+#[test]
+fn add_vti_message() {
+    let message = add_message(
+        test_handle()
+            .link()
+            .add()
+            .vti("vti0".into())
+            .local(Ipv4Addr::new(192, 0, 2, 1))
+            .remote(Ipv4Addr::new(192, 0, 2, 2))
+            .ikey(0x0102_0304)
+            .okey(0x0506_0708)
+            .up()
+            .into_link_add_request(),
+    );
+    assert_eq!(
+        message.nlas,
+        vec![
+            Nla::IfName("vti0".into()),
+            Nla::Info(vec![
+                Info::Kind(InfoKind::Vti),
+                Info::Data(InfoData::Vti(vec![
+                    InfoVti::Local(Ipv4Addr::new(192, 0, 2, 1).into()),
+                    InfoVti::Remote(Ipv4Addr::new(192, 0, 2, 2).into()),
+                    InfoVti::IKey(0x0102_0304),
+                    InfoVti::OKey(0x0506_0708),
+                ])),
+            ]),
+        ]
+    );
+    assert!(emits(&message, &[8, 0, 2, 0, 0x01, 0x02, 0x03, 0x04]));
+    assert!(emits(&message, &[8, 0, 3, 0, 0x05, 0x06, 0x07, 0x08]));
+}
+
+#[test]
+fn add_geneve_message() {
+    let message = add_message(
+        test_handle()
+            .link()
+            .add()
+            .geneve("gnv0".into(), 42)
+            .remote(Ipv4Addr::new(192, 0, 2, 2))
+            .port(6081)
+            .ttl(64)
+            .up()
+            .into_link_add_request(),
+    );
+    assert_eq!(
+        message.nlas,
+        vec![
+            Nla::IfName("gnv0".into()),
+            Nla::Info(vec![
+                Info::Kind(InfoKind::Geneve),
+                Info::Data(InfoData::Geneve(vec![
+                    InfoGeneve::Id(42),
+                    InfoGeneve::Remote(Ipv4Addr::new(192, 0, 2, 2)),
+                    InfoGeneve::Port(6081),
+                    InfoGeneve::Ttl(64),
+                ])),
+            ]),
+        ]
+    );
+    // The UDP port is in network byte order
+    assert!(emits(&message, &[6, 0, 5, 0, 0x17, 0xc1]));
+}
+
+#[test]
+fn bridge_vlan_del_message() {
+    let mut request = test_handle()
+        .link()
+        .bridge_vlan_del(3)
+        .vid(10)
+        .vid(20)
+        .bridge_self();
+    let message = request.message_mut();
+    assert_eq!(message.header.interface_family, AF_BRIDGE as u8);
+    assert_eq!(message.header.index, 3);
+    assert_eq!(
+        message.nlas,
+        vec![Nla::AfSpecBridge(vec![
+            AfSpecBridge::VlanInfo(BridgeVlanInfo { flags: 0, vid: 10 }),
+            AfSpecBridge::VlanInfo(BridgeVlanInfo { flags: 0, vid: 20 }),
+            AfSpecBridge::Flags(BRIDGE_FLAGS_SELF),
+        ])]
+    );
+}
@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::mdb::{MdbEntry, MdbGroup, MdbSrcAttr, MdbSrcListEntry, Nla, SetEntryAttr},
+    MdbMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to add a bridge port to a multicast group. This is equivalent to the
+/// `bridge mdb add` commands.
+///
+/// Entries are temporary by default: the bridge removes them when they expire, unless
+/// [`permanent`](MdbAddRequest::permanent) is used.
+pub struct MdbAddRequest {
+    handle: Handle,
+    message: MdbMessage,
+    replace: bool,
+}
+
+impl MdbAddRequest {
+    pub(crate) fn new(handle: Handle, bridge_index: u32, port_index: u32, group: MdbGroup) -> Self {
+        let mut message = MdbMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = bridge_index;
+        message.nlas.push(Nla::SetEntry(MdbEntry {
+            ifindex: port_index,
+            state: MDB_TEMPORARY,
+            group,
+            ..Default::default()
+        }));
+        MdbAddRequest {
+            handle,
+            message,
+            replace: false,
+        }
+    }
+
+    /// Only add the entry to the given VLAN (equivalent to `vid VID`)
+    pub fn vid(mut self, vid: u16) -> Self {
+        self.entry_mut().vid = vid;
+        self
+    }
+
+    /// Make the entry permanent (equivalent to `permanent`)
+    pub fn permanent(mut self) -> Self {
+        self.entry_mut().state = MDB_PERMANENT;
+        self
+    }
+
+    /// Make the entry a (S, G) entry for the given source (equivalent to `src SOURCE`)
+    pub fn source(self, source: IpAddr) -> Self {
+        self.set_entry_attr(SetEntryAttr::Source(source))
+    }
+
+    /// Set the sources of a (*, G) entry (equivalent to `source_list SOURCES`).
+    ///
+    /// This needs IGMPv3 or MLDv2 to be enabled on the bridge.
+    pub fn source_list(self, sources: Vec<IpAddr>) -> Self {
+        let sources = sources
+            .into_iter()
+            .map(|addr| MdbSrcListEntry {
+                nlas: vec![MdbSrcAttr::Address(addr)],
+            })
+            .collect();
+        self.set_entry_attr(SetEntryAttr::SrcList(sources))
+    }
+
+    /// Set the filter mode of a (*, G) entry: `MCAST_INCLUDE` or `MCAST_EXCLUDE`
+    /// (equivalent to `filter_mode MODE`)
+    pub fn filter_mode(self, mode: u8) -> Self {
+        self.set_entry_attr(SetEntryAttr::GroupMode(mode))
+    }
+
+    /// Set the protocol that installed the entry, as one of the `RTPROT_*` constants
+    /// (equivalent to `proto PROTO`)
+    pub fn protocol(self, protocol: u8) -> Self {
+        self.set_entry_attr(SetEntryAttr::RtProt(protocol))
+    }
+
+    /// Replace the existing entry for the same group and port.
+    pub fn replace(self) -> Self {
+        Self {
+            replace: true,
+            ..self
+        }
+    }
+
+    fn entry_mut(&mut self) -> &mut MdbEntry {
+        self.message
+            .nlas
+            .iter_mut()
+            .find_map(|nla| match nla {
+                Nla::SetEntry(entry) => Some(entry),
+                _ => None,
+            })
+            .expect("MDB add request without MDBA_SET_ENTRY attribute")
+    }
+
+    fn set_entry_attr(mut self, attr: SetEntryAttr) -> Self {
+        let attrs = self.message.nlas.iter_mut().find_map(|nla| match nla {
+            Nla::SetEntryAttrs(attrs) => Some(attrs),
+            _ => None,
+        });
+        match attrs {
+            Some(attrs) => attrs.push(attr),
+            None => self.message.nlas.push(Nla::SetEntryAttrs(vec![attr])),
+        }
+        self
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let MdbAddRequest {
+            mut handle,
+            message,
+            replace,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::NewMdb(message));
+        let replace = if replace { NLM_F_REPLACE } else { NLM_F_EXCL };
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | replace | NLM_F_CREATE;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut MdbMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use crate::{
+    packet::{
+        constants::*,
+        nlas::mdb::{MdbEntry, MdbGroup, Nla, SetEntryAttr},
+        MdbMessage,
+        NetlinkMessage,
+        RtnlMessage,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct MdbDelRequest {
+    handle: Handle,
+    message: MdbMessage,
+}
+
+impl MdbDelRequest {
+    pub(crate) fn new(handle: Handle, bridge_index: u32, port_index: u32, group: MdbGroup) -> Self {
+        let mut message = MdbMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = bridge_index;
+        message.nlas.push(Nla::SetEntry(MdbEntry {
+            ifindex: port_index,
+            group,
+            ..Default::default()
+        }));
+        MdbDelRequest { handle, message }
+    }
+
+    /// Only remove the entry of the given VLAN (equivalent to `vid VID`)
+    pub fn vid(mut self, vid: u16) -> Self {
+        for nla in self.message.nlas.iter_mut() {
+            if let Nla::SetEntry(entry) = nla {
+                entry.vid = vid;
+            }
+        }
+        self
+    }
+
+    /// Remove the (S, G) entry for the given source (equivalent to `src SOURCE`)
+    pub fn source(mut self, source: IpAddr) -> Self {
+        self.message
+            .nlas
+            .push(Nla::SetEntryAttrs(vec![SetEntryAttr::Source(source)]));
+        self
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let MdbDelRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelMdb(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    pub fn message_mut(&mut self) -> &mut MdbMessage {
+        &mut self.message
+    }
+}
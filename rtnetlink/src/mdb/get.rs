@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{constants::*, MdbMessage, NetlinkMessage, RtnlMessage};
+
+use crate::{try_rtnl, Error, Handle};
+
+pub struct MdbGetRequest {
+    handle: Handle,
+    message: MdbMessage,
+}
+
+impl MdbGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let mut message = MdbMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        MdbGetRequest { handle, message }
+    }
+
+    pub fn message_mut(&mut self) -> &mut MdbMessage {
+        &mut self.message
+    }
+
+    /// Execute the request. The kernel sends one message per bridge.
+    pub fn execute(self) -> impl TryStream<Ok = MdbMessage, Error = Error> {
+        let MdbGetRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetMdb(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => {
+                Either::Left(response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewMdb))))
+            }
+            Err(e) => Either::Right(future::err::<MdbMessage, Error>(e).into_stream()),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_route::nlas::mdb::MdbGroup;
+
+use crate::{Handle, MdbAddRequest, MdbDelRequest, MdbGetRequest};
+
+pub struct MdbHandle(Handle);
+
+impl MdbHandle {
+    pub fn new(handle: Handle) -> Self {
+        MdbHandle(handle)
+    }
+
+    /// Retrieve the multicast groups and router ports of the bridges (equivalent to
+    /// `bridge mdb show`)
+    pub fn get(&self) -> MdbGetRequest {
+        MdbGetRequest::new(self.0.clone())
+    }
+
+    /// Add the bridge port `port_index` of the bridge `bridge_index` to a multicast
+    /// group (equivalent to `bridge mdb add dev BRIDGE port PORT grp GROUP`)
+    pub fn add(&self, bridge_index: u32, port_index: u32, group: MdbGroup) -> MdbAddRequest {
+        MdbAddRequest::new(self.0.clone(), bridge_index, port_index, group)
+    }
+
+    /// Remove the bridge port `port_index` of the bridge `bridge_index` from a
+    /// multicast group (equivalent to `bridge mdb del dev BRIDGE port PORT grp GROUP`)
+    pub fn del(&self, bridge_index: u32, port_index: u32, group: MdbGroup) -> MdbDelRequest {
+        MdbDelRequest::new(self.0.clone(), bridge_index, port_index, group)
+    }
+}
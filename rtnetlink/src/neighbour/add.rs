@@ -4,7 +4,7 @@ use futures::stream::StreamExt;
 
 use netlink_packet_route::{
     constants::*,
-    neighbour::{NeighbourMessage, Nla},
+    neighbour::{FdbExtAttr, NeighbourMessage, Nla},
     NetlinkPayload,
     RtnlMessage,
 };
@@ -120,6 +120,101 @@ impl NeighbourAddRequest {
         self
     }
 
+    /// Set the VLAN of a FDB entry (see `NDA_VLAN` for details).
+    pub fn vlan(mut self, vid: u16) -> Self {
+        self.message.nlas.push(Nla::Vlan(vid));
+        self
+    }
+
+    /// Set the VNI of a VXLAN FDB entry (see `NDA_VNI` for details).
+    pub fn vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(Nla::Vni(vni));
+        self
+    }
+
+    /// Set the source VNI of a VXLAN FDB entry, for VXLAN devices in collect metadata mode
+    /// (see `NDA_SRC_VNI` for details).
+    pub fn source_vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(Nla::SourceVni(vni));
+        self
+    }
+
+    /// Set the destination UDP port of a VXLAN FDB entry (see `NDA_PORT` for details).
+    pub fn port(mut self, port: u16) -> Self {
+        self.message.nlas.push(Nla::Port(port));
+        self
+    }
+
+    /// Set the interface used to reach the remote VTEP of a VXLAN FDB entry (see
+    /// `NDA_IFINDEX` for details).
+    pub fn output_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::IfIndex(index));
+        self
+    }
+
+    /// Use a nexthop object (or group) to reach the remote VTEPs of a VXLAN FDB entry (see
+    /// `NDA_NH_ID` for details).
+    pub fn nexthop_id(mut self, id: u32) -> Self {
+        self.message.nlas.push(Nla::NhId(id));
+        self
+    }
+
+    /// Set the protocol that installed the entry. It should be one of the `RTPROT_*`
+    /// constants (see `NDA_PROTOCOL` for details).
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.message.nlas.push(Nla::Protocol(protocol));
+        self
+    }
+
+    /// Enable activity notifications for a FDB entry. It should be a combination of
+    /// `FDB_NOTIFY_BIT` and `FDB_NOTIFY_INACTIVE_BIT`.
+    pub fn activity_notify(mut self, value: u8) -> Self {
+        let attrs = self.message.nlas.iter_mut().find_map(|nla| match nla {
+            Nla::FdbExtAttrs(attrs) => Some(attrs),
+            _ => None,
+        });
+        let attr = FdbExtAttr::ActivityNotify(value);
+        match attrs {
+            Some(attrs) => attrs.push(attr),
+            None => self.message.nlas.push(Nla::FdbExtAttrs(vec![attr])),
+        }
+        self
+    }
+
+    /// Add the FDB entry to the device itself, for instance a VXLAN device (equivalent to
+    /// `self`).
+    pub fn bridge_self(mut self) -> Self {
+        self.message.header.flags |= NTF_SELF;
+        self
+    }
+
+    /// Add the FDB entry to the bridge the device is a port of (equivalent to `master`).
+    /// This is what the kernel does when neither this nor
+    /// [`bridge_self`](NeighbourAddRequest::bridge_self) is used.
+    pub fn bridge_master(mut self) -> Self {
+        self.message.header.flags |= NTF_MASTER;
+        self
+    }
+
+    /// Mark the entry as learned by an external control plane, such as an EVPN agent (see
+    /// `NTF_EXT_LEARNED` for details).
+    pub fn extern_learn(mut self) -> Self {
+        self.message.header.flags |= NTF_EXT_LEARNED;
+        self
+    }
+
+    /// Prevent the FDB entry from moving to another port (see `NTF_STICKY` for details).
+    pub fn sticky(mut self) -> Self {
+        self.message.header.flags |= NTF_STICKY;
+        self
+    }
+
+    /// Mark the neighbour as a router (see `NTF_ROUTER` for details).
+    pub fn router(mut self) -> Self {
+        self.message.header.flags |= NTF_ROUTER;
+        self
+    }
+
     /// Replace existing matching neighbor.
     pub fn replace(self) -> Self {
         Self {
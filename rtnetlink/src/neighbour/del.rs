@@ -4,7 +4,7 @@ use futures::stream::StreamExt;
 
 use netlink_packet_route::{
     constants::*,
-    neighbour::NeighbourMessage,
+    neighbour::{NeighbourMessage, Nla},
     NetlinkPayload,
     RtnlMessage,
 };
@@ -12,6 +12,7 @@ use netlink_packet_route::{
 use netlink_proto::packet::NetlinkMessage;
 
 use crate::{Error, Handle};
+use std::net::IpAddr;
 
 pub struct NeighbourDelRequest {
     handle: Handle,
@@ -23,6 +24,51 @@ impl NeighbourDelRequest {
         NeighbourDelRequest { handle, message }
     }
 
+    pub(crate) fn new_bridge(handle: Handle, index: u32, lla: &[u8]) -> Self {
+        let mut message = NeighbourMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = index;
+        message.nlas.push(Nla::LinkLocalAddress(lla.to_vec()));
+        NeighbourDelRequest { handle, message }
+    }
+
+    /// Only delete the FDB entry of the given VLAN (see `NDA_VLAN` for details).
+    pub fn vlan(mut self, vid: u16) -> Self {
+        self.message.nlas.push(Nla::Vlan(vid));
+        self
+    }
+
+    /// Only delete the VXLAN FDB entry pointing to the given remote VTEP (see `NDA_DST` for
+    /// details).
+    pub fn destination(mut self, addr: IpAddr) -> Self {
+        let addr = match addr {
+            IpAddr::V4(v4) => v4.octets().to_vec(),
+            IpAddr::V6(v6) => v6.octets().to_vec(),
+        };
+        self.message.nlas.push(Nla::Destination(addr));
+        self
+    }
+
+    /// Only delete the VXLAN FDB entry with the given VNI (see `NDA_VNI` for details).
+    pub fn vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(Nla::Vni(vni));
+        self
+    }
+
+    /// Delete the FDB entry from the device itself, for instance a VXLAN device (equivalent
+    /// to `self`).
+    pub fn bridge_self(mut self) -> Self {
+        self.message.header.flags |= NTF_SELF;
+        self
+    }
+
+    /// Delete the FDB entry from the bridge the device is a port of (equivalent to
+    /// `master`).
+    pub fn bridge_master(mut self) -> Self {
+        self.message.header.flags |= NTF_MASTER;
+        self
+    }
+
     /// Execute the request
     pub async fn execute(self) -> Result<(), Error> {
         let NeighbourDelRequest {
@@ -8,7 +8,7 @@ use futures::{
 
 use netlink_packet_route::{
     constants::*,
-    neighbour::NeighbourMessage,
+    neighbour::{NeighbourMessage, Nla},
     NetlinkPayload,
     RtnlMessage,
 };
@@ -28,12 +28,38 @@ impl NeighbourGetRequest {
         NeighbourGetRequest { handle, message }
     }
 
+    pub(crate) fn new_bridge(handle: Handle) -> Self {
+        let mut message = NeighbourMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        NeighbourGetRequest { handle, message }
+    }
+
     /// List neighbor proxies in the system (equivalent to: `ip neighbor show proxy`).
     pub fn proxies(mut self) -> Self {
         self.message.header.flags |= NTF_PROXY;
         self
     }
 
+    /// Only retrieve the entries whose device is enslaved to the given master device, for
+    /// instance the FDB entries of a bridge (equivalent to `bridge fdb show br BRIDGE`).
+    ///
+    /// For FDB entries, the kernel only applies this filter when strict checking is enabled
+    /// on the netlink socket (see `Socket::set_strict_checking`).
+    pub fn match_master(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Master(index));
+        self
+    }
+
+    /// Only retrieve the FDB entries of the given bridge port (equivalent to
+    /// `bridge fdb show brport PORT`).
+    ///
+    /// The kernel only applies this filter when strict checking is enabled on the netlink
+    /// socket (see `Socket::set_strict_checking`).
+    pub fn match_port(mut self, index: u32) -> Self {
+        self.message.header.ifindex = index;
+        self
+    }
+
     pub fn set_family(mut self, ip_version: IpVersion) -> Self {
         self.message.header.family = ip_version.family();
         self
@@ -16,6 +16,11 @@ impl NeighbourHandle {
         NeighbourGetRequest::new(self.0.clone())
     }
 
+    /// List FDB entries (equivalent to `bridge fdb show`)
+    pub fn get_bridge(&self) -> NeighbourGetRequest {
+        NeighbourGetRequest::new_bridge(self.0.clone())
+    }
+
     /// Add a new neighbour entry (equivalent to `ip neighbour add`)
     pub fn add(&self, index: u32, destination: IpAddr) -> NeighbourAddRequest {
         NeighbourAddRequest::new(self.0.clone(), index, destination)
@@ -30,4 +35,9 @@ impl NeighbourHandle {
     pub fn del(&self, message: NeighbourMessage) -> NeighbourDelRequest {
         NeighbourDelRequest::new(self.0.clone(), message)
     }
+
+    /// Delete a fdb entry (equivalent to `bridge fdb del`)
+    pub fn del_bridge(&self, index: u32, lla: &[u8]) -> NeighbourDelRequest {
+        NeighbourDelRequest::new_bridge(self.0.clone(), index, lla)
+    }
 }
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{constants::*, NeighbourTableMessage, NetlinkMessage, RtnlMessage};
+
+use crate::{try_rtnl, Error, Handle, IpVersion};
+
+pub struct NeighbourTableGetRequest {
+    handle: Handle,
+    message: NeighbourTableMessage,
+}
+
+impl NeighbourTableGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let message = NeighbourTableMessage::default();
+        NeighbourTableGetRequest { handle, message }
+    }
+
+    /// Only retrieve the neighbour tables of the given IP version
+    pub fn set_family(mut self, ip_version: IpVersion) -> Self {
+        self.message.header.family = ip_version.family();
+        self
+    }
+
+    pub fn message_mut(&mut self) -> &mut NeighbourTableMessage {
+        &mut self.message
+    }
+
+    /// Execute the request. The kernel sends one message for the default parameters of each
+    /// table, followed by one message per interface.
+    pub fn execute(self) -> impl TryStream<Ok = NeighbourTableMessage, Error = Error> {
+        let NeighbourTableGetRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetNeighbourTable(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewNeighbourTable))),
+            ),
+            Err(e) => Either::Right(future::err::<NeighbourTableMessage, Error>(e).into_stream()),
+        }
+    }
+}
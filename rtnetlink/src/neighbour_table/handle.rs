@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Handle, NeighbourTableGetRequest, NeighbourTableSetRequest};
+
+pub struct NeighbourTableHandle(Handle);
+
+impl NeighbourTableHandle {
+    pub fn new(handle: Handle) -> Self {
+        NeighbourTableHandle(handle)
+    }
+
+    /// Retrieve the neighbour tables, with their default and per-interface parameters
+    /// (equivalent to `ip ntable show`)
+    pub fn get(&self) -> NeighbourTableGetRequest {
+        NeighbourTableGetRequest::new(self.0.clone())
+    }
+
+    /// Change the parameters of the neighbour table with the given name, for instance
+    /// `arp_cache` or `ndisc_cache` (equivalent to `ip ntable change name NAME`)
+    pub fn set(&self, name: &str) -> NeighbourTableSetRequest {
+        NeighbourTableSetRequest::new(self.0.clone(), name)
+    }
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+
+mod handle;
+pub use self::handle::*;
+
+mod get;
+pub use self::get::*;
+
+mod set;
+pub use self::set::*;
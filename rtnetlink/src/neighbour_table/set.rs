@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::neighbour_table::{NeighbourTableParameter, Nla},
+    NeighbourTableMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle, IpVersion};
+
+/// A request to change the parameters of a neighbour table. This is equivalent to the
+/// `ip ntable change` commands.
+///
+/// The parameters apply to the table defaults, unless
+/// [`interface`](NeighbourTableSetRequest::interface) is used. Times are in milliseconds.
+pub struct NeighbourTableSetRequest {
+    handle: Handle,
+    message: NeighbourTableMessage,
+}
+
+impl NeighbourTableSetRequest {
+    pub(crate) fn new(handle: Handle, name: &str) -> Self {
+        let mut message = NeighbourTableMessage::default();
+        message.nlas.push(Nla::Name(name.to_string()));
+        NeighbourTableSetRequest { handle, message }
+    }
+
+    /// Only change the table of the given IP version
+    pub fn set_family(mut self, ip_version: IpVersion) -> Self {
+        self.message.header.family = ip_version.family();
+        self
+    }
+
+    /// Set the minimum number of entries below which the garbage collector does not run
+    /// (equivalent to `thresh1`). This only applies to the table defaults.
+    pub fn threshold1(mut self, value: u32) -> Self {
+        self.message.nlas.push(Nla::Threshold1(value));
+        self
+    }
+
+    /// Set the number of entries above which the garbage collector runs after 5 seconds
+    /// (equivalent to `thresh2`). This only applies to the table defaults.
+    pub fn threshold2(mut self, value: u32) -> Self {
+        self.message.nlas.push(Nla::Threshold2(value));
+        self
+    }
+
+    /// Set the maximum number of entries (equivalent to `thresh3`). This only applies to the
+    /// table defaults.
+    pub fn threshold3(mut self, value: u32) -> Self {
+        self.message.nlas.push(Nla::Threshold3(value));
+        self
+    }
+
+    /// Set the garbage collector interval (equivalent to `gc_int`). This only applies to the
+    /// table defaults.
+    pub fn gc_interval(mut self, value: u64) -> Self {
+        self.message.nlas.push(Nla::GcInterval(value));
+        self
+    }
+
+    /// Change the parameters of the given interface instead of the table defaults
+    /// (equivalent to `dev DEV`)
+    pub fn interface(self, index: u32) -> Self {
+        self.parameter(NeighbourTableParameter::IfIndex(index))
+    }
+
+    /// Equivalent to `base_reachable`
+    pub fn base_reachable_time(self, value: u64) -> Self {
+        self.parameter(NeighbourTableParameter::BaseReachableTime(value))
+    }
+
+    /// Equivalent to `retrans`
+    pub fn retrans_time(self, value: u64) -> Self {
+        self.parameter(NeighbourTableParameter::RetransTime(value))
+    }
+
+    /// Equivalent to `gc_stale`
+    pub fn gc_stale_time(self, value: u64) -> Self {
+        self.parameter(NeighbourTableParameter::GcStaleTime(value))
+    }
+
+    /// Equivalent to `delay_probe`
+    pub fn delay_probe_time(self, value: u64) -> Self {
+        self.parameter(NeighbourTableParameter::DelayProbeTime(value))
+    }
+
+    /// Set the maximum number of packets queued for an unresolved neighbour (equivalent to
+    /// `queue`)
+    pub fn queue_len(self, value: u32) -> Self {
+        self.parameter(NeighbourTableParameter::QueueLen(value))
+    }
+
+    /// Set the maximum number of bytes queued for an unresolved neighbour
+    pub fn queue_len_bytes(self, value: u32) -> Self {
+        self.parameter(NeighbourTableParameter::QueueLenBytes(value))
+    }
+
+    /// Equivalent to `app_probes`
+    pub fn app_probes(self, value: u32) -> Self {
+        self.parameter(NeighbourTableParameter::AppProbes(value))
+    }
+
+    /// Equivalent to `ucast_probes`
+    pub fn ucast_probes(self, value: u32) -> Self {
+        self.parameter(NeighbourTableParameter::UcastProbes(value))
+    }
+
+    /// Equivalent to `mcast_probes`
+    pub fn mcast_probes(self, value: u32) -> Self {
+        self.parameter(NeighbourTableParameter::McastProbes(value))
+    }
+
+    pub fn mcast_reprobes(self, value: u32) -> Self {
+        self.parameter(NeighbourTableParameter::McastReprobes(value))
+    }
+
+    /// Equivalent to `anycast_delay`
+    pub fn anycast_delay(self, value: u64) -> Self {
+        self.parameter(NeighbourTableParameter::AnycastDelay(value))
+    }
+
+    /// Equivalent to `proxy_delay`
+    pub fn proxy_delay(self, value: u64) -> Self {
+        self.parameter(NeighbourTableParameter::ProxyDelay(value))
+    }
+
+    /// Equivalent to `proxy_queue`
+    pub fn proxy_queue_len(self, value: u32) -> Self {
+        self.parameter(NeighbourTableParameter::ProxyQueueLen(value))
+    }
+
+    /// Equivalent to `locktime`
+    pub fn lock_time(self, value: u64) -> Self {
+        self.parameter(NeighbourTableParameter::LockTime(value))
+    }
+
+    /// Add a parameter to the `NDTA_PARMS` attribute of the request.
+    pub fn parameter(mut self, parameter: NeighbourTableParameter) -> Self {
+        let parms = self.message.nlas.iter_mut().find_map(|nla| match nla {
+            Nla::Parms(parms) => Some(parms),
+            _ => None,
+        });
+        match parms {
+            Some(parms) => parms.push(parameter),
+            None => self.message.nlas.push(Nla::Parms(vec![parameter])),
+        }
+        self
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let NeighbourTableSetRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::SetNeighbourTable(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut NeighbourTableMessage {
+        &mut self.message
+    }
+}
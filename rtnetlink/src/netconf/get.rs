@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::netconf::Nla,
+    NetconfMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle, IpVersion};
+
+/// A request to retrieve the IP configuration of the interfaces. This is equivalent to the
+/// `ip netconf show` commands.
+///
+/// Changes are notified to the `RTNLGRP_IPV4_NETCONF` and `RTNLGRP_IPV6_NETCONF` multicast
+/// groups, as `RtnlMessage::NewNetconf` messages holding the changed attributes only.
+pub struct NetconfGetRequest {
+    handle: Handle,
+    message: NetconfMessage,
+    dump: bool,
+}
+
+impl NetconfGetRequest {
+    pub(crate) fn new(handle: Handle, ip_version: IpVersion) -> Self {
+        let mut message = NetconfMessage::default();
+        message.header.family = ip_version.family();
+        NetconfGetRequest {
+            handle,
+            message,
+            dump: true,
+        }
+    }
+
+    /// Only retrieve the configuration of the interface with the given index (equivalent
+    /// to `ip netconf show dev DEV`). `NETCONFA_IFINDEX_ALL` and `NETCONFA_IFINDEX_DEFAULT`
+    /// retrieve the `all` and `default` configurations.
+    pub fn match_index(mut self, index: i32) -> Self {
+        self.message.nlas.push(Nla::IfIndex(index));
+        self.dump = false;
+        self
+    }
+
+    pub fn message_mut(&mut self) -> &mut NetconfMessage {
+        &mut self.message
+    }
+
+    pub fn execute(self) -> impl TryStream<Ok = NetconfMessage, Error = Error> {
+        let NetconfGetRequest {
+            mut handle,
+            message,
+            dump,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetNetconf(message));
+        req.header.flags = if dump {
+            NLM_F_REQUEST | NLM_F_DUMP
+        } else {
+            NLM_F_REQUEST
+        };
+
+        match handle.request(req) {
+            Ok(response) => {
+                Either::Left(response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewNetconf))))
+            }
+            Err(e) => Either::Right(future::err::<NetconfMessage, Error>(e).into_stream()),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Handle, IpVersion, NetconfGetRequest};
+
+pub struct NetconfHandle(Handle);
+
+impl NetconfHandle {
+    pub fn new(handle: Handle) -> Self {
+        NetconfHandle(handle)
+    }
+
+    /// Retrieve the per-interface IP configuration of the given IP version, such as
+    /// forwarding and reverse path filtering (equivalent to `ip netconf show`)
+    pub fn get(&self, ip_version: IpVersion) -> NetconfGetRequest {
+        NetconfGetRequest::new(self.0.clone(), ip_version)
+    }
+}
@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: MIT
+
+mod handle;
+pub use self::handle::*;
+
+mod get;
+pub use self::get::*;
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::nexthop::{NexthopGroup, Nla, ResGroupNla},
+    NetlinkMessage,
+    NexthopMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to create a new nexthop object. This is equivalent to the `ip nexthop add`
+/// commands.
+///
+/// Nexthops that are not groups need an address family, which is set either by
+/// [`gateway`](NexthopAddRequest::gateway) or explicitly with
+/// [`v4`](NexthopAddRequest::v4) or [`v6`](NexthopAddRequest::v6).
+pub struct NexthopAddRequest {
+    handle: Handle,
+    message: NexthopMessage,
+    replace: bool,
+}
+
+impl NexthopAddRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let mut message = NexthopMessage::default();
+        message.header.protocol = RTPROT_STATIC;
+
+        NexthopAddRequest {
+            handle,
+            message,
+            replace: false,
+        }
+    }
+
+    /// Sets the nexthop id. If unset, the kernel allocates one.
+    pub fn id(mut self, id: u32) -> Self {
+        self.message.nlas.push(Nla::Id(id));
+        self
+    }
+
+    /// Build an IP v4 nexthop
+    pub fn v4(mut self) -> Self {
+        self.message.header.family = AF_INET as u8;
+        self
+    }
+
+    /// Build an IP v6 nexthop
+    pub fn v6(mut self) -> Self {
+        self.message.header.family = AF_INET6 as u8;
+        self
+    }
+
+    /// Sets the output interface index.
+    pub fn output_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Oif(index));
+        self
+    }
+
+    /// Sets the gateway address, and the nexthop address family accordingly.
+    pub fn gateway(mut self, addr: IpAddr) -> Self {
+        let (family, gateway) = match addr {
+            IpAddr::V4(v4) => (AF_INET, v4.octets().to_vec()),
+            IpAddr::V6(v6) => (AF_INET6, v6.octets().to_vec()),
+        };
+        self.message.header.family = family as u8;
+        self.message.nlas.push(Nla::Gateway(gateway));
+        self
+    }
+
+    /// Sets the nexthop protocol.
+    ///
+    /// Default is static protocol.
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.message.header.protocol = protocol;
+        self
+    }
+
+    /// Pretend that the gateway is directly attached to the output interface, even if
+    /// it does not match any interface prefix (equivalent to `onlink`).
+    pub fn onlink(mut self) -> Self {
+        self.message.header.flags |= RTNH_F_ONLINK as u32;
+        self
+    }
+
+    /// Make the nexthop a blackhole: packets routed through it are silently dropped.
+    pub fn blackhole(mut self) -> Self {
+        self.message.nlas.push(Nla::Blackhole);
+        self
+    }
+
+    /// Mark the nexthop as usable by FDB entries (equivalent to `fdb`).
+    pub fn fdb(mut self) -> Self {
+        self.message.nlas.push(Nla::Fdb);
+        self
+    }
+
+    /// Make the nexthop a multipath group of existing nexthops.
+    ///
+    /// As expected by the kernel, the `weight` of each member is its actual weight minus
+    /// one.
+    pub fn group(mut self, members: Vec<NexthopGroup>) -> Self {
+        self.message.header.family = AF_UNSPEC as u8;
+        self.message.nlas.push(Nla::Group(members));
+        self.message
+            .nlas
+            .push(Nla::GroupType(NEXTHOP_GRP_TYPE_MPATH));
+        self
+    }
+
+    /// Make the nexthop a resilient group of existing nexthops, with the given number
+    /// of hash buckets.
+    ///
+    /// As expected by the kernel, the `weight` of each member is its actual weight minus
+    /// one.
+    pub fn resilient_group(mut self, members: Vec<NexthopGroup>, buckets: u16) -> Self {
+        self.message.header.family = AF_UNSPEC as u8;
+        self.message.nlas.push(Nla::Group(members));
+        self.message.nlas.push(Nla::GroupType(NEXTHOP_GRP_TYPE_RES));
+        self.res_group_nla(ResGroupNla::Buckets(buckets))
+    }
+
+    /// Sets the idle timer of a resilient group, in seconds.
+    pub fn idle_timer(self, seconds: u32) -> Self {
+        self.res_group_nla(ResGroupNla::IdleTimer(seconds))
+    }
+
+    /// Sets the unbalanced timer of a resilient group, in seconds.
+    pub fn unbalanced_timer(self, seconds: u32) -> Self {
+        self.res_group_nla(ResGroupNla::UnbalancedTimer(seconds))
+    }
+
+    fn res_group_nla(mut self, nla: ResGroupNla) -> Self {
+        let res_group = self.message.nlas.iter_mut().find_map(|nla| match nla {
+            Nla::ResGroup(nlas) => Some(nlas),
+            _ => None,
+        });
+        match res_group {
+            Some(nlas) => nlas.push(nla),
+            None => self.message.nlas.push(Nla::ResGroup(vec![nla])),
+        }
+        self
+    }
+
+    /// Replace the existing nexthop with the same id.
+    pub fn replace(self) -> Self {
+        Self {
+            replace: true,
+            ..self
+        }
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let NexthopAddRequest {
+            mut handle,
+            message,
+            replace,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::NewNexthop(message));
+        let replace = if replace { NLM_F_REPLACE } else { NLM_F_EXCL };
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | replace | NLM_F_CREATE;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut NexthopMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        nlas::nexthop::Nla,
+        NetlinkMessage,
+        NexthopMessage,
+        RtnlMessage,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct NexthopDelRequest {
+    handle: Handle,
+    message: NexthopMessage,
+}
+
+impl NexthopDelRequest {
+    pub(crate) fn new(handle: Handle, id: u32) -> Self {
+        let mut message = NexthopMessage::default();
+        message.nlas.push(Nla::Id(id));
+        NexthopDelRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let NexthopDelRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelNexthop(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    pub fn message_mut(&mut self) -> &mut NexthopMessage {
+        &mut self.message
+    }
+}
@@ -3,12 +3,25 @@
 use futures::stream::StreamExt;
 use std::{
     marker::PhantomData,
-    net::{Ipv4Addr, Ipv6Addr},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
 };
 
 use netlink_packet_route::{
     constants::*,
-    nlas::route::Nla,
+    nlas::route::{
+        Encap,
+        Ipv6SrHeader,
+        MplsIpTunnel,
+        MplsLabel,
+        NextHop,
+        Nla,
+        RouteVia,
+        Seg6Encap,
+        Seg6IpTunnel,
+        Seg6Local,
+        MPLS_LABEL_LEN,
+    },
+    traits::Emitable,
     NetlinkMessage,
     RouteMessage,
     RtnlMessage,
@@ -85,6 +98,66 @@ impl<T> RouteAddRequest<T> {
         self
     }
 
+    /// Use the nexthop object with the given id (see [`NexthopHandle`](crate::NexthopHandle)),
+    /// instead of an inline gateway and output interface.
+    pub fn nexthop_id(mut self, id: u32) -> Self {
+        self.message.nlas.push(Nla::NhId(id));
+        self
+    }
+
+    /// Sets the next-hops of a multipath route, each with its own output
+    /// interface, gateway, weight (`hops` is the weight minus one) and
+    /// flags. This is equivalent to `ip route add ... nexthop via GATEWAY dev
+    /// DEV weight WEIGHT nexthop ...`.
+    pub fn multipath(mut self, next_hops: Vec<NextHop>) -> Self {
+        self.message.nlas.push(Nla::MultiPath(next_hops));
+        self
+    }
+
+    /// Sets the lightweight tunnel encapsulation of the route. The
+    /// encapsulation type is set accordingly, except for `Encap::Other`
+    /// whose type must be pushed as a `Nla::EncapType` attribute.
+    pub fn encap(mut self, encap: Encap) -> Self {
+        if let Some(kind) = encap.kind() {
+            self.message.nlas.push(Nla::EncapType(kind));
+        }
+        self.message.nlas.push(Nla::Encap(encap));
+        self
+    }
+
+    /// Push the given MPLS labels on the packets, the outermost one first.
+    /// This is equivalent to `ip route add ... encap mpls LABEL/LABEL...`.
+    pub fn mpls_encap(self, labels: &[u32]) -> Self {
+        self.encap(Encap::Mpls(vec![MplsIpTunnel::Destination(
+            MplsLabel::stack(labels),
+        )]))
+    }
+
+    /// Steer the packets through the given segments, in that order. `mode` is
+    /// one of the `SEG6_IPTUN_MODE_*` constants. This is equivalent to
+    /// `ip route add ... encap seg6 mode MODE segs SEGMENTS`.
+    pub fn seg6_encap(self, mode: u32, segments: &[Ipv6Addr]) -> Self {
+        let mut header = Ipv6SrHeader::new(segments);
+        if mode == SEG6_IPTUN_MODE_INLINE {
+            // The kernel stores the original destination of the packet in
+            // the first segment of the header
+            header.segments.insert(0, Ipv6Addr::UNSPECIFIED);
+            header.segments_left += 1;
+        }
+        self.encap(Encap::Seg6(vec![Seg6IpTunnel::Srh(Seg6Encap {
+            mode,
+            header,
+        })]))
+    }
+
+    /// Apply a SRv6 behavior to the packets matching the route. The
+    /// attributes must include a `Seg6Local::Action`, along with the ones
+    /// the action requires. This is equivalent to `ip route add ... encap
+    /// seg6local action ACTION ...`.
+    pub fn seg6local(self, nlas: Vec<Seg6Local>) -> Self {
+        self.encap(Encap::Seg6Local(nlas))
+    }
+
     /// Build an IP v4 route request
     pub fn v4(mut self) -> RouteAddRequest<Ipv4Addr> {
         self.message.header.address_family = AF_INET as u8;
@@ -107,6 +180,18 @@ impl<T> RouteAddRequest<T> {
         }
     }
 
+    /// Build a MPLS route request, which switches the packets carrying a
+    /// given label (equivalent to `ip -f mpls route add`)
+    pub fn mpls(mut self) -> RouteAddRequest<MplsLabel> {
+        self.message.header.address_family = AF_MPLS as u8;
+        RouteAddRequest {
+            handle: self.handle,
+            message: self.message,
+            replace: false,
+            _phantom: Default::default(),
+        }
+    }
+
     /// Replace existing matching route.
     pub fn replace(self) -> Self {
         Self {
@@ -203,3 +288,42 @@ impl RouteAddRequest<Ipv6Addr> {
         self
     }
 }
+
+impl RouteAddRequest<MplsLabel> {
+    /// Sets the incoming label the route applies to.
+    pub fn label(mut self, label: u32) -> Self {
+        self.message.header.destination_prefix_length = 20;
+        let mut dst = vec![0; MPLS_LABEL_LEN];
+        MplsLabel::stack(&[label])[0].emit(&mut dst);
+        self.message.nlas.push(Nla::Destination(dst));
+        self
+    }
+
+    /// Sets the labels that replace the incoming one, the outermost one
+    /// first. Without them, the incoming label is popped. This is equivalent
+    /// to `ip -f mpls route add ... as LABEL/LABEL...`.
+    pub fn new_destination(mut self, labels: &[u32]) -> Self {
+        self.message
+            .nlas
+            .push(Nla::NewDestination(MplsLabel::stack(labels)));
+        self
+    }
+
+    /// Sets the gateway, which is usually an IP address.
+    pub fn via(mut self, addr: IpAddr) -> Self {
+        let via = match addr {
+            IpAddr::V4(addr) => RouteVia::Ipv4(addr),
+            IpAddr::V6(addr) => RouteVia::Ipv6(addr),
+        };
+        self.message.nlas.push(Nla::Via(via));
+        self
+    }
+
+    /// Sets whether the TTL of the label is copied to the IP header when the
+    /// last label is popped. Without it, the `net.mpls.ip_ttl_propagate`
+    /// sysctl applies.
+    pub fn ttl_propagate(mut self, enabled: bool) -> Self {
+        self.message.nlas.push(Nla::TtlPropagate(enabled as u8));
+        self
+    }
+}
@@ -22,6 +22,8 @@ pub enum IpVersion {
     V4,
     /// IPv6
     V6,
+    /// MPLS, whose routes are label switching entries
+    Mpls,
 }
 
 impl IpVersion {
@@ -29,6 +31,7 @@ impl IpVersion {
         match self {
             IpVersion::V4 => AF_INET as u8,
             IpVersion::V6 => AF_INET6 as u8,
+            IpVersion::Mpls => AF_MPLS as u8,
         }
     }
 }
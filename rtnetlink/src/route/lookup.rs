@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::route::Nla,
+    NetlinkMessage,
+    RouteFlags,
+    RouteMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle};
+
+/// A request to find the route the kernel would use for a packet. This is
+/// equivalent to the `ip route get` command.
+pub struct RouteLookupRequest {
+    handle: Handle,
+    message: RouteMessage,
+}
+
+fn addr_to_octets(addr: IpAddr) -> (u8, Vec<u8>) {
+    match addr {
+        IpAddr::V4(addr) => (32, addr.octets().to_vec()),
+        IpAddr::V6(addr) => (128, addr.octets().to_vec()),
+    }
+}
+
+impl RouteLookupRequest {
+    pub(crate) fn new(handle: Handle, destination: IpAddr) -> Self {
+        let mut message = RouteMessage::default();
+        message.header.address_family = match destination {
+            IpAddr::V4(_) => AF_INET as u8,
+            IpAddr::V6(_) => AF_INET6 as u8,
+        };
+        let (length, dst) = addr_to_octets(destination);
+        message.header.destination_prefix_length = length;
+        message.nlas.push(Nla::Destination(dst));
+        // Like iproute2, ask for the table the route was found in, instead of
+        // `RT_TABLE_MAIN`
+        message.header.flags = RouteFlags::RTM_F_LOOKUP_TABLE;
+
+        RouteLookupRequest { handle, message }
+    }
+
+    /// Sets the source address of the packet (equivalent to
+    /// `ip route get ... from ADDRESS`).
+    pub fn source(mut self, addr: IpAddr) -> Self {
+        let (length, src) = addr_to_octets(addr);
+        self.message.header.source_prefix_length = length;
+        self.message.nlas.push(Nla::Source(src));
+        self
+    }
+
+    /// Sets the interface the packet comes from, to look up the route of a
+    /// forwarded packet instead of a packet sent by the host (equivalent to
+    /// `ip route get ... iif DEV`).
+    pub fn input_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Iif(index));
+        self
+    }
+
+    /// Forces the interface the packet goes through (equivalent to
+    /// `ip route get ... oif DEV`).
+    pub fn output_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Oif(index));
+        self
+    }
+
+    /// Sets the firewall mark of the packet (equivalent to
+    /// `ip route get ... mark MARK`).
+    pub fn mark(mut self, mark: u32) -> Self {
+        self.message.nlas.push(Nla::Mark(mark));
+        self
+    }
+
+    /// Sets the user sending the packet (equivalent to
+    /// `ip route get ... uid UID`).
+    pub fn uid(mut self, uid: u32) -> Self {
+        self.message.nlas.push(Nla::Uid(uid));
+        self
+    }
+
+    /// Sets the type of service of the packet (equivalent to
+    /// `ip route get ... tos TOS`).
+    pub fn tos(mut self, tos: u8) -> Self {
+        self.message.header.tos = tos;
+        self
+    }
+
+    /// Sets the IP protocol of the packet, like `IPPROTO_TCP` (equivalent to
+    /// `ip route get ... ipproto PROTOCOL`).
+    pub fn ip_proto(mut self, protocol: u8) -> Self {
+        self.message.nlas.push(Nla::IpProto(protocol));
+        self
+    }
+
+    /// Sets the source port of the packet (equivalent to
+    /// `ip route get ... sport PORT`).
+    pub fn source_port(mut self, port: u16) -> Self {
+        self.message.nlas.push(Nla::SourcePort(port));
+        self
+    }
+
+    /// Sets the destination port of the packet (equivalent to
+    /// `ip route get ... dport PORT`).
+    pub fn destination_port(mut self, port: u16) -> Self {
+        self.message.nlas.push(Nla::DestinationPort(port));
+        self
+    }
+
+    /// Return the matching routing table entry, as found in dumps, instead of
+    /// the route resolved for the packet (equivalent to
+    /// `ip route get ... fibmatch`).
+    pub fn fib_match(mut self) -> Self {
+        self.message.header.flags |= RouteFlags::RTM_F_FIB_MATCH;
+        self
+    }
+
+    pub fn message_mut(&mut self) -> &mut RouteMessage {
+        &mut self.message
+    }
+
+    /// Execute the request, returning the route the packet would take.
+    pub async fn execute(self) -> Result<RouteMessage, Error> {
+        let mut handle = self.handle.clone();
+        let mut response = handle.request(self.into_netlink_message())?;
+        match response.next().await {
+            Some(msg) => Ok(try_rtnl!(msg, RtnlMessage::NewRoute)),
+            None => Err(Error::RequestFailed),
+        }
+    }
+
+    /// Build the netlink message sent by the request
+    pub(crate) fn into_netlink_message(self) -> NetlinkMessage<RtnlMessage> {
+        let mut req = NetlinkMessage::from(RtnlMessage::GetRoute(self.message));
+        req.header.flags = NLM_F_REQUEST;
+        req
+    }
+}
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr};
+
+use crate::{
+    packet::{
+        nlas::route::Nla,
+        NetlinkPayload,
+        RouteFlags,
+        RouteMessage,
+        RtnlMessage,
+        AF_INET,
+        NLM_F_DUMP,
+        NLM_F_REQUEST,
+    },
+    test_handle,
+    RouteLookupRequest,
+};
+
+const DESTINATION: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+
+/// Return the message of a route lookup request, checking that it is a single
+/// request and not a dump
+fn lookup_message(request: RouteLookupRequest) -> RouteMessage {
+    let message = request.into_netlink_message();
+    assert_eq!(message.header.flags, NLM_F_REQUEST);
+    assert_eq!(message.header.flags & NLM_F_DUMP, 0);
+    match message.payload {
+        NetlinkPayload::InnerMessage(RtnlMessage::GetRoute(message)) => message,
+        payload => panic!("expected a RTM_GETROUTE message, got {:?}", payload),
+    }
+}
+
+#[test]
+fn lookup_message_flags() {
+    let message = lookup_message(test_handle().route().lookup(DESTINATION));
+    assert_eq!(message.header.address_family, AF_INET as u8);
+    assert_eq!(message.header.destination_prefix_length, 32);
+    assert_eq!(message.header.flags, RouteFlags::RTM_F_LOOKUP_TABLE);
+    assert_eq!(message.nlas, vec![Nla::Destination(vec![192, 0, 2, 1])]);
+}
+
+#[test]
+fn lookup_message_fib_match() {
+    let message = lookup_message(test_handle().route().lookup(DESTINATION).fib_match());
+    assert_eq!(
+        message.header.flags,
+        RouteFlags::RTM_F_LOOKUP_TABLE | RouteFlags::RTM_F_FIB_MATCH
+    );
+}
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{constants::*, NetlinkMessage, RtnlMessage, StatsMessage};
+
+use crate::{try_rtnl, Error, Handle};
+
+pub struct StatsGetRequest {
+    handle: Handle,
+    message: StatsMessage,
+    dump: bool,
+}
+
+impl StatsGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        StatsGetRequest {
+            handle,
+            message: StatsMessage::default(),
+            dump: true,
+        }
+    }
+
+    /// Only retrieve the statistics of the link with the given index
+    pub fn match_index(mut self, index: u32) -> Self {
+        self.message.header.ifindex = index;
+        self.dump = false;
+        self
+    }
+
+    /// Select the statistics to retrieve. Each `IFLA_STATS_*` attribute is
+    /// selected by the bit `1 << (IFLA_STATS_* - 1)`. If no statistics are
+    /// selected, only `IFLA_STATS_LINK_64` is retrieved.
+    pub fn filter_mask(mut self, mask: u32) -> Self {
+        self.message.header.filter_mask = mask;
+        self
+    }
+
+    /// Also retrieve the statistics carried by the given `IFLA_STATS_*`
+    /// attribute, for instance `IFLA_STATS_LINK_XSTATS`. Attributes that
+    /// cannot be selected by the filter mask (`IFLA_STATS_UNSPEC`, or values
+    /// above 32) are ignored.
+    pub fn filter(mut self, attribute: u16) -> Self {
+        if let Some(bit) = (attribute as u32)
+            .checked_sub(1)
+            .and_then(|shift| 1u32.checked_shl(shift))
+        {
+            self.message.header.filter_mask |= bit;
+        }
+        self
+    }
+
+    pub fn message_mut(&mut self) -> &mut StatsMessage {
+        &mut self.message
+    }
+
+    pub fn execute(self) -> impl TryStream<Ok = StatsMessage, Error = Error> {
+        let StatsGetRequest {
+            mut handle,
+            mut message,
+            dump,
+        } = self;
+
+        // The kernel rejects requests that do not select any statistics
+        if message.header.filter_mask == 0 {
+            message.header.filter_mask = 1 << (IFLA_STATS_LINK_64 - 1);
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetStats(message));
+        req.header.flags = if dump {
+            NLM_F_REQUEST | NLM_F_DUMP
+        } else {
+            NLM_F_REQUEST
+        };
+
+        match handle.request(req) {
+            Ok(response) => {
+                Either::Left(response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewStats))))
+            }
+            Err(e) => Either::Right(future::err::<StatsMessage, Error>(e).into_stream()),
+        }
+    }
+}
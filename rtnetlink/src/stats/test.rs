@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    packet::{IFLA_STATS_AF_SPEC, IFLA_STATS_LINK_64, IFLA_STATS_LINK_XSTATS, IFLA_STATS_UNSPEC},
+    test_handle,
+};
+
+#[test]
+fn filter_mask() {
+    let mut request = test_handle()
+        .stats()
+        .get()
+        .filter(IFLA_STATS_LINK_64)
+        .filter(IFLA_STATS_LINK_XSTATS)
+        .filter(IFLA_STATS_AF_SPEC);
+    assert_eq!(
+        request.message_mut().header.filter_mask,
+        (1 << (IFLA_STATS_LINK_64 - 1))
+            | (1 << (IFLA_STATS_LINK_XSTATS - 1))
+            | (1 << (IFLA_STATS_AF_SPEC - 1))
+    );
+}
+
+#[test]
+fn filter_out_of_range() {
+    let mut request = test_handle()
+        .stats()
+        .get()
+        .filter(IFLA_STATS_UNSPEC)
+        .filter(32)
+        .filter(33)
+        .filter(u16::MAX);
+    assert_eq!(request.message_mut().header.filter_mask, 1 << 31);
+}
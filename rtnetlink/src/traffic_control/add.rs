@@ -0,0 +1,419 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        nlas::tc::{Nla, TcOptions},
+        NetlinkMessage,
+        RtnlMessage,
+        TcMessage,
+        ETH_P_ALL,
+        NLM_F_ACK,
+        NLM_F_CREATE,
+        NLM_F_EXCL,
+        NLM_F_REPLACE,
+        NLM_F_REQUEST,
+        TC_H_CLSACT,
+        TC_H_INGRESS,
+        TC_H_MAJ_MASK,
+        TC_H_MIN_EGRESS,
+        TC_H_MIN_INGRESS,
+        TC_H_ROOT,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+/// Flags for a request creating a new traffic control object, failing if it
+/// already exists (`tc ... add`).
+pub(crate) const TC_ADD_FLAGS: u16 = NLM_F_CREATE | NLM_F_EXCL;
+/// Flags for a request modifying an existing traffic control object
+/// (`tc ... change`).
+pub(crate) const TC_CHANGE_FLAGS: u16 = 0;
+/// Flags for a request creating a traffic control object, or replacing it if
+/// it already exists (`tc ... replace`).
+pub(crate) const TC_REPLACE_FLAGS: u16 = NLM_F_CREATE | NLM_F_REPLACE;
+
+/// Build a traffic control handle from its major and minor numbers, as
+/// written `major:minor` by `tc`.
+pub(crate) fn tc_handle(major: u16, minor: u16) -> u32 {
+    ((major as u32) << 16) | minor as u32
+}
+
+/// Set the `TCA_KIND` attribute of a message, replacing the existing one if
+/// any.
+pub(crate) fn set_kind(message: &mut TcMessage, kind: &str) {
+    message.nlas.retain(|nla| !matches!(nla, Nla::Kind(_)));
+    message.nlas.insert(0, Nla::Kind(kind.to_string()));
+}
+
+/// Set the `TCA_OPTIONS` attribute of a message, replacing the existing one
+/// if any. The `TCA_KIND` attribute is set accordingly when the kind of the
+/// options is known.
+pub(crate) fn set_options(message: &mut TcMessage, options: TcOptions) {
+    if let Some(kind) = options.kind() {
+        set_kind(message, kind);
+    }
+    message.nlas.retain(|nla| !matches!(nla, Nla::Options(_)));
+    message.nlas.push(Nla::Options(options));
+}
+
+/// Set the `TCA_CHAIN` attribute of a message, replacing the existing one if
+/// any.
+pub(crate) fn set_chain(message: &mut TcMessage, chain: u32) {
+    message.nlas.retain(|nla| !matches!(nla, Nla::Chain(_)));
+    message.nlas.push(Nla::Chain(chain.to_ne_bytes().to_vec()));
+}
+
+/// Set the priority of a filter, which is stored in the upper 16 bits of the
+/// `tcm_info` header field.
+pub(crate) fn set_filter_priority(message: &mut TcMessage, priority: u16) {
+    message.header.info = ((priority as u32) << 16) | (message.header.info & 0xffff);
+}
+
+/// Set the protocol of a filter, which is stored in network byte order in
+/// the lower 16 bits of the `tcm_info` header field.
+pub(crate) fn set_filter_protocol(message: &mut TcMessage, protocol: u16) {
+    message.header.info = (message.header.info & 0xffff_0000) | protocol.to_be() as u32;
+}
+
+/// Build the netlink message of a request changing traffic control objects,
+/// with the given flags in addition to `NLM_F_REQUEST | NLM_F_ACK`.
+pub(crate) fn tc_message(message: RtnlMessage, flags: u16) -> NetlinkMessage<RtnlMessage> {
+    let mut req = NetlinkMessage::from(message);
+    req.header.flags = NLM_F_REQUEST | NLM_F_ACK | flags;
+    req
+}
+
+pub(crate) async fn tc_request(
+    mut handle: Handle,
+    req: NetlinkMessage<RtnlMessage>,
+) -> Result<(), Error> {
+    let mut response = handle.request(req)?;
+    while let Some(message) = response.next().await {
+        try_nl!(message);
+    }
+    Ok(())
+}
+
+/// A request to add, change or replace a queueing discipline (equivalent to
+/// `tc qdisc {add|change|replace} dev <interface_name> ...`)
+pub struct QDiscNewRequest {
+    handle: Handle,
+    message: TcMessage,
+    flags: u16,
+}
+
+impl QDiscNewRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32, flags: u16) -> Self {
+        let mut message = TcMessage::default();
+        message.header.index = ifindex;
+        message.header.parent = TC_H_ROOT;
+        QDiscNewRequest {
+            handle,
+            message,
+            flags,
+        }
+    }
+
+    /// Set the handle of the qdisc (`handle major:minor`)
+    pub fn handle(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.handle = tc_handle(major, minor);
+        self
+    }
+
+    /// Attach the qdisc to the root of the interface (`root`). This is the
+    /// default.
+    pub fn root(mut self) -> Self {
+        self.message.header.parent = TC_H_ROOT;
+        self
+    }
+
+    /// Attach the qdisc to the given parent class (`parent major:minor`)
+    pub fn parent(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.parent = tc_handle(major, minor);
+        self
+    }
+
+    /// Create an `ingress` qdisc (`tc qdisc add dev <interface_name>
+    /// ingress`)
+    pub fn ingress(mut self) -> Self {
+        self.message.header.parent = TC_H_INGRESS;
+        self.message.header.handle = TC_H_MAJ_MASK;
+        set_kind(&mut self.message, "ingress");
+        self
+    }
+
+    /// Create a `clsact` qdisc (`tc qdisc add dev <interface_name> clsact`)
+    pub fn clsact(mut self) -> Self {
+        self.message.header.parent = TC_H_CLSACT;
+        self.message.header.handle = TC_H_MAJ_MASK;
+        set_kind(&mut self.message, "clsact");
+        self
+    }
+
+    /// Set the kind of the qdisc, for instance `"fq_codel"` or `"htb"`
+    pub fn kind(mut self, kind: &str) -> Self {
+        set_kind(&mut self.message, kind);
+        self
+    }
+
+    /// Set the options of the qdisc. This also sets its kind, unless the
+    /// options are [`TcOptions::Other`].
+    pub fn options(mut self, options: TcOptions) -> Self {
+        set_options(&mut self.message, options);
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut TcMessage {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let handle = self.handle.clone();
+        tc_request(handle, self.into_netlink_message()).await
+    }
+
+    /// Build the netlink message sent by the request
+    pub(crate) fn into_netlink_message(self) -> NetlinkMessage<RtnlMessage> {
+        tc_message(RtnlMessage::NewQueueDiscipline(self.message), self.flags)
+    }
+}
+
+/// A request to add, change or replace a traffic class (equivalent to
+/// `tc class {add|change|replace} dev <interface_name> ...`)
+pub struct TrafficClassNewRequest {
+    handle: Handle,
+    message: TcMessage,
+    flags: u16,
+}
+
+impl TrafficClassNewRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32, flags: u16) -> Self {
+        let mut message = TcMessage::default();
+        message.header.index = ifindex;
+        message.header.parent = TC_H_ROOT;
+        TrafficClassNewRequest {
+            handle,
+            message,
+            flags,
+        }
+    }
+
+    /// Set the identifier of the class (`classid major:minor`)
+    pub fn classid(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.handle = tc_handle(major, minor);
+        self
+    }
+
+    /// Attach the class to the root of the interface (`root`). This is the
+    /// default.
+    pub fn root(mut self) -> Self {
+        self.message.header.parent = TC_H_ROOT;
+        self
+    }
+
+    /// Attach the class to the given parent qdisc or class
+    /// (`parent major:minor`)
+    pub fn parent(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.parent = tc_handle(major, minor);
+        self
+    }
+
+    /// Set the kind of the class. It must match the kind of the qdisc the
+    /// class belongs to, for instance `"htb"`.
+    pub fn kind(mut self, kind: &str) -> Self {
+        set_kind(&mut self.message, kind);
+        self
+    }
+
+    /// Set the options of the class. This also sets its kind, unless the
+    /// options are [`TcOptions::Other`].
+    pub fn options(mut self, options: TcOptions) -> Self {
+        set_options(&mut self.message, options);
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut TcMessage {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let handle = self.handle.clone();
+        tc_request(handle, self.into_netlink_message()).await
+    }
+
+    /// Build the netlink message sent by the request
+    pub(crate) fn into_netlink_message(self) -> NetlinkMessage<RtnlMessage> {
+        tc_message(RtnlMessage::NewTrafficClass(self.message), self.flags)
+    }
+}
+
+/// A request to add, change or replace a filter (equivalent to
+/// `tc filter {add|change|replace} dev <interface_name> ...`)
+pub struct TrafficFilterNewRequest {
+    handle: Handle,
+    message: TcMessage,
+    flags: u16,
+}
+
+impl TrafficFilterNewRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32, flags: u16) -> Self {
+        let mut message = TcMessage::default();
+        message.header.index = ifindex;
+        message.header.parent = TC_H_ROOT;
+        set_filter_protocol(&mut message, ETH_P_ALL);
+        TrafficFilterNewRequest {
+            handle,
+            message,
+            flags,
+        }
+    }
+
+    /// Set the handle of the filter (`handle <handle>`). Its meaning
+    /// depends on the kind of filter.
+    pub fn handle(mut self, handle: u32) -> Self {
+        self.message.header.handle = handle;
+        self
+    }
+
+    /// Attach the filter to the root qdisc of the interface (`root`). This
+    /// is the default.
+    pub fn root(mut self) -> Self {
+        self.message.header.parent = TC_H_ROOT;
+        self
+    }
+
+    /// Attach the filter to the given qdisc or class (`parent major:minor`)
+    pub fn parent(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.parent = tc_handle(major, minor);
+        self
+    }
+
+    /// Attach the filter to the ingress hook of a `ingress` or `clsact`
+    /// qdisc (`ingress`)
+    pub fn ingress(mut self) -> Self {
+        self.message.header.parent = (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_INGRESS;
+        self
+    }
+
+    /// Attach the filter to the egress hook of a `clsact` qdisc (`egress`)
+    pub fn egress(mut self) -> Self {
+        self.message.header.parent = (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_EGRESS;
+        self
+    }
+
+    /// Set the priority of the filter (`priority <priority>`). When not set,
+    /// the kernel allocates one.
+    pub fn priority(mut self, priority: u16) -> Self {
+        set_filter_priority(&mut self.message, priority);
+        self
+    }
+
+    /// Set the protocol the filter applies to (`protocol <protocol>`), as
+    /// one of the `ETH_P_*` constants. Defaults to `ETH_P_ALL`.
+    pub fn protocol(mut self, protocol: u16) -> Self {
+        set_filter_protocol(&mut self.message, protocol);
+        self
+    }
+
+    /// Add the filter to the given chain (`chain <chain>`)
+    pub fn chain(mut self, chain: u32) -> Self {
+        set_chain(&mut self.message, chain);
+        self
+    }
+
+    /// Set the kind of the filter, for instance `"u32"` or `"matchall"`
+    pub fn kind(mut self, kind: &str) -> Self {
+        set_kind(&mut self.message, kind);
+        self
+    }
+
+    /// Set the options of the filter, including its actions. This also sets
+    /// the kind of classifier, unless the options are [`TcOptions::Other`].
+    pub fn options(mut self, options: TcOptions) -> Self {
+        set_options(&mut self.message, options);
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut TcMessage {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let handle = self.handle.clone();
+        tc_request(handle, self.into_netlink_message()).await
+    }
+
+    /// Build the netlink message sent by the request
+    pub(crate) fn into_netlink_message(self) -> NetlinkMessage<RtnlMessage> {
+        tc_message(RtnlMessage::NewTrafficFilter(self.message), self.flags)
+    }
+}
+
+/// A request to add a filter chain (equivalent to
+/// `tc chain add dev <interface_name> ... chain <chain>`)
+pub struct TrafficChainNewRequest {
+    handle: Handle,
+    message: TcMessage,
+}
+
+impl TrafficChainNewRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32, chain: u32) -> Self {
+        let mut message = TcMessage::default();
+        message.header.index = ifindex;
+        message.header.parent = TC_H_ROOT;
+        set_chain(&mut message, chain);
+        TrafficChainNewRequest { handle, message }
+    }
+
+    /// Add the chain to the root qdisc of the interface (`root`). This is
+    /// the default.
+    pub fn root(mut self) -> Self {
+        self.message.header.parent = TC_H_ROOT;
+        self
+    }
+
+    /// Add the chain to the given qdisc (`parent major:minor`)
+    pub fn parent(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.parent = tc_handle(major, minor);
+        self
+    }
+
+    /// Add the chain to the ingress hook of a `ingress` or `clsact` qdisc
+    /// (`ingress`)
+    pub fn ingress(mut self) -> Self {
+        self.message.header.parent = (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_INGRESS;
+        self
+    }
+
+    /// Add the chain to the egress hook of a `clsact` qdisc (`egress`)
+    pub fn egress(mut self) -> Self {
+        self.message.header.parent = (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_EGRESS;
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut TcMessage {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let handle = self.handle.clone();
+        tc_request(handle, self.into_netlink_message()).await
+    }
+
+    /// Build the netlink message sent by the request
+    pub(crate) fn into_netlink_message(self) -> NetlinkMessage<RtnlMessage> {
+        tc_message(RtnlMessage::NewTrafficChain(self.message), TC_ADD_FLAGS)
+    }
+}
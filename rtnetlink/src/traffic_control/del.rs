@@ -0,0 +1,287 @@
+// SPDX-License-Identifier: MIT
+
+use super::add::{
+    set_chain,
+    set_filter_priority,
+    set_filter_protocol,
+    set_kind,
+    tc_handle,
+    tc_message,
+    tc_request,
+};
+use crate::{
+    packet::{
+        NetlinkMessage,
+        RtnlMessage,
+        TcMessage,
+        TC_H_CLSACT,
+        TC_H_INGRESS,
+        TC_H_MAJ_MASK,
+        TC_H_MIN_EGRESS,
+        TC_H_MIN_INGRESS,
+        TC_H_ROOT,
+    },
+    Error,
+    Handle,
+};
+
+/// A request to delete a queueing discipline (equivalent to
+/// `tc qdisc del dev <interface_name> ...`)
+pub struct QDiscDelRequest {
+    handle: Handle,
+    message: TcMessage,
+}
+
+impl QDiscDelRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32) -> Self {
+        let mut message = TcMessage::default();
+        message.header.index = ifindex;
+        message.header.parent = TC_H_ROOT;
+        QDiscDelRequest { handle, message }
+    }
+
+    /// Select the qdisc by its handle (`handle major:minor`)
+    pub fn handle(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.handle = tc_handle(major, minor);
+        self
+    }
+
+    /// Delete the root qdisc of the interface (`root`). This is the default.
+    pub fn root(mut self) -> Self {
+        self.message.header.parent = TC_H_ROOT;
+        self
+    }
+
+    /// Delete the qdisc attached to the given parent class
+    /// (`parent major:minor`)
+    pub fn parent(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.parent = tc_handle(major, minor);
+        self
+    }
+
+    /// Delete the `ingress` qdisc of the interface (`ingress`)
+    pub fn ingress(mut self) -> Self {
+        self.message.header.parent = TC_H_INGRESS;
+        self.message.header.handle = TC_H_MAJ_MASK;
+        self
+    }
+
+    /// Delete the `clsact` qdisc of the interface (`clsact`)
+    pub fn clsact(mut self) -> Self {
+        self.message.header.parent = TC_H_CLSACT;
+        self.message.header.handle = TC_H_MAJ_MASK;
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut TcMessage {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let handle = self.handle.clone();
+        tc_request(handle, self.into_netlink_message()).await
+    }
+
+    /// Build the netlink message sent by the request
+    pub(crate) fn into_netlink_message(self) -> NetlinkMessage<RtnlMessage> {
+        tc_message(RtnlMessage::DelQueueDiscipline(self.message), 0)
+    }
+}
+
+/// A request to delete a traffic class (equivalent to
+/// `tc class del dev <interface_name> classid <classid>`)
+pub struct TrafficClassDelRequest {
+    handle: Handle,
+    message: TcMessage,
+}
+
+impl TrafficClassDelRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32) -> Self {
+        let mut message = TcMessage::default();
+        message.header.index = ifindex;
+        TrafficClassDelRequest { handle, message }
+    }
+
+    /// Select the class by its identifier (`classid major:minor`)
+    pub fn classid(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.handle = tc_handle(major, minor);
+        self
+    }
+
+    /// Select the class by its parent (`parent major:minor`)
+    pub fn parent(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.parent = tc_handle(major, minor);
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut TcMessage {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let handle = self.handle.clone();
+        tc_request(handle, self.into_netlink_message()).await
+    }
+
+    /// Build the netlink message sent by the request
+    pub(crate) fn into_netlink_message(self) -> NetlinkMessage<RtnlMessage> {
+        tc_message(RtnlMessage::DelTrafficClass(self.message), 0)
+    }
+}
+
+/// A request to delete filters (equivalent to
+/// `tc filter del dev <interface_name> ...`). Without a priority, all the
+/// filters attached to the parent are deleted.
+pub struct TrafficFilterDelRequest {
+    handle: Handle,
+    message: TcMessage,
+}
+
+impl TrafficFilterDelRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32) -> Self {
+        let mut message = TcMessage::default();
+        message.header.index = ifindex;
+        message.header.parent = TC_H_ROOT;
+        TrafficFilterDelRequest { handle, message }
+    }
+
+    /// Select the filter by its handle (`handle <handle>`). This requires
+    /// the priority, protocol and kind of the filter to be set as well.
+    pub fn handle(mut self, handle: u32) -> Self {
+        self.message.header.handle = handle;
+        self
+    }
+
+    /// Delete filters attached to the root qdisc of the interface (`root`).
+    /// This is the default.
+    pub fn root(mut self) -> Self {
+        self.message.header.parent = TC_H_ROOT;
+        self
+    }
+
+    /// Delete filters attached to the given qdisc or class
+    /// (`parent major:minor`)
+    pub fn parent(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.parent = tc_handle(major, minor);
+        self
+    }
+
+    /// Delete filters attached to the ingress hook of a `ingress` or
+    /// `clsact` qdisc (`ingress`)
+    pub fn ingress(mut self) -> Self {
+        self.message.header.parent = (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_INGRESS;
+        self
+    }
+
+    /// Delete filters attached to the egress hook of a `clsact` qdisc
+    /// (`egress`)
+    pub fn egress(mut self) -> Self {
+        self.message.header.parent = (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_EGRESS;
+        self
+    }
+
+    /// Select the filters by priority (`priority <priority>`)
+    pub fn priority(mut self, priority: u16) -> Self {
+        set_filter_priority(&mut self.message, priority);
+        self
+    }
+
+    /// Select the filters by protocol (`protocol <protocol>`), as one of the
+    /// `ETH_P_*` constants
+    pub fn protocol(mut self, protocol: u16) -> Self {
+        set_filter_protocol(&mut self.message, protocol);
+        self
+    }
+
+    /// Select the filters of the given chain (`chain <chain>`)
+    pub fn chain(mut self, chain: u32) -> Self {
+        set_chain(&mut self.message, chain);
+        self
+    }
+
+    /// Select the filters by kind, for instance `"u32"` or `"matchall"`
+    pub fn kind(mut self, kind: &str) -> Self {
+        set_kind(&mut self.message, kind);
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut TcMessage {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let handle = self.handle.clone();
+        tc_request(handle, self.into_netlink_message()).await
+    }
+
+    /// Build the netlink message sent by the request
+    pub(crate) fn into_netlink_message(self) -> NetlinkMessage<RtnlMessage> {
+        tc_message(RtnlMessage::DelTrafficFilter(self.message), 0)
+    }
+}
+
+/// A request to delete a filter chain, along with all the filters it
+/// contains (equivalent to `tc chain del dev <interface_name> ... chain
+/// <chain>`)
+pub struct TrafficChainDelRequest {
+    handle: Handle,
+    message: TcMessage,
+}
+
+impl TrafficChainDelRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32, chain: u32) -> Self {
+        let mut message = TcMessage::default();
+        message.header.index = ifindex;
+        message.header.parent = TC_H_ROOT;
+        set_chain(&mut message, chain);
+        TrafficChainDelRequest { handle, message }
+    }
+
+    /// Delete the chain of the root qdisc of the interface (`root`). This is
+    /// the default.
+    pub fn root(mut self) -> Self {
+        self.message.header.parent = TC_H_ROOT;
+        self
+    }
+
+    /// Delete the chain of the given qdisc (`parent major:minor`)
+    pub fn parent(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.parent = tc_handle(major, minor);
+        self
+    }
+
+    /// Delete the chain of the ingress hook of a `ingress` or `clsact` qdisc
+    /// (`ingress`)
+    pub fn ingress(mut self) -> Self {
+        self.message.header.parent = (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_INGRESS;
+        self
+    }
+
+    /// Delete the chain of the egress hook of a `clsact` qdisc (`egress`)
+    pub fn egress(mut self) -> Self {
+        self.message.header.parent = (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_EGRESS;
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut TcMessage {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let handle = self.handle.clone();
+        tc_request(handle, self.into_netlink_message()).await
+    }
+
+    /// Build the netlink message sent by the request
+    pub(crate) fn into_netlink_message(self) -> NetlinkMessage<RtnlMessage> {
+        tc_message(RtnlMessage::DelTrafficChain(self.message), 0)
+    }
+}
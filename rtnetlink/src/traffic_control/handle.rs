@@ -1,10 +1,21 @@
 // SPDX-License-Identifier: MIT
 
 use super::{
+    QDiscDelRequest,
     QDiscGetRequest,
+    QDiscNewRequest,
+    TrafficChainDelRequest,
     TrafficChainGetRequest,
+    TrafficChainNewRequest,
+    TrafficClassDelRequest,
     TrafficClassGetRequest,
+    TrafficClassNewRequest,
+    TrafficFilterDelRequest,
     TrafficFilterGetRequest,
+    TrafficFilterNewRequest,
+    TC_ADD_FLAGS,
+    TC_CHANGE_FLAGS,
+    TC_REPLACE_FLAGS,
 };
 use crate::Handle;
 
@@ -19,6 +30,30 @@ impl QDiscHandle {
     pub fn get(&mut self) -> QDiscGetRequest {
         QDiscGetRequest::new(self.0.clone())
     }
+
+    /// Add a qdisc to an interface (equivalent to
+    /// `tc qdisc add dev <interface_name> ...`)
+    pub fn add(&mut self, ifindex: i32) -> QDiscNewRequest {
+        QDiscNewRequest::new(self.0.clone(), ifindex, TC_ADD_FLAGS)
+    }
+
+    /// Change an existing qdisc of an interface (equivalent to
+    /// `tc qdisc change dev <interface_name> ...`)
+    pub fn change(&mut self, ifindex: i32) -> QDiscNewRequest {
+        QDiscNewRequest::new(self.0.clone(), ifindex, TC_CHANGE_FLAGS)
+    }
+
+    /// Add a qdisc to an interface, or replace the existing one (equivalent
+    /// to `tc qdisc replace dev <interface_name> ...`)
+    pub fn replace(&mut self, ifindex: i32) -> QDiscNewRequest {
+        QDiscNewRequest::new(self.0.clone(), ifindex, TC_REPLACE_FLAGS)
+    }
+
+    /// Delete a qdisc from an interface (equivalent to
+    /// `tc qdisc del dev <interface_name> ...`)
+    pub fn del(&mut self, ifindex: i32) -> QDiscDelRequest {
+        QDiscDelRequest::new(self.0.clone(), ifindex)
+    }
 }
 
 pub struct TrafficClassHandle {
@@ -36,6 +71,28 @@ impl TrafficClassHandle {
     pub fn get(&mut self) -> TrafficClassGetRequest {
         TrafficClassGetRequest::new(self.handle.clone(), self.ifindex)
     }
+
+    /// Add a class (equivalent to `tc class add dev <interface_name> ...`)
+    pub fn add(&mut self) -> TrafficClassNewRequest {
+        TrafficClassNewRequest::new(self.handle.clone(), self.ifindex, TC_ADD_FLAGS)
+    }
+
+    /// Change an existing class (equivalent to
+    /// `tc class change dev <interface_name> ...`)
+    pub fn change(&mut self) -> TrafficClassNewRequest {
+        TrafficClassNewRequest::new(self.handle.clone(), self.ifindex, TC_CHANGE_FLAGS)
+    }
+
+    /// Add a class, or replace the existing one (equivalent to
+    /// `tc class replace dev <interface_name> ...`)
+    pub fn replace(&mut self) -> TrafficClassNewRequest {
+        TrafficClassNewRequest::new(self.handle.clone(), self.ifindex, TC_REPLACE_FLAGS)
+    }
+
+    /// Delete a class (equivalent to `tc class del dev <interface_name> ...`)
+    pub fn del(&mut self) -> TrafficClassDelRequest {
+        TrafficClassDelRequest::new(self.handle.clone(), self.ifindex)
+    }
 }
 
 pub struct TrafficFilterHandle {
@@ -53,6 +110,28 @@ impl TrafficFilterHandle {
     pub fn get(&mut self) -> TrafficFilterGetRequest {
         TrafficFilterGetRequest::new(self.handle.clone(), self.ifindex)
     }
+
+    /// Add a filter (equivalent to `tc filter add dev <interface_name> ...`)
+    pub fn add(&mut self) -> TrafficFilterNewRequest {
+        TrafficFilterNewRequest::new(self.handle.clone(), self.ifindex, TC_ADD_FLAGS)
+    }
+
+    /// Change an existing filter (equivalent to
+    /// `tc filter change dev <interface_name> ...`)
+    pub fn change(&mut self) -> TrafficFilterNewRequest {
+        TrafficFilterNewRequest::new(self.handle.clone(), self.ifindex, TC_CHANGE_FLAGS)
+    }
+
+    /// Add a filter, or replace the existing one (equivalent to
+    /// `tc filter replace dev <interface_name> ...`)
+    pub fn replace(&mut self) -> TrafficFilterNewRequest {
+        TrafficFilterNewRequest::new(self.handle.clone(), self.ifindex, TC_REPLACE_FLAGS)
+    }
+
+    /// Delete a filter (equivalent to `tc filter del dev <interface_name> ...`)
+    pub fn del(&mut self) -> TrafficFilterDelRequest {
+        TrafficFilterDelRequest::new(self.handle.clone(), self.ifindex)
+    }
 }
 
 pub struct TrafficChainHandle {
@@ -70,4 +149,16 @@ impl TrafficChainHandle {
     pub fn get(&mut self) -> TrafficChainGetRequest {
         TrafficChainGetRequest::new(self.handle.clone(), self.ifindex)
     }
+
+    /// Add a chain (equivalent to
+    /// `tc chain add dev <interface_name> chain <chain>`)
+    pub fn add(&mut self, chain: u32) -> TrafficChainNewRequest {
+        TrafficChainNewRequest::new(self.handle.clone(), self.ifindex, chain)
+    }
+
+    /// Delete a chain and all its filters (equivalent to
+    /// `tc chain del dev <interface_name> chain <chain>`)
+    pub fn del(&mut self, chain: u32) -> TrafficChainDelRequest {
+        TrafficChainDelRequest::new(self.handle.clone(), self.ifindex, chain)
+    }
 }
@@ -6,5 +6,11 @@ pub use self::handle::*;
 mod get;
 pub use self::get::*;
 
+mod add;
+pub use self::add::*;
+
+mod del;
+pub use self::del::*;
+
 #[cfg(test)]
 mod test;
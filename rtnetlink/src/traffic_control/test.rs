@@ -10,9 +10,27 @@ use crate::{
     packet::{
         rtnl::tc::nlas::Nla::{Chain, HwOffload, Kind},
         ErrorMessage,
+        NetlinkMessage,
+        NetlinkPayload,
+        RtnlMessage,
+        TcHeader,
         TcMessage,
         AF_UNSPEC,
+        ETH_P_ALL,
+        ETH_P_IP,
+        NLM_F_ACK,
+        NLM_F_CREATE,
+        NLM_F_EXCL,
+        NLM_F_REPLACE,
+        NLM_F_REQUEST,
+        TC_H_CLSACT,
+        TC_H_INGRESS,
+        TC_H_MAJ_MASK,
+        TC_H_MIN_EGRESS,
+        TC_H_MIN_INGRESS,
+        TC_H_ROOT,
     },
+    test_handle,
     Error::NetlinkError,
 };
 
@@ -234,7 +252,7 @@ async fn _get_chains(ifindex: i32) -> Vec<TcMessage> {
             Ok(None) => {
                 break;
             }
-            Err(NetlinkError(ErrorMessage { code, header: _ })) => {
+            Err(NetlinkError(ErrorMessage { code, .. })) => {
                 assert_eq!(code, -95);
                 eprintln!(
                     "The chain in traffic control is not supported, \
@@ -285,3 +303,322 @@ fn test_get_traffic_classes_filters_and_chains() {
         assert_eq!(chains[0].nlas[0], Chain([0u8, 0, 0, 0].to_vec()));
     }
 }
+
+static TEST_WRITE_DUMMY_NIC: &str = "netlink-test-tc";
+
+async fn _add_and_remove_qdisc_and_filter(ifindex: i32) -> (Vec<TcMessage>, Vec<TcMessage>) {
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    handle
+        .qdisc()
+        .add(ifindex)
+        .clsact()
+        .execute()
+        .await
+        .unwrap();
+    handle
+        .traffic_filter(ifindex)
+        .add()
+        .ingress()
+        .priority(10)
+        .kind("matchall")
+        .execute()
+        .await
+        .unwrap();
+    let qdiscs: Vec<TcMessage> = handle
+        .qdisc()
+        .get()
+        .execute()
+        .try_filter(|qdisc| futures::future::ready(qdisc.header.index == ifindex))
+        .try_collect()
+        .await
+        .unwrap();
+    let filters: Vec<TcMessage> = handle
+        .traffic_filter(ifindex)
+        .get()
+        .execute()
+        .try_collect()
+        .await
+        .unwrap();
+
+    handle
+        .traffic_filter(ifindex)
+        .del()
+        .ingress()
+        .execute()
+        .await
+        .unwrap();
+    handle
+        .qdisc()
+        .del(ifindex)
+        .clsact()
+        .execute()
+        .await
+        .unwrap();
+    (qdiscs, filters)
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn test_add_and_del_qdisc_and_filter() {
+    let status = Command::new("ip")
+        .args(&["link", "add", TEST_WRITE_DUMMY_NIC, "type", "dummy"])
+        .status()
+        .expect("failed to run ip command");
+    assert!(status.success());
+    let output = Command::new("ip")
+        .args(&["-o", "link", "show", TEST_WRITE_DUMMY_NIC])
+        .output()
+        .expect("failed to run ip command");
+    let line = std::str::from_utf8(&output.stdout).unwrap();
+    let ifindex = line.split(": ").next().unwrap().parse::<i32>().unwrap();
+
+    let (qdiscs, filters) = Runtime::new()
+        .unwrap()
+        .block_on(_add_and_remove_qdisc_and_filter(ifindex));
+    let remaining = Runtime::new().unwrap().block_on(_get_filters(ifindex));
+    Command::new("ip")
+        .args(&["link", "del", TEST_WRITE_DUMMY_NIC])
+        .status()
+        .expect("failed to run ip command");
+
+    let clsact = qdiscs
+        .iter()
+        .find(|qdisc| qdisc.nlas.contains(&Kind("clsact".to_string())))
+        .expect("no clsact qdisc found");
+    assert_eq!(clsact.header.parent, TC_H_CLSACT);
+    assert_eq!(clsact.header.handle, TC_H_MAJ_MASK);
+    assert_eq!(filters.len(), 1);
+    assert_eq!(
+        filters[0].header.parent,
+        (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_INGRESS
+    );
+    assert_eq!(filters[0].header.info >> 16, 10);
+    assert_eq!(filters[0].header.info & 0xffff, ETH_P_ALL.to_be() as u32);
+    assert_eq!(filters[0].nlas[0], Kind("matchall".to_string()));
+    assert!(remaining.is_empty());
+}
+
+/// Return the flags and the payload of a request
+fn flags_and_payload(req: NetlinkMessage<RtnlMessage>) -> (u16, RtnlMessage) {
+    match req.payload {
+        NetlinkPayload::InnerMessage(message) => (req.header.flags, message),
+        payload => panic!("unexpected payload {:?}", payload),
+    }
+}
+
+fn header(index: i32, handle: u32, parent: u32, info: u32) -> TcHeader {
+    TcHeader {
+        family: 0,
+        index,
+        handle,
+        parent,
+        info,
+    }
+}
+
+const ADD_FLAGS: u16 = NLM_F_REQUEST | NLM_F_ACK | NLM_F_CREATE | NLM_F_EXCL;
+const CHANGE_FLAGS: u16 = NLM_F_REQUEST | NLM_F_ACK;
+const REPLACE_FLAGS: u16 = NLM_F_REQUEST | NLM_F_ACK | NLM_F_CREATE | NLM_F_REPLACE;
+const DEL_FLAGS: u16 = NLM_F_REQUEST | NLM_F_ACK;
+
+#[test]
+fn test_qdisc_messages() {
+    let handle = test_handle();
+
+    let req = handle.qdisc().add(3).ingress().into_netlink_message();
+    assert_eq!(
+        flags_and_payload(req),
+        (
+            ADD_FLAGS,
+            RtnlMessage::NewQueueDiscipline(TcMessage {
+                header: header(3, TC_H_MAJ_MASK, TC_H_INGRESS, 0),
+                nlas: vec![Kind("ingress".to_string())],
+            })
+        )
+    );
+
+    let req = handle
+        .qdisc()
+        .change(3)
+        .handle(1, 0)
+        .kind("htb")
+        .into_netlink_message();
+    assert_eq!(
+        flags_and_payload(req),
+        (
+            CHANGE_FLAGS,
+            RtnlMessage::NewQueueDiscipline(TcMessage {
+                header: header(3, 0x0001_0000, TC_H_ROOT, 0),
+                nlas: vec![Kind("htb".to_string())],
+            })
+        )
+    );
+
+    let req = handle
+        .qdisc()
+        .replace(3)
+        .parent(1, 10)
+        .handle(10, 0)
+        .kind("fq_codel")
+        .into_netlink_message();
+    assert_eq!(
+        flags_and_payload(req),
+        (
+            REPLACE_FLAGS,
+            RtnlMessage::NewQueueDiscipline(TcMessage {
+                header: header(3, 0x000a_0000, 0x0001_000a, 0),
+                nlas: vec![Kind("fq_codel".to_string())],
+            })
+        )
+    );
+
+    let req = handle.qdisc().del(3).clsact().into_netlink_message();
+    assert_eq!(
+        flags_and_payload(req),
+        (
+            DEL_FLAGS,
+            RtnlMessage::DelQueueDiscipline(TcMessage {
+                header: header(3, TC_H_MAJ_MASK, TC_H_CLSACT, 0),
+                nlas: vec![],
+            })
+        )
+    );
+}
+
+#[test]
+fn test_class_messages() {
+    let handle = test_handle();
+
+    let req = handle
+        .traffic_class(3)
+        .add()
+        .parent(1, 0)
+        .classid(1, 10)
+        .kind("htb")
+        .into_netlink_message();
+    assert_eq!(
+        flags_and_payload(req),
+        (
+            ADD_FLAGS,
+            RtnlMessage::NewTrafficClass(TcMessage {
+                header: header(3, 0x0001_000a, 0x0001_0000, 0),
+                nlas: vec![Kind("htb".to_string())],
+            })
+        )
+    );
+
+    let req = handle
+        .traffic_class(3)
+        .del()
+        .parent(1, 0)
+        .classid(1, 10)
+        .into_netlink_message();
+    assert_eq!(
+        flags_and_payload(req),
+        (
+            DEL_FLAGS,
+            RtnlMessage::DelTrafficClass(TcMessage {
+                header: header(3, 0x0001_000a, 0x0001_0000, 0),
+                nlas: vec![],
+            })
+        )
+    );
+}
+
+#[test]
+fn test_filter_messages() {
+    let handle = test_handle();
+    let info = (10 << 16) | ETH_P_IP.to_be() as u32;
+
+    let req = handle
+        .traffic_filter(3)
+        .add()
+        .ingress()
+        .priority(10)
+        .protocol(ETH_P_IP)
+        .handle(1)
+        .kind("matchall")
+        .into_netlink_message();
+    assert_eq!(
+        flags_and_payload(req),
+        (
+            ADD_FLAGS,
+            RtnlMessage::NewTrafficFilter(TcMessage {
+                header: header(3, 1, (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_INGRESS, info),
+                nlas: vec![Kind("matchall".to_string())],
+            })
+        )
+    );
+
+    // The protocol defaults to ETH_P_ALL
+    let req = handle
+        .traffic_filter(3)
+        .replace()
+        .parent(1, 0)
+        .priority(10)
+        .kind("matchall")
+        .into_netlink_message();
+    assert_eq!(
+        flags_and_payload(req),
+        (
+            REPLACE_FLAGS,
+            RtnlMessage::NewTrafficFilter(TcMessage {
+                header: header(3, 0, 0x0001_0000, (10 << 16) | ETH_P_ALL.to_be() as u32),
+                nlas: vec![Kind("matchall".to_string())],
+            })
+        )
+    );
+
+    let req = handle
+        .traffic_filter(3)
+        .del()
+        .egress()
+        .priority(10)
+        .protocol(ETH_P_IP)
+        .into_netlink_message();
+    assert_eq!(
+        flags_and_payload(req),
+        (
+            DEL_FLAGS,
+            RtnlMessage::DelTrafficFilter(TcMessage {
+                header: header(3, 0, (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_EGRESS, info),
+                nlas: vec![],
+            })
+        )
+    );
+}
+
+#[test]
+fn test_chain_messages() {
+    let handle = test_handle();
+
+    let req = handle
+        .traffic_chain(3)
+        .add(5)
+        .ingress()
+        .into_netlink_message();
+    assert_eq!(
+        flags_and_payload(req),
+        (
+            ADD_FLAGS,
+            RtnlMessage::NewTrafficChain(TcMessage {
+                header: header(3, 0, (TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_INGRESS, 0),
+                nlas: vec![Chain(5u32.to_ne_bytes().to_vec())],
+            })
+        )
+    );
+
+    let req = handle.traffic_chain(3).del(5).into_netlink_message();
+    assert_eq!(
+        flags_and_payload(req),
+        (
+            DEL_FLAGS,
+            RtnlMessage::DelTrafficChain(TcMessage {
+                header: header(3, 0, TC_H_ROOT, 0),
+                nlas: vec![Chain(5u32.to_ne_bytes().to_vec())],
+            })
+        )
+    );
+}